@@ -1,5 +1,5 @@
 import { jest } from "@jest/globals";
-import { Address, Keypair, nativeToScVal } from "@stellar/stellar-sdk";
+import { Address, Keypair, nativeToScVal, xdr } from "@stellar/stellar-sdk";
 
 const mockQuery =
   jest.fn<
@@ -111,7 +111,10 @@ function makeRawEvent(params: {
       return {
         ...base,
         topic: [scSymbol("LoanRequested"), scAddress(borrower)],
-        value: scI128(params.amount ?? 500),
+        value: xdr.ScVal.scvVec([
+          scAddress(makeAddress()),
+          scI128(params.amount ?? 500),
+        ]),
       };
     case "LoanApproved":
       return {
@@ -194,6 +194,11 @@ describe("POST /api/loans/request", () => {
     expect(response.body.success).toBe(true);
     expect(response.body.unsignedTxXdr).toBe("AAAA...base64xdr");
     expect(response.body.networkPassphrase).toBeDefined();
+    expect(mockBuildRequestLoanTx).toHaveBeenCalledWith(
+      TEST_BORROWER,
+      1000,
+      30 * 17280,
+    );
   });
 
   it("should reject missing amount", async () => {
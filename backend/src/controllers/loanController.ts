@@ -571,9 +571,10 @@ export const getLoanAmortizationSchedule = asyncHandler(
  * POST /api/loans/request
  */
 export const requestLoan = asyncHandler(async (req: Request, res: Response) => {
-  const { amount, borrowerPublicKey } = req.body as {
+  const { amount, borrowerPublicKey, termDays = 30 } = req.body as {
     amount: number;
     borrowerPublicKey: string;
+    termDays?: number;
   };
 
   if (borrowerPublicKey !== req.user?.publicKey) {
@@ -604,6 +605,7 @@ export const requestLoan = asyncHandler(async (req: Request, res: Response) => {
   const result = await sorobanService.buildRequestLoanTx(
     borrowerPublicKey,
     amount,
+    termDays * DEFAULT_TERM_LEDGERS,
   );
 
   logger.info("Loan request transaction built", {
@@ -221,7 +221,8 @@ router.get(
  *   post:
  *     summary: Build an unsigned loan request transaction
  *     description: >
- *       Builds an unsigned Soroban `request_loan(borrower, amount)` transaction XDR.
+ *       Builds an unsigned Soroban `request_loan(borrower, asset, amount, term_ledgers)`
+ *       transaction XDR for the pool token.
  *       The frontend signs it with the user's wallet and submits via POST /api/loans/submit.
  *     tags: [Loans]
  *     security:
@@ -243,6 +244,10 @@ router.get(
  *               borrowerPublicKey:
  *                 type: string
  *                 description: Borrower's Stellar public key (must match JWT)
+ *               termDays:
+ *                 type: number
+ *                 enum: [30, 60, 90]
+ *                 description: Loan term in days (defaults to 30)
  *     responses:
  *       200:
  *         description: Unsigned transaction XDR returned
@@ -8,6 +8,7 @@ const base64Regex = /^(?:[A-Za-z0-9+/]{4})*(?:[A-Za-z0-9+/]{2}==|[A-Za-z0-9+/]{3
 export const requestLoanSchema = z.object({
   amount: positiveAmountSchema,
   borrowerPublicKey: stellarAddressSchema,
+  termDays: z.union([z.literal(30), z.literal(60), z.literal(90)]).optional(),
 });
 
 export const repayLoanSchema = z.object({
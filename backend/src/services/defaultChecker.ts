@@ -1,4 +1,5 @@
 import {
+  Address,
   BASE_FEE,
   Keypair,
   Operation,
@@ -262,6 +263,10 @@ export class DefaultChecker {
   ): Promise<DefaultCheckBatchResult> {
     const account = await server.getAccount(signer.publicKey());
 
+    // The signer is the keeper and receives any default bounty.
+    const callerScVal = nativeToScVal(Address.fromString(signer.publicKey()), {
+      type: "address",
+    });
     const loanIdsScVal = nativeToScVal(loanIds, { type: "u32" });
 
     const tx = new TransactionBuilder(account, {
@@ -272,7 +277,7 @@ export class DefaultChecker {
         Operation.invokeContractFunction({
           contract: this.contractId,
           function: "check_defaults",
-          args: [loanIdsScVal],
+          args: [callerScVal, loanIdsScVal],
         }),
       )
       .setTimeout(30)
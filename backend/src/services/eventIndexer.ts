@@ -533,7 +533,7 @@ export class EventIndexer {
     if (type === "LoanRequested") {
       if (!event.topic[1]) return null;
       borrower = this.decodeAddress(event.topic[1]);
-      amount = this.decodeAmount(event.value);
+      amount = this.decodeRequestedAmount(event.value);
     } else if (type === "LoanApproved") {
       if (!event.topic[1]) return null;
       loanId = this.decodeLoanId(event.topic[1]);
@@ -665,6 +665,21 @@ export class EventIndexer {
     return native.toString();
   }
 
+  /**
+   * `LoanRequested` carries `(asset, amount)`; contracts deployed before
+   * multi-asset loans emitted the bare amount.
+   */
+  private decodeRequestedAmount(value: xdr.ScVal): string {
+    if (value.switch() === xdr.ScValType.scvVec()) {
+      const [, amount] = value.vec() ?? [];
+      if (!amount) {
+        throw new Error("LoanRequested value is missing the amount");
+      }
+      return this.decodeAmount(amount);
+    }
+    return this.decodeAmount(value);
+  }
+
   private decodeLoanId(value: xdr.ScVal): number | undefined {
     try {
       return Number(scValToNative(value));
@@ -129,15 +129,18 @@ class SorobanService {
   }
 
   /**
-   * Builds an unsigned Soroban `request_loan(borrower, amount)` transaction.
-   * Returns base64 XDR for the frontend to sign with the user's wallet.
+   * Builds an unsigned Soroban `request_loan(borrower, asset, amount, term_ledgers)`
+   * transaction, borrowing the pool token. Returns base64 XDR for the frontend
+   * to sign with the user's wallet.
    */
   async buildRequestLoanTx(
     borrowerPublicKey: string,
     amount: number,
+    termLedgers: number,
   ): Promise<{ unsignedTxXdr: string; networkPassphrase: string }> {
     const server = this.getRpcServer();
     const contractId = this.getLoanManagerContractId();
+    const assetAddress = this.getPoolTokenAddress();
     const passphrase = this.getNetworkPassphrase();
 
     const account = await server.getAccount(borrowerPublicKey);
@@ -145,7 +148,11 @@ class SorobanService {
     const borrowerScVal = nativeToScVal(Address.fromString(borrowerPublicKey), {
       type: "address",
     });
+    const assetScVal = nativeToScVal(Address.fromString(assetAddress), {
+      type: "address",
+    });
     const amountScVal = nativeToScVal(BigInt(amount), { type: "i128" });
+    const termScVal = nativeToScVal(termLedgers, { type: "u32" });
 
     const tx = new TransactionBuilder(account, {
       fee: BASE_FEE,
@@ -155,7 +162,7 @@ class SorobanService {
         Operation.invokeContractFunction({
           contract: contractId,
           function: "request_loan",
-          args: [borrowerScVal, amountScVal],
+          args: [borrowerScVal, assetScVal, amountScVal, termScVal],
         }),
       )
       .setTimeout(30)
@@ -166,7 +173,9 @@ class SorobanService {
 
     logger.info("Built request_loan transaction", {
       borrower: borrowerPublicKey,
+      asset: assetAddress,
       amount,
+      termLedgers,
     });
 
     return { unsignedTxXdr, networkPassphrase: passphrase };
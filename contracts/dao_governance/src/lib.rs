@@ -0,0 +1,757 @@
+#![no_std]
+
+//! Token-weighted governance for RemitLend.
+//!
+//! Holders escrow the governance token here to get voting power, which they
+//! can delegate. Every change in voting power is checkpointed, so a proposal
+//! counts votes as they stood when it was created. Passed proposals wait out
+//! a timelock and then run the same target calls the multisig makes
+//! (`set_admin`, `schedule_upgrade`, `set_guardian`, ...) plus arbitrary
+//! parameter setters.
+//!
+//! A target's admin seat can be held by this contract or by
+//! `GovernanceContract`; a `SetAdmin` action hands it between them, which
+//! keeps the multisig available as a fallback.
+
+#[cfg(test)]
+mod test;
+
+use soroban_sdk::token::Client as TokenClient;
+use soroban_sdk::{
+    contract, contracterror, contractimpl, contracttype, symbol_short, vec, Address, BytesN, Env,
+    IntoVal, String, Symbol, Val, Vec,
+};
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum DaoError {
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+    InvalidAmount = 3,
+    InsufficientDeposit = 4,
+    InvalidConfig = 5,
+    BelowProposalThreshold = 6,
+    InvalidActions = 7,
+    ProposalNotFound = 8,
+    VotingClosed = 9,
+    AlreadyVoted = 10,
+    NoVotingPower = 11,
+    ProposalNotSucceeded = 12,
+    ProposalNotQueued = 13,
+    TimelockActive = 14,
+    NotProposer = 15,
+    ProposalFinalized = 16,
+    /// `Invoke` may not call the governance token or this contract, which
+    /// would let a proposal move escrowed deposits.
+    ForbiddenTarget = 17,
+}
+
+/// Governance parameters. Changed only by an `UpdateConfig` action.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DaoConfig {
+    /// Seconds a proposal stays open for voting.
+    pub voting_period: u64,
+    /// Seconds between queueing a passed proposal and executing it.
+    pub timelock: u64,
+    /// For + abstain votes needed, in bps of voting power at the snapshot.
+    pub quorum_bps: u32,
+    /// For votes needed, in bps of for + against.
+    pub approval_bps: u32,
+    /// Voting power needed to open a proposal.
+    pub proposal_threshold: i128,
+}
+
+/// Something a passed proposal does. `Address` is the target contract.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DaoAction {
+    /// `target.set_admin(new_admin)`
+    SetAdmin(Address, Address),
+    /// `target.schedule_upgrade(wasm_hash, eta)`
+    ScheduleUpgrade(Address, BytesN<32>, u32),
+    /// `target.cancel_upgrade()`
+    CancelUpgrade(Address),
+    /// `target.set_guardian(guardian)`
+    SetGuardian(Address, Option<Address>),
+    /// `target.ratify_pause()`
+    RatifyPause(Address),
+    /// Any other admin entrypoint, e.g. a parameter setter. Never the
+    /// governance token or this contract.
+    Invoke(Address, Symbol, Vec<Val>),
+    /// Replace this contract's own config.
+    UpdateConfig(DaoConfig),
+}
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum VoteType {
+    Against,
+    For,
+    Abstain,
+}
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ProposalState {
+    Active,
+    Defeated,
+    Succeeded,
+    Queued,
+    Expired,
+    Executed,
+    Cancelled,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Proposal {
+    pub id: u32,
+    pub proposer: Address,
+    pub actions: Vec<DaoAction>,
+    pub description: String,
+    /// Votes are weighed as they stood just before this timestamp.
+    pub snapshot: u64,
+    pub vote_end: u64,
+    /// Total voting power at the snapshot.
+    pub total_votes: i128,
+    /// Thresholds in force when the proposal was made; later config
+    /// changes do not move them.
+    pub quorum_bps: u32,
+    pub approval_bps: u32,
+    pub for_votes: i128,
+    pub against_votes: i128,
+    pub abstain_votes: i128,
+    /// Earliest execution time, set by `queue`.
+    pub eta: Option<u64>,
+    pub executed: bool,
+    pub cancelled: bool,
+}
+
+/// Voting power from `timestamp` onwards.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Checkpoint {
+    pub timestamp: u64,
+    pub votes: i128,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    Token,
+    Config,
+    ProposalCount,
+    Version,
+    /// account → tokens escrowed
+    Deposit(Address),
+    /// account → delegatee; absent means the account votes itself
+    Delegate(Address),
+    /// (delegatee, index) → entry in the delegatee's voting power history
+    Checkpoint(Address, u32),
+    /// delegatee → number of checkpoints
+    CheckpointCount(Address),
+    /// index → entry in the total escrowed voting power history
+    TotalCheckpoint(u32),
+    TotalCheckpointCount,
+    Proposal(u32),
+    /// (proposal id, voter) → vote cast
+    Receipt(u32, Address),
+}
+
+#[contract]
+pub struct DaoGovernance;
+
+#[contractimpl]
+impl DaoGovernance {
+    const INSTANCE_TTL_THRESHOLD: u32 = 17280;
+    const INSTANCE_TTL_BUMP: u32 = 518400;
+    const PERSISTENT_TTL_THRESHOLD: u32 = 17280;
+    const PERSISTENT_TTL_BUMP: u32 = 518400;
+    const CURRENT_VERSION: u32 = 1;
+    /// Same floor as the multisig's admin-transfer timelock.
+    pub const MIN_TIMELOCK_SECONDS: u64 = 86_400;
+    pub const MIN_VOTING_PERIOD_SECONDS: u64 = 86_400;
+    /// How long a queued proposal stays executable after its eta.
+    pub const EXECUTION_GRACE_SECONDS: u64 = 604_800;
+    /// How long a passed proposal may wait to be queued after voting ends.
+    pub const QUEUE_GRACE_SECONDS: u64 = 604_800;
+    pub const MAX_ACTIONS: u32 = 10;
+
+    fn bump_instance_ttl(env: &Env) {
+        env.storage()
+            .instance()
+            .extend_ttl(Self::INSTANCE_TTL_THRESHOLD, Self::INSTANCE_TTL_BUMP);
+    }
+
+    fn bump_persistent_ttl(env: &Env, key: &DataKey) {
+        env.storage().persistent().extend_ttl(
+            key,
+            Self::PERSISTENT_TTL_THRESHOLD,
+            Self::PERSISTENT_TTL_BUMP,
+        );
+    }
+
+    fn token(env: &Env) -> Result<Address, DaoError> {
+        Self::bump_instance_ttl(env);
+        env.storage()
+            .instance()
+            .get(&DataKey::Token)
+            .ok_or(DaoError::NotInitialized)
+    }
+
+    fn config(env: &Env) -> Result<DaoConfig, DaoError> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Config)
+            .ok_or(DaoError::NotInitialized)
+    }
+
+    fn validate_config(config: &DaoConfig) -> Result<(), DaoError> {
+        if config.voting_period < Self::MIN_VOTING_PERIOD_SECONDS
+            || config.timelock < Self::MIN_TIMELOCK_SECONDS
+            || config.quorum_bps == 0
+            || config.quorum_bps > 10_000
+            || config.approval_bps < 5_000
+            || config.approval_bps > 10_000
+            || config.proposal_threshold < 0
+        {
+            return Err(DaoError::InvalidConfig);
+        }
+        Ok(())
+    }
+
+    fn read_deposit(env: &Env, account: &Address) -> i128 {
+        let key = DataKey::Deposit(account.clone());
+        let deposit = env.storage().persistent().get(&key).unwrap_or(0);
+        if deposit > 0 {
+            Self::bump_persistent_ttl(env, &key);
+        }
+        deposit
+    }
+
+    fn write_deposit(env: &Env, account: &Address, amount: i128) {
+        let key = DataKey::Deposit(account.clone());
+        if amount == 0 {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, &amount);
+            Self::bump_persistent_ttl(env, &key);
+        }
+    }
+
+    fn read_delegate(env: &Env, account: &Address) -> Address {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Delegate(account.clone()))
+            .unwrap_or(account.clone())
+    }
+
+    /// Count and entry keys of a voting power history: a delegatee's, or
+    /// the total when `account` is `None`.
+    fn checkpoint_count_key(account: &Option<Address>) -> DataKey {
+        match account {
+            Some(account) => DataKey::CheckpointCount(account.clone()),
+            None => DataKey::TotalCheckpointCount,
+        }
+    }
+
+    fn checkpoint_key(account: &Option<Address>, index: u32) -> DataKey {
+        match account {
+            Some(account) => DataKey::Checkpoint(account.clone(), index),
+            None => DataKey::TotalCheckpoint(index),
+        }
+    }
+
+    fn checkpoint_count(env: &Env, account: &Option<Address>) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&Self::checkpoint_count_key(account))
+            .unwrap_or(0)
+    }
+
+    fn read_checkpoint(env: &Env, account: &Option<Address>, index: u32) -> Checkpoint {
+        let key = Self::checkpoint_key(account, index);
+        let checkpoint = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .expect("checkpoint missing");
+        Self::bump_persistent_ttl(env, &key);
+        checkpoint
+    }
+
+    fn latest_votes(env: &Env, account: &Option<Address>) -> i128 {
+        match Self::checkpoint_count(env, account) {
+            0 => 0,
+            count => Self::read_checkpoint(env, account, count - 1).votes,
+        }
+    }
+
+    /// Votes recorded strictly before `timestamp`, found by binary search
+    /// over the indexed checkpoints.
+    fn votes_before(env: &Env, account: &Option<Address>, timestamp: u64) -> i128 {
+        let (mut low, mut high) = (0u32, Self::checkpoint_count(env, account));
+        while low < high {
+            let mid = (low + high) / 2;
+            if Self::read_checkpoint(env, account, mid).timestamp < timestamp {
+                low = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+        if low == 0 {
+            0
+        } else {
+            Self::read_checkpoint(env, account, low - 1).votes
+        }
+    }
+
+    /// Add `delta` to the history of `account` (or the total), folding
+    /// changes made at the same timestamp into one checkpoint.
+    fn push_checkpoint(env: &Env, account: &Option<Address>, delta: i128) {
+        let count = Self::checkpoint_count(env, account);
+        let now = env.ledger().timestamp();
+        let last = match count {
+            0 => None,
+            count => Some(Self::read_checkpoint(env, account, count - 1)),
+        };
+        let votes = last
+            .as_ref()
+            .map_or(0, |c| c.votes)
+            .checked_add(delta)
+            .expect("vote overflow");
+        let index = match last {
+            Some(last) if last.timestamp == now => count - 1,
+            _ => {
+                let count_key = Self::checkpoint_count_key(account);
+                env.storage().persistent().set(&count_key, &(count + 1));
+                Self::bump_persistent_ttl(env, &count_key);
+                count
+            }
+        };
+        let key = Self::checkpoint_key(account, index);
+        env.storage().persistent().set(
+            &key,
+            &Checkpoint {
+                timestamp: now,
+                votes,
+            },
+        );
+        Self::bump_persistent_ttl(env, &key);
+    }
+
+    fn move_votes(env: &Env, delegatee: &Address, delta: i128) {
+        Self::push_checkpoint(env, &Some(delegatee.clone()), delta);
+    }
+
+    fn validate_actions(env: &Env, actions: &Vec<DaoAction>) -> Result<(), DaoError> {
+        if actions.is_empty() || actions.len() > Self::MAX_ACTIONS {
+            return Err(DaoError::InvalidActions);
+        }
+        let token = Self::token(env)?;
+        for action in actions.iter() {
+            if let DaoAction::Invoke(target, _, _) = action {
+                if target == token || target == env.current_contract_address() {
+                    return Err(DaoError::ForbiddenTarget);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn read_proposal(env: &Env, proposal_id: u32) -> Result<Proposal, DaoError> {
+        let key = DataKey::Proposal(proposal_id);
+        let proposal = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .ok_or(DaoError::ProposalNotFound)?;
+        Self::bump_persistent_ttl(env, &key);
+        Ok(proposal)
+    }
+
+    fn write_proposal(env: &Env, proposal: &Proposal) {
+        let key = DataKey::Proposal(proposal.id);
+        env.storage().persistent().set(&key, proposal);
+        Self::bump_persistent_ttl(env, &key);
+    }
+
+    fn succeeded(proposal: &Proposal) -> bool {
+        let quorum = proposal
+            .total_votes
+            .checked_mul(proposal.quorum_bps as i128)
+            .expect("quorum overflow")
+            / 10_000;
+        let turnout = proposal.for_votes + proposal.abstain_votes;
+        let decisive = proposal.for_votes + proposal.against_votes;
+        let approved = proposal.for_votes > 0
+            && proposal.for_votes * 10_000 >= decisive * proposal.approval_bps as i128;
+        turnout >= quorum && approved
+    }
+
+    fn state_of(env: &Env, proposal: &Proposal) -> Result<ProposalState, DaoError> {
+        if proposal.cancelled {
+            return Ok(ProposalState::Cancelled);
+        }
+        if proposal.executed {
+            return Ok(ProposalState::Executed);
+        }
+        let now = env.ledger().timestamp();
+        if now < proposal.vote_end {
+            return Ok(ProposalState::Active);
+        }
+        if !Self::succeeded(proposal) {
+            return Ok(ProposalState::Defeated);
+        }
+        match proposal.eta {
+            None if now >= proposal.vote_end.saturating_add(Self::QUEUE_GRACE_SECONDS) => {
+                Ok(ProposalState::Expired)
+            }
+            None => Ok(ProposalState::Succeeded),
+            Some(eta) if now >= eta.saturating_add(Self::EXECUTION_GRACE_SECONDS) => {
+                Ok(ProposalState::Expired)
+            }
+            Some(_) => Ok(ProposalState::Queued),
+        }
+    }
+
+    fn run_action(env: &Env, action: DaoAction) -> Result<(), DaoError> {
+        match action {
+            DaoAction::SetAdmin(target, new_admin) => env.invoke_contract::<()>(
+                &target,
+                &Symbol::new(env, "set_admin"),
+                vec![env, new_admin.into_val(env)],
+            ),
+            DaoAction::ScheduleUpgrade(target, wasm_hash, eta) => env.invoke_contract::<()>(
+                &target,
+                &Symbol::new(env, "schedule_upgrade"),
+                vec![env, wasm_hash.into_val(env), eta.into_val(env)],
+            ),
+            DaoAction::CancelUpgrade(target) => {
+                env.invoke_contract::<()>(&target, &Symbol::new(env, "cancel_upgrade"), vec![env])
+            }
+            DaoAction::SetGuardian(target, guardian) => env.invoke_contract::<()>(
+                &target,
+                &Symbol::new(env, "set_guardian"),
+                vec![env, guardian.into_val(env)],
+            ),
+            DaoAction::RatifyPause(target) => {
+                env.invoke_contract::<()>(&target, &Symbol::new(env, "ratify_pause"), vec![env])
+            }
+            DaoAction::Invoke(target, function, args) => {
+                env.invoke_contract::<Val>(&target, &function, args);
+            }
+            DaoAction::UpdateConfig(config) => {
+                Self::validate_config(&config)?;
+                env.storage().instance().set(&DataKey::Config, &config);
+                env.events().publish((symbol_short!("DaoConfig"),), config);
+            }
+        }
+        Ok(())
+    }
+
+    // ── Initialization ────────────────────────────────────────────────────────
+
+    /// Set the governance token and initial parameters. There is no admin;
+    /// afterwards everything changes through proposals.
+    pub fn initialize(env: Env, token: Address, config: DaoConfig) -> Result<(), DaoError> {
+        if env.storage().instance().has(&DataKey::Token) {
+            return Err(DaoError::AlreadyInitialized);
+        }
+        Self::validate_config(&config)?;
+        env.storage().instance().set(&DataKey::Token, &token);
+        env.storage().instance().set(&DataKey::Config, &config);
+        env.storage().instance().set(&DataKey::ProposalCount, &0u32);
+        env.storage()
+            .instance()
+            .set(&DataKey::Version, &Self::CURRENT_VERSION);
+        Self::bump_instance_ttl(&env);
+        Ok(())
+    }
+
+    pub fn version(env: Env) -> u32 {
+        Self::bump_instance_ttl(&env);
+        env.storage().instance().get(&DataKey::Version).unwrap_or(0)
+    }
+
+    pub fn get_config(env: Env) -> Result<DaoConfig, DaoError> {
+        Self::bump_instance_ttl(&env);
+        Self::config(&env)
+    }
+
+    pub fn get_token(env: Env) -> Result<Address, DaoError> {
+        Self::token(&env)
+    }
+
+    // ── Voting power ──────────────────────────────────────────────────────────
+
+    /// Escrow governance tokens; voting power goes to the account's delegate.
+    pub fn deposit(env: Env, account: Address, amount: i128) -> Result<(), DaoError> {
+        account.require_auth();
+        if amount <= 0 {
+            return Err(DaoError::InvalidAmount);
+        }
+        let token = Self::token(&env)?;
+        TokenClient::new(&env, &token).transfer(&account, &env.current_contract_address(), &amount);
+
+        let balance = Self::read_deposit(&env, &account)
+            .checked_add(amount)
+            .expect("deposit overflow");
+        Self::write_deposit(&env, &account, balance);
+        Self::move_votes(&env, &Self::read_delegate(&env, &account), amount);
+        Self::push_checkpoint(&env, &None, amount);
+
+        env.events()
+            .publish((symbol_short!("DaoDep"), account), (amount, balance));
+        Ok(())
+    }
+
+    /// Return escrowed tokens. Votes already cast are unaffected, since they
+    /// were weighed at each proposal's snapshot.
+    pub fn withdraw(env: Env, account: Address, amount: i128) -> Result<(), DaoError> {
+        account.require_auth();
+        if amount <= 0 {
+            return Err(DaoError::InvalidAmount);
+        }
+        let token = Self::token(&env)?;
+        let balance = Self::read_deposit(&env, &account);
+        if amount > balance {
+            return Err(DaoError::InsufficientDeposit);
+        }
+
+        Self::write_deposit(&env, &account, balance - amount);
+        Self::move_votes(&env, &Self::read_delegate(&env, &account), -amount);
+        Self::push_checkpoint(&env, &None, -amount);
+        TokenClient::new(&env, &token).transfer(&env.current_contract_address(), &account, &amount);
+
+        env.events().publish(
+            (symbol_short!("DaoWdr"), account),
+            (amount, balance - amount),
+        );
+        Ok(())
+    }
+
+    /// Send the account's voting power to `delegatee`; delegate to yourself
+    /// to take it back.
+    pub fn delegate(env: Env, account: Address, delegatee: Address) {
+        account.require_auth();
+        let previous = Self::read_delegate(&env, &account);
+        if previous == delegatee {
+            return;
+        }
+
+        let key = DataKey::Delegate(account.clone());
+        if delegatee == account {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, &delegatee);
+            Self::bump_persistent_ttl(&env, &key);
+        }
+        let balance = Self::read_deposit(&env, &account);
+        if balance > 0 {
+            Self::move_votes(&env, &previous, -balance);
+            Self::move_votes(&env, &delegatee, balance);
+        }
+
+        env.events()
+            .publish((symbol_short!("DaoDeleg"), account), (previous, delegatee));
+    }
+
+    pub fn delegates(env: Env, account: Address) -> Address {
+        Self::read_delegate(&env, &account)
+    }
+
+    pub fn deposit_of(env: Env, account: Address) -> i128 {
+        Self::read_deposit(&env, &account)
+    }
+
+    pub fn get_votes(env: Env, account: Address) -> i128 {
+        Self::latest_votes(&env, &Some(account))
+    }
+
+    /// Voting power recorded strictly before `timestamp`.
+    pub fn get_past_votes(env: Env, account: Address, timestamp: u64) -> i128 {
+        Self::votes_before(&env, &Some(account), timestamp)
+    }
+
+    pub fn get_past_total_votes(env: Env, timestamp: u64) -> i128 {
+        Self::votes_before(&env, &None, timestamp)
+    }
+
+    // ── Proposals ─────────────────────────────────────────────────────────────
+
+    /// Open a proposal. Votes are weighed as they stood before this call,
+    /// so tokens deposited or delegated in the same ledger do not count.
+    pub fn propose(
+        env: Env,
+        proposer: Address,
+        actions: Vec<DaoAction>,
+        description: String,
+    ) -> Result<u32, DaoError> {
+        proposer.require_auth();
+        let config = Self::config(&env)?;
+        Self::validate_actions(&env, &actions)?;
+
+        let now = env.ledger().timestamp();
+        let proposer_votes = Self::get_past_votes(env.clone(), proposer.clone(), now);
+        if proposer_votes < config.proposal_threshold {
+            return Err(DaoError::BelowProposalThreshold);
+        }
+
+        let proposal_id: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::ProposalCount)
+            .unwrap_or(0)
+            + 1;
+        env.storage()
+            .instance()
+            .set(&DataKey::ProposalCount, &proposal_id);
+        Self::bump_instance_ttl(&env);
+
+        let proposal = Proposal {
+            id: proposal_id,
+            proposer: proposer.clone(),
+            actions,
+            description,
+            snapshot: now,
+            vote_end: now.saturating_add(config.voting_period),
+            total_votes: Self::get_past_total_votes(env.clone(), now),
+            quorum_bps: config.quorum_bps,
+            approval_bps: config.approval_bps,
+            for_votes: 0,
+            against_votes: 0,
+            abstain_votes: 0,
+            eta: None,
+            executed: false,
+            cancelled: false,
+        };
+        Self::write_proposal(&env, &proposal);
+
+        env.events().publish(
+            (symbol_short!("DaoProp"), proposer),
+            (proposal_id, proposal.vote_end),
+        );
+        Ok(proposal_id)
+    }
+
+    /// Cast the voter's snapshot voting power. One vote per proposal.
+    pub fn cast_vote(
+        env: Env,
+        voter: Address,
+        proposal_id: u32,
+        support: VoteType,
+    ) -> Result<i128, DaoError> {
+        voter.require_auth();
+        let mut proposal = Self::read_proposal(&env, proposal_id)?;
+        if Self::state_of(&env, &proposal)? != ProposalState::Active {
+            return Err(DaoError::VotingClosed);
+        }
+        let receipt_key = DataKey::Receipt(proposal_id, voter.clone());
+        if env.storage().persistent().has(&receipt_key) {
+            return Err(DaoError::AlreadyVoted);
+        }
+        let weight = Self::get_past_votes(env.clone(), voter.clone(), proposal.snapshot);
+        if weight <= 0 {
+            return Err(DaoError::NoVotingPower);
+        }
+
+        match support {
+            VoteType::For => proposal.for_votes += weight,
+            VoteType::Against => proposal.against_votes += weight,
+            VoteType::Abstain => proposal.abstain_votes += weight,
+        }
+        Self::write_proposal(&env, &proposal);
+        env.storage().persistent().set(&receipt_key, &support);
+        Self::bump_persistent_ttl(&env, &receipt_key);
+
+        env.events().publish(
+            (symbol_short!("DaoVote"), voter),
+            (proposal_id, support, weight),
+        );
+        Ok(weight)
+    }
+
+    /// Start the timelock on a passed proposal. Callable by anyone.
+    pub fn queue(env: Env, proposal_id: u32) -> Result<u64, DaoError> {
+        let mut proposal = Self::read_proposal(&env, proposal_id)?;
+        if Self::state_of(&env, &proposal)? != ProposalState::Succeeded {
+            return Err(DaoError::ProposalNotSucceeded);
+        }
+        let eta = env
+            .ledger()
+            .timestamp()
+            .saturating_add(Self::config(&env)?.timelock);
+        proposal.eta = Some(eta);
+        Self::write_proposal(&env, &proposal);
+
+        env.events()
+            .publish((symbol_short!("DaoQueue"),), (proposal_id, eta));
+        Ok(eta)
+    }
+
+    /// Run a queued proposal's actions once its timelock has passed.
+    /// Callable by anyone; any failing action reverts the whole execution.
+    pub fn execute(env: Env, proposal_id: u32) -> Result<(), DaoError> {
+        let mut proposal = Self::read_proposal(&env, proposal_id)?;
+        if Self::state_of(&env, &proposal)? != ProposalState::Queued {
+            return Err(DaoError::ProposalNotQueued);
+        }
+        if env.ledger().timestamp() < proposal.eta.unwrap() {
+            return Err(DaoError::TimelockActive);
+        }
+
+        proposal.executed = true;
+        Self::write_proposal(&env, &proposal);
+        for action in proposal.actions.iter() {
+            Self::run_action(&env, action)?;
+        }
+        Self::bump_instance_ttl(&env);
+
+        env.events()
+            .publish((symbol_short!("DaoExec"),), proposal_id);
+        Ok(())
+    }
+
+    /// Withdraw a proposal that has not been executed. Proposer only.
+    pub fn cancel(env: Env, proposer: Address, proposal_id: u32) -> Result<(), DaoError> {
+        proposer.require_auth();
+        let mut proposal = Self::read_proposal(&env, proposal_id)?;
+        if proposal.proposer != proposer {
+            return Err(DaoError::NotProposer);
+        }
+        if proposal.executed || proposal.cancelled {
+            return Err(DaoError::ProposalFinalized);
+        }
+        proposal.cancelled = true;
+        Self::write_proposal(&env, &proposal);
+
+        env.events()
+            .publish((symbol_short!("DaoCancel"), proposer), proposal_id);
+        Ok(())
+    }
+
+    pub fn get_proposal(env: Env, proposal_id: u32) -> Result<Proposal, DaoError> {
+        Self::read_proposal(&env, proposal_id)
+    }
+
+    pub fn proposal_state(env: Env, proposal_id: u32) -> Result<ProposalState, DaoError> {
+        let proposal = Self::read_proposal(&env, proposal_id)?;
+        Self::state_of(&env, &proposal)
+    }
+
+    pub fn get_receipt(env: Env, proposal_id: u32, voter: Address) -> Option<VoteType> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Receipt(proposal_id, voter))
+    }
+
+    pub fn proposal_count(env: Env) -> u32 {
+        Self::bump_instance_ttl(&env);
+        env.storage()
+            .instance()
+            .get(&DataKey::ProposalCount)
+            .unwrap_or(0)
+    }
+}
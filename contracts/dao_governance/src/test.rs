@@ -0,0 +1,425 @@
+use super::*;
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::token::StellarAssetClient;
+use soroban_sdk::{contract, contractimpl, symbol_short, Address, Env, String, Vec};
+
+#[contract]
+pub struct MockTarget;
+
+#[contractimpl]
+impl MockTarget {
+    pub fn set_guardian(env: Env, guardian: Option<Address>) {
+        match guardian {
+            Some(guardian) => env
+                .storage()
+                .instance()
+                .set(&symbol_short!("guardian"), &guardian),
+            None => env.storage().instance().remove(&symbol_short!("guardian")),
+        }
+    }
+    pub fn get_guardian(env: Env) -> Option<Address> {
+        env.storage().instance().get(&symbol_short!("guardian"))
+    }
+    pub fn set_rate(env: Env, rate: u32) {
+        env.storage().instance().set(&symbol_short!("rate"), &rate);
+    }
+    pub fn get_rate(env: Env) -> Option<u32> {
+        env.storage().instance().get(&symbol_short!("rate"))
+    }
+}
+
+fn default_config() -> DaoConfig {
+    DaoConfig {
+        voting_period: 3 * 86_400,
+        timelock: 86_400,
+        quorum_bps: 2_000,
+        approval_bps: 5_000,
+        proposal_threshold: 100,
+    }
+}
+
+fn setup(env: &Env) -> (DaoGovernanceClient<'_>, StellarAssetClient<'_>, Address) {
+    env.mock_all_auths();
+    let token_admin = Address::generate(env);
+    let token = env.register_stellar_asset_contract_v2(token_admin);
+    let dao_id = env.register(DaoGovernance, ());
+    let dao = DaoGovernanceClient::new(env, &dao_id);
+    dao.initialize(&token.address(), &default_config());
+    let target = env.register(MockTarget, ());
+    (dao, StellarAssetClient::new(env, &token.address()), target)
+}
+
+fn funded_voter(
+    env: &Env,
+    token: &StellarAssetClient,
+    dao: &DaoGovernanceClient,
+    amount: i128,
+) -> Address {
+    let voter = Address::generate(env);
+    token.mint(&voter, &amount);
+    dao.deposit(&voter, &amount);
+    voter
+}
+
+fn advance(env: &Env, seconds: u64) {
+    env.ledger().with_mut(|li| {
+        li.timestamp += seconds;
+        li.sequence_number += 1;
+    });
+}
+
+fn guardian_proposal(env: &Env, target: &Address, guardian: &Address) -> Vec<DaoAction> {
+    Vec::from_array(
+        env,
+        [DaoAction::SetGuardian(
+            target.clone(),
+            Some(guardian.clone()),
+        )],
+    )
+}
+
+#[test]
+fn initialize_rejects_invalid_config_and_reinit() {
+    let env = Env::default();
+    let (dao, token, _target) = setup(&env);
+    assert_eq!(dao.version(), 1);
+    assert_eq!(
+        dao.try_initialize(&token.address, &default_config()),
+        Err(Ok(DaoError::AlreadyInitialized))
+    );
+
+    let other = env.register(DaoGovernance, ());
+    let other = DaoGovernanceClient::new(&env, &other);
+    let mut config = default_config();
+    config.timelock = 3_600;
+    assert_eq!(
+        other.try_initialize(&token.address, &config),
+        Err(Ok(DaoError::InvalidConfig))
+    );
+}
+
+#[test]
+fn passed_proposal_executes_after_timelock() {
+    let env = Env::default();
+    let (dao, token, target) = setup(&env);
+    let alice = funded_voter(&env, &token, &dao, 600);
+    let bob = funded_voter(&env, &token, &dao, 400);
+    advance(&env, 10);
+
+    let guardian = Address::generate(&env);
+    let id = dao.propose(
+        &alice,
+        &guardian_proposal(&env, &target, &guardian),
+        &String::from_str(&env, "appoint guardian"),
+    );
+    assert_eq!(dao.cast_vote(&alice, &id, &VoteType::For), 600);
+    assert_eq!(dao.cast_vote(&bob, &id, &VoteType::Against), 400);
+    assert_eq!(
+        dao.try_cast_vote(&alice, &id, &VoteType::For),
+        Err(Ok(DaoError::AlreadyVoted))
+    );
+    assert_eq!(dao.get_receipt(&id, &bob), Some(VoteType::Against));
+    assert_eq!(dao.try_queue(&id), Err(Ok(DaoError::ProposalNotSucceeded)));
+
+    advance(&env, default_config().voting_period);
+    assert_eq!(dao.proposal_state(&id), ProposalState::Succeeded);
+    let eta = dao.queue(&id);
+    assert_eq!(dao.try_execute(&id), Err(Ok(DaoError::TimelockActive)));
+
+    advance(&env, eta - env.ledger().timestamp());
+    dao.execute(&id);
+    assert_eq!(dao.proposal_state(&id), ProposalState::Executed);
+    assert_eq!(
+        MockTargetClient::new(&env, &target).get_guardian(),
+        Some(guardian)
+    );
+}
+
+#[test]
+fn votes_are_weighed_at_the_snapshot() {
+    let env = Env::default();
+    let (dao, token, target) = setup(&env);
+    let alice = funded_voter(&env, &token, &dao, 500);
+    advance(&env, 10);
+
+    let id = dao.propose(
+        &alice,
+        &guardian_proposal(&env, &target, &alice),
+        &String::from_str(&env, "snapshot"),
+    );
+    // Tokens escrowed after the proposal was opened carry no weight on it.
+    let late = funded_voter(&env, &token, &dao, 10_000);
+    assert_eq!(
+        dao.try_cast_vote(&late, &id, &VoteType::Against),
+        Err(Ok(DaoError::NoVotingPower))
+    );
+    assert_eq!(dao.get_proposal(&id).total_votes, 500);
+
+    // Withdrawing after the snapshot does not shrink the vote.
+    dao.withdraw(&alice, &500);
+    assert_eq!(dao.cast_vote(&alice, &id, &VoteType::For), 500);
+    assert_eq!(dao.get_votes(&alice), 0);
+}
+
+#[test]
+fn delegation_moves_voting_power() {
+    let env = Env::default();
+    let (dao, token, target) = setup(&env);
+    let alice = funded_voter(&env, &token, &dao, 300);
+    let bob = funded_voter(&env, &token, &dao, 200);
+    dao.delegate(&alice, &bob);
+    assert_eq!(dao.delegates(&alice), bob);
+    assert_eq!(dao.get_votes(&bob), 500);
+    assert_eq!(dao.get_votes(&alice), 0);
+    advance(&env, 10);
+
+    let id = dao.propose(
+        &bob,
+        &guardian_proposal(&env, &target, &bob),
+        &String::from_str(&env, "delegated"),
+    );
+    assert_eq!(
+        dao.try_cast_vote(&alice, &id, &VoteType::For),
+        Err(Ok(DaoError::NoVotingPower))
+    );
+    assert_eq!(dao.cast_vote(&bob, &id, &VoteType::For), 500);
+
+    dao.delegate(&alice, &alice);
+    assert_eq!(dao.get_votes(&alice), 300);
+    assert_eq!(dao.get_past_votes(&bob, &(env.ledger().timestamp())), 500);
+}
+
+#[test]
+fn proposal_without_quorum_is_defeated() {
+    let env = Env::default();
+    let (dao, token, target) = setup(&env);
+    let alice = funded_voter(&env, &token, &dao, 150);
+    let _whale = funded_voter(&env, &token, &dao, 10_000);
+    advance(&env, 10);
+
+    let id = dao.propose(
+        &alice,
+        &guardian_proposal(&env, &target, &alice),
+        &String::from_str(&env, "no quorum"),
+    );
+    dao.cast_vote(&alice, &id, &VoteType::For);
+    advance(&env, default_config().voting_period);
+
+    assert_eq!(dao.proposal_state(&id), ProposalState::Defeated);
+    assert_eq!(dao.try_queue(&id), Err(Ok(DaoError::ProposalNotSucceeded)));
+}
+
+#[test]
+fn proposing_requires_threshold_and_valid_actions() {
+    let env = Env::default();
+    let (dao, token, target) = setup(&env);
+    let small = funded_voter(&env, &token, &dao, 50);
+    advance(&env, 10);
+
+    assert_eq!(
+        dao.try_propose(
+            &small,
+            &guardian_proposal(&env, &target, &small),
+            &String::from_str(&env, "too small"),
+        ),
+        Err(Ok(DaoError::BelowProposalThreshold))
+    );
+    assert_eq!(
+        dao.try_propose(&small, &Vec::new(&env), &String::from_str(&env, "empty")),
+        Err(Ok(DaoError::InvalidActions))
+    );
+}
+
+#[test]
+fn parameters_and_config_change_through_proposals() {
+    let env = Env::default();
+    let (dao, token, target) = setup(&env);
+    let alice = funded_voter(&env, &token, &dao, 1_000);
+    advance(&env, 10);
+
+    let mut new_config = default_config();
+    new_config.quorum_bps = 5_000;
+    let actions = Vec::from_array(
+        &env,
+        [
+            DaoAction::Invoke(
+                target.clone(),
+                Symbol::new(&env, "set_rate"),
+                Vec::from_array(&env, [1_500u32.into_val(&env)]),
+            ),
+            DaoAction::UpdateConfig(new_config.clone()),
+        ],
+    );
+    let id = dao.propose(&alice, &actions, &String::from_str(&env, "params"));
+    dao.cast_vote(&alice, &id, &VoteType::For);
+    advance(&env, default_config().voting_period);
+    dao.queue(&id);
+    advance(&env, default_config().timelock);
+    dao.execute(&id);
+
+    assert_eq!(MockTargetClient::new(&env, &target).get_rate(), Some(1_500));
+    assert_eq!(dao.get_config(), new_config);
+    assert_eq!(dao.try_execute(&id), Err(Ok(DaoError::ProposalNotQueued)));
+}
+
+#[test]
+fn thresholds_are_fixed_when_proposing() {
+    let env = Env::default();
+    let (dao, token, target) = setup(&env);
+    let alice = funded_voter(&env, &token, &dao, 300);
+    let bob = funded_voter(&env, &token, &dao, 700);
+    advance(&env, 10);
+
+    let mut new_config = default_config();
+    new_config.quorum_bps = 5_000;
+    let raise = dao.propose(
+        &bob,
+        &Vec::from_array(&env, [DaoAction::UpdateConfig(new_config.clone())]),
+        &String::from_str(&env, "raise quorum"),
+    );
+    let guardian = Address::generate(&env);
+    let pending = dao.propose(
+        &alice,
+        &guardian_proposal(&env, &target, &guardian),
+        &String::from_str(&env, "appoint guardian"),
+    );
+    dao.cast_vote(&bob, &raise, &VoteType::For);
+    dao.cast_vote(&alice, &pending, &VoteType::For);
+    advance(&env, default_config().voting_period);
+    dao.queue(&raise);
+    advance(&env, default_config().timelock);
+    dao.execute(&raise);
+    assert_eq!(dao.get_config(), new_config);
+
+    // 30% turnout met the 20% quorum in force when it was proposed.
+    assert_eq!(dao.proposal_state(&pending), ProposalState::Succeeded);
+    dao.queue(&pending);
+    advance(&env, default_config().timelock);
+    dao.execute(&pending);
+    assert_eq!(
+        MockTargetClient::new(&env, &target).get_guardian(),
+        Some(guardian)
+    );
+}
+
+#[test]
+fn cancelled_and_expired_proposals_cannot_execute() {
+    let env = Env::default();
+    let (dao, token, target) = setup(&env);
+    let alice = funded_voter(&env, &token, &dao, 1_000);
+    let bob = funded_voter(&env, &token, &dao, 1_000);
+    advance(&env, 10);
+
+    let cancelled = dao.propose(
+        &alice,
+        &guardian_proposal(&env, &target, &alice),
+        &String::from_str(&env, "cancel me"),
+    );
+    assert_eq!(
+        dao.try_cancel(&bob, &cancelled),
+        Err(Ok(DaoError::NotProposer))
+    );
+    dao.cancel(&alice, &cancelled);
+    assert_eq!(dao.proposal_state(&cancelled), ProposalState::Cancelled);
+    assert_eq!(
+        dao.try_cast_vote(&bob, &cancelled, &VoteType::For),
+        Err(Ok(DaoError::VotingClosed))
+    );
+
+    let stale = dao.propose(
+        &bob,
+        &guardian_proposal(&env, &target, &bob),
+        &String::from_str(&env, "let it lapse"),
+    );
+    dao.cast_vote(&bob, &stale, &VoteType::For);
+    advance(&env, default_config().voting_period);
+    dao.queue(&stale);
+    advance(
+        &env,
+        default_config().timelock + DaoGovernance::EXECUTION_GRACE_SECONDS,
+    );
+    assert_eq!(dao.proposal_state(&stale), ProposalState::Expired);
+    assert_eq!(
+        dao.try_execute(&stale),
+        Err(Ok(DaoError::ProposalNotQueued))
+    );
+
+    let unqueued = dao.propose(
+        &alice,
+        &guardian_proposal(&env, &target, &alice),
+        &String::from_str(&env, "never queued"),
+    );
+    dao.cast_vote(&alice, &unqueued, &VoteType::For);
+    advance(&env, default_config().voting_period);
+    assert_eq!(dao.proposal_state(&unqueued), ProposalState::Succeeded);
+    advance(&env, DaoGovernance::QUEUE_GRACE_SECONDS);
+    assert_eq!(dao.proposal_state(&unqueued), ProposalState::Expired);
+    assert_eq!(
+        dao.try_queue(&unqueued),
+        Err(Ok(DaoError::ProposalNotSucceeded))
+    );
+}
+
+#[test]
+fn invoke_cannot_target_the_token_or_the_dao() {
+    let env = Env::default();
+    let (dao, token, target) = setup(&env);
+    let alice = funded_voter(&env, &token, &dao, 1_000);
+    advance(&env, 10);
+
+    let drain = DaoAction::Invoke(
+        token.address.clone(),
+        Symbol::new(&env, "transfer"),
+        Vec::from_array(
+            &env,
+            [
+                dao.address.into_val(&env),
+                alice.into_val(&env),
+                1_000i128.into_val(&env),
+            ],
+        ),
+    );
+    let reenter = DaoAction::Invoke(
+        dao.address.clone(),
+        Symbol::new(&env, "withdraw"),
+        Vec::from_array(&env, [alice.into_val(&env), 1_000i128.into_val(&env)]),
+    );
+    for action in [drain, reenter] {
+        let actions = Vec::from_array(
+            &env,
+            [
+                DaoAction::SetGuardian(target.clone(), Some(alice.clone())),
+                action,
+            ],
+        );
+        assert_eq!(
+            dao.try_propose(&alice, &actions, &String::from_str(&env, "drain")),
+            Err(Ok(DaoError::ForbiddenTarget))
+        );
+    }
+    assert_eq!(dao.proposal_count(), 0);
+}
+
+#[test]
+fn past_votes_are_found_across_many_checkpoints() {
+    let env = Env::default();
+    let (dao, token, _target) = setup(&env);
+    let alice = funded_voter(&env, &token, &dao, 100);
+    let start = env.ledger().timestamp();
+    for _ in 0..20 {
+        advance(&env, 10);
+        token.mint(&alice, &100);
+        dao.deposit(&alice, &100);
+    }
+    // Two changes in one ledger share a checkpoint.
+    dao.withdraw(&alice, &50);
+
+    assert_eq!(dao.get_votes(&alice), 2_050);
+    assert_eq!(dao.get_past_votes(&alice, &start), 0);
+    assert_eq!(dao.get_past_votes(&alice, &(start + 1)), 100);
+    assert_eq!(dao.get_past_votes(&alice, &(start + 10)), 100);
+    assert_eq!(dao.get_past_votes(&alice, &(start + 11)), 200);
+    assert_eq!(dao.get_past_votes(&alice, &(start + 105)), 1_100);
+    assert_eq!(dao.get_past_votes(&alice, &(start + 1_000)), 2_050);
+    assert_eq!(dao.get_past_total_votes(&(start + 105)), 1_100);
+}
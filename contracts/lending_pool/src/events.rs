@@ -1,4 +1,6 @@
-use soroban_sdk::{Address, Env, Symbol};
+use soroban_sdk::{Address, BytesN, Env, Symbol};
+
+use crate::{AdminAction, ParamLimit, ParamOverride, PoolOperation, PoolParam};
 
 pub fn deposit(env: &Env, provider: Address, token: Address, amount: i128, shares_minted: i128) {
     let topics = (Symbol::new(env, "Deposit"), provider, token);
@@ -45,3 +47,146 @@ pub fn admin_transferred(env: &Env, new_admin: Address) {
     let topics = (Symbol::new(env, "AdminTransferred"),);
     env.events().publish(topics, new_admin);
 }
+
+pub fn lock_created(
+    env: &Env,
+    provider: Address,
+    token: Address,
+    lock_id: u32,
+    amount: i128,
+    shares_minted: i128,
+    unlock_ledger: u32,
+) {
+    let topics = (Symbol::new(env, "LockCreated"), provider, token);
+    env.events()
+        .publish(topics, (lock_id, amount, shares_minted, unlock_ledger));
+}
+
+pub fn lock_withdrawn(
+    env: &Env,
+    provider: Address,
+    token: Address,
+    lock_id: u32,
+    amount: i128,
+    boost_paid: i128,
+    penalty: i128,
+) {
+    let topics = (Symbol::new(env, "LockWithdrawn"), provider, token);
+    env.events()
+        .publish(topics, (lock_id, amount, boost_paid, penalty));
+}
+
+pub fn interest_recorded(env: &Env, token: Address, amount: i128, boost_cut: i128) {
+    let topics = (Symbol::new(env, "InterestRecorded"), token);
+    env.events().publish(topics, (amount, boost_cut));
+}
+
+pub fn loan_manager_updated(env: &Env, old_manager: Option<Address>, new_manager: Address) {
+    let topics = (Symbol::new(env, "LoanManagerUpdated"),);
+    env.events().publish(topics, (old_manager, new_manager));
+}
+
+pub fn lock_term_updated(env: &Env, term_ledgers: u32, old_boost_bps: u32, new_boost_bps: u32) {
+    let topics = (Symbol::new(env, "LockTermUpdated"), term_ledgers);
+    env.events().publish(topics, (old_boost_bps, new_boost_bps));
+}
+
+pub fn boost_share_updated(env: &Env, old_bps: u32, new_bps: u32) {
+    let topics = (Symbol::new(env, "BoostShareUpdated"),);
+    env.events().publish(topics, (old_bps, new_bps));
+}
+
+pub fn early_exit_penalty_updated(env: &Env, old_bps: u32, new_bps: u32) {
+    let topics = (Symbol::new(env, "EarlyExitPenaltyUpdated"),);
+    env.events().publish(topics, (old_bps, new_bps));
+}
+
+pub fn max_provider_position_updated(env: &Env, token: Address, old_max: i128, new_max: i128) {
+    let topics = (Symbol::new(env, "MaxProviderPositionUpdated"), token);
+    env.events().publish(topics, (old_max, new_max));
+}
+
+pub fn allowlist_toggled(env: &Env, token: Address, enabled: bool) {
+    let topics = (Symbol::new(env, "AllowlistToggled"), token);
+    env.events().publish(topics, enabled);
+}
+
+pub fn lender_allowed(env: &Env, token: Address, lender: Address) {
+    let topics = (Symbol::new(env, "LenderAllowed"), token);
+    env.events().publish(topics, lender);
+}
+
+pub fn lender_removed(env: &Env, token: Address, lender: Address) {
+    let topics = (Symbol::new(env, "LenderRemoved"), token);
+    env.events().publish(topics, lender);
+}
+
+pub fn upgrade_scheduled(env: &Env, wasm_hash: BytesN<32>, eta: u32) {
+    let topics = (Symbol::new(env, "UpgradeScheduled"),);
+    env.events().publish(topics, (wasm_hash, eta));
+}
+
+pub fn upgrade_cancelled(env: &Env, wasm_hash: BytesN<32>) {
+    let topics = (Symbol::new(env, "UpgradeCancelled"),);
+    env.events().publish(topics, wasm_hash);
+}
+
+pub fn upgrade_executed(env: &Env, old_version: u32, wasm_hash: BytesN<32>) {
+    let topics = (Symbol::new(env, "ContractUpgraded"),);
+    env.events().publish(topics, (old_version, wasm_hash));
+}
+
+pub fn migration_step_completed(env: &Env, from_version: u32, to_version: u32) {
+    let topics = (Symbol::new(env, "MigrationStepCompleted"),);
+    env.events().publish(topics, (from_version, to_version));
+}
+
+pub fn guardian_set(env: &Env, guardian: Option<Address>) {
+    let topics = (Symbol::new(env, "GuardianSet"),);
+    env.events().publish(topics, guardian);
+}
+
+pub fn governance_set(env: &Env, governance: Address) {
+    let topics = (Symbol::new(env, "GovernanceSet"),);
+    env.events().publish(topics, governance);
+}
+
+pub fn guardian_pause_ledgers_updated(env: &Env, ledgers: u32) {
+    let topics = (Symbol::new(env, "GuardianPauseLedgers"),);
+    env.events().publish(topics, ledgers);
+}
+
+pub fn guardian_paused(env: &Env, guardian: Address, expiry: u32) {
+    let topics = (Symbol::new(env, "PoolGuardianPaused"), guardian);
+    env.events().publish(topics, expiry);
+}
+
+pub fn pause_ratified(env: &Env, expiry: u32) {
+    let topics = (Symbol::new(env, "PoolPauseRatified"),);
+    env.events().publish(topics, expiry);
+}
+
+pub fn operation_pause_set(env: &Env, operation: PoolOperation, paused: bool) {
+    let topics = (Symbol::new(env, "PoolOperationPauseSet"), operation);
+    env.events().publish(topics, paused);
+}
+
+pub fn param_limit_set(env: &Env, param: PoolParam, limit: Option<ParamLimit>) {
+    let topics = (Symbol::new(env, "PoolParamLimitSet"), param);
+    env.events().publish(topics, limit);
+}
+
+pub fn param_override_scheduled(env: &Env, param: PoolParam, change: ParamOverride, eta: u32) {
+    let topics = (Symbol::new(env, "PoolParamOverrideScheduled"), param);
+    env.events().publish(topics, (change, eta));
+}
+
+pub fn param_override_cancelled(env: &Env, param: PoolParam) {
+    let topics = (Symbol::new(env, "PoolParamOverrideCancelled"), param);
+    env.events().publish(topics, ());
+}
+
+pub fn admin_action_logged(env: &Env, index: u32, entry: AdminAction) {
+    let topics = (Symbol::new(env, "AdminAction"), entry.action.clone());
+    env.events().publish(topics, (index, entry));
+}
@@ -1,7 +1,8 @@
 #![no_std]
 use soroban_sdk::token::Client as TokenClient;
 use soroban_sdk::{
-    contract, contracterror, contractimpl, contracttype, Address, BytesN, Env, Symbol,
+    contract, contracterror, contractimpl, contracttype, Address, BytesN, Env, Map, Symbol,
+    TryFromVal, Val, Vec,
 };
 
 mod events;
@@ -19,6 +20,161 @@ pub enum PoolError {
     InsufficientLiquidity = 7,
     InvalidMaxPoolSize = 9,
     NoProposedAdmin = 10,
+    InvalidLockTerm = 11,
+    LockNotFound = 12,
+    LockNotMatured = 13,
+    ProviderMismatch = 14,
+    InvalidConfiguration = 15,
+    PositionLimitExceeded = 16,
+    LenderNotAllowed = 17,
+    InvalidUpgradeEta = 18,
+    NoUpgradeScheduled = 19,
+    UpgradeTimelockActive = 20,
+    GuardianNotSet = 21,
+    AlreadyPaused = 22,
+    NoGuardianPause = 23,
+    InvalidPauseWindow = 24,
+    OperationPaused = 25,
+    ParamChangeTooLarge = 26,
+    InvalidParamLimit = 27,
+    ParamOverrideRequired = 28,
+    ParamOverrideTimelockActive = 29,
+    NoParamOverride = 30,
+    /// The guardian's pause lapsed and governance has not paused, unpaused
+    /// or ratified since.
+    GuardianPauseSpent = 31,
+    /// Stored data predates `CURRENT_VERSION`; run `migrate_batch` first.
+    MigrationPending = 32,
+    /// `migrate_batch` was asked to start past the stored cursor.
+    MigrationCursorAhead = 33,
+    InvalidMigrationBatch = 34,
+    /// `set_guardian` was called before `set_governance`.
+    GovernanceNotSet = 35,
+}
+
+/// Operations that can be paused individually with `pause_operation`.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PoolOperation {
+    Deposits,
+    Withdrawals,
+}
+
+/// Numeric parameters whose setters are subject to `ParamLimit`s.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PoolParam {
+    MaxPoolSize(Address),
+    MaxProviderPosition(Address),
+    WithdrawalCooldown,
+    /// Boost for the lock term with this many ledgers
+    LockTermBoost(u32),
+    BoostShareBps,
+    EarlyExitPenaltyBps,
+    GuardianPauseLedgers,
+}
+
+/// How far a parameter may move within one window. The allowance is the
+/// larger of `max_change` (in the parameter's own units) and `max_change_bps`
+/// of the value at the start of the window.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParamLimit {
+    pub max_change: i128,
+    pub max_change_bps: u32,
+    pub window_ledgers: u32,
+}
+
+/// The value a parameter started its current limit window at.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParamWindow {
+    pub start_ledger: u32,
+    pub baseline: i128,
+}
+
+/// A change that exceeds the registered limits: a value jump, or a looser
+/// (or removed) limit.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParamOverride {
+    Value(i128),
+    Limit(ParamLimit),
+    RemoveLimit,
+}
+
+/// An override waiting out `PARAM_OVERRIDE_DELAY_LEDGERS`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingParamOverride {
+    pub change: ParamOverride,
+    pub eta: u32,
+}
+
+/// A before or after value in an `AdminAction`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AdminValue {
+    None,
+    Bool(bool),
+    U32(u32),
+    I128(i128),
+    Address(Address),
+    Hash(BytesN<32>),
+    /// Per-operation pause flag
+    Operation(PoolOperation, bool),
+    Limit(PoolParam, ParamLimit),
+    Override(PoolParam, ParamOverride),
+    /// token → amount, for per-token caps
+    TokenAmount(Address, i128),
+    /// token → allowlist enabled
+    TokenFlag(Address, bool),
+    /// (token, lender) allowlist entry
+    Lender(Address, Address),
+    /// lock term in ledgers → boost in bps
+    LockTerm(u32, u32),
+}
+
+/// One privileged call, as recorded in the admin audit log.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminAction {
+    pub ledger: u32,
+    pub actor: Address,
+    /// Name of the entrypoint that was called
+    pub action: Symbol,
+    pub old: AdminValue,
+    pub new: AdminValue,
+}
+
+/// Where storage stands relative to the running code.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MigrationStatus {
+    /// Schema version of stored data
+    pub version: u32,
+    /// `CURRENT_VERSION` of the running code
+    pub target_version: u32,
+    /// Next entry the current step will process
+    pub cursor: u32,
+    pub complete: bool,
+}
+
+/// One completed migration step.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MigrationRecord {
+    pub from_version: u32,
+    pub to_version: u32,
+    pub ledger: u32,
+}
+
+/// An upgrade announced ahead of time; executable from ledger `eta`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ScheduledUpgrade {
+    pub wasm_hash: BytesN<32>,
+    pub eta: u32,
 }
 
 /// Storage keys.
@@ -50,7 +206,106 @@ pub enum DataKey {
     /// token → number of active depositors
     DepositorCount(Address),
     ProposedAdmin,
+    /// Wasm hash announced by `schedule_upgrade`
+    ScheduledUpgrade,
+    /// Governance contract that appoints the guardian
+    Governance,
+    /// Address allowed to call `guardian_pause`
+    Guardian,
+    /// Ledger at which the active guardian pause lapses; absent for admin pauses
+    GuardianPauseExpiry,
+    /// How many ledgers a guardian pause lasts unless ratified
+    GuardianPauseLedgers,
+    /// Per-operation pause flag, independent of the global `Paused`
+    OperationPaused(PoolOperation),
+    /// param → registered rate-of-change limit
+    ParamLimit(PoolParam),
+    /// param → its limit was removed through an override, so the default is
+    /// not seeded again
+    ParamLimitRemoved(PoolParam),
+    /// param → current limit window
+    ParamWindow(PoolParam),
+    /// param → override waiting for its timelock
+    ParamOverride(PoolParam),
+    /// Number of entries in the admin audit log
+    AdminLogLen,
+    /// page index → up to `ADMIN_LOG_PAGE_SIZE` audit log entries
+    AdminLogPage(u32),
     Version,
+    /// Next entry the in-progress migration step will process
+    MigrationCursor,
+    /// Completed migration steps, oldest first
+    MigrationLog,
+    /// LoanManager allowed to report interest income via `record_interest`
+    LoanManager,
+    /// Share of reported interest (bps) diverted into the lock boost reserve
+    BoostShareBps,
+    /// Penalty (bps of position value) charged on early exit from a lock
+    EarlyExitPenaltyBps,
+    /// lock term in ledgers → boost multiplier in bps (10_000 = 1x weight)
+    LockTermBoost(u32),
+    /// Monotonic id source for locked positions
+    LockCounter,
+    /// lock id → LockedPosition
+    Lock(u32),
+    /// (provider, token) → ids of that provider's open locks
+    ProviderLocks(Address, Address),
+    /// token → LP shares held in locked positions
+    LockedShares(Address),
+    /// token → sum of boost weights across open locks
+    TotalLockWeight(Address),
+    /// token → accumulated boost per unit of weight, scaled by BOOST_PRECISION
+    AccBoostPerWeight(Address),
+    /// token → interest set aside for lock boosts; excluded from share pricing
+    BoostReserve(Address),
+    /// (provider, token) → principal paid in for the liquid shares still held
+    CostBasis(Address, Address),
+    /// (provider, token) → cumulative gain (or loss) realised on withdrawals
+    RealizedYield(Address, Address),
+    /// token → max asset value a single provider may hold (0 = unlimited)
+    MaxProviderPosition(Address),
+    /// token → whether only allowlisted lenders may deposit
+    AllowlistEnabled(Address),
+    /// (token, provider) → lender approved for an allowlisted pool
+    AllowedLender(Address, Address),
+}
+
+/// A deposit locked until `unlock_ledger` in exchange for a yield boost.
+///
+/// The position's shares live in the same share pool as liquid deposits, so
+/// it earns the base yield through the exchange rate. On top of that it
+/// accrues a pro-rata slice of the boost reserve according to
+/// `boost_weight`, using the usual accumulator / reward-debt scheme.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct LockedPosition {
+    pub provider: Address,
+    pub token: Address,
+    /// Principal deposited; the lock's cost basis.
+    pub amount: i128,
+    pub shares: i128,
+    pub boost_weight: i128,
+    pub boost_debt: i128,
+    pub locked_at: u32,
+    pub unlock_ledger: u32,
+    /// Early-exit penalty (bps) in force when the lock was opened; later
+    /// changes to `EarlyExitPenaltyBps` do not reach it.
+    pub penalty_bps: u32,
+}
+
+/// `LockedPosition` as stored before version 5 fixed the penalty per
+/// lock. Only read by the 4 → 5 migration step.
+#[contracttype]
+#[derive(Clone)]
+pub struct LockedPositionV4 {
+    pub provider: Address,
+    pub token: Address,
+    pub amount: i128,
+    pub shares: i128,
+    pub boost_weight: i128,
+    pub boost_debt: i128,
+    pub locked_at: u32,
+    pub unlock_ledger: u32,
 }
 
 #[contracttype]
@@ -66,6 +321,25 @@ pub struct PoolStats {
     pub utilization_bps: u32,
 }
 
+/// Snapshot of a depositor's liquid position with its profit and loss.
+///
+/// `cost_basis` is the principal still attributed to the held shares; it is
+/// reduced pro rata as shares are redeemed. `realized_pnl` accumulates the
+/// difference between what was paid out and the basis removed, across both
+/// liquid withdrawals and closed locked positions.
+///
+/// Shares held since before cost-basis tracking have no recorded basis, so
+/// `cost_basis` and `unrealized_pnl` are `None` until the position is closed.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct DepositorPosition {
+    pub shares: i128,
+    pub value: i128,
+    pub cost_basis: Option<i128>,
+    pub unrealized_pnl: Option<i128>,
+    pub realized_pnl: i128,
+}
+
 #[contract]
 pub struct LendingPool;
 
@@ -75,8 +349,19 @@ impl LendingPool {
     const INSTANCE_TTL_BUMP: u32 = 518400;
     const PERSISTENT_TTL_THRESHOLD: u32 = 17280;
     const PERSISTENT_TTL_BUMP: u32 = 518400;
-    const CURRENT_VERSION: u32 = 3;
+    const CURRENT_VERSION: u32 = 5;
+    const MIN_UPGRADE_DELAY_LEDGERS: u32 = 17280;
+    const PARAM_OVERRIDE_DELAY_LEDGERS: u32 = 7 * 17280;
+    const DEFAULT_PARAM_WINDOW_LEDGERS: u32 = 17280;
+    const DEFAULT_PARAM_MAX_CHANGE_BPS: u32 = 5000;
+    pub const ADMIN_LOG_PAGE_SIZE: u32 = 50;
+    pub const MAX_ADMIN_LOG_LIMIT: u32 = 100;
+    pub const MIGRATE_BATCH_SIZE: u32 = 50;
+    const DEFAULT_GUARDIAN_PAUSE_LEDGERS: u32 = 3 * 17280;
     const DEFAULT_WITHDRAWAL_COOLDOWN: u32 = 1_440;
+    const DEFAULT_BOOST_SHARE_BPS: u32 = 1_000;
+    const DEFAULT_EARLY_EXIT_PENALTY_BPS: u32 = 500;
+    const BOOST_PRECISION: i128 = 1_000_000_000_000;
 
     // ── TTL helpers ───────────────────────────────────────────────────────
 
@@ -108,6 +393,89 @@ impl LendingPool {
         TokenClient::new(env, token).balance(&env.current_contract_address())
     }
 
+    /// Pool balance backing LP shares: everything except the boost reserve,
+    /// which belongs to locked positions only.
+    fn total_assets(env: &Env, token: &Address) -> i128 {
+        Self::read_pool_balance(env, token)
+            .saturating_sub(Self::boost_reserve(env, token))
+            .max(0)
+    }
+
+    fn read_instance_i128(env: &Env, key: &DataKey) -> i128 {
+        Self::bump_instance_ttl(env);
+        env.storage().instance().get(key).unwrap_or(0)
+    }
+
+    fn boost_reserve(env: &Env, token: &Address) -> i128 {
+        Self::read_instance_i128(env, &DataKey::BoostReserve(token.clone()))
+    }
+
+    fn boost_share_bps(env: &Env) -> u32 {
+        Self::bump_instance_ttl(env);
+        env.storage()
+            .instance()
+            .get(&DataKey::BoostShareBps)
+            .unwrap_or(Self::DEFAULT_BOOST_SHARE_BPS)
+    }
+
+    fn early_exit_penalty_bps(env: &Env) -> u32 {
+        Self::bump_instance_ttl(env);
+        env.storage()
+            .instance()
+            .get(&DataKey::EarlyExitPenaltyBps)
+            .unwrap_or(Self::DEFAULT_EARLY_EXIT_PENALTY_BPS)
+    }
+
+    fn read_lock(env: &Env, lock_id: u32) -> Result<LockedPosition, PoolError> {
+        let key = DataKey::Lock(lock_id);
+        let lock: LockedPosition = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .ok_or(PoolError::LockNotFound)?;
+        Self::bump_persistent_ttl(env, &key);
+        Ok(lock)
+    }
+
+    fn read_provider_locks(env: &Env, provider: &Address, token: &Address) -> Vec<u32> {
+        let key = DataKey::ProviderLocks(provider.clone(), token.clone());
+        let locks: Option<Vec<u32>> = env.storage().persistent().get(&key);
+        match locks {
+            Some(locks) => {
+                Self::bump_persistent_ttl(env, &key);
+                locks
+            }
+            None => Vec::new(env),
+        }
+    }
+
+    fn write_provider_locks(env: &Env, provider: &Address, token: &Address, locks: &Vec<u32>) {
+        let key = DataKey::ProviderLocks(provider.clone(), token.clone());
+        if locks.is_empty() {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, locks);
+            Self::bump_persistent_ttl(env, &key);
+        }
+    }
+
+    fn pending_boost(env: &Env, lock: &LockedPosition) -> i128 {
+        let acc = Self::read_instance_i128(env, &DataKey::AccBoostPerWeight(lock.token.clone()));
+        lock.boost_weight
+            .checked_mul(acc)
+            .map(|v| v / Self::BOOST_PRECISION)
+            .expect("boost overflow")
+            .saturating_sub(lock.boost_debt)
+            .max(0)
+    }
+
+    fn validate_bps(bps: u32) -> Result<(), PoolError> {
+        if bps > 10_000 {
+            return Err(PoolError::InvalidConfiguration);
+        }
+        Ok(())
+    }
+
     fn total_deposits(env: &Env, token: &Address) -> i128 {
         Self::bump_instance_ttl(env);
         env.storage()
@@ -142,6 +510,82 @@ impl LendingPool {
         deposit_ledger
     }
 
+    fn read_persistent_i128(env: &Env, key: &DataKey) -> i128 {
+        let value: Option<i128> = env.storage().persistent().get(key);
+        match value {
+            Some(value) => {
+                Self::bump_persistent_ttl(env, key);
+                value
+            }
+            None => 0,
+        }
+    }
+
+    fn write_persistent_i128(env: &Env, key: &DataKey, value: i128) {
+        env.storage().persistent().set(key, &value);
+        Self::bump_persistent_ttl(env, key);
+    }
+
+    /// Principal attributed to `provider`'s liquid shares, or `None` when
+    /// they hold shares from before cost-basis tracking began.
+    fn read_cost_basis(env: &Env, provider: &Address, token: &Address) -> Option<i128> {
+        let key = DataKey::CostBasis(provider.clone(), token.clone());
+        match env.storage().persistent().get::<DataKey, i128>(&key) {
+            Some(basis) => {
+                Self::bump_persistent_ttl(env, &key);
+                Some(basis)
+            }
+            None if Self::read_shares(env, provider, token) > 0 => None,
+            None => Some(0),
+        }
+    }
+
+    fn add_realized_yield(env: &Env, provider: &Address, token: &Address, delta: i128) {
+        if delta == 0 {
+            return;
+        }
+        let key = DataKey::RealizedYield(provider.clone(), token.clone());
+        let realized = Self::read_persistent_i128(env, &key)
+            .checked_add(delta)
+            .expect("realized yield overflow");
+        Self::write_persistent_i128(env, &key, realized);
+    }
+
+    fn provider_locked_shares(env: &Env, provider: &Address, token: &Address) -> i128 {
+        let mut locked_shares = 0i128;
+        for lock_id in Self::read_provider_locks(env, provider, token).iter() {
+            if let Ok(lock) = Self::read_lock(env, lock_id) {
+                locked_shares = locked_shares
+                    .checked_add(lock.shares)
+                    .expect("locked shares overflow");
+            }
+        }
+        locked_shares
+    }
+
+    fn max_provider_position(env: &Env, token: &Address) -> i128 {
+        env.storage()
+            .instance()
+            .get(&DataKey::MaxProviderPosition(token.clone()))
+            .unwrap_or(0)
+    }
+
+    fn allowlist_enabled(env: &Env, token: &Address) -> bool {
+        env.storage()
+            .instance()
+            .get(&DataKey::AllowlistEnabled(token.clone()))
+            .unwrap_or(false)
+    }
+
+    fn is_lender_allowed(env: &Env, token: &Address, provider: &Address) -> bool {
+        let key = DataKey::AllowedLender(token.clone(), provider.clone());
+        let allowed = env.storage().persistent().has(&key);
+        if allowed {
+            Self::bump_persistent_ttl(env, &key);
+        }
+        allowed
+    }
+
     fn read_depositor_count(env: &Env, token: &Address) -> u32 {
         env.storage()
             .instance()
@@ -157,19 +601,343 @@ impl LendingPool {
             .unwrap_or(Self::DEFAULT_WITHDRAWAL_COOLDOWN)
     }
 
-    fn assert_not_paused(env: &Env) -> Result<(), PoolError> {
-        Self::bump_instance_ttl(env);
+    /// Effective pause state. A guardian pause stops counting once its
+    /// expiry ledger is reached, without needing a write.
+    fn paused_now(env: &Env) -> bool {
         let paused: bool = env
             .storage()
             .instance()
             .get(&DataKey::Paused)
             .unwrap_or(false);
-        if paused {
+        if !paused {
+            return false;
+        }
+        match env
+            .storage()
+            .instance()
+            .get::<DataKey, u32>(&DataKey::GuardianPauseExpiry)
+        {
+            Some(expiry) => env.ledger().sequence() < expiry,
+            None => true,
+        }
+    }
+
+    fn admin_log_len(env: &Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::AdminLogLen)
+            .unwrap_or(0)
+    }
+
+    fn read_admin_log_page(env: &Env, page: u32) -> Vec<AdminAction> {
+        let key = DataKey::AdminLogPage(page);
+        match env
+            .storage()
+            .persistent()
+            .get::<DataKey, Vec<AdminAction>>(&key)
+        {
+            Some(entries) => {
+                Self::bump_persistent_ttl(env, &key);
+                entries
+            }
+            None => Vec::new(env),
+        }
+    }
+
+    /// Append a privileged call to the admin audit log. Entries are never
+    /// rewritten or removed.
+    fn log_admin_action(
+        env: &Env,
+        actor: &Address,
+        action: &str,
+        old: AdminValue,
+        new: AdminValue,
+    ) {
+        let index = Self::admin_log_len(env);
+        let page_index = index / Self::ADMIN_LOG_PAGE_SIZE;
+        let mut page = Self::read_admin_log_page(env, page_index);
+        let entry = AdminAction {
+            ledger: env.ledger().sequence(),
+            actor: actor.clone(),
+            action: Symbol::new(env, action),
+            old,
+            new,
+        };
+        page.push_back(entry.clone());
+
+        let key = DataKey::AdminLogPage(page_index);
+        env.storage().persistent().set(&key, &page);
+        Self::bump_persistent_ttl(env, &key);
+        env.storage()
+            .instance()
+            .set(&DataKey::AdminLogLen, &(index + 1));
+        Self::bump_instance_ttl(env);
+        admin_action_logged(env, index, entry);
+    }
+
+    fn read_param_limit(env: &Env, param: &PoolParam) -> Option<ParamLimit> {
+        let key = DataKey::ParamLimit(param.clone());
+        let limit = env.storage().persistent().get(&key);
+        if limit.is_some() {
+            Self::bump_persistent_ttl(env, &key);
+        }
+        limit
+    }
+
+    /// Limit every parameter starts with. `max_change` is the allowance
+    /// when the value is small; otherwise half the value may move per day.
+    fn default_param_limit(param: &PoolParam) -> ParamLimit {
+        let max_change = match param {
+            PoolParam::MaxPoolSize(_) | PoolParam::MaxProviderPosition(_) => 0,
+            PoolParam::WithdrawalCooldown | PoolParam::GuardianPauseLedgers => 17_280,
+            PoolParam::LockTermBoost(_) => 2_500,
+            PoolParam::BoostShareBps | PoolParam::EarlyExitPenaltyBps => 500,
+        };
+        ParamLimit {
+            max_change,
+            max_change_bps: Self::DEFAULT_PARAM_MAX_CHANGE_BPS,
+            window_ledgers: Self::DEFAULT_PARAM_WINDOW_LEDGERS,
+        }
+    }
+
+    /// Register the default limit for `param` unless it already has one or
+    /// its limit was removed through an override.
+    fn seed_param_limit(env: &Env, param: &PoolParam) {
+        let key = DataKey::ParamLimit(param.clone());
+        if env.storage().persistent().has(&key)
+            || env
+                .storage()
+                .persistent()
+                .has(&DataKey::ParamLimitRemoved(param.clone()))
+        {
+            return;
+        }
+        env.storage()
+            .persistent()
+            .set(&key, &Self::default_param_limit(param));
+        Self::bump_persistent_ttl(env, &key);
+    }
+
+    fn seed_global_param_limits(env: &Env) {
+        for param in [
+            PoolParam::WithdrawalCooldown,
+            PoolParam::BoostShareBps,
+            PoolParam::EarlyExitPenaltyBps,
+            PoolParam::GuardianPauseLedgers,
+        ] {
+            Self::seed_param_limit(env, &param);
+        }
+    }
+
+    fn validate_param_limit(limit: &ParamLimit) -> Result<(), PoolError> {
+        if limit.max_change < 0 || limit.window_ledgers == 0 {
+            return Err(PoolError::InvalidParamLimit);
+        }
+        Ok(())
+    }
+
+    /// Use up the pending override for `param` if it is `expected` and its
+    /// timelock has passed.
+    fn consume_param_override(
+        env: &Env,
+        param: &PoolParam,
+        expected: &ParamOverride,
+    ) -> Result<(), PoolError> {
+        let key = DataKey::ParamOverride(param.clone());
+        let pending: PendingParamOverride = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .filter(|pending: &PendingParamOverride| pending.change == *expected)
+            .ok_or(PoolError::ParamOverrideRequired)?;
+        if env.ledger().sequence() < pending.eta {
+            return Err(PoolError::ParamOverrideTimelockActive);
+        }
+        env.storage().persistent().remove(&key);
+        Ok(())
+    }
+
+    /// Enforce `param`'s rate-of-change limit on a move from `old` to `new`.
+    /// Movement is measured from the value at the start of the current
+    /// window, so several small steps cannot add up past the allowance. A
+    /// matured override for exactly `new` lets a larger jump through and
+    /// starts a fresh window.
+    fn check_param_change(
+        env: &Env,
+        param: PoolParam,
+        old: i128,
+        new: i128,
+    ) -> Result<(), PoolError> {
+        if old == new {
+            return Ok(());
+        }
+        let Some(limit) = Self::read_param_limit(env, &param) else {
+            return Ok(());
+        };
+
+        let now = env.ledger().sequence();
+        let window_key = DataKey::ParamWindow(param.clone());
+        let mut window = env
+            .storage()
+            .persistent()
+            .get::<DataKey, ParamWindow>(&window_key)
+            .filter(|window| now < window.start_ledger.saturating_add(limit.window_ledgers))
+            .unwrap_or(ParamWindow {
+                start_ledger: now,
+                baseline: old,
+            });
+
+        let relative = window
+            .baseline
+            .checked_abs()
+            .and_then(|value| value.checked_mul(limit.max_change_bps as i128))
+            .map(|value| value / 10_000)
+            .unwrap_or(i128::MAX);
+        let allowance = limit.max_change.max(relative);
+        let moved = new
+            .checked_sub(window.baseline)
+            .and_then(i128::checked_abs)
+            .unwrap_or(i128::MAX);
+        if moved > allowance {
+            Self::consume_param_override(env, &param, &ParamOverride::Value(new)).map_err(
+                |err| {
+                    if err == PoolError::ParamOverrideRequired {
+                        PoolError::ParamChangeTooLarge
+                    } else {
+                        err
+                    }
+                },
+            )?;
+            window = ParamWindow {
+                start_ledger: now,
+                baseline: new,
+            };
+        }
+
+        env.storage().persistent().set(&window_key, &window);
+        Self::bump_persistent_ttl(env, &window_key);
+        Ok(())
+    }
+
+    fn operation_paused(env: &Env, operation: PoolOperation) -> bool {
+        env.storage()
+            .instance()
+            .get(&DataKey::OperationPaused(operation))
+            .unwrap_or(false)
+    }
+
+    /// `assert_not_paused`, plus the operation's own pause flag.
+    fn assert_operation_active(env: &Env, operation: PoolOperation) -> Result<(), PoolError> {
+        Self::assert_not_paused(env)?;
+        if Self::operation_paused(env, operation) {
+            return Err(PoolError::OperationPaused);
+        }
+        Ok(())
+    }
+
+    fn assert_not_paused(env: &Env) -> Result<(), PoolError> {
+        Self::bump_instance_ttl(env);
+        if Self::stored_version(env) < Self::CURRENT_VERSION {
+            return Err(PoolError::MigrationPending);
+        }
+        if Self::paused_now(env) {
             return Err(PoolError::ContractPaused);
         }
         Ok(())
     }
 
+    // ── Migration ─────────────────────────────────────────────────────────
+
+    fn stored_version(env: &Env) -> u32 {
+        env.storage().instance().get(&DataKey::Version).unwrap_or(0)
+    }
+
+    fn migration_cursor(env: &Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::MigrationCursor)
+            .unwrap_or(1)
+    }
+
+    fn migration_status(env: &Env) -> MigrationStatus {
+        let version = Self::stored_version(env);
+        MigrationStatus {
+            version,
+            target_version: Self::CURRENT_VERSION,
+            cursor: Self::migration_cursor(env),
+            complete: version >= Self::CURRENT_VERSION,
+        }
+    }
+
+    /// Whether the step from `from_version` rewrites `Lock(id)` entries and
+    /// so must be batched.
+    fn step_migrates_locks(from_version: u32) -> bool {
+        from_version == 4
+    }
+
+    /// Per-lock work of the step from `from_version`.
+    fn migrate_lock(env: &Env, from_version: u32, lock_id: u32) {
+        if from_version == 4 {
+            // 4 → 5: locks record their own early-exit penalty. The rate in
+            // force when older locks opened was never stored, so they keep
+            // the one in force at migration.
+            let key = DataKey::Lock(lock_id);
+            let Some(raw) = env
+                .storage()
+                .persistent()
+                .get::<DataKey, Map<Symbol, Val>>(&key)
+            else {
+                return;
+            };
+            // Already rewritten by an earlier, overlapping batch.
+            if raw.contains_key(Symbol::new(env, "penalty_bps")) {
+                return;
+            }
+            let old = LockedPositionV4::try_from_val(env, &raw.to_val())
+                .expect("malformed version 4 lock");
+            let lock = LockedPosition {
+                provider: old.provider,
+                token: old.token,
+                amount: old.amount,
+                shares: old.shares,
+                boost_weight: old.boost_weight,
+                boost_debt: old.boost_debt,
+                locked_at: old.locked_at,
+                unlock_ledger: old.unlock_ledger,
+                penalty_bps: Self::early_exit_penalty_bps(env),
+            };
+            env.storage().persistent().set(&key, &lock);
+            Self::bump_persistent_ttl(env, &key);
+        }
+    }
+
+    /// Instance-level work of the step from `from_version`, then records
+    /// the step and advances the stored version. Returns the new version.
+    fn complete_migration_step(env: &Env, from_version: u32) -> u32 {
+        if from_version == 3 {
+            // 3 → 4: rate-of-change limits stop being opt-in. Per-token and
+            // per-term parameters pick theirs up at their next setter call.
+            Self::seed_global_param_limits(env);
+        }
+
+        let to_version = from_version + 1;
+        let mut log: Vec<MigrationRecord> = env
+            .storage()
+            .instance()
+            .get(&DataKey::MigrationLog)
+            .unwrap_or(Vec::new(env));
+        log.push_back(MigrationRecord {
+            from_version,
+            to_version,
+            ledger: env.ledger().sequence(),
+        });
+        env.storage().instance().set(&DataKey::MigrationLog, &log);
+        env.storage().instance().set(&DataKey::Version, &to_version);
+        env.storage().instance().remove(&DataKey::MigrationCursor);
+        migration_step_completed(env, from_version, to_version);
+        to_version
+    }
+
     // ── Share / asset math ────────────────────────────────────────────────
 
     /// LP shares to mint for `amount` of deposited assets.
@@ -236,7 +1004,7 @@ impl LendingPool {
         }
 
         let cur_total_shares = Self::total_shares(env, token);
-        let total_assets = Self::read_pool_balance(env, token);
+        let total_assets = Self::total_assets(env, token);
         let assets_to_return = Self::calc_assets_to_redeem(shares, total_assets, cur_total_shares);
 
         if assets_to_return <= 0 {
@@ -249,12 +1017,35 @@ impl LendingPool {
             &assets_to_return,
         );
 
+        // Release cost basis pro rata to the shares burned; the rest of the
+        // payout is realised yield. Without a recorded basis the split is
+        // unknown, so nothing is realised.
+        let basis_key = DataKey::CostBasis(provider.clone(), token.clone());
+        let cost_basis = Self::read_cost_basis(env, provider, token);
+        let basis_released = cost_basis.map(|basis| {
+            basis
+                .checked_mul(shares)
+                .and_then(|v| v.checked_div(cur_shares))
+                .expect("cost basis overflow")
+        });
+        if let Some(basis_released) = basis_released {
+            Self::add_realized_yield(
+                env,
+                provider,
+                token,
+                assets_to_return
+                    .checked_sub(basis_released)
+                    .expect("realized yield overflow"),
+            );
+        }
+
         let share_key = DataKey::Shares(provider.clone(), token.clone());
         let deposit_key = DataKey::DepositTimestamp(provider.clone(), token.clone());
         let remaining = cur_shares.checked_sub(shares).expect("share underflow");
         if remaining == 0 {
             env.storage().persistent().remove(&share_key);
             env.storage().persistent().remove(&deposit_key);
+            env.storage().persistent().remove(&basis_key);
             let count = Self::read_depositor_count(env, token);
             env.storage().instance().set(
                 &DataKey::DepositorCount(token.clone()),
@@ -264,6 +1055,9 @@ impl LendingPool {
             env.storage().persistent().set(&share_key, &remaining);
             Self::bump_persistent_ttl(env, &share_key);
             Self::bump_persistent_ttl(env, &deposit_key);
+            if let (Some(basis), Some(released)) = (cost_basis, basis_released) {
+                Self::write_persistent_i128(env, &basis_key, basis - released);
+            }
         }
 
         let new_total_shares = cur_total_shares
@@ -289,46 +1083,525 @@ impl LendingPool {
         Ok(())
     }
 
-    // ── Admin / lifecycle ─────────────────────────────────────────────────
-
-    pub fn initialize(env: Env, admin: Address) -> Result<(), PoolError> {
-        if env.storage().instance().has(&DataKey::Admin) {
-            return Err(PoolError::AlreadyInitialized);
+    /// Pull `amount` of `token` from `provider` and mint the matching LP
+    /// shares into the pool totals. The caller credits the shares to either
+    /// a liquid balance or a locked position.
+    fn mint_shares(
+        env: &Env,
+        provider: &Address,
+        token: &Address,
+        amount: i128,
+    ) -> Result<i128, PoolError> {
+        if amount <= 0 {
+            return Err(PoolError::InvalidAmount);
         }
-        env.storage().instance().set(&DataKey::Admin, &admin);
-        env.storage().instance().set(&DataKey::Paused, &false);
-        env.storage().instance().set(
-            &DataKey::WithdrawalCooldown,
-            &Self::DEFAULT_WITHDRAWAL_COOLDOWN,
-        );
-        env.storage()
-            .instance()
-            .set(&DataKey::Version, &Self::CURRENT_VERSION);
-        Self::bump_instance_ttl(&env);
-        Ok(())
-    }
-
-    pub fn version(env: Env) -> u32 {
-        Self::bump_instance_ttl(&env);
-        env.storage().instance().get(&DataKey::Version).unwrap_or(0)
-    }
 
-    pub fn get_admin(env: Env) -> Address {
-        Self::admin(&env)
+        // MaxPoolSize cap uses tracked principal, not pool balance.
+        let max: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey::MaxPoolSize(token.clone()))
+            .unwrap_or(0);
+        if max > 0 {
+            let total = Self::total_deposits(env, token);
+            if total.checked_add(amount).expect("overflow") > max {
+                return Err(PoolError::PoolSizeExceeded);
+            }
+        }
+
+        if Self::allowlist_enabled(env, token) && !Self::is_lender_allowed(env, token, provider) {
+            return Err(PoolError::LenderNotAllowed);
+        }
+
+        // Snapshot pool state *before* the transfer so the share price
+        // reflects the pre-deposit pool composition.
+        let total_assets_before = Self::total_assets(env, token);
+        let cur_total_shares = Self::total_shares(env, token);
+
+        // Per-provider cap covers liquid and locked holdings at current value.
+        let max_position = Self::max_provider_position(env, token);
+        if max_position > 0 {
+            let held_shares = Self::read_shares(env, provider, token)
+                .checked_add(Self::provider_locked_shares(env, provider, token))
+                .expect("overflow");
+            let held_value = if cur_total_shares == 0 {
+                0
+            } else {
+                Self::calc_assets_to_redeem(held_shares, total_assets_before, cur_total_shares)
+            };
+            if held_value.checked_add(amount).expect("overflow") > max_position {
+                return Err(PoolError::PositionLimitExceeded);
+            }
+        }
+
+        let shares_to_mint =
+            Self::calc_shares_to_mint(amount, total_assets_before, cur_total_shares);
+        if shares_to_mint <= 0 {
+            return Err(PoolError::InvalidAmount);
+        }
+
+        TokenClient::new(env, token).transfer(provider, &env.current_contract_address(), &amount);
+
+        let new_total_shares = cur_total_shares
+            .checked_add(shares_to_mint)
+            .expect("total shares overflow");
+        env.storage()
+            .instance()
+            .set(&DataKey::TotalShares(token.clone()), &new_total_shares);
+
+        let new_total_deposits = Self::total_deposits(env, token)
+            .checked_add(amount)
+            .expect("total deposits overflow");
+        env.storage()
+            .instance()
+            .set(&DataKey::TotalDeposits(token.clone()), &new_total_deposits);
+
+        Ok(shares_to_mint)
     }
 
-    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
+    /// Close a locked position, paying out its share value and boost.
+    ///
+    /// Before `unlock_ledger` the exit is only allowed with `allow_early`, in
+    /// which case the early-exit penalty stays in the pool and the pending
+    /// boost is released back to it — both accrue to the remaining LPs.
+    fn close_lock(
+        env: &Env,
+        provider: &Address,
+        lock_id: u32,
+        allow_early: bool,
+    ) -> Result<(), PoolError> {
+        let lock = Self::read_lock(env, lock_id)?;
+        if lock.provider != *provider {
+            return Err(PoolError::ProviderMismatch);
+        }
+
+        let is_early = env.ledger().sequence() < lock.unlock_ledger;
+        if is_early && !allow_early {
+            return Err(PoolError::LockNotMatured);
+        }
+
+        let token = lock.token.clone();
+        let cur_total_shares = Self::total_shares(env, &token);
+        let share_value = Self::calc_assets_to_redeem(
+            lock.shares,
+            Self::total_assets(env, &token),
+            cur_total_shares,
+        );
+        let boost = Self::pending_boost(env, &lock);
+
+        let penalty = if is_early {
+            share_value
+                .checked_mul(lock.penalty_bps as i128)
+                .map(|v| v / 10_000)
+                .expect("penalty overflow")
+        } else {
+            0
+        };
+        let boost_paid = if is_early { 0 } else { boost };
+        let payout = share_value
+            .checked_sub(penalty)
+            .and_then(|v| v.checked_add(boost_paid))
+            .expect("payout overflow");
+
+        env.storage().persistent().remove(&DataKey::Lock(lock_id));
+        let mut provider_locks = Self::read_provider_locks(env, provider, &token);
+        if let Some(idx) = provider_locks.first_index_of(lock_id) {
+            provider_locks.remove(idx);
+        }
+        Self::write_provider_locks(env, provider, &token, &provider_locks);
+
+        let instance = env.storage().instance();
+        instance.set(
+            &DataKey::TotalShares(token.clone()),
+            &cur_total_shares
+                .checked_sub(lock.shares)
+                .expect("total shares underflow"),
+        );
+        instance.set(
+            &DataKey::LockedShares(token.clone()),
+            &Self::read_instance_i128(env, &DataKey::LockedShares(token.clone()))
+                .saturating_sub(lock.shares),
+        );
+        instance.set(
+            &DataKey::TotalLockWeight(token.clone()),
+            &Self::read_instance_i128(env, &DataKey::TotalLockWeight(token.clone()))
+                .saturating_sub(lock.boost_weight),
+        );
+        // Whether paid out or forfeited, the boost leaves the reserve.
+        instance.set(
+            &DataKey::BoostReserve(token.clone()),
+            &Self::boost_reserve(env, &token).saturating_sub(boost),
+        );
+        instance.set(
+            &DataKey::TotalDeposits(token.clone()),
+            &Self::total_deposits(env, &token).saturating_sub(share_value),
+        );
+        Self::bump_instance_ttl(env);
+
+        if payout > 0 {
+            TokenClient::new(env, &token).transfer(
+                &env.current_contract_address(),
+                provider,
+                &payout,
+            );
+        }
+
+        Self::add_realized_yield(
+            env,
+            provider,
+            &token,
+            payout
+                .checked_sub(lock.amount)
+                .expect("realized yield overflow"),
+        );
+
+        lock_withdrawn(
+            env,
+            provider.clone(),
+            token,
+            lock_id,
+            payout,
+            boost_paid,
+            penalty,
+        );
+        Ok(())
+    }
+
+    // ── Admin / lifecycle ─────────────────────────────────────────────────
+
+    pub fn initialize(env: Env, admin: Address) -> Result<(), PoolError> {
+        if env.storage().instance().has(&DataKey::Admin) {
+            return Err(PoolError::AlreadyInitialized);
+        }
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().set(&DataKey::Paused, &false);
+        env.storage().instance().set(
+            &DataKey::WithdrawalCooldown,
+            &Self::DEFAULT_WITHDRAWAL_COOLDOWN,
+        );
+        env.storage()
+            .instance()
+            .set(&DataKey::Version, &Self::CURRENT_VERSION);
+        Self::seed_global_param_limits(&env);
+        Self::log_admin_action(
+            &env,
+            &admin,
+            "initialize",
+            AdminValue::None,
+            AdminValue::Address(admin.clone()),
+        );
+        Self::bump_instance_ttl(&env);
+        Ok(())
+    }
+
+    pub fn version(env: Env) -> u32 {
+        Self::bump_instance_ttl(&env);
+        env.storage().instance().get(&DataKey::Version).unwrap_or(0)
+    }
+
+    pub fn get_admin(env: Env) -> Address {
+        Self::admin(&env)
+    }
+
+    /// Announce an upgrade to `wasm_hash`, executable from ledger `eta`,
+    /// which must be at least `MIN_UPGRADE_DELAY_LEDGERS` away. Replaces
+    /// any upgrade already scheduled.
+    pub fn schedule_upgrade(env: Env, wasm_hash: BytesN<32>, eta: u32) -> Result<(), PoolError> {
+        Self::admin(&env).require_auth();
+        let earliest = env
+            .ledger()
+            .sequence()
+            .saturating_add(Self::MIN_UPGRADE_DELAY_LEDGERS);
+        if eta < earliest {
+            return Err(PoolError::InvalidUpgradeEta);
+        }
+
+        env.storage().instance().set(
+            &DataKey::ScheduledUpgrade,
+            &ScheduledUpgrade {
+                wasm_hash: wasm_hash.clone(),
+                eta,
+            },
+        );
+        Self::bump_instance_ttl(&env);
+        Self::log_admin_action(
+            &env,
+            &Self::admin(&env),
+            "schedule_upgrade",
+            AdminValue::None,
+            AdminValue::Hash(wasm_hash.clone()),
+        );
+        upgrade_scheduled(&env, wasm_hash, eta);
+        Ok(())
+    }
+
+    pub fn cancel_upgrade(env: Env) -> Result<(), PoolError> {
         Self::admin(&env).require_auth();
-        let old_version = Self::version(env.clone());
-        let new_version = old_version.saturating_add(1);
+        let scheduled: ScheduledUpgrade = env
+            .storage()
+            .instance()
+            .get(&DataKey::ScheduledUpgrade)
+            .ok_or(PoolError::NoUpgradeScheduled)?;
+        env.storage().instance().remove(&DataKey::ScheduledUpgrade);
+        Self::log_admin_action(
+            &env,
+            &Self::admin(&env),
+            "cancel_upgrade",
+            AdminValue::Hash(scheduled.wasm_hash.clone()),
+            AdminValue::None,
+        );
+        upgrade_cancelled(&env, scheduled.wasm_hash);
+        Ok(())
+    }
+
+    /// Swap in the scheduled wasm once its eta has passed. `Version` is
+    /// left alone: the new code records its own `CURRENT_VERSION` in
+    /// `migrate`.
+    pub fn execute_upgrade(env: Env) -> Result<(), PoolError> {
+        Self::admin(&env).require_auth();
+        let scheduled: ScheduledUpgrade = env
+            .storage()
+            .instance()
+            .get(&DataKey::ScheduledUpgrade)
+            .ok_or(PoolError::NoUpgradeScheduled)?;
+        if env.ledger().sequence() < scheduled.eta {
+            return Err(PoolError::UpgradeTimelockActive);
+        }
+
+        env.storage().instance().remove(&DataKey::ScheduledUpgrade);
+        Self::log_admin_action(
+            &env,
+            &Self::admin(&env),
+            "execute_upgrade",
+            AdminValue::U32(Self::version(env.clone())),
+            AdminValue::Hash(scheduled.wasm_hash.clone()),
+        );
+        upgrade_executed(
+            &env,
+            Self::version(env.clone()),
+            scheduled.wasm_hash.clone(),
+        );
+        env.deployer()
+            .update_current_contract_wasm(scheduled.wasm_hash);
+        Ok(())
+    }
+
+    pub fn get_scheduled_upgrade(env: Env) -> Option<ScheduledUpgrade> {
+        Self::bump_instance_ttl(&env);
+        env.storage().instance().get(&DataKey::ScheduledUpgrade)
+    }
+
+    /// Advance the pending storage migration by one `MIGRATE_BATCH_SIZE`
+    /// slice from the stored cursor. Call repeatedly until
+    /// `get_migration_status` reports it complete; `migrate_batch` gives
+    /// control over the slice.
+    pub fn migrate(env: Env) -> Result<(), PoolError> {
+        let cursor = Self::migration_cursor(&env);
+        Self::migrate_batch(env, cursor, Self::MIGRATE_BATCH_SIZE)?;
+        Ok(())
+    }
+
+    /// Run the next slice of the pending storage migration.
+    ///
+    /// Steps go one version at a time. Steps that rewrite locked positions
+    /// walk `Lock(id)` from the stored cursor, `limit` ids per call; `start`
+    /// may repeat already-processed ids (steps are idempotent) but may not
+    /// skip ahead of the cursor. Steps that only touch instance config run
+    /// inline. Deposits, withdrawals and lock operations return
+    /// `MigrationPending` until the stored version reaches
+    /// `CURRENT_VERSION`.
+    pub fn migrate_batch(env: Env, start: u32, limit: u32) -> Result<MigrationStatus, PoolError> {
+        Self::admin(&env).require_auth();
+        if limit == 0 {
+            return Err(PoolError::InvalidMigrationBatch);
+        }
+
+        let entry_version = Self::stored_version(&env);
+        let mut version = entry_version;
+        while version < Self::CURRENT_VERSION {
+            if Self::step_migrates_locks(version) {
+                let cursor = Self::migration_cursor(&env);
+                if start > cursor {
+                    return Err(PoolError::MigrationCursorAhead);
+                }
+                let last_lock_id: u32 = env
+                    .storage()
+                    .instance()
+                    .get(&DataKey::LockCounter)
+                    .unwrap_or(0);
+                let end = start
+                    .saturating_add(limit)
+                    .min(last_lock_id.saturating_add(1));
+                for lock_id in start..end {
+                    Self::migrate_lock(&env, version, lock_id);
+                }
+
+                let next_cursor = cursor.max(end);
+                if next_cursor <= last_lock_id {
+                    env.storage()
+                        .instance()
+                        .set(&DataKey::MigrationCursor, &next_cursor);
+                    break;
+                }
+            }
+            version = Self::complete_migration_step(&env, version);
+        }
+        Self::log_admin_action(
+            &env,
+            &Self::admin(&env),
+            "migrate_batch",
+            AdminValue::U32(entry_version),
+            AdminValue::U32(version),
+        );
+        Self::bump_instance_ttl(&env);
+        Ok(Self::migration_status(&env))
+    }
+
+    pub fn get_migration_status(env: Env) -> MigrationStatus {
+        Self::bump_instance_ttl(&env);
+        Self::migration_status(&env)
+    }
+
+    pub fn get_migration_log(env: Env) -> Vec<MigrationRecord> {
+        Self::bump_instance_ttl(&env);
         env.storage()
             .instance()
-            .set(&DataKey::Version, &new_version);
-        env.events().publish(
-            (Symbol::new(&env, "ContractUpgraded"),),
-            (old_version, new_version),
+            .get(&DataKey::MigrationLog)
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Register, tighten, loosen or remove the rate-of-change limit on
+    /// `param`. Registering or tightening applies at once; loosening or
+    /// removing needs a matured `ParamOverride::Limit` for the same limit.
+    pub fn set_param_limit(
+        env: Env,
+        param: PoolParam,
+        limit: Option<ParamLimit>,
+    ) -> Result<(), PoolError> {
+        Self::admin(&env).require_auth();
+        if let Some(limit) = &limit {
+            Self::validate_param_limit(limit)?;
+        }
+
+        let old_limit = Self::read_param_limit(&env, &param);
+        let tightening = match (old_limit.clone(), &limit) {
+            (None, _) => true,
+            (Some(old), Some(new)) => {
+                new.max_change <= old.max_change
+                    && new.max_change_bps <= old.max_change_bps
+                    && new.window_ledgers >= old.window_ledgers
+            }
+            (Some(_), None) => false,
+        };
+        if !tightening {
+            Self::consume_param_override(
+                &env,
+                &param,
+                &match &limit {
+                    Some(limit) => ParamOverride::Limit(limit.clone()),
+                    None => ParamOverride::RemoveLimit,
+                },
+            )?;
+        }
+
+        let key = DataKey::ParamLimit(param.clone());
+        let removed_key = DataKey::ParamLimitRemoved(param.clone());
+        match &limit {
+            Some(limit) => {
+                env.storage().persistent().set(&key, limit);
+                Self::bump_persistent_ttl(&env, &key);
+                env.storage().persistent().remove(&removed_key);
+            }
+            None => {
+                env.storage().persistent().remove(&key);
+                env.storage().persistent().set(&removed_key, &true);
+                Self::bump_persistent_ttl(&env, &removed_key);
+            }
+        }
+        Self::log_admin_action(
+            &env,
+            &Self::admin(&env),
+            "set_param_limit",
+            old_limit.map_or(AdminValue::None, |old| {
+                AdminValue::Limit(param.clone(), old)
+            }),
+            limit.clone().map_or(AdminValue::None, |new| {
+                AdminValue::Limit(param.clone(), new)
+            }),
         );
-        env.deployer().update_current_contract_wasm(new_wasm_hash);
+        param_limit_set(&env, param, limit);
+        Ok(())
+    }
+
+    pub fn get_param_limit(env: Env, param: PoolParam) -> Option<ParamLimit> {
+        Self::read_param_limit(&env, &param)
+    }
+
+    /// Announce a change that exceeds `param`'s limits. Once
+    /// `PARAM_OVERRIDE_DELAY_LEDGERS` have passed, the matching setter (or
+    /// `set_param_limit`) call goes through. Replaces any earlier override
+    /// for the same parameter.
+    pub fn schedule_param_override(
+        env: Env,
+        param: PoolParam,
+        change: ParamOverride,
+    ) -> Result<u32, PoolError> {
+        Self::admin(&env).require_auth();
+        if let ParamOverride::Limit(limit) = &change {
+            Self::validate_param_limit(limit)?;
+        }
+
+        let eta = env
+            .ledger()
+            .sequence()
+            .saturating_add(Self::PARAM_OVERRIDE_DELAY_LEDGERS);
+        let key = DataKey::ParamOverride(param.clone());
+        let replaced: Option<PendingParamOverride> = env.storage().persistent().get(&key);
+        env.storage().persistent().set(
+            &key,
+            &PendingParamOverride {
+                change: change.clone(),
+                eta,
+            },
+        );
+        Self::bump_persistent_ttl(&env, &key);
+        Self::log_admin_action(
+            &env,
+            &Self::admin(&env),
+            "schedule_param_override",
+            replaced.map_or(AdminValue::None, |old| {
+                AdminValue::Override(param.clone(), old.change)
+            }),
+            AdminValue::Override(param.clone(), change.clone()),
+        );
+        param_override_scheduled(&env, param, change, eta);
+        Ok(eta)
+    }
+
+    pub fn cancel_param_override(env: Env, param: PoolParam) -> Result<(), PoolError> {
+        Self::admin(&env).require_auth();
+        let key = DataKey::ParamOverride(param.clone());
+        let pending: PendingParamOverride = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .ok_or(PoolError::NoParamOverride)?;
+        env.storage().persistent().remove(&key);
+        Self::log_admin_action(
+            &env,
+            &Self::admin(&env),
+            "cancel_param_override",
+            AdminValue::Override(param.clone(), pending.change),
+            AdminValue::None,
+        );
+        param_override_cancelled(&env, param);
+        Ok(())
+    }
+
+    pub fn get_param_override(env: Env, param: PoolParam) -> Option<PendingParamOverride> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ParamOverride(param))
     }
 
     pub fn set_max_pool_size(env: Env, token: Address, max: i128) -> Result<(), PoolError> {
@@ -338,27 +1611,54 @@ impl LendingPool {
         }
 
         let old_max = Self::get_max_pool_size(env.clone(), token.clone());
-
-        env.storage()
-            .instance()
-            .set(&DataKey::MaxPoolSize(token.clone()), &max);
+        let param = PoolParam::MaxPoolSize(token.clone());
+        let key = DataKey::MaxPoolSize(token.clone());
+        // A cap configured before limits were seeded picks up its default
+        // now; one set for the first time is limited from then on.
+        if env.storage().instance().has(&key) {
+            Self::seed_param_limit(&env, &param);
+        }
+        Self::check_param_change(&env, param.clone(), old_max, max)?;
+        env.storage().instance().set(&key, &max);
         Self::bump_instance_ttl(&env);
+        Self::seed_param_limit(&env, &param);
 
+        Self::log_admin_action(
+            &env,
+            &Self::admin(&env),
+            "set_max_pool_size",
+            AdminValue::TokenAmount(token.clone(), old_max),
+            AdminValue::TokenAmount(token.clone(), max),
+        );
         deposit_cap_updated(&env, token, old_max, max);
         Ok(())
     }
 
-    pub fn set_withdrawal_cooldown(env: Env, ledgers: u32) {
+    pub fn set_withdrawal_cooldown(env: Env, ledgers: u32) -> Result<(), PoolError> {
         Self::admin(&env).require_auth();
 
         let old_cooldown = Self::get_withdrawal_cooldown(env.clone());
 
+        Self::check_param_change(
+            &env,
+            PoolParam::WithdrawalCooldown,
+            old_cooldown as i128,
+            ledgers as i128,
+        )?;
         env.storage()
             .instance()
             .set(&DataKey::WithdrawalCooldown, &ledgers);
         Self::bump_instance_ttl(&env);
 
+        Self::log_admin_action(
+            &env,
+            &Self::admin(&env),
+            "set_withdrawal_cooldown",
+            AdminValue::U32(old_cooldown),
+            AdminValue::U32(ledgers),
+        );
         withdrawal_cooldown_updated(&env, old_cooldown, ledgers);
+        Ok(())
     }
 
     pub fn get_max_pool_size(env: Env, token: Address) -> i128 {
@@ -369,16 +1669,251 @@ impl LendingPool {
             .unwrap_or(0)
     }
 
+    /// Caps the asset value any single provider may hold in the `token` pool,
+    /// counting both liquid and locked shares. `0` removes the limit.
+    ///
+    /// Only new deposits are checked; positions already above a lowered cap
+    /// are not forced out.
+    pub fn set_max_provider_position(env: Env, token: Address, max: i128) -> Result<(), PoolError> {
+        Self::admin(&env).require_auth();
+        if max < 0 {
+            return Err(PoolError::InvalidMaxPoolSize);
+        }
+
+        let old_max = Self::max_provider_position(&env, &token);
+        let param = PoolParam::MaxProviderPosition(token.clone());
+        let key = DataKey::MaxProviderPosition(token.clone());
+        if env.storage().instance().has(&key) {
+            Self::seed_param_limit(&env, &param);
+        }
+        Self::check_param_change(&env, param.clone(), old_max, max)?;
+        env.storage().instance().set(&key, &max);
+        Self::bump_instance_ttl(&env);
+        Self::seed_param_limit(&env, &param);
+
+        Self::log_admin_action(
+            &env,
+            &Self::admin(&env),
+            "set_max_provider_position",
+            AdminValue::TokenAmount(token.clone(), old_max),
+            AdminValue::TokenAmount(token.clone(), max),
+        );
+        max_provider_position_updated(&env, token, old_max, max);
+        Ok(())
+    }
+
+    pub fn get_max_provider_position(env: Env, token: Address) -> i128 {
+        Self::bump_instance_ttl(&env);
+        Self::max_provider_position(&env, &token)
+    }
+
+    /// Restricts deposits into the `token` pool to allowlisted lenders.
+    /// Existing holders can always withdraw.
+    pub fn set_allowlist_enabled(env: Env, token: Address, enabled: bool) {
+        Self::admin(&env).require_auth();
+
+        Self::log_admin_action(
+            &env,
+            &Self::admin(&env),
+            "set_allowlist_enabled",
+            AdminValue::TokenFlag(
+                token.clone(),
+                env.storage()
+                    .instance()
+                    .get(&DataKey::AllowlistEnabled(token.clone()))
+                    .unwrap_or(false),
+            ),
+            AdminValue::TokenFlag(token.clone(), enabled),
+        );
+        env.storage()
+            .instance()
+            .set(&DataKey::AllowlistEnabled(token.clone()), &enabled);
+        Self::bump_instance_ttl(&env);
+
+        allowlist_toggled(&env, token, enabled);
+    }
+
+    pub fn is_allowlist_enabled(env: Env, token: Address) -> bool {
+        Self::bump_instance_ttl(&env);
+        Self::allowlist_enabled(&env, &token)
+    }
+
+    pub fn add_allowed_lender(env: Env, token: Address, lender: Address) {
+        Self::admin(&env).require_auth();
+
+        let key = DataKey::AllowedLender(token.clone(), lender.clone());
+        env.storage().persistent().set(&key, &true);
+        Self::bump_persistent_ttl(&env, &key);
+        Self::bump_instance_ttl(&env);
+
+        Self::log_admin_action(
+            &env,
+            &Self::admin(&env),
+            "add_allowed_lender",
+            AdminValue::None,
+            AdminValue::Lender(token.clone(), lender.clone()),
+        );
+        lender_allowed(&env, token, lender);
+    }
+
+    pub fn remove_allowed_lender(env: Env, token: Address, lender: Address) {
+        Self::admin(&env).require_auth();
+
+        env.storage()
+            .persistent()
+            .remove(&DataKey::AllowedLender(token.clone(), lender.clone()));
+        Self::bump_instance_ttl(&env);
+
+        Self::log_admin_action(
+            &env,
+            &Self::admin(&env),
+            "remove_allowed_lender",
+            AdminValue::Lender(token.clone(), lender.clone()),
+            AdminValue::None,
+        );
+        lender_removed(&env, token, lender);
+    }
+
+    pub fn is_allowed_lender(env: Env, token: Address, lender: Address) -> bool {
+        Self::is_lender_allowed(&env, &token, &lender)
+    }
+
     pub fn get_total_deposits(env: Env, token: Address) -> i128 {
         Self::total_deposits(&env, &token)
     }
 
-    pub fn get_total_shares(env: Env, token: Address) -> i128 {
-        Self::total_shares(&env, &token)
+    pub fn get_total_shares(env: Env, token: Address) -> i128 {
+        Self::total_shares(&env, &token)
+    }
+
+    pub fn get_withdrawal_cooldown(env: Env) -> u32 {
+        Self::withdrawal_cooldown(&env)
+    }
+
+    pub fn set_loan_manager(env: Env, loan_manager: Address) {
+        Self::admin(&env).require_auth();
+
+        let old_manager = env.storage().instance().get(&DataKey::LoanManager);
+        env.storage()
+            .instance()
+            .set(&DataKey::LoanManager, &loan_manager);
+        Self::bump_instance_ttl(&env);
+
+        Self::log_admin_action(
+            &env,
+            &Self::admin(&env),
+            "set_loan_manager",
+            old_manager
+                .clone()
+                .map_or(AdminValue::None, AdminValue::Address),
+            AdminValue::Address(loan_manager.clone()),
+        );
+        loan_manager_updated(&env, old_manager, loan_manager);
+    }
+
+    pub fn get_loan_manager(env: Env) -> Option<Address> {
+        Self::bump_instance_ttl(&env);
+        env.storage().instance().get(&DataKey::LoanManager)
+    }
+
+    /// Offer a lock term of `term_ledgers` with a `boost_bps` weight
+    /// multiplier (10_000 = 1x). A `boost_bps` of 0 withdraws the term for
+    /// new locks; existing locks are unaffected.
+    pub fn set_lock_term(env: Env, term_ledgers: u32, boost_bps: u32) -> Result<(), PoolError> {
+        Self::admin(&env).require_auth();
+        if term_ledgers == 0 {
+            return Err(PoolError::InvalidLockTerm);
+        }
+
+        let key = DataKey::LockTermBoost(term_ledgers);
+        let param = PoolParam::LockTermBoost(term_ledgers);
+        let old_boost: Option<u32> = env.storage().instance().get(&key);
+        if old_boost.is_some() {
+            Self::seed_param_limit(&env, &param);
+        }
+        let old_boost = old_boost.unwrap_or(0);
+        Self::check_param_change(&env, param.clone(), old_boost as i128, boost_bps as i128)?;
+        if boost_bps == 0 {
+            env.storage().instance().remove(&key);
+        } else {
+            env.storage().instance().set(&key, &boost_bps);
+            Self::seed_param_limit(&env, &param);
+        }
+        Self::bump_instance_ttl(&env);
+
+        Self::log_admin_action(
+            &env,
+            &Self::admin(&env),
+            "set_lock_term",
+            AdminValue::LockTerm(term_ledgers, old_boost),
+            AdminValue::LockTerm(term_ledgers, boost_bps),
+        );
+        lock_term_updated(&env, term_ledgers, old_boost, boost_bps);
+        Ok(())
+    }
+
+    /// Boost multiplier for `term_ledgers`, or 0 if the term is not offered.
+    pub fn get_lock_term_boost(env: Env, term_ledgers: u32) -> u32 {
+        Self::bump_instance_ttl(&env);
+        env.storage()
+            .instance()
+            .get(&DataKey::LockTermBoost(term_ledgers))
+            .unwrap_or(0)
+    }
+
+    pub fn set_boost_share_bps(env: Env, bps: u32) -> Result<(), PoolError> {
+        Self::admin(&env).require_auth();
+        Self::validate_bps(bps)?;
+
+        let old_bps = Self::boost_share_bps(&env);
+        Self::check_param_change(&env, PoolParam::BoostShareBps, old_bps as i128, bps as i128)?;
+        env.storage().instance().set(&DataKey::BoostShareBps, &bps);
+        Self::bump_instance_ttl(&env);
+
+        Self::log_admin_action(
+            &env,
+            &Self::admin(&env),
+            "set_boost_share_bps",
+            AdminValue::U32(old_bps),
+            AdminValue::U32(bps),
+        );
+        boost_share_updated(&env, old_bps, bps);
+        Ok(())
+    }
+
+    pub fn get_boost_share_bps(env: Env) -> u32 {
+        Self::boost_share_bps(&env)
+    }
+
+    pub fn set_early_exit_penalty_bps(env: Env, bps: u32) -> Result<(), PoolError> {
+        Self::admin(&env).require_auth();
+        Self::validate_bps(bps)?;
+
+        let old_bps = Self::early_exit_penalty_bps(&env);
+        Self::check_param_change(
+            &env,
+            PoolParam::EarlyExitPenaltyBps,
+            old_bps as i128,
+            bps as i128,
+        )?;
+        env.storage()
+            .instance()
+            .set(&DataKey::EarlyExitPenaltyBps, &bps);
+        Self::bump_instance_ttl(&env);
+
+        Self::log_admin_action(
+            &env,
+            &Self::admin(&env),
+            "set_early_exit_penalty_bps",
+            AdminValue::U32(old_bps),
+            AdminValue::U32(bps),
+        );
+        early_exit_penalty_updated(&env, old_bps, bps);
+        Ok(())
     }
 
-    pub fn get_withdrawal_cooldown(env: Env) -> u32 {
-        Self::withdrawal_cooldown(&env)
+    pub fn get_early_exit_penalty_bps(env: Env) -> u32 {
+        Self::early_exit_penalty_bps(&env)
     }
 
     // ── Core pool operations ──────────────────────────────────────────────
@@ -396,41 +1931,11 @@ impl LendingPool {
         amount: i128,
     ) -> Result<(), PoolError> {
         provider.require_auth();
-        Self::assert_not_paused(&env)?;
-
-        if amount <= 0 {
-            return Err(PoolError::InvalidAmount);
-        }
-
-        // MaxPoolSize cap uses tracked principal, not pool balance.
-        let max: i128 = env
-            .storage()
-            .instance()
-            .get(&DataKey::MaxPoolSize(token.clone()))
-            .unwrap_or(0);
-        if max > 0 {
-            let total = Self::total_deposits(&env, &token);
-            if total.checked_add(amount).expect("overflow") > max {
-                return Err(PoolError::PoolSizeExceeded);
-            }
-        }
-
-        // Snapshot pool state *before* the transfer so the share price
-        // reflects the pre-deposit pool composition.
-        let total_assets_before = Self::read_pool_balance(&env, &token);
-        let cur_total_shares = Self::total_shares(&env, &token);
-
-        let shares_to_mint =
-            Self::calc_shares_to_mint(amount, total_assets_before, cur_total_shares);
-        if shares_to_mint <= 0 {
-            return Err(PoolError::InvalidAmount);
-        }
+        Self::assert_operation_active(&env, PoolOperation::Deposits)?;
 
-        TokenClient::new(&env, &token).transfer(
-            &provider,
-            &env.current_contract_address(),
-            &amount,
-        );
+        let shares_to_mint = Self::mint_shares(&env, &provider, &token, amount)?;
+        // Read before the new shares land so a legacy position stays unknown.
+        let cost_basis = Self::read_cost_basis(&env, &provider, &token);
 
         // Track new depositors.
         let existing_shares = Self::read_shares(&env, &provider, &token);
@@ -454,19 +1959,14 @@ impl LendingPool {
             .set(&deposit_key, &current_ledger);
         Self::bump_persistent_ttl(&env, &deposit_key);
 
-        let new_total_shares = cur_total_shares
-            .checked_add(shares_to_mint)
-            .expect("total shares overflow");
-        env.storage()
-            .instance()
-            .set(&DataKey::TotalShares(token.clone()), &new_total_shares);
-
-        let new_total_deposits = Self::total_deposits(&env, &token)
-            .checked_add(amount)
-            .expect("total deposits overflow");
-        env.storage()
-            .instance()
-            .set(&DataKey::TotalDeposits(token.clone()), &new_total_deposits);
+        if let Some(cost_basis) = cost_basis {
+            let cost_basis = cost_basis.checked_add(amount).expect("cost basis overflow");
+            Self::write_persistent_i128(
+                &env,
+                &DataKey::CostBasis(provider.clone(), token.clone()),
+                cost_basis,
+            );
+        }
 
         Self::bump_instance_ttl(&env);
         deposit(
@@ -481,9 +1981,8 @@ impl LendingPool {
 
     /// Returns `(shares, current_asset_value)` for `provider` in the `token` pool.
     ///
-    /// Net yield = `current_asset_value - original_deposit`.  Since original
-    /// deposit amounts are not stored per-depositor, callers derive yield by
-    /// comparing `current_asset_value` against their own recorded cost basis.
+    /// See `get_position` for the same figures alongside the tracked cost
+    /// basis and realised / unrealised yield.
     pub fn get_depositor_yield(env: Env, provider: Address, token: Address) -> (i128, i128) {
         let shares = Self::read_shares(&env, &provider, &token);
         if shares == 0 {
@@ -493,14 +1992,31 @@ impl LendingPool {
         if cur_total_shares == 0 {
             return (shares, 0);
         }
-        let asset_value = Self::calc_assets_to_redeem(
-            shares,
-            Self::read_pool_balance(&env, &token),
-            cur_total_shares,
-        );
+        let asset_value =
+            Self::calc_assets_to_redeem(shares, Self::total_assets(&env, &token), cur_total_shares);
         (shares, asset_value)
     }
 
+    /// Full profit-and-loss view of `provider`'s liquid position in `token`.
+    ///
+    /// Positions opened before cost-basis tracking existed carry no recorded
+    /// basis and report `None` for it and for the unrealised yield.
+    pub fn get_position(env: Env, provider: Address, token: Address) -> DepositorPosition {
+        let shares = Self::read_shares(&env, &provider, &token);
+        let value = Self::get_deposit(env.clone(), provider.clone(), token.clone());
+        let cost_basis = Self::read_cost_basis(&env, &provider, &token);
+        let realized_pnl =
+            Self::read_persistent_i128(&env, &DataKey::RealizedYield(provider, token));
+
+        DepositorPosition {
+            shares,
+            value,
+            cost_basis,
+            unrealized_pnl: cost_basis.map(|basis| value.checked_sub(basis).expect("pnl overflow")),
+            realized_pnl,
+        }
+    }
+
     /// Underlying asset value of `provider`'s LP shares (principal + yield).
     pub fn get_deposit(env: Env, provider: Address, token: Address) -> i128 {
         let shares = Self::read_shares(&env, &provider, &token);
@@ -511,11 +2027,7 @@ impl LendingPool {
         if cur_total_shares == 0 {
             return 0;
         }
-        Self::calc_assets_to_redeem(
-            shares,
-            Self::read_pool_balance(&env, &token),
-            cur_total_shares,
-        )
+        Self::calc_assets_to_redeem(shares, Self::total_assets(&env, &token), cur_total_shares)
     }
 
     /// Raw LP share balance for `provider` in the `token` pool.
@@ -535,7 +2047,7 @@ impl LendingPool {
         shares: i128,
     ) -> Result<(), PoolError> {
         provider.require_auth();
-        Self::assert_not_paused(&env)?;
+        Self::assert_operation_active(&env, PoolOperation::Withdrawals)?;
         Self::assert_withdrawal_cooldown_elapsed(&env, &provider, &token);
         Self::redeem_shares(&env, &provider, &token, shares)
     }
@@ -550,6 +2062,212 @@ impl LendingPool {
         Self::redeem_shares(&env, &provider, &token, shares)
     }
 
+    // ── Time-locked deposits ──────────────────────────────────────────────
+
+    /// Deposit `amount` of `token` locked for `term_ledgers`.
+    ///
+    /// The term must be one configured via `set_lock_term`; its boost
+    /// multiplier sets the position's weight in the boost reserve. Returns
+    /// the id of the new locked position.
+    pub fn deposit_locked(
+        env: Env,
+        provider: Address,
+        token: Address,
+        amount: i128,
+        term_ledgers: u32,
+    ) -> Result<u32, PoolError> {
+        provider.require_auth();
+        Self::assert_operation_active(&env, PoolOperation::Deposits)?;
+
+        let boost_bps: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::LockTermBoost(term_ledgers))
+            .ok_or(PoolError::InvalidLockTerm)?;
+
+        let shares = Self::mint_shares(&env, &provider, &token, amount)?;
+        let boost_weight = shares
+            .checked_mul(boost_bps as i128)
+            .map(|v| v / 10_000)
+            .expect("boost weight overflow");
+        let acc = Self::read_instance_i128(&env, &DataKey::AccBoostPerWeight(token.clone()));
+
+        let current_ledger = env.ledger().sequence();
+        let lock = LockedPosition {
+            provider: provider.clone(),
+            token: token.clone(),
+            amount,
+            shares,
+            boost_weight,
+            boost_debt: boost_weight
+                .checked_mul(acc)
+                .map(|v| v / Self::BOOST_PRECISION)
+                .expect("boost overflow"),
+            locked_at: current_ledger,
+            unlock_ledger: current_ledger.saturating_add(term_ledgers),
+            penalty_bps: Self::early_exit_penalty_bps(&env),
+        };
+
+        let lock_id = env
+            .storage()
+            .instance()
+            .get::<_, u32>(&DataKey::LockCounter)
+            .unwrap_or(0)
+            .checked_add(1)
+            .expect("lock id overflow");
+        let instance = env.storage().instance();
+        instance.set(&DataKey::LockCounter, &lock_id);
+        instance.set(
+            &DataKey::LockedShares(token.clone()),
+            &Self::read_instance_i128(&env, &DataKey::LockedShares(token.clone()))
+                .checked_add(shares)
+                .expect("locked shares overflow"),
+        );
+        instance.set(
+            &DataKey::TotalLockWeight(token.clone()),
+            &Self::read_instance_i128(&env, &DataKey::TotalLockWeight(token.clone()))
+                .checked_add(boost_weight)
+                .expect("lock weight overflow"),
+        );
+
+        let lock_key = DataKey::Lock(lock_id);
+        env.storage().persistent().set(&lock_key, &lock);
+        Self::bump_persistent_ttl(&env, &lock_key);
+
+        let mut provider_locks = Self::read_provider_locks(&env, &provider, &token);
+        provider_locks.push_back(lock_id);
+        Self::write_provider_locks(&env, &provider, &token, &provider_locks);
+
+        Self::bump_instance_ttl(&env);
+        lock_created(
+            &env,
+            provider,
+            token,
+            lock_id,
+            amount,
+            shares,
+            lock.unlock_ledger,
+        );
+        Ok(lock_id)
+    }
+
+    /// Withdraw a matured locked position: its share value plus the boost
+    /// it accrued.
+    pub fn withdraw_locked(env: Env, provider: Address, lock_id: u32) -> Result<(), PoolError> {
+        provider.require_auth();
+        Self::assert_operation_active(&env, PoolOperation::Withdrawals)?;
+        Self::close_lock(&env, &provider, lock_id, false)
+    }
+
+    /// Exit a locked position before `unlock_ledger`.
+    ///
+    /// The early-exit penalty is deducted from the share value and the
+    /// accrued boost is forfeited; both stay in the pool for remaining LPs.
+    /// After maturity this behaves exactly like `withdraw_locked`.
+    pub fn exit_lock_early(env: Env, provider: Address, lock_id: u32) -> Result<(), PoolError> {
+        provider.require_auth();
+        Self::assert_operation_active(&env, PoolOperation::Withdrawals)?;
+        Self::close_lock(&env, &provider, lock_id, true)
+    }
+
+    /// Report interest income that has just been paid into the pool.
+    ///
+    /// Only the configured LoanManager may call this. `BoostShareBps` of the
+    /// amount is moved into the boost reserve for locked positions; the rest
+    /// stays in the share price. A no-op until a LoanManager is set, and
+    /// nothing is diverted while no locks are open.
+    pub fn record_interest(env: Env, token: Address, amount: i128) -> Result<(), PoolError> {
+        if amount <= 0 {
+            return Err(PoolError::InvalidAmount);
+        }
+        let Some(loan_manager) = env
+            .storage()
+            .instance()
+            .get::<_, Address>(&DataKey::LoanManager)
+        else {
+            return Ok(());
+        };
+        loan_manager.require_auth();
+
+        let total_weight = Self::read_instance_i128(&env, &DataKey::TotalLockWeight(token.clone()));
+        let boost_cut = if total_weight > 0 {
+            amount
+                .checked_mul(Self::boost_share_bps(&env) as i128)
+                .map(|v| v / 10_000)
+                .expect("boost cut overflow")
+        } else {
+            0
+        };
+
+        if boost_cut > 0 {
+            let reserve_key = DataKey::BoostReserve(token.clone());
+            let acc_key = DataKey::AccBoostPerWeight(token.clone());
+            let acc_delta = boost_cut
+                .checked_mul(Self::BOOST_PRECISION)
+                .map(|v| v / total_weight)
+                .expect("boost accumulator overflow");
+            env.storage().instance().set(
+                &reserve_key,
+                &Self::read_instance_i128(&env, &reserve_key)
+                    .checked_add(boost_cut)
+                    .expect("boost reserve overflow"),
+            );
+            env.storage().instance().set(
+                &acc_key,
+                &Self::read_instance_i128(&env, &acc_key)
+                    .checked_add(acc_delta)
+                    .expect("boost accumulator overflow"),
+            );
+            Self::bump_instance_ttl(&env);
+        }
+
+        interest_recorded(&env, token, amount, boost_cut);
+        Ok(())
+    }
+
+    pub fn get_lock(env: Env, lock_id: u32) -> Option<LockedPosition> {
+        Self::read_lock(&env, lock_id).ok()
+    }
+
+    /// Ids of `provider`'s open locked positions in the `token` pool.
+    pub fn get_locks(env: Env, provider: Address, token: Address) -> Vec<u32> {
+        Self::read_provider_locks(&env, &provider, &token)
+    }
+
+    /// Boost accrued so far by a locked position; paid out only at maturity.
+    pub fn get_pending_boost(env: Env, lock_id: u32) -> i128 {
+        Self::read_lock(&env, lock_id)
+            .map(|lock| Self::pending_boost(&env, &lock))
+            .unwrap_or(0)
+    }
+
+    /// Current asset value of all of `provider`'s locked shares, excluding
+    /// pending boost.
+    pub fn get_locked_balance(env: Env, provider: Address, token: Address) -> i128 {
+        let cur_total_shares = Self::total_shares(&env, &token);
+        if cur_total_shares == 0 {
+            return 0;
+        }
+        Self::calc_assets_to_redeem(
+            Self::provider_locked_shares(&env, &provider, &token),
+            Self::total_assets(&env, &token),
+            cur_total_shares,
+        )
+    }
+
+    /// Asset value of `provider`'s freely withdrawable (unlocked) shares.
+    pub fn get_liquid_balance(env: Env, provider: Address, token: Address) -> i128 {
+        Self::get_deposit(env, provider, token)
+    }
+
+    pub fn get_total_locked_shares(env: Env, token: Address) -> i128 {
+        Self::read_instance_i128(&env, &DataKey::LockedShares(token))
+    }
+
+    pub fn get_boost_reserve(env: Env, token: Address) -> i128 {
+        Self::boost_reserve(&env, &token)
+    }
+
     // ── Queries ───────────────────────────────────────────────────────────
 
     pub fn get_pool_stats(env: Env, token: Address) -> PoolStats {
@@ -576,6 +2294,37 @@ impl LendingPool {
 
     // ── Admin governance ──────────────────────────────────────────────────
 
+    /// Up to `limit` admin audit log entries starting at index `cursor`,
+    /// oldest first. `limit` is capped at `MAX_ADMIN_LOG_LIMIT`.
+    pub fn get_admin_actions(env: Env, cursor: u32, limit: u32) -> Vec<AdminAction> {
+        let len = Self::admin_log_len(&env);
+        let mut entries = Vec::new(&env);
+        if cursor >= len {
+            return entries;
+        }
+        let end = cursor
+            .saturating_add(limit.min(Self::MAX_ADMIN_LOG_LIMIT))
+            .min(len);
+
+        let mut page_index = cursor / Self::ADMIN_LOG_PAGE_SIZE;
+        let mut page = Self::read_admin_log_page(&env, page_index);
+        for idx in cursor..end {
+            if idx / Self::ADMIN_LOG_PAGE_SIZE != page_index {
+                page_index = idx / Self::ADMIN_LOG_PAGE_SIZE;
+                page = Self::read_admin_log_page(&env, page_index);
+            }
+            entries.push_back(
+                page.get(idx % Self::ADMIN_LOG_PAGE_SIZE)
+                    .expect("admin log page truncated"),
+            );
+        }
+        entries
+    }
+
+    pub fn get_admin_action_count(env: Env) -> u32 {
+        Self::admin_log_len(&env)
+    }
+
     pub fn propose_admin(env: Env, new_admin: Address) {
         let current_admin = Self::admin(&env);
         current_admin.require_auth();
@@ -585,6 +2334,13 @@ impl LendingPool {
             .set(&DataKey::ProposedAdmin, &new_admin);
         Self::bump_instance_ttl(&env);
 
+        Self::log_admin_action(
+            &env,
+            &current_admin,
+            "propose_admin",
+            AdminValue::Address(current_admin.clone()),
+            AdminValue::Address(new_admin.clone()),
+        );
         admin_proposed(&env, current_admin.clone(), new_admin.clone());
     }
 
@@ -596,6 +2352,13 @@ impl LendingPool {
             .ok_or(PoolError::NoProposedAdmin)?;
         proposed_admin.require_auth();
 
+        Self::log_admin_action(
+            &env,
+            &proposed_admin,
+            "accept_admin",
+            AdminValue::Address(Self::admin(&env)),
+            AdminValue::Address(proposed_admin.clone()),
+        );
         env.storage()
             .instance()
             .set(&DataKey::Admin, &proposed_admin);
@@ -610,6 +2373,13 @@ impl LendingPool {
         let current_admin = Self::admin(&env);
         current_admin.require_auth();
 
+        Self::log_admin_action(
+            &env,
+            &current_admin,
+            "set_admin",
+            AdminValue::Address(current_admin.clone()),
+            AdminValue::Address(new_admin.clone()),
+        );
         env.storage().instance().set(&DataKey::Admin, &new_admin);
         env.storage().instance().remove(&DataKey::ProposedAdmin);
         Self::bump_instance_ttl(&env);
@@ -619,7 +2389,17 @@ impl LendingPool {
 
     pub fn pause(env: Env) {
         Self::admin(&env).require_auth();
+        Self::log_admin_action(
+            &env,
+            &Self::admin(&env),
+            "pause",
+            AdminValue::Bool(Self::paused_now(&env)),
+            AdminValue::Bool(true),
+        );
         env.storage().instance().set(&DataKey::Paused, &true);
+        env.storage()
+            .instance()
+            .remove(&DataKey::GuardianPauseExpiry);
         Self::bump_instance_ttl(&env);
 
         pool_paused(&env);
@@ -627,18 +2407,227 @@ impl LendingPool {
 
     pub fn unpause(env: Env) {
         Self::admin(&env).require_auth();
+        Self::log_admin_action(
+            &env,
+            &Self::admin(&env),
+            "unpause",
+            AdminValue::Bool(Self::paused_now(&env)),
+            AdminValue::Bool(false),
+        );
         env.storage().instance().set(&DataKey::Paused, &false);
+        env.storage()
+            .instance()
+            .remove(&DataKey::GuardianPauseExpiry);
         Self::bump_instance_ttl(&env);
 
         pool_unpaused(&env);
     }
 
-    pub fn is_paused(env: Env) -> bool {
+    /// Record the governance contract that appoints the guardian. The admin
+    /// names it once; after that only the current governance can move it.
+    pub fn set_governance(env: Env, governance: Address) {
+        let current: Option<Address> = env.storage().instance().get(&DataKey::Governance);
+        let actor = current.clone().unwrap_or_else(|| Self::admin(&env));
+        actor.require_auth();
+        Self::log_admin_action(
+            &env,
+            &actor,
+            "set_governance",
+            current.map_or(AdminValue::None, AdminValue::Address),
+            AdminValue::Address(governance.clone()),
+        );
+        env.storage()
+            .instance()
+            .set(&DataKey::Governance, &governance);
+        Self::bump_instance_ttl(&env);
+        governance_set(&env, governance);
+    }
+
+    pub fn get_governance(env: Env) -> Option<Address> {
+        Self::bump_instance_ttl(&env);
+        env.storage().instance().get(&DataKey::Governance)
+    }
+
+    /// Name the guardian allowed to call `guardian_pause`, or clear it with
+    /// `None`. Only the governance recorded by `set_governance` may do this;
+    /// the admin alone cannot appoint the account that can pause it.
+    pub fn set_guardian(env: Env, guardian: Option<Address>) -> Result<(), PoolError> {
+        let governance: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Governance)
+            .ok_or(PoolError::GovernanceNotSet)?;
+        governance.require_auth();
+        Self::log_admin_action(
+            &env,
+            &governance,
+            "set_guardian",
+            env.storage()
+                .instance()
+                .get::<DataKey, Address>(&DataKey::Guardian)
+                .map_or(AdminValue::None, AdminValue::Address),
+            guardian
+                .clone()
+                .map_or(AdminValue::None, AdminValue::Address),
+        );
+        match &guardian {
+            Some(guardian) => env.storage().instance().set(&DataKey::Guardian, guardian),
+            None => env.storage().instance().remove(&DataKey::Guardian),
+        }
+        Self::bump_instance_ttl(&env);
+        guardian_set(&env, guardian);
+        Ok(())
+    }
+
+    pub fn get_guardian(env: Env) -> Option<Address> {
         Self::bump_instance_ttl(&env);
+        env.storage().instance().get(&DataKey::Guardian)
+    }
+
+    pub fn set_guardian_pause_ledgers(env: Env, ledgers: u32) -> Result<(), PoolError> {
+        Self::admin(&env).require_auth();
+        if ledgers == 0 {
+            return Err(PoolError::InvalidPauseWindow);
+        }
+        let old_ledgers = Self::get_guardian_pause_ledgers(env.clone());
+        Self::check_param_change(
+            &env,
+            PoolParam::GuardianPauseLedgers,
+            old_ledgers as i128,
+            ledgers as i128,
+        )?;
         env.storage()
             .instance()
-            .get(&DataKey::Paused)
-            .unwrap_or(false)
+            .set(&DataKey::GuardianPauseLedgers, &ledgers);
+        Self::bump_instance_ttl(&env);
+        Self::log_admin_action(
+            &env,
+            &Self::admin(&env),
+            "set_guardian_pause_ledgers",
+            AdminValue::U32(old_ledgers),
+            AdminValue::U32(ledgers),
+        );
+        guardian_pause_ledgers_updated(&env, ledgers);
+        Ok(())
+    }
+
+    pub fn get_guardian_pause_ledgers(env: Env) -> u32 {
+        Self::bump_instance_ttl(&env);
+        env.storage()
+            .instance()
+            .get(&DataKey::GuardianPauseLedgers)
+            .unwrap_or(Self::DEFAULT_GUARDIAN_PAUSE_LEDGERS)
+    }
+
+    /// Pause immediately on the guardian's authority. The pause lapses after
+    /// `get_guardian_pause_ledgers` ledgers unless the admin ratifies it; the
+    /// guardian has no way to unpause. Returns the expiry ledger.
+    ///
+    /// The guardian gets one pause per governance decision: once it lapses,
+    /// the admin has to pause, unpause or ratify before it can pause again.
+    pub fn guardian_pause(env: Env) -> Result<u32, PoolError> {
+        let guardian: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Guardian)
+            .ok_or(PoolError::GuardianNotSet)?;
+        guardian.require_auth();
+        if Self::paused_now(&env) {
+            return Err(PoolError::AlreadyPaused);
+        }
+        // A lapsed guardian pause keeps its expiry until the admin acts.
+        if env.storage().instance().has(&DataKey::GuardianPauseExpiry) {
+            return Err(PoolError::GuardianPauseSpent);
+        }
+
+        let expiry = env
+            .ledger()
+            .sequence()
+            .saturating_add(Self::get_guardian_pause_ledgers(env.clone()));
+        env.storage().instance().set(&DataKey::Paused, &true);
+        env.storage()
+            .instance()
+            .set(&DataKey::GuardianPauseExpiry, &expiry);
+        Self::bump_instance_ttl(&env);
+        Self::log_admin_action(
+            &env,
+            &guardian,
+            "guardian_pause",
+            AdminValue::None,
+            AdminValue::U32(expiry),
+        );
+        guardian_paused(&env, guardian, expiry);
+        Ok(expiry)
+    }
+
+    /// Turn the active guardian pause into an indefinite one. Admin only.
+    pub fn ratify_pause(env: Env) -> Result<(), PoolError> {
+        Self::admin(&env).require_auth();
+        let expiry =
+            Self::get_guardian_pause_expiry(env.clone()).ok_or(PoolError::NoGuardianPause)?;
+        env.storage()
+            .instance()
+            .remove(&DataKey::GuardianPauseExpiry);
+        Self::bump_instance_ttl(&env);
+        Self::log_admin_action(
+            &env,
+            &Self::admin(&env),
+            "ratify_pause",
+            AdminValue::U32(expiry),
+            AdminValue::None,
+        );
+        pause_ratified(&env, expiry);
+        Ok(())
+    }
+
+    /// Expiry ledger of the guardian pause currently in force, if any.
+    pub fn get_guardian_pause_expiry(env: Env) -> Option<u32> {
+        Self::bump_instance_ttl(&env);
+        if !Self::paused_now(&env) {
+            return None;
+        }
+        env.storage().instance().get(&DataKey::GuardianPauseExpiry)
+    }
+
+    /// Pause a single operation without touching the rest of the pool.
+    /// `emergency_withdraw` stays available either way.
+    pub fn pause_operation(env: Env, operation: PoolOperation) {
+        Self::set_operation_paused(&env, operation, true);
+    }
+
+    pub fn unpause_operation(env: Env, operation: PoolOperation) {
+        Self::set_operation_paused(&env, operation, false);
+    }
+
+    /// The operation's own flag; the global pause is reported by `is_paused`.
+    pub fn is_operation_paused(env: Env, operation: PoolOperation) -> bool {
+        Self::bump_instance_ttl(&env);
+        Self::operation_paused(&env, operation)
+    }
+
+    fn set_operation_paused(env: &Env, operation: PoolOperation, paused: bool) {
+        Self::admin(env).require_auth();
+        Self::log_admin_action(
+            env,
+            &Self::admin(env),
+            if paused {
+                "pause_operation"
+            } else {
+                "unpause_operation"
+            },
+            AdminValue::Operation(operation, Self::operation_paused(env, operation)),
+            AdminValue::Operation(operation, paused),
+        );
+        env.storage()
+            .instance()
+            .set(&DataKey::OperationPaused(operation), &paused);
+        Self::bump_instance_ttl(env);
+        operation_pause_set(env, operation, paused);
+    }
+
+    pub fn is_paused(env: Env) -> bool {
+        Self::bump_instance_ttl(&env);
+        Self::paused_now(&env)
     }
 
     pub fn pool_balance(env: Env, token: Address) -> i128 {
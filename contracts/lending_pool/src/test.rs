@@ -1,8 +1,12 @@
-use crate::{LendingPool, LendingPoolClient};
-use soroban_sdk::testutils::{Address as _, Ledger as _};
+use crate::{
+    AdminValue, DataKey, DepositorPosition, LendingPool, LendingPoolClient, LockedPosition,
+    LockedPositionV4, MigrationRecord, ParamLimit, ParamOverride, PoolError, PoolOperation,
+    PoolParam,
+};
+use soroban_sdk::testutils::{Address as _, Ledger as _, MockAuth, MockAuthInvoke};
 use soroban_sdk::token::Client as TokenClient;
 use soroban_sdk::token::StellarAssetClient;
-use soroban_sdk::{Address, BytesN, Env};
+use soroban_sdk::{Address, BytesN, Env, IntoVal, Val, Vec};
 
 fn create_token_contract<'a>(
     env: &Env,
@@ -14,6 +18,13 @@ fn create_token_contract<'a>(
     (contract_id.address(), stellar_asset_client, token_client)
 }
 
+/// Step past `param`'s seeded limit: schedule an override for `value` and
+/// move the ledger to its eta, so the next setter call goes through.
+fn override_param(env: &Env, pool_client: &LendingPoolClient, param: PoolParam, value: i128) {
+    let eta = pool_client.schedule_param_override(&param, &ParamOverride::Value(value));
+    env.ledger().with_mut(|li| li.sequence_number = eta);
+}
+
 fn create_upgrade_hash(env: &Env) -> BytesN<32> {
     BytesN::from_array(env, &[7u8; 32])
 }
@@ -28,7 +39,57 @@ fn test_version_is_initialized() {
     let pool_client = LendingPoolClient::new(&env, &pool_id);
 
     pool_client.initialize(&admin);
-    assert_eq!(pool_client.version(), 3);
+    assert_eq!(pool_client.version(), 5);
+}
+
+#[test]
+fn test_set_guardian_requires_governance_not_just_the_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let pool_id = env.register(LendingPool, ());
+    let pool_client = LendingPoolClient::new(&env, &pool_id);
+    pool_client.initialize(&admin);
+
+    let guardian = Address::generate(&env);
+    assert_eq!(
+        pool_client.try_set_guardian(&Some(guardian.clone())),
+        Err(Ok(PoolError::GovernanceNotSet))
+    );
+    let governance = Address::generate(&env);
+    pool_client.set_governance(&governance);
+
+    // A call signed by the admin alone no longer appoints a guardian.
+    let as_signer = |signer: &Address, fn_name: &'static str, args: Vec<Val>| {
+        env.mock_auths(&[MockAuth {
+            address: signer,
+            invoke: &MockAuthInvoke {
+                contract: &pool_client.address,
+                fn_name,
+                args,
+                sub_invokes: &[],
+            },
+        }]);
+    };
+    as_signer(
+        &admin,
+        "set_guardian",
+        (Some(guardian.clone()),).into_val(&env),
+    );
+    assert!(pool_client
+        .try_set_guardian(&Some(guardian.clone()))
+        .is_err());
+    assert_eq!(pool_client.get_guardian(), None);
+    as_signer(&admin, "set_governance", (admin.clone(),).into_val(&env));
+    assert!(pool_client.try_set_governance(&admin).is_err());
+
+    as_signer(
+        &governance,
+        "set_guardian",
+        (Some(guardian.clone()),).into_val(&env),
+    );
+    pool_client.set_guardian(&Some(guardian.clone()));
+    assert_eq!(pool_client.get_guardian(), Some(guardian));
 }
 
 #[test]
@@ -43,7 +104,99 @@ fn test_upgrade_requires_admin_auth() {
     pool_client.initialize(&admin);
 
     env.mock_auths(&[]);
-    pool_client.upgrade(&create_upgrade_hash(&env));
+    pool_client.schedule_upgrade(&create_upgrade_hash(&env), &20_000);
+}
+
+#[test]
+fn test_upgrade_waits_for_scheduled_eta() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let pool_id = env.register(LendingPool, ());
+    let pool_client = LendingPoolClient::new(&env, &pool_id);
+    pool_client.initialize(&admin);
+
+    let result = pool_client.try_schedule_upgrade(&create_upgrade_hash(&env), &100);
+    assert_eq!(result, Err(Ok(PoolError::InvalidUpgradeEta)));
+
+    pool_client.schedule_upgrade(&create_upgrade_hash(&env), &20_000);
+    assert_eq!(
+        pool_client.try_execute_upgrade(),
+        Err(Ok(PoolError::UpgradeTimelockActive))
+    );
+    pool_client.cancel_upgrade();
+    assert_eq!(pool_client.get_scheduled_upgrade(), None);
+    assert_eq!(pool_client.version(), 5);
+}
+
+#[test]
+fn test_migrate_records_the_new_version_and_guards_writes_until_done() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let provider = Address::generate(&env);
+    let pool_id = env.register(LendingPool, ());
+    let pool_client = LendingPoolClient::new(&env, &pool_id);
+    let (token, token_admin, _) = create_token_contract(&env, &admin);
+    token_admin.mint(&provider, &1_000);
+    pool_client.initialize(&admin);
+
+    // Stored data from before the upgrade, when limits were opt-in.
+    env.as_contract(&pool_id, || {
+        env.storage().instance().set(&DataKey::Version, &2u32);
+        env.storage()
+            .persistent()
+            .remove(&DataKey::ParamLimit(PoolParam::EarlyExitPenaltyBps));
+    });
+    assert_eq!(
+        pool_client.try_deposit(&provider, &token, &500),
+        Err(Ok(PoolError::MigrationPending))
+    );
+    assert_eq!(
+        pool_client.try_migrate_batch(&1, &0),
+        Err(Ok(PoolError::InvalidMigrationBatch))
+    );
+    assert_eq!(
+        pool_client.try_migrate_batch(&2, &10),
+        Err(Ok(PoolError::MigrationCursorAhead))
+    );
+
+    pool_client.migrate();
+    let status = pool_client.get_migration_status();
+    assert!(status.complete);
+    assert_eq!(status.version, 5);
+    assert_eq!(pool_client.version(), 5);
+    assert_eq!(
+        pool_client.get_migration_log(),
+        soroban_sdk::vec![
+            &env,
+            MigrationRecord {
+                from_version: 2,
+                to_version: 3,
+                ledger: env.ledger().sequence(),
+            },
+            MigrationRecord {
+                from_version: 3,
+                to_version: 4,
+                ledger: env.ledger().sequence(),
+            },
+            MigrationRecord {
+                from_version: 4,
+                to_version: 5,
+                ledger: env.ledger().sequence(),
+            }
+        ]
+    );
+    let log = pool_client.get_admin_actions(&0, &100);
+    let entry = log.get(log.len() - 1).unwrap();
+    assert_eq!(entry.old, AdminValue::U32(2));
+    assert_eq!(entry.new, AdminValue::U32(5));
+    assert!(pool_client
+        .get_param_limit(&PoolParam::EarlyExitPenaltyBps)
+        .is_some());
+
+    pool_client.deposit(&provider, &token, &500);
+    assert_eq!(pool_client.get_shares(&provider, &token), 500);
 }
 
 // ── Deposit ───────────────────────────────────────────────────────────────────
@@ -879,3 +1032,713 @@ fn test_get_depositor_yield_reflects_accrued_interest() {
     assert_eq!(shares2, 1000);
     assert_eq!(asset_value2, 1200); // 1000 shares * 1200 assets / 1000 total_shares
 }
+
+// ── Position P&L ──────────────────────────────────────────────────────────────
+
+#[test]
+fn test_position_tracks_cost_basis_and_realized_yield() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let token_admin = Address::generate(&env);
+    let (token_id, stellar_asset_client, token_client) = create_token_contract(&env, &token_admin);
+    let pool_id = env.register(LendingPool, ());
+    let pool_client = LendingPoolClient::new(&env, &pool_id);
+    pool_client.initialize(&token_admin);
+    pool_client.set_withdrawal_cooldown(&0);
+
+    let provider = Address::generate(&env);
+    stellar_asset_client.mint(&provider, &2_000);
+    pool_client.deposit(&provider, &token_id, &1_000);
+    stellar_asset_client.mint(&pool_id, &200);
+
+    let position = pool_client.get_position(&provider, &token_id);
+    assert_eq!(
+        position,
+        DepositorPosition {
+            shares: 1_000,
+            value: 1_200,
+            cost_basis: Some(1_000),
+            unrealized_pnl: Some(200),
+            realized_pnl: 0,
+        }
+    );
+
+    // Redeeming half the shares releases half the basis: 600 out, 100 realised.
+    pool_client.withdraw(&provider, &token_id, &500);
+    let position = pool_client.get_position(&provider, &token_id);
+    assert_eq!(position.cost_basis, Some(500));
+    assert_eq!(position.value, 600);
+    assert_eq!(position.unrealized_pnl, Some(100));
+    assert_eq!(position.realized_pnl, 100);
+
+    // A top-up adds to the basis at the current exchange rate.
+    pool_client.deposit(&provider, &token_id, &600);
+    let position = pool_client.get_position(&provider, &token_id);
+    assert_eq!(position.shares, 1_000);
+    assert_eq!(position.cost_basis, Some(1_100));
+    assert_eq!(position.unrealized_pnl, Some(100));
+
+    // Closing the position realises the remainder and clears the basis.
+    pool_client.withdraw(&provider, &token_id, &1_000);
+    let position = pool_client.get_position(&provider, &token_id);
+    assert_eq!(position.shares, 0);
+    assert_eq!(position.cost_basis, Some(0));
+    assert_eq!(position.unrealized_pnl, Some(0));
+    assert_eq!(position.realized_pnl, 200);
+    assert_eq!(token_client.balance(&provider), 2_200);
+}
+
+#[test]
+fn test_position_opened_before_cost_basis_tracking_reports_unknown_basis() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let token_admin = Address::generate(&env);
+    let (token_id, stellar_asset_client, _) = create_token_contract(&env, &token_admin);
+    let pool_id = env.register(LendingPool, ());
+    let pool_client = LendingPoolClient::new(&env, &pool_id);
+    pool_client.initialize(&token_admin);
+    pool_client.set_withdrawal_cooldown(&0);
+
+    let provider = Address::generate(&env);
+    stellar_asset_client.mint(&provider, &2_000);
+    pool_client.deposit(&provider, &token_id, &1_000);
+    stellar_asset_client.mint(&pool_id, &200);
+    // Shares deposited before the basis was recorded.
+    env.as_contract(&pool_id, || {
+        env.storage()
+            .persistent()
+            .remove(&DataKey::CostBasis(provider.clone(), token_id.clone()));
+    });
+
+    let position = pool_client.get_position(&provider, &token_id);
+    assert_eq!(position.value, 1_200);
+    assert_eq!(position.cost_basis, None);
+    assert_eq!(position.unrealized_pnl, None);
+
+    // Top-ups and partial exits neither invent a basis nor realise yield.
+    pool_client.deposit(&provider, &token_id, &600);
+    pool_client.withdraw(&provider, &token_id, &500);
+    let position = pool_client.get_position(&provider, &token_id);
+    assert_eq!(position.cost_basis, None);
+    assert_eq!(position.realized_pnl, 0);
+
+    // Once closed, the next deposit starts a tracked position.
+    pool_client.withdraw(&provider, &token_id, &position.shares);
+    pool_client.deposit(&provider, &token_id, &300);
+    let position = pool_client.get_position(&provider, &token_id);
+    assert_eq!(position.cost_basis, Some(300));
+    assert_eq!(position.unrealized_pnl, Some(0));
+}
+
+#[test]
+fn test_position_realizes_loss_on_early_lock_exit() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (pool_client, token_id, stellar_asset_client, _, _) = setup_lock_pool(&env);
+    let locker = Address::generate(&env);
+    stellar_asset_client.mint(&locker, &1_000);
+    let lock_id = pool_client.deposit_locked(&locker, &token_id, &1_000, &LOCK_TERM);
+
+    // Locked principal is not part of the liquid position.
+    assert_eq!(
+        pool_client.get_position(&locker, &token_id).cost_basis,
+        Some(0)
+    );
+
+    // 5 % penalty on the 1000 share value.
+    pool_client.exit_lock_early(&locker, &lock_id);
+    let position = pool_client.get_position(&locker, &token_id);
+    assert_eq!(position.shares, 0);
+    assert_eq!(position.realized_pnl, -50);
+}
+
+// ── Lender limits ─────────────────────────────────────────────────────────────
+
+#[test]
+fn test_max_provider_position_caps_each_lender() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (pool_client, token_id, stellar_asset_client, _, _) = setup_lock_pool(&env);
+    let lender = Address::generate(&env);
+    let other = Address::generate(&env);
+    stellar_asset_client.mint(&lender, &2_000);
+    stellar_asset_client.mint(&other, &2_000);
+
+    assert_eq!(
+        pool_client.try_set_max_provider_position(&token_id, &-1),
+        Err(Ok(PoolError::InvalidMaxPoolSize))
+    );
+    pool_client.set_max_provider_position(&token_id, &1_500);
+    assert_eq!(pool_client.get_max_provider_position(&token_id), 1_500);
+
+    // Locked holdings count towards the cap.
+    pool_client.deposit_locked(&lender, &token_id, &1_000, &LOCK_TERM);
+    pool_client.deposit(&lender, &token_id, &500);
+    assert_eq!(
+        pool_client.try_deposit(&lender, &token_id, &1),
+        Err(Ok(PoolError::PositionLimitExceeded))
+    );
+
+    // The cap is per lender, not pool-wide.
+    pool_client.deposit(&other, &token_id, &1_500);
+
+    // Lifting the cap is a jump past its seeded limit.
+    assert_eq!(
+        pool_client.try_set_max_provider_position(&token_id, &0),
+        Err(Ok(PoolError::ParamChangeTooLarge))
+    );
+    override_param(
+        &env,
+        &pool_client,
+        PoolParam::MaxProviderPosition(token_id.clone()),
+        0,
+    );
+    pool_client.set_max_provider_position(&token_id, &0);
+    pool_client.deposit(&lender, &token_id, &500);
+}
+
+#[test]
+fn test_allowlist_mode_restricts_deposits_to_approved_lenders() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (pool_client, token_id, stellar_asset_client, _, _) = setup_lock_pool(&env);
+    let lender = Address::generate(&env);
+    stellar_asset_client.mint(&lender, &3_000);
+
+    pool_client.deposit(&lender, &token_id, &1_000);
+
+    pool_client.set_allowlist_enabled(&token_id, &true);
+    assert!(pool_client.is_allowlist_enabled(&token_id));
+    assert_eq!(
+        pool_client.try_deposit(&lender, &token_id, &1_000),
+        Err(Ok(PoolError::LenderNotAllowed))
+    );
+    assert_eq!(
+        pool_client.try_deposit_locked(&lender, &token_id, &1_000, &LOCK_TERM),
+        Err(Ok(PoolError::LenderNotAllowed))
+    );
+
+    pool_client.add_allowed_lender(&token_id, &lender);
+    assert!(pool_client.is_allowed_lender(&token_id, &lender));
+    pool_client.deposit(&lender, &token_id, &1_000);
+
+    // Removal blocks new deposits but never traps existing funds.
+    pool_client.remove_allowed_lender(&token_id, &lender);
+    assert!(!pool_client.is_allowed_lender(&token_id, &lender));
+    assert_eq!(
+        pool_client.try_deposit(&lender, &token_id, &1_000),
+        Err(Ok(PoolError::LenderNotAllowed))
+    );
+    pool_client.withdraw(&lender, &token_id, &2_000);
+}
+
+// ── Time-locked deposits ──────────────────────────────────────────────────────
+
+const LOCK_TERM: u32 = 17_280;
+
+fn setup_lock_pool<'a>(
+    env: &Env,
+) -> (
+    LendingPoolClient<'a>,
+    Address,
+    StellarAssetClient<'a>,
+    TokenClient<'a>,
+    Address,
+) {
+    let admin = Address::generate(env);
+    let (token_id, stellar_asset_client, token_client) = create_token_contract(env, &admin);
+    let pool_id = env.register(LendingPool, ());
+    let pool_client = LendingPoolClient::new(env, &pool_id);
+    pool_client.initialize(&admin);
+    pool_client.set_withdrawal_cooldown(&0);
+
+    // 2x weight for the one-day term; 10 % of interest funds boosts (default).
+    pool_client.set_lock_term(&LOCK_TERM, &20_000);
+    let loan_manager = Address::generate(env);
+    pool_client.set_loan_manager(&loan_manager);
+
+    (
+        pool_client,
+        token_id,
+        stellar_asset_client,
+        token_client,
+        loan_manager,
+    )
+}
+
+#[test]
+fn test_deposit_locked_rejects_unconfigured_term() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (pool_client, token_id, stellar_asset_client, _, _) = setup_lock_pool(&env);
+    let provider = Address::generate(&env);
+    stellar_asset_client.mint(&provider, &1_000);
+
+    let result = pool_client.try_deposit_locked(&provider, &token_id, &1_000, &(LOCK_TERM + 1));
+    assert_eq!(result, Err(Ok(PoolError::InvalidLockTerm)));
+
+    override_param(&env, &pool_client, PoolParam::LockTermBoost(LOCK_TERM), 0);
+    pool_client.set_lock_term(&LOCK_TERM, &0);
+    let result = pool_client.try_deposit_locked(&provider, &token_id, &1_000, &LOCK_TERM);
+    assert_eq!(result, Err(Ok(PoolError::InvalidLockTerm)));
+}
+
+#[test]
+fn test_locked_and_liquid_balances_are_reported_separately() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (pool_client, token_id, stellar_asset_client, _, _) = setup_lock_pool(&env);
+    let provider = Address::generate(&env);
+    stellar_asset_client.mint(&provider, &3_000);
+
+    pool_client.deposit(&provider, &token_id, &1_000);
+    let lock_id = pool_client.deposit_locked(&provider, &token_id, &2_000, &LOCK_TERM);
+
+    assert_eq!(pool_client.get_liquid_balance(&provider, &token_id), 1_000);
+    assert_eq!(pool_client.get_locked_balance(&provider, &token_id), 2_000);
+    assert_eq!(pool_client.get_total_locked_shares(&token_id), 2_000);
+    assert_eq!(pool_client.get_total_shares(&token_id), 3_000);
+    assert_eq!(pool_client.get_total_deposits(&token_id), 3_000);
+
+    let lock = pool_client.get_lock(&lock_id).unwrap();
+    assert_eq!(lock.shares, 2_000);
+    assert_eq!(lock.boost_weight, 4_000);
+    assert_eq!(lock.unlock_ledger, env.ledger().sequence() + LOCK_TERM);
+    assert_eq!(
+        pool_client.get_locks(&provider, &token_id),
+        soroban_sdk::vec![&env, lock_id]
+    );
+
+    // Locked shares cannot be redeemed through the liquid withdraw path.
+    let result = pool_client.try_withdraw(&provider, &token_id, &1_001);
+    assert_eq!(result, Err(Ok(PoolError::InsufficientBalance)));
+}
+
+#[test]
+fn test_matured_lock_receives_share_value_plus_boost() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (pool_client, token_id, stellar_asset_client, token_client, _) = setup_lock_pool(&env);
+    let liquid = Address::generate(&env);
+    let locker = Address::generate(&env);
+    stellar_asset_client.mint(&liquid, &1_000);
+    stellar_asset_client.mint(&locker, &1_000);
+
+    pool_client.deposit(&liquid, &token_id, &1_000);
+    let lock_id = pool_client.deposit_locked(&locker, &token_id, &1_000, &LOCK_TERM);
+
+    // 200 of interest lands in the pool; 10 % (20) is set aside for boosts.
+    stellar_asset_client.mint(&pool_client.address, &200);
+    pool_client.record_interest(&token_id, &200);
+    assert_eq!(pool_client.get_boost_reserve(&token_id), 20);
+    assert_eq!(pool_client.get_pending_boost(&lock_id), 20);
+
+    // Remaining 180 is shared through the exchange rate.
+    assert_eq!(pool_client.get_liquid_balance(&liquid, &token_id), 1_090);
+    assert_eq!(pool_client.get_locked_balance(&locker, &token_id), 1_090);
+
+    let result = pool_client.try_withdraw_locked(&locker, &lock_id);
+    assert_eq!(result, Err(Ok(PoolError::LockNotMatured)));
+
+    env.ledger()
+        .set_sequence_number(env.ledger().sequence() + LOCK_TERM);
+    pool_client.withdraw_locked(&locker, &lock_id);
+
+    assert_eq!(token_client.balance(&locker), 1_110);
+    assert_eq!(pool_client.get_boost_reserve(&token_id), 0);
+    assert_eq!(pool_client.get_lock(&lock_id), None);
+    assert_eq!(pool_client.get_locks(&locker, &token_id).len(), 0);
+
+    pool_client.withdraw(&liquid, &token_id, &1_000);
+    assert_eq!(token_client.balance(&liquid), 1_090);
+    assert_eq!(token_client.balance(&pool_client.address), 0);
+}
+
+#[test]
+fn test_early_exit_penalty_and_forfeited_boost_go_to_remaining_lps() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (pool_client, token_id, stellar_asset_client, token_client, _) = setup_lock_pool(&env);
+    let liquid = Address::generate(&env);
+    let locker = Address::generate(&env);
+    stellar_asset_client.mint(&liquid, &1_000);
+    stellar_asset_client.mint(&locker, &1_000);
+
+    pool_client.deposit(&liquid, &token_id, &1_000);
+    let lock_id = pool_client.deposit_locked(&locker, &token_id, &1_000, &LOCK_TERM);
+
+    stellar_asset_client.mint(&pool_client.address, &200);
+    pool_client.record_interest(&token_id, &200);
+
+    // Share value 1090, 5 % penalty (54), boost of 20 forfeited.
+    pool_client.exit_lock_early(&locker, &lock_id);
+    assert_eq!(token_client.balance(&locker), 1_036);
+    assert_eq!(pool_client.get_boost_reserve(&token_id), 0);
+    assert_eq!(pool_client.get_total_locked_shares(&token_id), 0);
+
+    assert_eq!(pool_client.get_liquid_balance(&liquid, &token_id), 1_164);
+}
+
+#[test]
+fn test_early_exit_uses_the_penalty_in_force_at_lock_time() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (pool_client, token_id, stellar_asset_client, token_client, _) = setup_lock_pool(&env);
+    let locker = Address::generate(&env);
+    stellar_asset_client.mint(&locker, &1_000);
+    let lock_id = pool_client.deposit_locked(&locker, &token_id, &1_000, &LOCK_TERM);
+    assert_eq!(pool_client.get_lock(&lock_id).unwrap().penalty_bps, 500);
+
+    // Raising the penalty afterwards only applies to new locks.
+    pool_client.set_early_exit_penalty_bps(&1_000);
+    pool_client.exit_lock_early(&locker, &lock_id);
+    assert_eq!(token_client.balance(&locker), 950);
+
+    stellar_asset_client.mint(&locker, &1_000);
+    let second = pool_client.deposit_locked(&locker, &token_id, &1_000, &LOCK_TERM);
+    assert_eq!(pool_client.get_lock(&second).unwrap().penalty_bps, 1_000);
+}
+
+#[test]
+fn test_migrate_batch_pins_the_penalty_on_existing_locks() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (pool_client, token_id, stellar_asset_client, _, _) = setup_lock_pool(&env);
+    let locker = Address::generate(&env);
+    stellar_asset_client.mint(&locker, &3_000);
+    for _ in 0..3 {
+        pool_client.deposit_locked(&locker, &token_id, &1_000, &LOCK_TERM);
+    }
+
+    // Locks written before the penalty was stored on the record.
+    env.as_contract(&pool_client.address, || {
+        for lock_id in 1..=3u32 {
+            let key = DataKey::Lock(lock_id);
+            let lock: LockedPosition = env.storage().persistent().get(&key).unwrap();
+            let old = LockedPositionV4 {
+                provider: lock.provider,
+                token: lock.token,
+                amount: lock.amount,
+                shares: lock.shares,
+                boost_weight: lock.boost_weight,
+                boost_debt: lock.boost_debt,
+                locked_at: lock.locked_at,
+                unlock_ledger: lock.unlock_ledger,
+            };
+            env.storage().persistent().set(&key, &old);
+        }
+        env.storage().instance().set(&DataKey::Version, &4u32);
+    });
+
+    pool_client.migrate_batch(&1, &2);
+    let status = pool_client.get_migration_status();
+    assert!(!status.complete);
+    assert_eq!(status.cursor, 3);
+    assert_eq!(
+        pool_client.try_deposit(&locker, &token_id, &1),
+        Err(Ok(PoolError::MigrationPending))
+    );
+
+    pool_client.migrate_batch(&3, &2);
+    assert!(pool_client.get_migration_status().complete);
+    assert_eq!(pool_client.version(), 5);
+    for lock_id in 1..=3u32 {
+        assert_eq!(pool_client.get_lock(&lock_id).unwrap().penalty_bps, 500);
+    }
+}
+
+#[test]
+fn test_lock_can_only_be_closed_by_its_provider() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (pool_client, token_id, stellar_asset_client, _, _) = setup_lock_pool(&env);
+    let locker = Address::generate(&env);
+    let other = Address::generate(&env);
+    stellar_asset_client.mint(&locker, &1_000);
+    let lock_id = pool_client.deposit_locked(&locker, &token_id, &1_000, &LOCK_TERM);
+
+    let result = pool_client.try_exit_lock_early(&other, &lock_id);
+    assert_eq!(result, Err(Ok(PoolError::ProviderMismatch)));
+    let result = pool_client.try_withdraw_locked(&locker, &(lock_id + 1));
+    assert_eq!(result, Err(Ok(PoolError::LockNotFound)));
+}
+
+#[test]
+fn test_record_interest_without_locks_or_loan_manager_diverts_nothing() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (token_id, _, _) = create_token_contract(&env, &admin);
+    let pool_id = env.register(LendingPool, ());
+    let pool_client = LendingPoolClient::new(&env, &pool_id);
+    pool_client.initialize(&admin);
+
+    // No LoanManager configured: the hook is a no-op.
+    pool_client.record_interest(&token_id, &1_000);
+    assert_eq!(pool_client.get_boost_reserve(&token_id), 0);
+
+    // LoanManager configured but no open locks: nothing is diverted.
+    pool_client.set_loan_manager(&Address::generate(&env));
+    pool_client.record_interest(&token_id, &1_000);
+    assert_eq!(pool_client.get_boost_reserve(&token_id), 0);
+}
+
+#[test]
+fn test_lock_config_setters_validate_bps() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (pool_client, _, _, _, _) = setup_lock_pool(&env);
+
+    assert_eq!(
+        pool_client.try_set_boost_share_bps(&10_001),
+        Err(Ok(PoolError::InvalidConfiguration))
+    );
+    assert_eq!(
+        pool_client.try_set_early_exit_penalty_bps(&10_001),
+        Err(Ok(PoolError::InvalidConfiguration))
+    );
+    assert_eq!(
+        pool_client.try_set_lock_term(&0, &10_000),
+        Err(Ok(PoolError::InvalidLockTerm))
+    );
+
+    pool_client.set_boost_share_bps(&1_500);
+    pool_client.set_early_exit_penalty_bps(&1_000);
+    assert_eq!(pool_client.get_boost_share_bps(), 1_500);
+    assert_eq!(pool_client.get_early_exit_penalty_bps(), 1_000);
+    assert_eq!(pool_client.get_lock_term_boost(&LOCK_TERM), 20_000);
+}
+
+#[test]
+fn test_guardian_pause_blocks_deposits_and_lapses() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let token_admin = Address::generate(&env);
+    let pool_id = env.register(LendingPool, ());
+    let pool_client = LendingPoolClient::new(&env, &pool_id);
+    pool_client.initialize(&token_admin);
+    // Shortening the pause is a jump past its seeded limit.
+    override_param(&env, &pool_client, PoolParam::GuardianPauseLedgers, 100);
+
+    let (token_id, stellar_asset_client, _token_client) = create_token_contract(&env, &token_admin);
+    let provider = Address::generate(&env);
+    stellar_asset_client.mint(&provider, &1_000);
+
+    assert_eq!(
+        pool_client.try_guardian_pause(),
+        Err(Ok(PoolError::GuardianNotSet))
+    );
+    let guardian = Address::generate(&env);
+    pool_client.set_governance(&Address::generate(&env));
+    pool_client.set_guardian(&Some(guardian.clone()));
+    assert_eq!(pool_client.get_guardian(), Some(guardian));
+    assert_eq!(
+        pool_client.try_set_guardian_pause_ledgers(&0),
+        Err(Ok(PoolError::InvalidPauseWindow))
+    );
+    pool_client.set_guardian_pause_ledgers(&100);
+
+    let expiry = pool_client.guardian_pause();
+    assert_eq!(expiry, env.ledger().sequence() + 100);
+    assert_eq!(pool_client.get_guardian_pause_expiry(), Some(expiry));
+    assert!(pool_client.is_paused());
+    assert_eq!(
+        pool_client.try_guardian_pause(),
+        Err(Ok(PoolError::AlreadyPaused))
+    );
+    assert_eq!(
+        pool_client.try_deposit(&provider, &token_id, &500),
+        Err(Ok(PoolError::ContractPaused))
+    );
+
+    env.ledger().with_mut(|li| li.sequence_number = expiry);
+    assert!(!pool_client.is_paused());
+    assert_eq!(pool_client.get_guardian_pause_expiry(), None);
+    pool_client.deposit(&provider, &token_id, &500);
+
+    // No second pause until governance acts.
+    assert_eq!(
+        pool_client.try_guardian_pause(),
+        Err(Ok(PoolError::GuardianPauseSpent))
+    );
+    pool_client.unpause();
+    assert_eq!(pool_client.guardian_pause(), expiry + 100);
+}
+
+#[test]
+fn test_paused_withdrawals_leave_deposits_open() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let token_admin = Address::generate(&env);
+    let (token_id, stellar_asset_client, _token_client) = create_token_contract(&env, &token_admin);
+
+    let pool_id = env.register(LendingPool, ());
+    let pool_client = LendingPoolClient::new(&env, &pool_id);
+    pool_client.initialize(&token_admin);
+    pool_client.set_withdrawal_cooldown(&0);
+
+    let provider = Address::generate(&env);
+    stellar_asset_client.mint(&provider, &1_000);
+
+    pool_client.pause_operation(&PoolOperation::Withdrawals);
+    assert!(pool_client.is_operation_paused(&PoolOperation::Withdrawals));
+    assert!(!pool_client.is_paused());
+
+    pool_client.deposit(&provider, &token_id, &1_000);
+    assert_eq!(
+        pool_client.try_withdraw(&provider, &token_id, &500),
+        Err(Ok(PoolError::OperationPaused))
+    );
+
+    pool_client.unpause_operation(&PoolOperation::Withdrawals);
+    pool_client.withdraw(&provider, &token_id, &500);
+}
+
+#[test]
+fn test_param_limit_bounds_cooldown_changes_per_window() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let pool_id = env.register(LendingPool, ());
+    let pool_client = LendingPoolClient::new(&env, &pool_id);
+    pool_client.initialize(&admin);
+    let start = pool_client.get_withdrawal_cooldown();
+
+    pool_client.set_param_limit(
+        &PoolParam::WithdrawalCooldown,
+        &Some(ParamLimit {
+            max_change: 100,
+            max_change_bps: 0,
+            window_ledgers: 17_280,
+        }),
+    );
+    pool_client.set_withdrawal_cooldown(&(start + 60));
+    assert_eq!(
+        pool_client.try_set_withdrawal_cooldown(&(start + 120)),
+        Err(Ok(PoolError::ParamChangeTooLarge))
+    );
+
+    env.ledger().with_mut(|li| li.sequence_number += 17_280);
+    pool_client.set_withdrawal_cooldown(&(start + 160));
+
+    assert_eq!(
+        pool_client.try_cancel_param_override(&PoolParam::WithdrawalCooldown),
+        Err(Ok(PoolError::NoParamOverride))
+    );
+    pool_client.schedule_param_override(
+        &PoolParam::WithdrawalCooldown,
+        &ParamOverride::Value((start + 10_000) as i128),
+    );
+    pool_client.cancel_param_override(&PoolParam::WithdrawalCooldown);
+    assert_eq!(
+        pool_client.get_param_override(&PoolParam::WithdrawalCooldown),
+        None
+    );
+    assert_eq!(
+        pool_client.try_set_param_limit(
+            &PoolParam::WithdrawalCooldown,
+            &Some(ParamLimit {
+                max_change: 100,
+                max_change_bps: 0,
+                window_ledgers: 0,
+            }),
+        ),
+        Err(Ok(PoolError::InvalidParamLimit))
+    );
+}
+
+#[test]
+fn test_limits_are_seeded_for_every_pool_param() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token = Address::generate(&env);
+    let pool_id = env.register(LendingPool, ());
+    let pool_client = LendingPoolClient::new(&env, &pool_id);
+    pool_client.initialize(&admin);
+
+    assert!(pool_client
+        .get_param_limit(&PoolParam::WithdrawalCooldown)
+        .is_some());
+    assert_eq!(
+        pool_client.try_set_early_exit_penalty_bps(&10_000),
+        Err(Ok(PoolError::ParamChangeTooLarge))
+    );
+
+    // Per-token and per-term values are limited once first set.
+    assert_eq!(
+        pool_client.get_param_limit(&PoolParam::MaxPoolSize(token.clone())),
+        None
+    );
+    pool_client.set_max_pool_size(&token, &100_000);
+    assert!(pool_client
+        .get_param_limit(&PoolParam::MaxPoolSize(token.clone()))
+        .is_some());
+    assert_eq!(
+        pool_client.try_set_max_pool_size(&token, &1_000_000),
+        Err(Ok(PoolError::ParamChangeTooLarge))
+    );
+    pool_client.set_lock_term(&17_280, &12_000);
+    assert_eq!(
+        pool_client.try_set_lock_term(&17_280, &30_000),
+        Err(Ok(PoolError::ParamChangeTooLarge))
+    );
+
+    // A limit removed through an override is not seeded again.
+    let param = PoolParam::MaxPoolSize(token.clone());
+    let eta = pool_client.schedule_param_override(&param, &ParamOverride::RemoveLimit);
+    env.ledger().with_mut(|li| li.sequence_number = eta);
+    pool_client.set_param_limit(&param, &None);
+    pool_client.set_max_pool_size(&token, &1_000_000);
+    assert_eq!(pool_client.get_param_limit(&param), None);
+}
+
+#[test]
+fn test_admin_log_records_cap_changes_per_token() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token = Address::generate(&env);
+    let pool_id = env.register(LendingPool, ());
+    let pool_client = LendingPoolClient::new(&env, &pool_id);
+    pool_client.initialize(&admin);
+
+    let old_max = pool_client.get_max_pool_size(&token);
+    pool_client.set_max_pool_size(&token, &5_000);
+    pool_client.set_max_pool_size(&token, &7_500);
+
+    assert_eq!(pool_client.get_admin_action_count(), 3);
+    let log = pool_client.get_admin_actions(&1, &10);
+    assert_eq!(log.len(), 2);
+    assert_eq!(
+        log.get(0).unwrap().old,
+        AdminValue::TokenAmount(token.clone(), old_max)
+    );
+    assert_eq!(
+        log.get(1).unwrap().old,
+        AdminValue::TokenAmount(token.clone(), 5_000)
+    );
+    assert_eq!(
+        log.get(1).unwrap().new,
+        AdminValue::TokenAmount(token, 7_500)
+    );
+}
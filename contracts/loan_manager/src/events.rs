@@ -1,8 +1,10 @@
-use soroban_sdk::{symbol_short, Address, Env, String, Symbol};
+use soroban_sdk::{symbol_short, Address, BytesN, Env, String, Symbol};
 
-pub fn loan_requested(env: &Env, borrower: Address, amount: i128) {
+use crate::{AdminAction, AssetConfig, LoanOperation, LoanParam, ParamLimit, ParamOverride};
+
+pub fn loan_requested(env: &Env, borrower: Address, asset: Address, amount: i128) {
     let topics = (Symbol::new(env, "LoanRequested"), borrower);
-    env.events().publish(topics, amount);
+    env.events().publish(topics, (asset, amount));
 }
 
 pub fn loan_approved(env: &Env, loan_id: u32, borrower: Address) {
@@ -130,3 +132,118 @@ pub fn collateral_liquidated(env: &Env, loan_id: u32, amount: i128) {
     let topics = (Symbol::new(env, "CollateralLiquidated"), loan_id);
     env.events().publish(topics, amount);
 }
+
+pub fn keeper_rewarded(env: &Env, loan_id: u32, keeper: Address, asset: Address, amount: i128) {
+    let topics = (Symbol::new(env, "KeeperRewarded"), loan_id, keeper);
+    env.events().publish(topics, (asset, amount));
+}
+
+pub fn keeper_bounty_bps_updated(env: &Env, old_bps: u32, new_bps: u32) {
+    let topics = (Symbol::new(env, "KeeperBountyBpsUpdated"),);
+    env.events().publish(topics, (old_bps, new_bps));
+}
+
+pub fn keeper_reserve_bounty_updated(env: &Env, old_amount: i128, new_amount: i128) {
+    let topics = (Symbol::new(env, "KeeperReserveBountyUpdated"),);
+    env.events().publish(topics, (old_amount, new_amount));
+}
+
+pub fn keeper_reserve_funded(env: &Env, funder: Address, amount: i128) {
+    let topics = (Symbol::new(env, "KeeperReserveFunded"), funder);
+    env.events().publish(topics, amount);
+}
+
+pub fn asset_config_updated(env: &Env, admin: Address, asset: Address, config: AssetConfig) {
+    let topics = (Symbol::new(env, "AssetConfigUpdated"), admin, asset);
+    env.events().publish(topics, config);
+}
+
+pub fn asset_removed(env: &Env, admin: Address, asset: Address) {
+    let topics = (Symbol::new(env, "AssetRemoved"), admin);
+    env.events().publish(topics, asset);
+}
+
+pub fn min_remittance_months_updated(env: &Env, admin: Address, old_months: u32, new_months: u32) {
+    let topics = (Symbol::new(env, "MinRemittanceMonthsUpdated"), admin);
+    env.events().publish(topics, (old_months, new_months));
+}
+
+pub fn borrower_migrated(env: &Env, from: Address, to: Address, loan_count: u32) {
+    let topics = (Symbol::new(env, "BorrowerMigrated"), from, to);
+    env.events().publish(topics, loan_count);
+}
+
+pub fn loan_migration_toggled(env: &Env, admin: Address, enabled: bool) {
+    let topics = (Symbol::new(env, "LoanMigrationToggled"), admin);
+    env.events().publish(topics, enabled);
+}
+
+pub fn upgrade_scheduled(env: &Env, wasm_hash: BytesN<32>, eta: u32) {
+    let topics = (Symbol::new(env, "UpgradeScheduled"),);
+    env.events().publish(topics, (wasm_hash, eta));
+}
+
+pub fn upgrade_cancelled(env: &Env, wasm_hash: BytesN<32>) {
+    let topics = (Symbol::new(env, "UpgradeCancelled"),);
+    env.events().publish(topics, wasm_hash);
+}
+
+pub fn upgrade_executed(env: &Env, old_version: u32, wasm_hash: BytesN<32>) {
+    let topics = (Symbol::new(env, "ContractUpgraded"),);
+    env.events().publish(topics, (old_version, wasm_hash));
+}
+
+pub fn migration_step_completed(env: &Env, from_version: u32, to_version: u32) {
+    let topics = (Symbol::new(env, "MigrationStepCompleted"),);
+    env.events().publish(topics, (from_version, to_version));
+}
+
+pub fn guardian_set(env: &Env, guardian: Option<Address>) {
+    let topics = (Symbol::new(env, "GuardianSet"),);
+    env.events().publish(topics, guardian);
+}
+
+pub fn governance_set(env: &Env, governance: Address) {
+    let topics = (Symbol::new(env, "GovernanceSet"),);
+    env.events().publish(topics, governance);
+}
+
+pub fn guardian_pause_ledgers_updated(env: &Env, ledgers: u32) {
+    let topics = (Symbol::new(env, "GuardianPauseLedgers"),);
+    env.events().publish(topics, ledgers);
+}
+
+pub fn guardian_paused(env: &Env, guardian: Address, expiry: u32) {
+    let topics = (Symbol::new(env, "GuardianPaused"), guardian);
+    env.events().publish(topics, expiry);
+}
+
+pub fn pause_ratified(env: &Env, expiry: u32) {
+    let topics = (Symbol::new(env, "PauseRatified"),);
+    env.events().publish(topics, expiry);
+}
+
+pub fn operation_pause_set(env: &Env, operation: LoanOperation, paused: bool) {
+    let topics = (Symbol::new(env, "OperationPauseSet"), operation);
+    env.events().publish(topics, paused);
+}
+
+pub fn param_limit_set(env: &Env, param: LoanParam, limit: Option<ParamLimit>) {
+    let topics = (Symbol::new(env, "ParamLimitSet"), param);
+    env.events().publish(topics, limit);
+}
+
+pub fn param_override_scheduled(env: &Env, param: LoanParam, change: ParamOverride, eta: u32) {
+    let topics = (Symbol::new(env, "ParamOverrideScheduled"), param);
+    env.events().publish(topics, (change, eta));
+}
+
+pub fn param_override_cancelled(env: &Env, param: LoanParam) {
+    let topics = (Symbol::new(env, "ParamOverrideCancelled"), param);
+    env.events().publish(topics, ());
+}
+
+pub fn admin_action_logged(env: &Env, index: u32, entry: AdminAction) {
+    let topics = (Symbol::new(env, "AdminAction"), entry.action.clone());
+    env.events().publish(topics, (index, entry));
+}
@@ -1,20 +1,44 @@
 #![no_std]
 use soroban_sdk::{
     contract, contractclient, contracterror, contractimpl, contracttype, symbol_short, Address,
-    BytesN, Env, String, Symbol, Vec,
+    BytesN, Env, Map, String, Symbol, TryFromVal, Val, Vec,
 };
 
 #[contractclient(name = "NftClient")]
 pub trait RemittanceNftInterface {
-    fn get_score(env: Env, user: Address) -> u32;
+    fn get_score_for(env: Env, user: Address, consumer: Address) -> u32;
+    fn grant_access(
+        env: Env,
+        user: Address,
+        consumer: Address,
+        scope: AccessScope,
+        expiry_ledger: u32,
+    );
+    fn get_access_grant(env: Env, user: Address, consumer: Address) -> Option<AccessGrant>;
     fn update_score(env: Env, user: Address, repayment_amount: i128, minter: Option<Address>);
     fn apply_score_delta(env: Env, user: Address, delta: i32, minter: Option<Address>);
     fn decrease_score(env: Env, user: Address, penalty_points: u32, minter: Option<Address>);
+    fn record_repayment(
+        env: Env,
+        user: Address,
+        repayment_amount: i128,
+        completed: bool,
+        ledgers_late: u32,
+        minter: Option<Address>,
+    );
+    fn apply_default_penalty(env: Env, user: Address, minter: Option<Address>);
+    fn verify_history_record(
+        env: Env,
+        user: Address,
+        record: RemittanceRecord,
+        proof: Vec<BytesN<32>>,
+    ) -> bool;
     fn seize_collateral(env: Env, user: Address, minter: Option<Address>);
     fn is_seized(env: Env, user: Address) -> bool;
     fn record_default(env: Env, user: Address, minter: Option<Address>);
     fn is_authorized_minter(env: Env, minter: Address) -> bool;
     fn is_paused(env: Env) -> bool;
+    fn is_operation_paused(env: Env, operation: NftOperation) -> bool;
 }
 
 #[contractclient(name = "RateOracleClient")]
@@ -26,10 +50,22 @@ pub trait RateOracleInterface {
 pub trait LendingPoolInterface {
     fn is_paused(env: Env) -> bool;
     fn pool_balance(env: Env, token: Address) -> i128;
+    fn record_interest(env: Env, token: Address, amount: i128);
 }
 
 mod events;
 
+/// Mirror of RemittanceNFT's history leaf, passed through to
+/// `verify_history_record`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RemittanceRecord {
+    pub period: u32,
+    pub amount: i128,
+    pub corridor: Symbol,
+    pub count: u32,
+}
+
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub enum LoanError {
@@ -55,6 +91,42 @@ pub enum LoanError {
     NftPaused = 20,
     InvalidConfiguration = 21,
     SeizedBorrower = 22,
+    UnsupportedAsset = 23,
+    InvalidHistoryProof = 24,
+    InsufficientRemittanceHistory = 25,
+    ActiveLoansBlockTransfer = 26,
+    InvalidUpgradeEta = 27,
+    NoUpgradeScheduled = 28,
+    UpgradeTimelockActive = 29,
+    MigrationPending = 30,
+    MigrationCursorAhead = 31,
+    InvalidMigrationBatch = 32,
+    GuardianNotSet = 33,
+    AlreadyPaused = 34,
+    NoGuardianPause = 35,
+    InvalidPauseWindow = 36,
+    OperationPaused = 37,
+    ParamChangeTooLarge = 38,
+    InvalidParamLimit = 39,
+    ParamOverrideRequired = 40,
+    ParamOverrideTimelockActive = 41,
+    NoParamOverride = 42,
+    /// The guardian's pause lapsed and governance has not paused, unpaused
+    /// or ratified since.
+    GuardianPauseSpent = 43,
+    /// `set_guardian` was called before `set_governance`.
+    GovernanceNotSet = 44,
+}
+
+/// Per-loan outcome of `check_defaults`, in the order the ids were given.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DefaultCheck {
+    /// Marked defaulted; carries the bounty paid to the caller.
+    Defaulted(i128),
+    NotFound,
+    NotActive,
+    NotPastDue,
 }
 
 #[contracttype]
@@ -89,6 +161,235 @@ pub struct Loan {
     // Capped at MaxExtensions to prevent indefinite deferral.
     pub extension_count: u32,
     pub term_ledgers: u32,
+    /// Token the principal is disbursed and repaid in.
+    pub asset: Address,
+    /// Token posted as collateral, fixed at request time so later config
+    /// changes cannot strand deposited collateral.
+    pub collateral_asset: Address,
+}
+
+/// `Loan` as stored before version 4 added per-loan assets. Only read by
+/// the 3 → 4 migration step.
+#[contracttype]
+#[derive(Clone)]
+pub struct LoanV3 {
+    pub borrower: Address,
+    pub amount: i128,
+    pub collateral_amount: i128,
+    pub principal_paid: i128,
+    pub interest_paid: i128,
+    pub accrued_interest: i128,
+    pub late_fee_paid: i128,
+    pub accrued_late_fee: i128,
+    pub interest_rate_bps: u32,
+    pub due_date: u32,
+    pub last_interest_ledger: u32,
+    pub last_late_fee_ledger: u32,
+    pub status: LoanStatus,
+    pub interest_residual: i128,
+    pub extension_count: u32,
+    pub term_ledgers: u32,
+}
+
+/// Per-asset lending configuration.
+///
+/// `None` fields fall back to the contract-wide setting (`MaxLoanAmount`,
+/// `InterestRateBps`, `MinRepaymentAmount`), so an asset only needs to
+/// override what differs from the protocol defaults.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AssetConfig {
+    pub max_loan_amount: Option<i128>,
+    pub interest_rate_bps: Option<u32>,
+    pub min_repayment_amount: Option<i128>,
+    pub collateral_asset: Address,
+}
+
+/// Operations that can be paused individually with `pause_operation`.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LoanOperation {
+    NewLoans,
+    Approvals,
+    Repayments,
+    Collateral,
+    Refinancing,
+    Defaults,
+}
+
+/// Mirror of RemittanceNFT's pausable operations.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum NftOperation {
+    Minting,
+    Transfers,
+    ScoreUpdates,
+}
+
+/// Mirror of RemittanceNFT's read scopes for private records.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AccessScope {
+    Score,
+    Metadata,
+    History,
+}
+
+/// Mirror of RemittanceNFT's access grant.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AccessGrant {
+    pub scope: AccessScope,
+    pub expiry_ledger: u32,
+}
+
+/// A stretch of ledgers during which repayments were blocked. `end` is
+/// `None` while the block has no scheduled end.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PauseWindow {
+    pub start: u32,
+    pub end: Option<u32>,
+}
+
+/// Progress of `prune_repay_pause_windows` through the loan ids.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PauseWindowScan {
+    pub next_loan_id: u32,
+    /// Ledger the pass started at; loans approved since measure pauses
+    /// from no earlier than this.
+    pub started_at: u32,
+    /// Earliest ledger an open loan seen so far measures pauses from.
+    pub floor: u32,
+}
+
+/// Numeric parameters whose setters are subject to `ParamLimit`s.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum LoanParam {
+    LateFeeRate,
+    GracePeriod,
+    DefaultWindow,
+    MinScore,
+    MinRemittanceMonths,
+    MaxLoanAmount,
+    MinRepaymentAmount,
+    MaxLoansPerBorrower,
+    InterestRate,
+    DefaultTerm,
+    MinTerm,
+    MaxTerm,
+    GuardianPauseLedgers,
+    KeeperBountyBps,
+    KeeperReserveBounty,
+    /// Per-asset overrides in `AssetConfig`, compared as effective values
+    AssetMaxLoanAmount(Address),
+    AssetInterestRate(Address),
+    AssetMinRepaymentAmount(Address),
+    /// Not numeric: every change needs a matured `ParamOverride::Address`.
+    RateOracle,
+}
+
+/// How far a parameter may move within one window. The allowance is the
+/// larger of `max_change` (in the parameter's own units) and `max_change_bps`
+/// of the value at the start of the window.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParamLimit {
+    pub max_change: i128,
+    pub max_change_bps: u32,
+    pub window_ledgers: u32,
+}
+
+/// The value a parameter started its current limit window at.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParamWindow {
+    pub start_ledger: u32,
+    pub baseline: i128,
+}
+
+/// A change that exceeds the registered limits: a value jump, or a looser
+/// (or removed) limit.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParamOverride {
+    Value(i128),
+    Limit(ParamLimit),
+    RemoveLimit,
+    /// New address for an address-valued parameter
+    Address(Address),
+}
+
+/// An override waiting out `PARAM_OVERRIDE_DELAY_LEDGERS`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingParamOverride {
+    pub change: ParamOverride,
+    pub eta: u32,
+}
+
+/// A before or after value in an `AdminAction`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AdminValue {
+    None,
+    Bool(bool),
+    U32(u32),
+    I128(i128),
+    Address(Address),
+    Hash(BytesN<32>),
+    /// Per-operation pause flag
+    Operation(LoanOperation, bool),
+    Limit(LoanParam, ParamLimit),
+    Override(LoanParam, ParamOverride),
+    /// Asset config as it stood before or after the call
+    Asset(Address, AssetConfig),
+    /// Loan id → principal outstanding before or after the call
+    Loan(u32, i128),
+}
+
+/// One privileged call, as recorded in the admin audit log.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminAction {
+    pub ledger: u32,
+    pub actor: Address,
+    /// Name of the entrypoint that was called
+    pub action: Symbol,
+    pub old: AdminValue,
+    pub new: AdminValue,
+}
+
+/// Where storage stands relative to the running code.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MigrationStatus {
+    /// Schema version of stored data
+    pub version: u32,
+    /// `CURRENT_VERSION` of the running code
+    pub target_version: u32,
+    /// Next entry the current step will process
+    pub cursor: u32,
+    pub complete: bool,
+}
+
+/// One completed migration step.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MigrationRecord {
+    pub from_version: u32,
+    pub to_version: u32,
+    pub ledger: u32,
+}
+
+/// An upgrade announced ahead of time; executable from ledger `eta`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ScheduledUpgrade {
+    pub wasm_hash: BytesN<32>,
+    pub eta: u32,
 }
 
 #[contracttype]
@@ -105,6 +406,7 @@ pub enum DataKey {
     MaxLoanAmount,
     MaxLoansPerBorrower,
     BorrowerLoanCount(Address),
+    /// Loan ids per borrower; persistent since version 5, instance before
     BorrowerLoans(Address),
     Paused,
     InterestRateBps,
@@ -118,6 +420,56 @@ pub enum DataKey {
     DefaultWindowLedgers,
     RateOracle,
     ProposedAdmin,
+    /// Wasm hash announced by `schedule_upgrade`
+    ScheduledUpgrade,
+    /// Governance contract that appoints the guardian
+    Governance,
+    /// Address allowed to call `guardian_pause`
+    Guardian,
+    /// Ledger at which the active guardian pause lapses; absent for admin pauses
+    GuardianPauseExpiry,
+    /// How many ledgers a guardian pause lasts unless ratified
+    GuardianPauseLedgers,
+    /// Per-operation pause flag, independent of the global `Paused`
+    OperationPaused(LoanOperation),
+    /// Windows in which repayments were blocked, oldest first; interest
+    /// and late fees do not accrue inside them
+    RepayPauseWindows,
+    /// Running `prune_repay_pause_windows` pass
+    RepayPauseScan,
+    /// param → registered rate-of-change limit
+    ParamLimit(LoanParam),
+    /// param → current limit window
+    ParamWindow(LoanParam),
+    /// param → override waiting for its timelock
+    ParamOverride(LoanParam),
+    /// Number of entries in the admin audit log
+    AdminLogLen,
+    /// page index → up to `ADMIN_LOG_PAGE_SIZE` audit log entries
+    AdminLogPage(u32),
+    /// Next loan id the running migration step will process
+    MigrationCursor,
+    /// Completed migration steps, oldest first
+    MigrationLog,
+    /// token → AssetConfig; presence marks the token as supported
+    AssetConfig(Address),
+    /// Whitelist of tokens that new loans may be requested in
+    SupportedAssets,
+    /// Months of proven remittances that can stand in for `MinScore`
+    /// (0 = path disabled)
+    MinRemittanceMonths,
+    /// Whether open loans follow their borrower's NFT on transfer; when
+    /// off, such transfers are refused (default on)
+    LoanMigrationEnabled,
+    /// Share of seized collateral (bps) paid to whoever marks a default
+    KeeperBountyBps,
+    /// Flat bounty in `Token` paid from `KeeperReserve` when a default
+    /// yields no collateral bounty
+    KeeperReserveBounty,
+    /// `Token` balance set aside for keeper bounties
+    KeeperReserve,
+    /// loan id → address that marked the default (persistent)
+    DefaultKeeper(u32),
 }
 
 #[contract]
@@ -131,15 +483,21 @@ impl LoanManager {
     const PERSISTENT_TTL_BUMP: u32 = 518400;
     const DEFAULT_INTEREST_RATE_BPS: u32 = 1200;
     const DEFAULT_TERM_LEDGERS: u32 = 17280;
-    const CURRENT_VERSION: u32 = 3;
+    const CURRENT_VERSION: u32 = 6;
+    const MIN_UPGRADE_DELAY_LEDGERS: u32 = 17280;
+    const PARAM_OVERRIDE_DELAY_LEDGERS: u32 = 7 * 17280;
+    const DEFAULT_PARAM_WINDOW_LEDGERS: u32 = 17280;
+    const DEFAULT_PARAM_MAX_CHANGE_BPS: u32 = 5000;
+    pub const ADMIN_LOG_PAGE_SIZE: u32 = 50;
+    pub const MAX_ADMIN_LOG_LIMIT: u32 = 100;
+    pub const MAX_KEEPER_BOUNTY_BPS: u32 = 2000;
+    const DEFAULT_GUARDIAN_PAUSE_LEDGERS: u32 = 3 * 17280;
     const DEFAULT_LATE_FEE_RATE_BPS: u32 = 500;
     const MAX_LATE_FEE_CAP_BPS: u32 = 2500;
     const DEFAULT_MAX_LOAN_AMOUNT: i128 = 50_000;
     const DEFAULT_MAX_LOANS_PER_BORROWER: u32 = 3;
     const DEFAULT_GRACE_PERIOD_LEDGERS: u32 = 4_320;
     const DEFAULT_DEFAULT_WINDOW_LEDGERS: u32 = Self::DEFAULT_TERM_LEDGERS;
-    const LATE_REPAYMENT_SCORE_PENALTY: i32 = 10;
-    const DEFAULT_SCORE_PENALTY_POINTS: u32 = 50;
     const DEFAULT_MIN_REPAYMENT_AMOUNT: i128 = 100;
 
     fn bump_instance_ttl(env: &Env) {
@@ -212,7 +570,13 @@ impl LoanManager {
         }
     }
 
-    fn compute_interest_rate(env: &Env, borrower: &Address, amount: i128, score: u32) -> u32 {
+    fn compute_interest_rate(
+        env: &Env,
+        asset: &Address,
+        borrower: &Address,
+        amount: i128,
+        score: u32,
+    ) -> u32 {
         if let Some(oracle_addr) = env
             .storage()
             .instance()
@@ -221,8 +585,62 @@ impl LoanManager {
             let client = RateOracleClient::new(env, &oracle_addr);
             client.get_rate(borrower, &amount, &score)
         } else {
-            Self::read_interest_rate(env)
+            Self::asset_interest_rate(env, asset)
+        }
+    }
+
+    fn asset_interest_rate(env: &Env, asset: &Address) -> u32 {
+        Self::read_asset_config(env, asset)
+            .and_then(|config| config.interest_rate_bps)
+            .unwrap_or_else(|| Self::read_interest_rate(env))
+    }
+
+    fn read_asset_config(env: &Env, asset: &Address) -> Option<AssetConfig> {
+        Self::bump_instance_ttl(env);
+        env.storage()
+            .instance()
+            .get(&DataKey::AssetConfig(asset.clone()))
+    }
+
+    fn supported_assets(env: &Env) -> Vec<Address> {
+        Self::bump_instance_ttl(env);
+        env.storage()
+            .instance()
+            .get(&DataKey::SupportedAssets)
+            .unwrap_or(Vec::new(env))
+    }
+
+    fn require_supported_asset(env: &Env, asset: &Address) -> Result<AssetConfig, LoanError> {
+        Self::read_asset_config(env, asset).ok_or(LoanError::UnsupportedAsset)
+    }
+
+    fn validate_asset_config(config: &AssetConfig) -> Result<(), LoanError> {
+        if matches!(config.max_loan_amount, Some(amount) if amount <= 0) {
+            return Err(LoanError::InvalidAmount);
+        }
+        if config.interest_rate_bps == Some(0) {
+            return Err(LoanError::InvalidRate);
+        }
+        if matches!(config.min_repayment_amount, Some(amount) if amount < 0) {
+            return Err(LoanError::InvalidAmount);
+        }
+        Ok(())
+    }
+
+    fn write_asset_config(env: &Env, asset: &Address, config: &AssetConfig) {
+        env.storage()
+            .instance()
+            .set(&DataKey::AssetConfig(asset.clone()), config);
+
+        let mut assets = Self::supported_assets(env);
+        if !assets.contains(asset) {
+            assets.push_back(asset.clone());
+            env.storage()
+                .instance()
+                .set(&DataKey::SupportedAssets, &assets);
+            Self::seed_param_limits(env, Self::asset_params(env, asset));
         }
+        Self::bump_instance_ttl(env);
     }
 
     fn read_default_term(env: &Env) -> u32 {
@@ -233,16 +651,401 @@ impl LoanManager {
             .unwrap_or(Self::DEFAULT_TERM_LEDGERS)
     }
 
-    fn require_not_paused(env: &Env) -> Result<(), LoanError> {
-        Self::bump_instance_ttl(env);
+    /// Effective pause state. A guardian pause stops counting once its
+    /// expiry ledger is reached, without needing a write.
+    fn paused_now(env: &Env) -> bool {
         let paused: bool = env
             .storage()
             .instance()
             .get(&DataKey::Paused)
             .unwrap_or(false);
-        if paused {
+        if !paused {
+            return false;
+        }
+        match env
+            .storage()
+            .instance()
+            .get::<DataKey, u32>(&DataKey::GuardianPauseExpiry)
+        {
+            Some(expiry) => env.ledger().sequence() < expiry,
+            None => true,
+        }
+    }
+
+    fn admin_log_len(env: &Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::AdminLogLen)
+            .unwrap_or(0)
+    }
+
+    fn read_admin_log_page(env: &Env, page: u32) -> Vec<AdminAction> {
+        let key = DataKey::AdminLogPage(page);
+        match env
+            .storage()
+            .persistent()
+            .get::<DataKey, Vec<AdminAction>>(&key)
+        {
+            Some(entries) => {
+                Self::bump_persistent_ttl(env, &key);
+                entries
+            }
+            None => Vec::new(env),
+        }
+    }
+
+    /// Append a privileged call to the admin audit log. Entries are never
+    /// rewritten or removed.
+    fn log_admin_action(
+        env: &Env,
+        actor: &Address,
+        action: &str,
+        old: AdminValue,
+        new: AdminValue,
+    ) {
+        let index = Self::admin_log_len(env);
+        let page_index = index / Self::ADMIN_LOG_PAGE_SIZE;
+        let mut page = Self::read_admin_log_page(env, page_index);
+        let entry = AdminAction {
+            ledger: env.ledger().sequence(),
+            actor: actor.clone(),
+            action: Symbol::new(env, action),
+            old,
+            new,
+        };
+        page.push_back(entry.clone());
+
+        let key = DataKey::AdminLogPage(page_index);
+        env.storage().persistent().set(&key, &page);
+        Self::bump_persistent_ttl(env, &key);
+        env.storage()
+            .instance()
+            .set(&DataKey::AdminLogLen, &(index + 1));
+        Self::bump_instance_ttl(env);
+        events::admin_action_logged(env, index, entry);
+    }
+
+    fn read_param_limit(env: &Env, param: &LoanParam) -> Option<ParamLimit> {
+        let key = DataKey::ParamLimit(param.clone());
+        let limit = env.storage().persistent().get(&key);
+        if limit.is_some() {
+            Self::bump_persistent_ttl(env, &key);
+        }
+        limit
+    }
+
+    /// Limit seeded for `param` at initialization: half the current value
+    /// per day, or a fixed step in the parameter's units for small values.
+    fn default_param_limit(param: &LoanParam) -> Option<ParamLimit> {
+        let max_change = match param {
+            LoanParam::LateFeeRate
+            | LoanParam::InterestRate
+            | LoanParam::AssetInterestRate(_)
+            | LoanParam::KeeperBountyBps => 500,
+            LoanParam::GracePeriod
+            | LoanParam::DefaultWindow
+            | LoanParam::DefaultTerm
+            | LoanParam::MinTerm
+            | LoanParam::MaxTerm
+            | LoanParam::GuardianPauseLedgers => 17_280,
+            LoanParam::MinScore => 50,
+            LoanParam::MinRemittanceMonths => 3,
+            LoanParam::MaxLoansPerBorrower => 2,
+            LoanParam::MaxLoanAmount | LoanParam::AssetMaxLoanAmount(_) => {
+                Self::DEFAULT_MAX_LOAN_AMOUNT
+            }
+            LoanParam::MinRepaymentAmount
+            | LoanParam::AssetMinRepaymentAmount(_)
+            | LoanParam::KeeperReserveBounty => Self::DEFAULT_MIN_REPAYMENT_AMOUNT,
+            LoanParam::RateOracle => return None,
+        };
+        Some(ParamLimit {
+            max_change,
+            max_change_bps: Self::DEFAULT_PARAM_MAX_CHANGE_BPS,
+            window_ledgers: Self::DEFAULT_PARAM_WINDOW_LEDGERS,
+        })
+    }
+
+    /// Register the default limit for each of `params` that has none, so
+    /// limits are in force without the admin opting in. Existing limits
+    /// are left alone.
+    fn seed_param_limits(env: &Env, params: Vec<LoanParam>) {
+        for param in params.iter() {
+            let key = DataKey::ParamLimit(param.clone());
+            if env.storage().persistent().has(&key) {
+                continue;
+            }
+            if let Some(limit) = Self::default_param_limit(&param) {
+                env.storage().persistent().set(&key, &limit);
+                Self::bump_persistent_ttl(env, &key);
+            }
+        }
+    }
+
+    fn global_params(env: &Env) -> Vec<LoanParam> {
+        Vec::from_array(
+            env,
+            [
+                LoanParam::LateFeeRate,
+                LoanParam::GracePeriod,
+                LoanParam::DefaultWindow,
+                LoanParam::MinScore,
+                LoanParam::MinRemittanceMonths,
+                LoanParam::MaxLoanAmount,
+                LoanParam::MinRepaymentAmount,
+                LoanParam::MaxLoansPerBorrower,
+                LoanParam::InterestRate,
+                LoanParam::DefaultTerm,
+                LoanParam::MinTerm,
+                LoanParam::MaxTerm,
+                LoanParam::GuardianPauseLedgers,
+                LoanParam::KeeperBountyBps,
+                LoanParam::KeeperReserveBounty,
+            ],
+        )
+    }
+
+    fn asset_params(env: &Env, asset: &Address) -> Vec<LoanParam> {
+        Vec::from_array(
+            env,
+            [
+                LoanParam::AssetMaxLoanAmount(asset.clone()),
+                LoanParam::AssetInterestRate(asset.clone()),
+                LoanParam::AssetMinRepaymentAmount(asset.clone()),
+            ],
+        )
+    }
+
+    fn validate_param_limit(limit: &ParamLimit) -> Result<(), LoanError> {
+        if limit.max_change < 0 || limit.window_ledgers == 0 {
+            return Err(LoanError::InvalidParamLimit);
+        }
+        Ok(())
+    }
+
+    /// Use up the pending override for `param` if it is `expected` and its
+    /// timelock has passed.
+    fn consume_param_override(
+        env: &Env,
+        param: &LoanParam,
+        expected: &ParamOverride,
+    ) -> Result<(), LoanError> {
+        let key = DataKey::ParamOverride(param.clone());
+        let pending: PendingParamOverride = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .filter(|pending: &PendingParamOverride| pending.change == *expected)
+            .ok_or(LoanError::ParamOverrideRequired)?;
+        if env.ledger().sequence() < pending.eta {
+            return Err(LoanError::ParamOverrideTimelockActive);
+        }
+        env.storage().persistent().remove(&key);
+        Ok(())
+    }
+
+    /// Enforce `param`'s rate-of-change limit on a move from `old` to `new`.
+    /// Movement is measured from the value at the start of the current
+    /// window, so several small steps cannot add up past the allowance. A
+    /// matured override for exactly `new` lets a larger jump through and
+    /// starts a fresh window.
+    fn check_param_change(
+        env: &Env,
+        param: LoanParam,
+        old: i128,
+        new: i128,
+    ) -> Result<(), LoanError> {
+        if old == new {
+            return Ok(());
+        }
+        let Some(limit) = Self::read_param_limit(env, &param) else {
+            return Ok(());
+        };
+
+        let now = env.ledger().sequence();
+        let window_key = DataKey::ParamWindow(param.clone());
+        let mut window = env
+            .storage()
+            .persistent()
+            .get::<DataKey, ParamWindow>(&window_key)
+            .filter(|window| now < window.start_ledger.saturating_add(limit.window_ledgers))
+            .unwrap_or(ParamWindow {
+                start_ledger: now,
+                baseline: old,
+            });
+
+        let relative = window
+            .baseline
+            .checked_abs()
+            .and_then(|value| value.checked_mul(limit.max_change_bps as i128))
+            .map(|value| value / 10_000)
+            .unwrap_or(i128::MAX);
+        let allowance = limit.max_change.max(relative);
+        let moved = new
+            .checked_sub(window.baseline)
+            .and_then(i128::checked_abs)
+            .unwrap_or(i128::MAX);
+        if moved > allowance {
+            Self::consume_param_override(env, &param, &ParamOverride::Value(new)).map_err(
+                |err| {
+                    if err == LoanError::ParamOverrideRequired {
+                        LoanError::ParamChangeTooLarge
+                    } else {
+                        err
+                    }
+                },
+            )?;
+            window = ParamWindow {
+                start_ledger: now,
+                baseline: new,
+            };
+        }
+
+        env.storage().persistent().set(&window_key, &window);
+        Self::bump_persistent_ttl(env, &window_key);
+        Ok(())
+    }
+
+    fn operation_paused(env: &Env, operation: LoanOperation) -> bool {
+        env.storage()
+            .instance()
+            .get(&DataKey::OperationPaused(operation))
+            .unwrap_or(false)
+    }
+
+    /// `require_not_paused`, plus the operation's own pause flag.
+    fn require_operation_active(env: &Env, operation: LoanOperation) -> Result<(), LoanError> {
+        Self::require_not_paused(env)?;
+        if Self::operation_paused(env, operation) {
+            return Err(LoanError::OperationPaused);
+        }
+        Ok(())
+    }
+
+    fn read_repay_pause_windows(env: &Env) -> Vec<PauseWindow> {
+        env.storage()
+            .instance()
+            .get(&DataKey::RepayPauseWindows)
+            .unwrap_or(Vec::new(env))
+    }
+
+    /// Open, extend or close the current repayment pause window so it
+    /// matches the pause state just written. Call after every change to
+    /// `Paused`, `GuardianPauseExpiry`, the `Repayments` flag or `Version`.
+    fn sync_repay_pause_window(env: &Env) {
+        let now = env.ledger().sequence();
+        let globally_paused: bool = env
+            .storage()
+            .instance()
+            .get(&DataKey::Paused)
+            .unwrap_or(false);
+        let guardian_expiry: Option<u32> =
+            env.storage().instance().get(&DataKey::GuardianPauseExpiry);
+        let blocked_until = if Self::operation_paused(env, LoanOperation::Repayments)
+            || (globally_paused && guardian_expiry.is_none())
+            || Self::stored_version(env) < Self::CURRENT_VERSION
+        {
+            Some(None)
+        } else if globally_paused && guardian_expiry.is_some_and(|expiry| now < expiry) {
+            Some(guardian_expiry)
+        } else {
+            None
+        };
+
+        let mut windows = Self::read_repay_pause_windows(env);
+        let open = windows
+            .last()
+            .filter(|window| window.end.is_none_or(|end| end > now));
+        match (open, blocked_until) {
+            (Some(mut window), Some(end)) => {
+                window.end = end;
+                windows.set(windows.len() - 1, window);
+            }
+            (Some(mut window), None) => {
+                window.end = Some(now);
+                windows.set(windows.len() - 1, window);
+            }
+            (None, Some(end)) => windows.push_back(PauseWindow { start: now, end }),
+            (None, None) => return,
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::RepayPauseWindows, &windows);
+    }
+
+    /// Open an unbounded repayment pause window from now. `execute_upgrade`
+    /// calls this because the new code refuses repayments with
+    /// `MigrationPending` until `migrate_batch` catches the stored version
+    /// up, and that code is what closes the window again.
+    fn open_repay_pause_window(env: &Env) {
+        let now = env.ledger().sequence();
+        let mut windows = Self::read_repay_pause_windows(env);
+        match windows
+            .last()
+            .filter(|window| window.end.is_none_or(|end| end > now))
+        {
+            Some(mut window) => {
+                window.end = None;
+                windows.set(windows.len() - 1, window);
+            }
+            None => windows.push_back(PauseWindow {
+                start: now,
+                end: None,
+            }),
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::RepayPauseWindows, &windows);
+    }
+
+    /// Earliest ledger `loan` still measures repayment pauses from, or
+    /// `None` once it no longer accrues.
+    fn pause_reference_ledger(loan: &Loan) -> Option<u32> {
+        match loan.status {
+            LoanStatus::Approved => Some(loan.last_interest_ledger.min(loan.due_date)),
+            _ => None,
+        }
+    }
+
+    /// Ledgers in `[from, to)` during which repayments were blocked.
+    fn repay_paused_ledgers(env: &Env, from: u32, to: u32) -> u32 {
+        let mut paused = 0u32;
+        for window in Self::read_repay_pause_windows(env).iter() {
+            let start = window.start.max(from);
+            let end = window.end.unwrap_or(to).min(to);
+            if end > start {
+                paused += end - start;
+            }
+        }
+        paused
+    }
+
+    /// Repayments answer only to this contract's own pauses, which are the
+    /// ones recorded in `RepayPauseWindows`; a pool or NFT pause must not
+    /// leave borrowers accruing interest they are unable to pay. Closes a
+    /// window an upgrade left open when no migration followed it.
+    fn require_repayments_open(env: &Env) -> Result<(), LoanError> {
+        Self::require_not_paused_locally(env)?;
+        if Self::operation_paused(env, LoanOperation::Repayments) {
+            return Err(LoanError::OperationPaused);
+        }
+        Self::sync_repay_pause_window(env);
+        Ok(())
+    }
+
+    fn require_not_paused_locally(env: &Env) -> Result<(), LoanError> {
+        Self::bump_instance_ttl(env);
+        if Self::stored_version(env) < Self::CURRENT_VERSION {
+            return Err(LoanError::MigrationPending);
+        }
+        if Self::paused_now(env) {
             return Err(LoanError::ContractPaused);
         }
+        Ok(())
+    }
+
+    fn require_not_paused(env: &Env) -> Result<(), LoanError> {
+        Self::require_not_paused_locally(env)?;
         // Cascade: also check whether the LendingPool is paused.
         if let Some(pool_addr) = env
             .storage()
@@ -290,7 +1093,9 @@ impl LoanManager {
             return;
         }
 
-        let elapsed_ledgers = current_ledger - loan.last_interest_ledger;
+        let elapsed_ledgers = current_ledger
+            - loan.last_interest_ledger
+            - Self::repay_paused_ledgers(env, loan.last_interest_ledger, current_ledger);
         const PRECISION: i128 = 1_000_000;
 
         // Calculate interest with high precision to avoid truncation for small loans
@@ -346,6 +1151,18 @@ impl LoanManager {
             .unwrap_or(Self::DEFAULT_DEFAULT_WINDOW_LEDGERS)
     }
 
+    /// Last ledger `loan` can still be repaid or refinanced; it may be
+    /// defaulted after it. Ledgers past the due date when repayments were
+    /// blocked do not count against the borrower's default window.
+    fn default_eligible_after(env: &Env, loan: &Loan, now: u32) -> u32 {
+        loan.due_date
+            .checked_add(Self::default_window_ledgers(env))
+            .and_then(|ledger| {
+                ledger.checked_add(Self::repay_paused_ledgers(env, loan.due_date, now))
+            })
+            .expect("default window overflow")
+    }
+
     fn max_loan_amount(env: &Env) -> i128 {
         Self::bump_instance_ttl(env);
         env.storage()
@@ -354,21 +1171,282 @@ impl LoanManager {
             .unwrap_or(Self::DEFAULT_MAX_LOAN_AMOUNT)
     }
 
-    fn max_loans_per_borrower(env: &Env) -> u32 {
-        Self::bump_instance_ttl(env);
+    fn min_remittance_months(env: &Env) -> u32 {
         env.storage()
             .instance()
-            .get(&DataKey::MaxLoansPerBorrower)
-            .unwrap_or(Self::DEFAULT_MAX_LOANS_PER_BORROWER)
+            .get(&DataKey::MinRemittanceMonths)
+            .unwrap_or(0)
     }
 
-    fn min_repayment_amount(env: &Env) -> i128 {
-        Self::bump_instance_ttl(env);
-        env.storage()
-            .instance()
-            .get(&DataKey::MinRepaymentAmount)
-            .unwrap_or(Self::DEFAULT_MIN_REPAYMENT_AMOUNT)
-    }
+    /// Check that `records` are committed to by the borrower's NFT history
+    /// root and span at least `MinRemittanceMonths` distinct periods.
+    fn require_remittance_history(
+        env: &Env,
+        nft_client: &NftClient,
+        borrower: &Address,
+        records: &Vec<RemittanceRecord>,
+        proofs: &Vec<Vec<BytesN<32>>>,
+    ) -> Result<(), LoanError> {
+        let required = Self::min_remittance_months(env);
+        if required == 0 {
+            return Err(LoanError::InsufficientScore);
+        }
+        if records.len() != proofs.len() {
+            return Err(LoanError::InvalidHistoryProof);
+        }
+
+        let mut periods: Vec<u32> = Vec::new(env);
+        for (record, proof) in records.iter().zip(proofs.iter()) {
+            if !nft_client.verify_history_record(borrower, &record, &proof) {
+                return Err(LoanError::InvalidHistoryProof);
+            }
+            if !periods.contains(record.period) {
+                periods.push_back(record.period);
+            }
+        }
+        if periods.len() < required {
+            return Err(LoanError::InsufficientRemittanceHistory);
+        }
+        Ok(())
+    }
+
+    fn max_loans_per_borrower(env: &Env) -> u32 {
+        Self::bump_instance_ttl(env);
+        env.storage()
+            .instance()
+            .get(&DataKey::MaxLoansPerBorrower)
+            .unwrap_or(Self::DEFAULT_MAX_LOANS_PER_BORROWER)
+    }
+
+    fn min_repayment_amount(env: &Env) -> i128 {
+        Self::bump_instance_ttl(env);
+        env.storage()
+            .instance()
+            .get(&DataKey::MinRepaymentAmount)
+            .unwrap_or(Self::DEFAULT_MIN_REPAYMENT_AMOUNT)
+    }
+
+    fn max_loan_amount_for(env: &Env, asset: &Address) -> i128 {
+        Self::read_asset_config(env, asset)
+            .and_then(|config| config.max_loan_amount)
+            .unwrap_or_else(|| Self::max_loan_amount(env))
+    }
+
+    fn min_repayment_amount_for(env: &Env, asset: &Address) -> i128 {
+        Self::read_asset_config(env, asset)
+            .and_then(|config| config.min_repayment_amount)
+            .unwrap_or_else(|| Self::min_repayment_amount(env))
+    }
+
+    /// Borrower's score as seen by this contract, which reads private
+    /// records through the grant `grant_score_access` records. 0 without an
+    /// NFT or access.
+    fn borrower_score(env: &Env, nft_client: &NftClient, borrower: &Address) -> u32 {
+        match nft_client.try_get_score_for(borrower, &env.current_contract_address()) {
+            Ok(Ok(score)) => score,
+            _ => 0,
+        }
+    }
+
+    /// Let this contract read `borrower`'s score until a loan of `term`
+    /// ledgers taken now could be defaulted. Called from entrypoints the
+    /// borrower signs; a grant that already lasts that long is left alone,
+    /// and a broader scope is kept.
+    fn grant_score_access(env: &Env, nft_client: &NftClient, borrower: &Address, term: u32) {
+        let this = env.current_contract_address();
+        let expiry_ledger = env
+            .ledger()
+            .sequence()
+            .saturating_add(term)
+            .saturating_add(Self::default_window_ledgers(env));
+        let existing = nft_client.get_access_grant(borrower, &this);
+        if existing
+            .as_ref()
+            .is_some_and(|grant| grant.expiry_ledger >= expiry_ledger)
+        {
+            return;
+        }
+        let scope = existing.map_or(AccessScope::Score, |grant| grant.scope);
+        nft_client.grant_access(borrower, &this, &scope, &expiry_ledger);
+    }
+
+    fn loan_migration_enabled(env: &Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&DataKey::LoanMigrationEnabled)
+            .unwrap_or(true)
+    }
+
+    fn stored_version(env: &Env) -> u32 {
+        env.storage().instance().get(&DataKey::Version).unwrap_or(0)
+    }
+
+    fn migration_cursor(env: &Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::MigrationCursor)
+            .unwrap_or(1)
+    }
+
+    fn migration_status(env: &Env) -> MigrationStatus {
+        let version = Self::stored_version(env);
+        MigrationStatus {
+            version,
+            target_version: Self::CURRENT_VERSION,
+            cursor: Self::migration_cursor(env),
+            complete: version >= Self::CURRENT_VERSION,
+        }
+    }
+
+    /// Whether the step from `from_version` rewrites `Loan(id)`-indexed
+    /// entries and so must be batched.
+    fn step_migrates_loans(from_version: u32) -> bool {
+        from_version == 3 || from_version == 4
+    }
+
+    /// Per-loan work of the step from `from_version`.
+    fn migrate_loan(env: &Env, from_version: u32, loan_id: u32) {
+        if from_version == 3 {
+            // 3 → 4: loans gain `asset` and `collateral_asset`. Before
+            // multi-asset support both were the single configured `Token`.
+            let key = DataKey::Loan(loan_id);
+            let Some(raw) = env
+                .storage()
+                .persistent()
+                .get::<DataKey, Map<Symbol, Val>>(&key)
+            else {
+                return;
+            };
+            // Already rewritten by an earlier, overlapping batch.
+            if raw.contains_key(Symbol::new(env, "asset")) {
+                return;
+            }
+            let old = LoanV3::try_from_val(env, &raw.to_val()).expect("malformed version 3 loan");
+            let token: Address = env
+                .storage()
+                .instance()
+                .get(&DataKey::Token)
+                .expect("not initialized");
+            let loan = Loan {
+                borrower: old.borrower,
+                amount: old.amount,
+                collateral_amount: old.collateral_amount,
+                principal_paid: old.principal_paid,
+                interest_paid: old.interest_paid,
+                accrued_interest: old.accrued_interest,
+                late_fee_paid: old.late_fee_paid,
+                accrued_late_fee: old.accrued_late_fee,
+                interest_rate_bps: old.interest_rate_bps,
+                due_date: old.due_date,
+                last_interest_ledger: old.last_interest_ledger,
+                last_late_fee_ledger: old.last_late_fee_ledger,
+                status: old.status,
+                interest_residual: old.interest_residual,
+                extension_count: old.extension_count,
+                term_ledgers: old.term_ledgers,
+                asset: token.clone(),
+                collateral_asset: token,
+            };
+            env.storage().persistent().set(&key, &loan);
+            Self::bump_persistent_ttl(env, &key);
+        } else if from_version == 4 {
+            // 4 → 5: borrower loan lists move out of instance storage, which
+            // is loaded on every call and must stay small.
+            let Some(loan) = env
+                .storage()
+                .persistent()
+                .get::<DataKey, Loan>(&DataKey::Loan(loan_id))
+            else {
+                return;
+            };
+            let key = DataKey::BorrowerLoans(loan.borrower.clone());
+            if let Some(loan_ids) = env.storage().instance().get::<DataKey, Vec<u32>>(&key) {
+                Self::write_borrower_loans(env, &loan.borrower, &loan_ids);
+            }
+        }
+    }
+
+    /// Instance-level work of the step from `from_version`, then records
+    /// the step and advances the stored version. Returns the new version.
+    fn complete_migration_step(env: &Env, from_version: u32) -> u32 {
+        if from_version < 4 {
+            if !env.storage().instance().has(&DataKey::LateFeeRateBps) {
+                env.storage()
+                    .instance()
+                    .set(&DataKey::LateFeeRateBps, &Self::DEFAULT_LATE_FEE_RATE_BPS);
+            }
+            // Pre-multi-asset deployments only knew a single `Token`; whitelist
+            // it so existing borrowers can keep requesting loans in it.
+            if !env.storage().instance().has(&DataKey::SupportedAssets) {
+                if let Some(token) = env.storage().instance().get::<_, Address>(&DataKey::Token) {
+                    Self::write_asset_config(
+                        env,
+                        &token,
+                        &AssetConfig {
+                            max_loan_amount: None,
+                            interest_rate_bps: None,
+                            min_repayment_amount: None,
+                            collateral_asset: token.clone(),
+                        },
+                    );
+                }
+            }
+        }
+
+        if from_version == 5 {
+            // 5 → 6: rate-of-change limits stop being opt-in.
+            Self::seed_param_limits(env, Self::global_params(env));
+            for asset in Self::supported_assets(env).iter() {
+                Self::seed_param_limits(env, Self::asset_params(env, &asset));
+            }
+        }
+
+        let to_version = from_version + 1;
+        let mut log: Vec<MigrationRecord> = env
+            .storage()
+            .instance()
+            .get(&DataKey::MigrationLog)
+            .unwrap_or(Vec::new(env));
+        log.push_back(MigrationRecord {
+            from_version,
+            to_version,
+            ledger: env.ledger().sequence(),
+        });
+        env.storage().instance().set(&DataKey::MigrationLog, &log);
+        env.storage().instance().set(&DataKey::Version, &to_version);
+        env.storage().instance().remove(&DataKey::MigrationCursor);
+        events::migration_step_completed(env, from_version, to_version);
+        to_version
+    }
+
+    fn read_borrower_loans(env: &Env, borrower: &Address) -> Vec<u32> {
+        let key = DataKey::BorrowerLoans(borrower.clone());
+        if let Some(loan_ids) = env.storage().persistent().get(&key) {
+            Self::bump_persistent_ttl(env, &key);
+            return loan_ids;
+        }
+        // Not yet moved by the version 5 migration.
+        env.storage().instance().get(&key).unwrap_or(Vec::new(env))
+    }
+
+    fn write_borrower_loans(env: &Env, borrower: &Address, loan_ids: &Vec<u32>) {
+        let key = DataKey::BorrowerLoans(borrower.clone());
+        env.storage().persistent().set(&key, loan_ids);
+        Self::bump_persistent_ttl(env, &key);
+        env.storage().instance().remove(&key);
+    }
+
+    fn has_open_loans(env: &Env, borrower: &Address) -> bool {
+        let loan_ids = Self::read_borrower_loans(env, borrower);
+        loan_ids.iter().any(|loan_id| {
+            env.storage()
+                .persistent()
+                .get::<DataKey, Loan>(&DataKey::Loan(loan_id))
+                .is_some_and(|loan| {
+                    matches!(loan.status, LoanStatus::Pending | LoanStatus::Approved)
+                })
+        })
+    }
 
     fn borrower_loan_count(env: &Env, borrower: &Address) -> u32 {
         let key = DataKey::BorrowerLoanCount(borrower.clone());
@@ -435,7 +1513,9 @@ impl LoanManager {
             .checked_add(loan.accrued_interest)
             .expect("debt overflow");
 
-        let overdue_ledgers = current_ledger - late_fee_start;
+        let overdue_ledgers = current_ledger
+            - late_fee_start
+            - Self::repay_paused_ledgers(env, late_fee_start, current_ledger);
         let incremental_fee = remaining_debt
             .checked_mul(Self::late_fee_rate_bps(env) as i128)
             .and_then(|value| value.checked_mul(overdue_ledgers as i128))
@@ -588,19 +1668,17 @@ impl LoanManager {
         env.storage().persistent().set(&loan_key, &loan);
         Self::bump_persistent_ttl(env, &loan_key);
 
-        let token: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Token)
-            .expect("token not set");
-        let token_client = TokenClient::new(env, &token);
+        let token_client = TokenClient::new(env, &loan.collateral_asset);
         token_client.transfer(&env.current_contract_address(), recipient, &collateral);
 
         // Emit collateral returned event
         events::collateral_returned(env, recipient.clone(), loan_id, collateral);
     }
 
-    fn seize_collateral_internal(env: &Env, loan_id: u32) {
+    /// Sends a defaulted loan's collateral to the pool, less the keeper's
+    /// share. Falls back to the reserve bounty when that share is zero.
+    /// Returns the bounty paid to `keeper`.
+    fn seize_collateral_internal(env: &Env, loan_id: u32, keeper: &Address) -> i128 {
         use soroban_sdk::token::TokenClient;
 
         let loan_key = DataKey::Loan(loan_id);
@@ -612,27 +1690,126 @@ impl LoanManager {
 
         let collateral = loan.collateral_amount;
         if collateral <= 0 {
-            return;
+            return Self::pay_reserve_bounty(env, loan_id, keeper);
         }
 
         loan.collateral_amount = 0;
         env.storage().persistent().set(&loan_key, &loan);
         Self::bump_persistent_ttl(env, &loan_key);
 
-        let token: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Token)
-            .expect("token not set");
+        let bounty = collateral
+            .checked_mul(Self::keeper_bounty_bps(env) as i128)
+            .expect("keeper bounty overflow")
+            / 10_000;
+
         let lending_pool: Address = env
             .storage()
             .instance()
             .get(&DataKey::LendingPool)
             .expect("lending pool not set");
-        let token_client = TokenClient::new(env, &token);
-        token_client.transfer(&env.current_contract_address(), &lending_pool, &collateral);
+        let token_client = TokenClient::new(env, &loan.collateral_asset);
+        token_client.transfer(
+            &env.current_contract_address(),
+            &lending_pool,
+            &(collateral - bounty),
+        );
 
         events::collateral_liquidated(env, loan_id, collateral);
+
+        if bounty == 0 {
+            return Self::pay_reserve_bounty(env, loan_id, keeper);
+        }
+        token_client.transfer(&env.current_contract_address(), keeper, &bounty);
+        events::keeper_rewarded(env, loan_id, keeper.clone(), loan.collateral_asset, bounty);
+        bounty
+    }
+
+    /// Pays `keeper` the flat reserve bounty, or whatever is left in the
+    /// reserve if that is less, in `Token`. Returns the amount paid.
+    fn pay_reserve_bounty(env: &Env, loan_id: u32, keeper: &Address) -> i128 {
+        use soroban_sdk::token::TokenClient;
+
+        let reserve = Self::keeper_reserve(env);
+        let bounty = Self::keeper_reserve_bounty(env).min(reserve);
+        if bounty <= 0 {
+            return 0;
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::KeeperReserve, &(reserve - bounty));
+
+        let token: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Token)
+            .expect("not initialized");
+        TokenClient::new(env, &token).transfer(&env.current_contract_address(), keeper, &bounty);
+        events::keeper_rewarded(env, loan_id, keeper.clone(), token, bounty);
+        bounty
+    }
+
+    fn keeper_bounty_bps(env: &Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::KeeperBountyBps)
+            .unwrap_or(0)
+    }
+
+    fn keeper_reserve_bounty(env: &Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&DataKey::KeeperReserveBounty)
+            .unwrap_or(0)
+    }
+
+    fn keeper_reserve(env: &Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&DataKey::KeeperReserve)
+            .unwrap_or(0)
+    }
+
+    /// Marks `loan_id` defaulted and pays `keeper` its bounty. A loan moves
+    /// from `Approved` to `Defaulted` only once, so its bounty cannot be
+    /// claimed twice.
+    fn process_default(env: &Env, keeper: &Address, loan_id: u32) -> Result<i128, LoanError> {
+        let loan_key = DataKey::Loan(loan_id);
+        let mut loan: Loan = env
+            .storage()
+            .persistent()
+            .get(&loan_key)
+            .ok_or(LoanError::LoanNotFound)?;
+        Self::bump_persistent_ttl(env, &loan_key);
+
+        if loan.status != LoanStatus::Approved {
+            return Err(LoanError::LoanNotActive);
+        }
+
+        let current_ledger = env.ledger().sequence();
+        if current_ledger <= Self::default_eligible_after(env, &loan, current_ledger) {
+            return Err(LoanError::LoanNotPastDue);
+        }
+
+        loan.status = LoanStatus::Defaulted;
+        env.storage().persistent().set(&loan_key, &loan);
+        Self::bump_persistent_ttl(env, &loan_key);
+        let keeper_key = DataKey::DefaultKeeper(loan_id);
+        env.storage().persistent().set(&keeper_key, keeper);
+        Self::bump_persistent_ttl(env, &keeper_key);
+        Self::decrement_borrower_loan_count(env, &loan.borrower);
+        let bounty = Self::seize_collateral_internal(env, loan_id, keeper);
+
+        let nft_contract = Self::nft_contract(env);
+        let nft_client = NftClient::new(env, &nft_contract);
+        nft_client.apply_default_penalty(&loan.borrower, &Some(env.current_contract_address()));
+        nft_client.record_default(&loan.borrower, &Some(env.current_contract_address()));
+
+        events::loan_defaulted(env, loan_id, loan.borrower.clone());
+
+        // Remove loan from storage after emitting terminal event
+        // env.storage().persistent().remove(&loan_key);
+
+        Ok(bounty)
     }
 
     pub fn initialize(
@@ -652,6 +1829,16 @@ impl LoanManager {
             .instance()
             .set(&DataKey::LendingPool, &lending_pool);
         env.storage().instance().set(&DataKey::Token, &token);
+        Self::write_asset_config(
+            &env,
+            &token,
+            &AssetConfig {
+                max_loan_amount: None,
+                interest_rate_bps: None,
+                min_repayment_amount: None,
+                collateral_asset: token.clone(),
+            },
+        );
         env.storage().instance().set(&DataKey::Admin, &admin);
         env.storage().instance().set(&DataKey::LoanCounter, &0u32);
         env.storage().instance().set(&DataKey::Paused, &false);
@@ -681,6 +1868,14 @@ impl LoanManager {
             &DataKey::DefaultWindowLedgers,
             &Self::DEFAULT_DEFAULT_WINDOW_LEDGERS,
         );
+        Self::seed_param_limits(&env, Self::global_params(&env));
+        Self::log_admin_action(
+            &env,
+            &admin,
+            "initialize",
+            AdminValue::None,
+            AdminValue::Address(admin.clone()),
+        );
         Self::bump_instance_ttl(&env);
         Ok(())
     }
@@ -690,48 +1885,228 @@ impl LoanManager {
         env.storage().instance().get(&DataKey::Version).unwrap_or(0)
     }
 
-    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
+    /// Announce an upgrade to `wasm_hash`, executable from ledger `eta`,
+    /// which must be at least `MIN_UPGRADE_DELAY_LEDGERS` away. Replaces
+    /// any upgrade already scheduled.
+    pub fn schedule_upgrade(env: Env, wasm_hash: BytesN<32>, eta: u32) -> Result<(), LoanError> {
         Self::admin(&env).require_auth();
-        let old_version = Self::version(env.clone());
-        let new_version = old_version.saturating_add(1);
-        env.storage()
+        let earliest = env
+            .ledger()
+            .sequence()
+            .saturating_add(Self::MIN_UPGRADE_DELAY_LEDGERS);
+        if eta < earliest {
+            return Err(LoanError::InvalidUpgradeEta);
+        }
+
+        env.storage().instance().set(
+            &DataKey::ScheduledUpgrade,
+            &ScheduledUpgrade {
+                wasm_hash: wasm_hash.clone(),
+                eta,
+            },
+        );
+        Self::bump_instance_ttl(&env);
+        Self::log_admin_action(
+            &env,
+            &Self::admin(&env),
+            "schedule_upgrade",
+            AdminValue::None,
+            AdminValue::Hash(wasm_hash.clone()),
+        );
+        events::upgrade_scheduled(&env, wasm_hash, eta);
+        Ok(())
+    }
+
+    pub fn cancel_upgrade(env: Env) -> Result<(), LoanError> {
+        Self::admin(&env).require_auth();
+        let scheduled: ScheduledUpgrade = env
+            .storage()
             .instance()
-            .set(&DataKey::Version, &new_version);
-        env.events().publish(
-            (Symbol::new(&env, "ContractUpgraded"),),
-            (old_version, new_version),
+            .get(&DataKey::ScheduledUpgrade)
+            .ok_or(LoanError::NoUpgradeScheduled)?;
+        env.storage().instance().remove(&DataKey::ScheduledUpgrade);
+        Self::log_admin_action(
+            &env,
+            &Self::admin(&env),
+            "cancel_upgrade",
+            AdminValue::Hash(scheduled.wasm_hash.clone()),
+            AdminValue::None,
+        );
+        events::upgrade_cancelled(&env, scheduled.wasm_hash);
+        Ok(())
+    }
+
+    /// Swap in the scheduled wasm once its eta has passed. `Version` is
+    /// left alone: the new code records its own `CURRENT_VERSION` in
+    /// `migrate_batch`. Repayments count as paused from here until then.
+    pub fn execute_upgrade(env: Env) -> Result<(), LoanError> {
+        Self::admin(&env).require_auth();
+        let scheduled: ScheduledUpgrade = env
+            .storage()
+            .instance()
+            .get(&DataKey::ScheduledUpgrade)
+            .ok_or(LoanError::NoUpgradeScheduled)?;
+        if env.ledger().sequence() < scheduled.eta {
+            return Err(LoanError::UpgradeTimelockActive);
+        }
+
+        env.storage().instance().remove(&DataKey::ScheduledUpgrade);
+        Self::log_admin_action(
+            &env,
+            &Self::admin(&env),
+            "execute_upgrade",
+            AdminValue::U32(Self::version(env.clone())),
+            AdminValue::Hash(scheduled.wasm_hash.clone()),
+        );
+        events::upgrade_executed(
+            &env,
+            Self::version(env.clone()),
+            scheduled.wasm_hash.clone(),
         );
-        env.deployer().update_current_contract_wasm(new_wasm_hash);
+        Self::open_repay_pause_window(&env);
+        env.deployer()
+            .update_current_contract_wasm(scheduled.wasm_hash);
+        Ok(())
+    }
+
+    pub fn get_scheduled_upgrade(env: Env) -> Option<ScheduledUpgrade> {
+        Self::bump_instance_ttl(&env);
+        env.storage().instance().get(&DataKey::ScheduledUpgrade)
     }
 
-    pub fn migrate(env: Env) {
+    /// Run the next slice of the pending storage migration.
+    ///
+    /// Steps go one version at a time. Steps that rewrite per-loan entries
+    /// walk `Loan(id)` from the stored cursor, `limit` ids per call; `start`
+    /// may repeat already-processed ids (steps are idempotent) but may not
+    /// skip ahead of the cursor. Steps that only touch instance config run
+    /// inline. Normal entrypoints return `MigrationPending` until the
+    /// stored version reaches `CURRENT_VERSION`.
+    pub fn migrate_batch(env: Env, start: u32, limit: u32) -> Result<MigrationStatus, LoanError> {
         Self::admin(&env).require_auth();
+        if limit == 0 {
+            return Err(LoanError::InvalidMigrationBatch);
+        }
 
-        if !env.storage().instance().has(&DataKey::LateFeeRateBps) {
-            env.storage()
+        // Upgrades from code that predates `open_repay_pause_window` start
+        // the window here instead.
+        Self::sync_repay_pause_window(&env);
+        let mut version = Self::stored_version(&env);
+        let entry_version = version;
+        if version < Self::CURRENT_VERSION && Self::step_migrates_loans(version) {
+            let cursor = Self::migration_cursor(&env);
+            if start > cursor {
+                return Err(LoanError::MigrationCursorAhead);
+            }
+            let last_loan_id: u32 = env
+                .storage()
                 .instance()
-                .set(&DataKey::LateFeeRateBps, &Self::DEFAULT_LATE_FEE_RATE_BPS);
+                .get(&DataKey::LoanCounter)
+                .unwrap_or(0);
+            let end = start
+                .saturating_add(limit)
+                .min(last_loan_id.saturating_add(1));
+            for loan_id in start..end {
+                Self::migrate_loan(&env, version, loan_id);
+            }
+
+            let next_cursor = cursor.max(end);
+            if next_cursor <= last_loan_id {
+                env.storage()
+                    .instance()
+                    .set(&DataKey::MigrationCursor, &next_cursor);
+                Self::bump_instance_ttl(&env);
+                Self::log_admin_action(
+                    &env,
+                    &Self::admin(&env),
+                    "migrate_batch",
+                    AdminValue::U32(entry_version),
+                    AdminValue::U32(version),
+                );
+                return Ok(Self::migration_status(&env));
+            }
+            version = Self::complete_migration_step(&env, version);
         }
+
+        while version < Self::CURRENT_VERSION && !Self::step_migrates_loans(version) {
+            version = Self::complete_migration_step(&env, version);
+        }
+        Self::sync_repay_pause_window(&env);
+        Self::log_admin_action(
+            &env,
+            &Self::admin(&env),
+            "migrate_batch",
+            AdminValue::U32(entry_version),
+            AdminValue::U32(version),
+        );
+        Self::bump_instance_ttl(&env);
+        Ok(Self::migration_status(&env))
+    }
+
+    pub fn get_migration_status(env: Env) -> MigrationStatus {
+        Self::bump_instance_ttl(&env);
+        Self::migration_status(&env)
+    }
+
+    pub fn get_migration_log(env: Env) -> Vec<MigrationRecord> {
+        Self::bump_instance_ttl(&env);
         env.storage()
             .instance()
-            .set(&DataKey::Version, &Self::CURRENT_VERSION);
-        Self::bump_instance_ttl(&env);
+            .get(&DataKey::MigrationLog)
+            .unwrap_or(Vec::new(&env))
     }
 
+    /// Request a loan of `amount` denominated in `asset`.
+    ///
+    /// `asset` must be on the supported-asset whitelist. Its `AssetConfig`
+    /// determines the loan ceiling, interest rate (unless a rate oracle is
+    /// set) and the token collateral must be posted in.
     pub fn request_loan(
         env: Env,
         borrower: Address,
+        asset: Address,
+        amount: i128,
+        term: u32,
+    ) -> Result<u32, LoanError> {
+        Self::create_loan_request(env, borrower, asset, amount, term, None)
+    }
+
+    /// Request a loan, offering Merkle proofs of remittance history as an
+    /// alternative to meeting `MinScore`.
+    ///
+    /// `records[i]` must be proven against the borrower's NFT history root
+    /// by `proofs[i]`, and the records must cover at least
+    /// `MinRemittanceMonths` distinct periods. The proofs are only checked
+    /// when the borrower's score is below the minimum.
+    pub fn request_loan_with_history(
+        env: Env,
+        borrower: Address,
+        asset: Address,
+        amount: i128,
+        term: u32,
+        records: Vec<RemittanceRecord>,
+        proofs: Vec<Vec<BytesN<32>>>,
+    ) -> Result<u32, LoanError> {
+        Self::create_loan_request(env, borrower, asset, amount, term, Some((records, proofs)))
+    }
+
+    fn create_loan_request(
+        env: Env,
+        borrower: Address,
+        asset: Address,
         amount: i128,
         term: u32,
+        history: Option<(Vec<RemittanceRecord>, Vec<Vec<BytesN<32>>>)>,
     ) -> Result<u32, LoanError> {
         borrower.require_auth();
-        Self::require_not_paused(&env)?;
+        Self::require_operation_active(&env, LoanOperation::NewLoans)?;
 
         if amount <= 0 {
             return Err(LoanError::InvalidAmount);
         }
 
-        let max_loan_amount = Self::max_loan_amount(&env);
+        let asset_config = Self::require_supported_asset(&env, &asset)?;
+        let max_loan_amount = Self::max_loan_amount_for(&env, &asset);
         if amount > max_loan_amount {
             return Err(LoanError::InvalidAmount);
         }
@@ -747,14 +2122,24 @@ impl LoanManager {
             .ok_or(LoanError::NotInitialized)?;
         let nft_client = NftClient::new(&env, &nft_contract);
 
-        let score = nft_client.get_score(&borrower);
+        Self::grant_score_access(&env, &nft_client, &borrower, term);
+        let score = Self::borrower_score(&env, &nft_client, &borrower);
         let min_score: u32 = env
             .storage()
             .instance()
             .get(&DataKey::MinScore)
             .unwrap_or(500);
         if score < min_score {
-            return Err(LoanError::InsufficientScore);
+            match history {
+                Some((records, proofs)) => Self::require_remittance_history(
+                    &env,
+                    &nft_client,
+                    &borrower,
+                    &records,
+                    &proofs,
+                )?,
+                None => return Err(LoanError::InsufficientScore),
+            }
         }
         if nft_client.is_seized(&borrower) {
             return Err(LoanError::SeizedBorrower);
@@ -782,7 +2167,7 @@ impl LoanManager {
             accrued_interest: 0,
             late_fee_paid: 0,
             accrued_late_fee: 0,
-            interest_rate_bps: Self::compute_interest_rate(&env, &borrower, amount, score),
+            interest_rate_bps: Self::compute_interest_rate(&env, &asset, &borrower, amount, score),
             due_date: 0,
             last_interest_ledger: 0,
             last_late_fee_ledger: 0,
@@ -790,6 +2175,8 @@ impl LoanManager {
             interest_residual: 0,
             extension_count: 0,
             term_ledgers: term,
+            asset: asset.clone(),
+            collateral_asset: asset_config.collateral_asset,
         };
 
         env.storage()
@@ -807,19 +2194,11 @@ impl LoanManager {
         Self::bump_persistent_ttl(&env, &DataKey::Loan(loan_counter));
 
         // Add loan ID to borrower's loan list
-        let borrower_loans_key = DataKey::BorrowerLoans(borrower.clone());
-        let mut borrower_loans: Vec<u32> = env
-            .storage()
-            .instance()
-            .get(&borrower_loans_key)
-            .unwrap_or(Vec::new(&env));
+        let mut borrower_loans = Self::read_borrower_loans(&env, &borrower);
         borrower_loans.push_back(loan_counter);
-        env.storage()
-            .instance()
-            .set(&borrower_loans_key, &borrower_loans);
-        Self::bump_instance_ttl(&env);
+        Self::write_borrower_loans(&env, &borrower, &borrower_loans);
 
-        events::loan_requested(&env, borrower.clone(), amount);
+        events::loan_requested(&env, borrower.clone(), asset, amount);
         env.events()
             .publish((symbol_short!("LoanReq"), borrower), loan_counter);
         Ok(loan_counter)
@@ -831,7 +2210,7 @@ impl LoanManager {
         // ── CHECKS ──────────────────────────────────────────────────────────
         let admin = Self::admin(&env);
         admin.require_auth();
-        Self::require_not_paused(&env)?;
+        Self::require_operation_active(&env, LoanOperation::Approvals)?;
 
         let loan_key = DataKey::Loan(loan_id);
         let mut loan: Loan = env
@@ -845,17 +2224,16 @@ impl LoanManager {
             return Err(LoanError::LoanNotPending);
         }
 
+        // Delisted assets keep servicing existing loans but disburse no more.
+        Self::require_supported_asset(&env, &loan.asset)?;
+
         // Read all instance-level config before any state mutations.
         let lending_pool: Address = env
             .storage()
             .instance()
             .get(&DataKey::LendingPool)
             .expect("lending pool not set");
-        let token: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Token)
-            .expect("token not set");
+        let token = loan.asset.clone();
 
         // Cross-contract READ for liquidity check — still in the CHECKS phase.
         let pool_client = PoolClient::new(&env, &lending_pool);
@@ -885,6 +2263,13 @@ impl LoanManager {
         let token_client = TokenClient::new(&env, &token);
         token_client.transfer(&lending_pool, &borrower, &transfer_amount);
 
+        Self::log_admin_action(
+            &env,
+            &admin,
+            "approve_loan",
+            AdminValue::None,
+            AdminValue::U32(loan_id),
+        );
         events::loan_approved(&env, loan_id, borrower.clone());
         events::loan_approved_by_admin(&env, admin, loan_id, borrower);
 
@@ -907,7 +2292,7 @@ impl LoanManager {
         use soroban_sdk::token::TokenClient;
 
         borrower.require_auth();
-        Self::require_not_paused(&env)?;
+        Self::require_repayments_open(&env)?;
 
         if amount <= 0 {
             return Err(LoanError::InvalidAmount);
@@ -931,11 +2316,7 @@ impl LoanManager {
 
         // Allow repayment until end of default window
         let current_ledger = env.ledger().sequence();
-        let default_ends = loan
-            .due_date
-            .checked_add(Self::default_window_ledgers(&env))
-            .expect("default window overflow");
-        if current_ledger > default_ends {
+        if current_ledger > Self::default_eligible_after(&env, &loan, current_ledger) {
             return Err(LoanError::LoanPastDue);
         }
 
@@ -944,7 +2325,7 @@ impl LoanManager {
             return Err(LoanError::RepaymentExceedsDebt);
         }
 
-        let min_repayment_amount = Self::min_repayment_amount(&env);
+        let min_repayment_amount = Self::min_repayment_amount_for(&env, &loan.asset);
 
         // Allow below-minimum repayment only when it fully clears the remaining debt
         // or when the remaining debt itself is just small rounding dust.
@@ -954,17 +2335,12 @@ impl LoanManager {
             panic!("repayment amount below minimum");
         }
 
-        let token: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Token)
-            .expect("token not set");
         let lending_pool: Address = env
             .storage()
             .instance()
             .get(&DataKey::LendingPool)
             .expect("lending pool not set");
-        let token_client = TokenClient::new(&env, &token);
+        let token_client = TokenClient::new(&env, &loan.asset);
         token_client.transfer(&borrower, &lending_pool, &amount);
 
         let (principal_payment, interest_payment, late_fee_payment) =
@@ -991,11 +2367,22 @@ impl LoanManager {
             .checked_add(principal_payment)
             .expect("principal paid overflow");
 
-        let was_late = env.ledger().sequence()
-            > loan
-                .due_date
-                .checked_add(Self::grace_period_ledgers(&env))
-                .expect("grace period overflow");
+        // Report the income portion so the pool can fund lock boosts from it.
+        let interest_income = interest_payment
+            .checked_add(late_fee_payment)
+            .expect("interest income overflow");
+        if interest_income > 0 {
+            PoolClient::new(&env, &lending_pool).record_interest(&loan.asset, &interest_income);
+        }
+
+        let grace_ends = loan
+            .due_date
+            .checked_add(Self::grace_period_ledgers(&env))
+            .expect("grace period overflow");
+        let now = env.ledger().sequence();
+        let ledgers_late = now
+            .saturating_sub(grace_ends)
+            .saturating_sub(Self::repay_paused_ledgers(&env, grace_ends, now));
 
         let mut completed = false;
 
@@ -1035,22 +2422,23 @@ impl LoanManager {
         if amount >= 100 {
             let nft_contract = Self::nft_contract(&env);
             let nft_client = NftClient::new(&env, &nft_contract);
-            let borrower_score = nft_client.get_score(&borrower);
-            if borrower_score > 0 {
-                // get_score returns 0 for burned/non-existent NFTs
-                if completed && was_late {
-                    nft_client.decrease_score(
-                        &borrower,
-                        &Self::LATE_REPAYMENT_SCORE_PENALTY.unsigned_abs(),
-                        &Some(env.current_contract_address()),
-                    );
-                } else {
-                    nft_client.update_score(
-                        &borrower,
-                        &amount,
-                        &Some(env.current_contract_address()),
-                    );
-                }
+            let borrower_score = Self::borrower_score(&env, &nft_client, &borrower);
+            // A paused NFT, or paused score updates on it, skips the credit
+            // rather than block the repayment.
+            if borrower_score > 0
+                && !nft_client.is_paused()
+                && !nft_client.is_operation_paused(&NftOperation::ScoreUpdates)
+            {
+                // get_score returns 0 for burned/non-existent NFTs.
+                // Points, bonuses and lateness penalties follow the NFT's
+                // scoring policy.
+                nft_client.record_repayment(
+                    &borrower,
+                    &amount,
+                    &completed,
+                    &ledgers_late,
+                    &Some(env.current_contract_address()),
+                );
             }
         }
 
@@ -1069,7 +2457,7 @@ impl LoanManager {
     pub fn deposit_collateral(env: Env, loan_id: u32, amount: i128) -> Result<(), LoanError> {
         use soroban_sdk::token::TokenClient;
 
-        Self::require_not_paused(&env)?;
+        Self::require_operation_active(&env, LoanOperation::Collateral)?;
 
         if amount <= 0 {
             return Err(LoanError::InvalidAmount);
@@ -1099,12 +2487,7 @@ impl LoanManager {
             return Err(LoanError::SeizedBorrower);
         }
 
-        let token: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Token)
-            .expect("token not set");
-        let token_client = TokenClient::new(&env, &token);
+        let token_client = TokenClient::new(&env, &loan.collateral_asset);
         token_client.transfer(&loan.borrower, &env.current_contract_address(), &amount);
 
         let loan_key = DataKey::Loan(loan_id);
@@ -1131,7 +2514,7 @@ impl LoanManager {
     }
 
     pub fn release_collateral(env: Env, loan_id: u32) -> Result<(), LoanError> {
-        Self::require_not_paused(&env)?;
+        Self::require_operation_active(&env, LoanOperation::Collateral)?;
 
         let loan_key = DataKey::Loan(loan_id);
         let loan: Loan = env
@@ -1189,7 +2572,7 @@ impl LoanManager {
 
     pub fn reject_loan(env: Env, loan_id: u32, reason: String) -> Result<(), LoanError> {
         Self::admin(&env).require_auth();
-        Self::require_not_paused(&env)?;
+        Self::require_operation_active(&env, LoanOperation::Approvals)?;
 
         let loan_key = DataKey::Loan(loan_id);
         let mut loan: Loan = env
@@ -1210,6 +2593,13 @@ impl LoanManager {
         loan.collateral_amount = 0;
         env.storage().persistent().set(&loan_key, &loan);
         Self::bump_persistent_ttl(&env, &loan_key);
+        Self::log_admin_action(
+            &env,
+            &Self::admin(&env),
+            "reject_loan",
+            AdminValue::None,
+            AdminValue::U32(loan_id),
+        );
         events::loan_rejected(&env, loan_id, reason);
 
         Ok(())
@@ -1228,7 +2618,7 @@ impl LoanManager {
     ) -> Result<(), LoanError> {
         use soroban_sdk::token::TokenClient;
 
-        Self::require_not_paused(&env)?;
+        Self::require_operation_active(&env, LoanOperation::Refinancing)?;
 
         // Both borrower and admin must authorise.
         let admin = Self::admin(&env);
@@ -1251,18 +2641,14 @@ impl LoanManager {
 
         // Allow refinance until end of default window
         let current_ledger = env.ledger().sequence();
-        let default_ends = loan
-            .due_date
-            .checked_add(Self::default_window_ledgers(&env))
-            .expect("default window overflow");
-        if current_ledger > default_ends {
+        if current_ledger > Self::default_eligible_after(&env, &loan, current_ledger) {
             return Err(LoanError::LoanPastDue);
         }
 
         if new_amount <= 0 {
             return Err(LoanError::InvalidAmount);
         }
-        if new_amount > Self::max_loan_amount(&env) {
+        if new_amount > Self::max_loan_amount_for(&env, &loan.asset) {
             return Err(LoanError::InvalidAmount);
         }
 
@@ -1288,7 +2674,8 @@ impl LoanManager {
             .ok_or(LoanError::NotInitialized)?;
         let nft_client = NftClient::new(&env, &nft_contract);
 
-        let current_score = nft_client.get_score(&loan.borrower);
+        Self::grant_score_access(&env, &nft_client, &loan.borrower, new_term);
+        let current_score = Self::borrower_score(&env, &nft_client, &loan.borrower);
         let min_score: u32 = env
             .storage()
             .instance()
@@ -1322,17 +2709,13 @@ impl LoanManager {
         // Adjust principal to new_amount.
         let remaining_principal = Self::remaining_principal(&loan);
 
-        let token: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Token)
-            .expect("token not set");
         let lending_pool: Address = env
             .storage()
             .instance()
             .get(&DataKey::LendingPool)
             .expect("lending pool not set");
-        let token_client = TokenClient::new(&env, &token);
+        let token_client = TokenClient::new(&env, &loan.asset);
+        let collateral_client = TokenClient::new(&env, &loan.collateral_asset);
 
         match new_amount.cmp(&remaining_principal) {
             core::cmp::Ordering::Greater => {
@@ -1362,7 +2745,7 @@ impl LoanManager {
                         .checked_div(remaining_principal)
                         .expect("division by zero");
                     if collateral_to_return > 0 {
-                        token_client.transfer(
+                        collateral_client.transfer(
                             &env.current_contract_address(),
                             &loan.borrower,
                             &collateral_to_return,
@@ -1380,14 +2763,26 @@ impl LoanManager {
         // Reset loan terms with new amount and rate.
         loan.amount = new_amount;
         loan.principal_paid = 0;
-        loan.interest_rate_bps =
-            Self::compute_interest_rate(&env, &loan.borrower, new_amount, current_score);
+        loan.interest_rate_bps = Self::compute_interest_rate(
+            &env,
+            &loan.asset,
+            &loan.borrower,
+            new_amount,
+            current_score,
+        );
         loan.last_interest_ledger = current_ledger;
         loan.due_date = current_ledger + new_term;
         loan.last_late_fee_ledger = loan.due_date;
 
         env.storage().persistent().set(&loan_key, &loan);
         Self::bump_persistent_ttl(&env, &loan_key);
+        Self::log_admin_action(
+            &env,
+            &admin,
+            "refinance_loan",
+            AdminValue::Loan(loan_id, remaining_principal),
+            AdminValue::Loan(loan_id, new_amount),
+        );
 
         events::loan_refinanced(&env, loan_id, loan.borrower.clone(), new_amount, new_term);
 
@@ -1402,15 +2797,156 @@ impl LoanManager {
         admin.require_auth();
 
         let old_rate = Self::late_fee_rate_bps(&env);
+        Self::check_param_change(
+            &env,
+            LoanParam::LateFeeRate,
+            old_rate as i128,
+            rate_bps as i128,
+        )?;
         env.storage()
             .instance()
             .set(&DataKey::LateFeeRateBps, &rate_bps);
         Self::bump_instance_ttl(&env);
+        Self::log_admin_action(
+            &env,
+            &admin,
+            "set_late_fee_rate",
+            AdminValue::U32(old_rate),
+            AdminValue::U32(rate_bps),
+        );
         events::late_fee_rate_updated(&env, admin, old_rate, rate_bps);
 
         Ok(())
     }
 
+    /// Register, tighten, loosen or remove the rate-of-change limit on
+    /// `param`. Registering or tightening applies at once; loosening or
+    /// removing needs a matured `ParamOverride::Limit` for the same limit.
+    pub fn set_param_limit(
+        env: Env,
+        param: LoanParam,
+        limit: Option<ParamLimit>,
+    ) -> Result<(), LoanError> {
+        Self::admin(&env).require_auth();
+        if let Some(limit) = &limit {
+            Self::validate_param_limit(limit)?;
+        }
+
+        let old_limit = Self::read_param_limit(&env, &param);
+        let tightening = match (old_limit.clone(), &limit) {
+            (None, _) => true,
+            (Some(old), Some(new)) => {
+                new.max_change <= old.max_change
+                    && new.max_change_bps <= old.max_change_bps
+                    && new.window_ledgers >= old.window_ledgers
+            }
+            (Some(_), None) => false,
+        };
+        if !tightening {
+            Self::consume_param_override(
+                &env,
+                &param,
+                &match &limit {
+                    Some(limit) => ParamOverride::Limit(limit.clone()),
+                    None => ParamOverride::RemoveLimit,
+                },
+            )?;
+        }
+
+        let key = DataKey::ParamLimit(param.clone());
+        match &limit {
+            Some(limit) => {
+                env.storage().persistent().set(&key, limit);
+                Self::bump_persistent_ttl(&env, &key);
+            }
+            None => env.storage().persistent().remove(&key),
+        }
+        Self::log_admin_action(
+            &env,
+            &Self::admin(&env),
+            "set_param_limit",
+            old_limit.map_or(AdminValue::None, |old| {
+                AdminValue::Limit(param.clone(), old)
+            }),
+            limit.clone().map_or(AdminValue::None, |new| {
+                AdminValue::Limit(param.clone(), new)
+            }),
+        );
+        events::param_limit_set(&env, param, limit);
+        Ok(())
+    }
+
+    pub fn get_param_limit(env: Env, param: LoanParam) -> Option<ParamLimit> {
+        Self::read_param_limit(&env, &param)
+    }
+
+    /// Announce a change that exceeds `param`'s limits. Once
+    /// `PARAM_OVERRIDE_DELAY_LEDGERS` have passed, the matching setter (or
+    /// `set_param_limit`) call goes through. Replaces any earlier override
+    /// for the same parameter.
+    pub fn schedule_param_override(
+        env: Env,
+        param: LoanParam,
+        change: ParamOverride,
+    ) -> Result<u32, LoanError> {
+        Self::admin(&env).require_auth();
+        if let ParamOverride::Limit(limit) = &change {
+            Self::validate_param_limit(limit)?;
+        }
+
+        let eta = env
+            .ledger()
+            .sequence()
+            .saturating_add(Self::PARAM_OVERRIDE_DELAY_LEDGERS);
+        let key = DataKey::ParamOverride(param.clone());
+        let replaced: Option<PendingParamOverride> = env.storage().persistent().get(&key);
+        env.storage().persistent().set(
+            &key,
+            &PendingParamOverride {
+                change: change.clone(),
+                eta,
+            },
+        );
+        Self::bump_persistent_ttl(&env, &key);
+        Self::log_admin_action(
+            &env,
+            &Self::admin(&env),
+            "schedule_param_override",
+            replaced.map_or(AdminValue::None, |old| {
+                AdminValue::Override(param.clone(), old.change)
+            }),
+            AdminValue::Override(param.clone(), change.clone()),
+        );
+        events::param_override_scheduled(&env, param, change, eta);
+        Ok(eta)
+    }
+
+    pub fn cancel_param_override(env: Env, param: LoanParam) -> Result<(), LoanError> {
+        Self::admin(&env).require_auth();
+        let key = DataKey::ParamOverride(param.clone());
+        let pending: PendingParamOverride = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .ok_or(LoanError::NoParamOverride)?;
+        env.storage().persistent().remove(&key);
+        Self::log_admin_action(
+            &env,
+            &Self::admin(&env),
+            "cancel_param_override",
+            AdminValue::Override(param.clone(), pending.change),
+            AdminValue::None,
+        );
+        events::param_override_cancelled(&env, param);
+        Ok(())
+    }
+
+    pub fn get_param_override(env: Env, param: LoanParam) -> Option<PendingParamOverride> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ParamOverride(param))
+    }
+
     pub fn get_late_fee_rate(env: Env) -> u32 {
         Self::late_fee_rate_bps(&env)
     }
@@ -1430,10 +2966,23 @@ impl LoanManager {
         }
 
         let old_ledgers = Self::grace_period_ledgers(&env);
+        Self::check_param_change(
+            &env,
+            LoanParam::GracePeriod,
+            old_ledgers as i128,
+            ledgers as i128,
+        )?;
         env.storage()
             .instance()
             .set(&DataKey::GracePeriodLedgers, &ledgers);
         Self::bump_instance_ttl(&env);
+        Self::log_admin_action(
+            &env,
+            &admin,
+            "set_grace_period_ledgers",
+            AdminValue::U32(old_ledgers),
+            AdminValue::U32(ledgers),
+        );
         events::grace_period_updated(&env, admin, old_ledgers, ledgers);
         Ok(())
     }
@@ -1462,10 +3011,23 @@ impl LoanManager {
         }
 
         let old_ledgers = Self::default_window_ledgers(&env);
+        Self::check_param_change(
+            &env,
+            LoanParam::DefaultWindow,
+            old_ledgers as i128,
+            ledgers as i128,
+        )?;
         env.storage()
             .instance()
             .set(&DataKey::DefaultWindowLedgers, &ledgers);
         Self::bump_instance_ttl(&env);
+        Self::log_admin_action(
+            &env,
+            &admin,
+            "set_default_window_ledgers",
+            AdminValue::U32(old_ledgers),
+            AdminValue::U32(ledgers),
+        );
         events::default_window_updated(&env, admin, old_ledgers, ledgers);
         Ok(())
     }
@@ -1474,7 +3036,7 @@ impl LoanManager {
         Self::default_window_ledgers(&env)
     }
 
-    pub fn set_min_score(env: Env, min_score: u32) {
+    pub fn set_min_score(env: Env, min_score: u32) -> Result<(), LoanError> {
         Self::admin(&env).require_auth();
 
         let old_score: u32 = env
@@ -1482,9 +3044,56 @@ impl LoanManager {
             .instance()
             .get(&DataKey::MinScore)
             .unwrap_or(500);
+        Self::check_param_change(
+            &env,
+            LoanParam::MinScore,
+            old_score as i128,
+            min_score as i128,
+        )?;
         env.storage().instance().set(&DataKey::MinScore, &min_score);
         Self::bump_instance_ttl(&env);
+        Self::log_admin_action(
+            &env,
+            &Self::admin(&env),
+            "set_min_score",
+            AdminValue::U32(old_score),
+            AdminValue::U32(min_score),
+        );
         events::min_score_updated(&env, old_score, min_score);
+        Ok(())
+    }
+
+    /// Set how many months of proven remittances `request_loan_with_history`
+    /// accepts in place of the minimum score. `0` disables that path.
+    pub fn set_min_remittance_months(env: Env, months: u32) -> Result<(), LoanError> {
+        let admin = Self::admin(&env);
+        admin.require_auth();
+
+        let old_months = Self::min_remittance_months(&env);
+        Self::check_param_change(
+            &env,
+            LoanParam::MinRemittanceMonths,
+            old_months as i128,
+            months as i128,
+        )?;
+        env.storage()
+            .instance()
+            .set(&DataKey::MinRemittanceMonths, &months);
+        Self::bump_instance_ttl(&env);
+        Self::log_admin_action(
+            &env,
+            &admin,
+            "set_min_remittance_months",
+            AdminValue::U32(old_months),
+            AdminValue::U32(months),
+        );
+        events::min_remittance_months_updated(&env, admin, old_months, months);
+        Ok(())
+    }
+
+    pub fn get_min_remittance_months(env: Env) -> u32 {
+        Self::bump_instance_ttl(&env);
+        Self::min_remittance_months(&env)
     }
 
     pub fn set_max_loan_amount(env: Env, amount: i128) -> Result<(), LoanError> {
@@ -1500,10 +3109,18 @@ impl LoanManager {
         admin.require_auth();
 
         let old_amount = Self::max_loan_amount(&env);
+        Self::check_param_change(&env, LoanParam::MaxLoanAmount, old_amount, amount)?;
         env.storage()
             .instance()
             .set(&DataKey::MaxLoanAmount, &amount);
         Self::bump_instance_ttl(&env);
+        Self::log_admin_action(
+            &env,
+            &admin,
+            "set_max_loan_amount",
+            AdminValue::I128(old_amount),
+            AdminValue::I128(amount),
+        );
         events::max_loan_amount_updated(&env, admin, old_amount, amount);
 
         Ok(())
@@ -1513,7 +3130,104 @@ impl LoanManager {
         Self::max_loan_amount(&env)
     }
 
-    pub fn set_min_repayment_amount(env: Env, amount: i128) {
+    /// Add `asset` to the supported-asset whitelist, or replace its config if
+    /// it is already listed.
+    pub fn set_asset_config(
+        env: Env,
+        asset: Address,
+        config: AssetConfig,
+    ) -> Result<(), LoanError> {
+        Self::validate_asset_config(&config)?;
+
+        let admin = Self::admin(&env);
+        admin.require_auth();
+
+        Self::check_param_change(
+            &env,
+            LoanParam::AssetMaxLoanAmount(asset.clone()),
+            Self::max_loan_amount_for(&env, &asset),
+            config
+                .max_loan_amount
+                .unwrap_or_else(|| Self::max_loan_amount(&env)),
+        )?;
+        Self::check_param_change(
+            &env,
+            LoanParam::AssetInterestRate(asset.clone()),
+            Self::asset_interest_rate(&env, &asset) as i128,
+            config
+                .interest_rate_bps
+                .unwrap_or_else(|| Self::read_interest_rate(&env)) as i128,
+        )?;
+        Self::check_param_change(
+            &env,
+            LoanParam::AssetMinRepaymentAmount(asset.clone()),
+            Self::min_repayment_amount_for(&env, &asset),
+            config
+                .min_repayment_amount
+                .unwrap_or_else(|| Self::min_repayment_amount(&env)),
+        )?;
+        Self::log_admin_action(
+            &env,
+            &admin,
+            "set_asset_config",
+            Self::read_asset_config(&env, &asset).map_or(AdminValue::None, |old| {
+                AdminValue::Asset(asset.clone(), old)
+            }),
+            AdminValue::Asset(asset.clone(), config.clone()),
+        );
+        Self::write_asset_config(&env, &asset, &config);
+        events::asset_config_updated(&env, admin, asset, config);
+
+        Ok(())
+    }
+
+    /// Remove `asset` from the whitelist. Existing loans in the asset can
+    /// still be repaid, but no new loans can be requested or approved.
+    pub fn remove_supported_asset(env: Env, asset: Address) -> Result<(), LoanError> {
+        let admin = Self::admin(&env);
+        admin.require_auth();
+
+        Self::require_supported_asset(&env, &asset)?;
+        Self::log_admin_action(
+            &env,
+            &admin,
+            "remove_supported_asset",
+            Self::read_asset_config(&env, &asset)
+                .map_or(AdminValue::Address(asset.clone()), |old| {
+                    AdminValue::Asset(asset.clone(), old)
+                }),
+            AdminValue::None,
+        );
+        env.storage()
+            .instance()
+            .remove(&DataKey::AssetConfig(asset.clone()));
+
+        let mut assets = Self::supported_assets(&env);
+        if let Some(idx) = assets.first_index_of(&asset) {
+            assets.remove(idx);
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::SupportedAssets, &assets);
+        Self::bump_instance_ttl(&env);
+        events::asset_removed(&env, admin, asset);
+
+        Ok(())
+    }
+
+    pub fn get_asset_config(env: Env, asset: Address) -> Option<AssetConfig> {
+        Self::read_asset_config(&env, &asset)
+    }
+
+    pub fn get_supported_assets(env: Env) -> Vec<Address> {
+        Self::supported_assets(&env)
+    }
+
+    pub fn is_supported_asset(env: Env, asset: Address) -> bool {
+        Self::read_asset_config(&env, &asset).is_some()
+    }
+
+    pub fn set_min_repayment_amount(env: Env, amount: i128) -> Result<(), LoanError> {
         if amount < 0 {
             panic!("min repayment amount cannot be negative");
         }
@@ -1526,11 +3240,20 @@ impl LoanManager {
         admin.require_auth();
 
         let old_amount = Self::min_repayment_amount(&env);
+        Self::check_param_change(&env, LoanParam::MinRepaymentAmount, old_amount, amount)?;
         env.storage()
             .instance()
             .set(&DataKey::MinRepaymentAmount, &amount);
         Self::bump_instance_ttl(&env);
+        Self::log_admin_action(
+            &env,
+            &admin,
+            "set_min_repayment_amount",
+            AdminValue::I128(old_amount),
+            AdminValue::I128(amount),
+        );
         events::min_repayment_updated(&env, admin, old_amount, amount);
+        Ok(())
     }
 
     pub fn get_min_repayment_amount(env: Env) -> i128 {
@@ -1550,10 +3273,23 @@ impl LoanManager {
         admin.require_auth();
 
         let old_max = Self::max_loans_per_borrower(&env);
+        Self::check_param_change(
+            &env,
+            LoanParam::MaxLoansPerBorrower,
+            old_max as i128,
+            max_loans as i128,
+        )?;
         env.storage()
             .instance()
             .set(&DataKey::MaxLoansPerBorrower, &max_loans);
         Self::bump_instance_ttl(&env);
+        Self::log_admin_action(
+            &env,
+            &admin,
+            "set_max_loans_per_borrower",
+            AdminValue::U32(old_max),
+            AdminValue::U32(max_loans),
+        );
         events::max_loans_per_borrower_updated(&env, admin, old_max, max_loans);
 
         Ok(())
@@ -1585,10 +3321,97 @@ impl LoanManager {
 
     pub fn get_borrower_loans(env: Env, borrower: Address) -> Vec<u32> {
         Self::bump_instance_ttl(&env);
+        Self::read_borrower_loans(&env, &borrower)
+    }
+
+    /// Called by the NFT contract when a credit record moves from `from` to
+    /// `to`; re-points every loan of `from` at `to` in the same transaction.
+    /// With migration disabled, a plain transfer by a borrower with open
+    /// loans is refused instead. Recoveries always carry loans over. Open
+    /// loans only move with `to`'s signature and within its loan cap.
+    pub fn on_record_moved(
+        env: Env,
+        from: Address,
+        to: Address,
+        recovery: bool,
+    ) -> Result<(), LoanError> {
+        Self::nft_contract(&env).require_auth();
+        if Self::has_open_loans(&env, &from) {
+            if !recovery && !Self::loan_migration_enabled(&env) {
+                return Err(LoanError::ActiveLoansBlockTransfer);
+            }
+            to.require_auth();
+            let combined = Self::borrower_loan_count(&env, &to)
+                .checked_add(Self::borrower_loan_count(&env, &from))
+                .expect("borrower loan count overflow");
+            if combined > Self::max_loans_per_borrower(&env) {
+                return Err(LoanError::MaxLoansReached);
+            }
+        }
+
+        let from_loans = Self::read_borrower_loans(&env, &from);
+        let mut to_loans = Self::read_borrower_loans(&env, &to);
+
+        for loan_id in from_loans.iter() {
+            let loan_key = DataKey::Loan(loan_id);
+            if let Some(mut loan) = env.storage().persistent().get::<DataKey, Loan>(&loan_key) {
+                loan.borrower = to.clone();
+                env.storage().persistent().set(&loan_key, &loan);
+                Self::bump_persistent_ttl(&env, &loan_key);
+            }
+            to_loans.push_back(loan_id);
+        }
+        if !from_loans.is_empty() {
+            Self::write_borrower_loans(&env, &to, &to_loans);
+            let from_loans_key = DataKey::BorrowerLoans(from.clone());
+            env.storage().persistent().remove(&from_loans_key);
+            env.storage().instance().remove(&from_loans_key);
+        }
+
+        let moved_count = Self::borrower_loan_count(&env, &from);
+        if moved_count > 0 {
+            let to_count_key = DataKey::BorrowerLoanCount(to.clone());
+            let to_count = Self::borrower_loan_count(&env, &to)
+                .checked_add(moved_count)
+                .expect("borrower loan count overflow");
+            env.storage().persistent().set(&to_count_key, &to_count);
+            Self::bump_persistent_ttl(&env, &to_count_key);
+            env.storage()
+                .persistent()
+                .remove(&DataKey::BorrowerLoanCount(from.clone()));
+        }
+        Self::bump_instance_ttl(&env);
+
+        events::borrower_migrated(&env, from, to, from_loans.len());
+        Ok(())
+    }
+
+    pub fn set_loan_migration_enabled(env: Env, enabled: bool) {
+        let admin = Self::admin(&env);
+        admin.require_auth();
+        Self::log_admin_action(
+            &env,
+            &admin,
+            "set_loan_migration_enabled",
+            AdminValue::Bool(Self::loan_migration_enabled(&env)),
+            AdminValue::Bool(enabled),
+        );
         env.storage()
             .instance()
-            .get(&DataKey::BorrowerLoans(borrower))
-            .unwrap_or(Vec::new(&env))
+            .set(&DataKey::LoanMigrationEnabled, &enabled);
+        Self::bump_instance_ttl(&env);
+        events::loan_migration_toggled(&env, admin, enabled);
+    }
+
+    pub fn is_loan_migration_enabled(env: Env) -> bool {
+        Self::bump_instance_ttl(&env);
+        Self::loan_migration_enabled(&env)
+    }
+
+    /// True while any of the borrower's loans is pending or approved.
+    pub fn has_active_loans(env: Env, borrower: Address) -> bool {
+        Self::bump_instance_ttl(&env);
+        Self::has_open_loans(&env, &borrower)
     }
 
     pub fn get_min_score(env: Env) -> u32 {
@@ -1606,10 +3429,23 @@ impl LoanManager {
         }
 
         let old_rate = Self::read_interest_rate(&env);
+        Self::check_param_change(
+            &env,
+            LoanParam::InterestRate,
+            old_rate as i128,
+            rate_bps as i128,
+        )?;
         env.storage()
             .instance()
             .set(&DataKey::InterestRateBps, &rate_bps);
         Self::bump_instance_ttl(&env);
+        Self::log_admin_action(
+            &env,
+            &Self::admin(&env),
+            "set_interest_rate",
+            AdminValue::U32(old_rate),
+            AdminValue::U32(rate_bps),
+        );
         events::interest_rate_updated(&env, old_rate, rate_bps);
 
         Ok(())
@@ -1619,15 +3455,33 @@ impl LoanManager {
         Self::read_interest_rate(&env)
     }
 
-    pub fn set_rate_oracle(env: Env, rate_oracle: Address) {
+    /// Price new loans through `rate_oracle`. The oracle sets every rate at
+    /// once, so the change only goes through a matured
+    /// `ParamOverride::Address` scheduled for `LoanParam::RateOracle`.
+    pub fn set_rate_oracle(env: Env, rate_oracle: Address) -> Result<(), LoanError> {
         Self::admin(&env).require_auth();
+        Self::consume_param_override(
+            &env,
+            &LoanParam::RateOracle,
+            &ParamOverride::Address(rate_oracle.clone()),
+        )?;
 
         let old_oracle = env.storage().instance().get(&DataKey::RateOracle);
         env.storage()
             .instance()
             .set(&DataKey::RateOracle, &rate_oracle);
         Self::bump_instance_ttl(&env);
+        Self::log_admin_action(
+            &env,
+            &Self::admin(&env),
+            "set_rate_oracle",
+            old_oracle
+                .clone()
+                .map_or(AdminValue::None, AdminValue::Address),
+            AdminValue::Address(rate_oracle.clone()),
+        );
         events::rate_oracle_updated(&env, old_oracle, rate_oracle);
+        Ok(())
     }
 
     pub fn get_rate_oracle(env: Env) -> Option<Address> {
@@ -1642,10 +3496,23 @@ impl LoanManager {
         }
 
         let old_term = Self::read_default_term(&env);
+        Self::check_param_change(
+            &env,
+            LoanParam::DefaultTerm,
+            old_term as i128,
+            ledgers as i128,
+        )?;
         env.storage()
             .instance()
             .set(&DataKey::DefaultTermLedgers, &ledgers);
         Self::bump_instance_ttl(&env);
+        Self::log_admin_action(
+            &env,
+            &Self::admin(&env),
+            "set_default_term",
+            AdminValue::U32(old_term),
+            AdminValue::U32(ledgers),
+        );
         events::default_term_updated(&env, old_term, ledgers);
 
         Ok(())
@@ -1668,10 +3535,24 @@ impl LoanManager {
             return Err(LoanError::InvalidTerm);
         }
         Self::admin(&env).require_auth();
+        let old_min_term = Self::get_min_term_ledgers(env.clone());
+        Self::check_param_change(
+            &env,
+            LoanParam::MinTerm,
+            old_min_term as i128,
+            min_term as i128,
+        )?;
         env.storage()
             .instance()
             .set(&DataKey::MinTermLedgers, &min_term);
         Self::bump_instance_ttl(&env);
+        Self::log_admin_action(
+            &env,
+            &Self::admin(&env),
+            "set_min_term_ledgers",
+            AdminValue::U32(old_min_term),
+            AdminValue::U32(min_term),
+        );
         events::term_limits_updated(&env, min_term, max_term);
 
         Ok(())
@@ -1698,10 +3579,24 @@ impl LoanManager {
             return Err(LoanError::InvalidTerm);
         }
         Self::admin(&env).require_auth();
+        let old_max_term = Self::get_max_term_ledgers(env.clone());
+        Self::check_param_change(
+            &env,
+            LoanParam::MaxTerm,
+            old_max_term as i128,
+            max_term as i128,
+        )?;
         env.storage()
             .instance()
             .set(&DataKey::MaxTermLedgers, &max_term);
         Self::bump_instance_ttl(&env);
+        Self::log_admin_action(
+            &env,
+            &Self::admin(&env),
+            "set_max_term_ledgers",
+            AdminValue::U32(old_max_term),
+            AdminValue::U32(max_term),
+        );
         events::term_limits_updated(&env, min_term, max_term);
 
         Ok(())
@@ -1715,6 +3610,37 @@ impl LoanManager {
             .unwrap_or(Self::DEFAULT_TERM_LEDGERS)
     }
 
+    /// Up to `limit` admin audit log entries starting at index `cursor`,
+    /// oldest first. `limit` is capped at `MAX_ADMIN_LOG_LIMIT`.
+    pub fn get_admin_actions(env: Env, cursor: u32, limit: u32) -> Vec<AdminAction> {
+        let len = Self::admin_log_len(&env);
+        let mut entries = Vec::new(&env);
+        if cursor >= len {
+            return entries;
+        }
+        let end = cursor
+            .saturating_add(limit.min(Self::MAX_ADMIN_LOG_LIMIT))
+            .min(len);
+
+        let mut page_index = cursor / Self::ADMIN_LOG_PAGE_SIZE;
+        let mut page = Self::read_admin_log_page(&env, page_index);
+        for idx in cursor..end {
+            if idx / Self::ADMIN_LOG_PAGE_SIZE != page_index {
+                page_index = idx / Self::ADMIN_LOG_PAGE_SIZE;
+                page = Self::read_admin_log_page(&env, page_index);
+            }
+            entries.push_back(
+                page.get(idx % Self::ADMIN_LOG_PAGE_SIZE)
+                    .expect("admin log page truncated"),
+            );
+        }
+        entries
+    }
+
+    pub fn get_admin_action_count(env: Env) -> u32 {
+        Self::admin_log_len(&env)
+    }
+
     pub fn propose_admin(env: Env, new_admin: Address) {
         let current_admin = Self::admin(&env);
         current_admin.require_auth();
@@ -1723,6 +3649,13 @@ impl LoanManager {
             .instance()
             .set(&DataKey::ProposedAdmin, &new_admin);
         Self::bump_instance_ttl(&env);
+        Self::log_admin_action(
+            &env,
+            &current_admin,
+            "propose_admin",
+            AdminValue::Address(current_admin.clone()),
+            AdminValue::Address(new_admin.clone()),
+        );
         env.events().publish(
             (Symbol::new(&env, "AdminProposed"), current_admin),
             new_admin,
@@ -1737,6 +3670,13 @@ impl LoanManager {
             .ok_or(LoanError::NotInitialized)?;
         proposed_admin.require_auth();
 
+        Self::log_admin_action(
+            &env,
+            &proposed_admin,
+            "accept_admin",
+            AdminValue::Address(Self::admin(&env)),
+            AdminValue::Address(proposed_admin.clone()),
+        );
         env.storage()
             .instance()
             .set(&DataKey::Admin, &proposed_admin);
@@ -1749,8 +3689,19 @@ impl LoanManager {
 
     pub fn pause(env: Env) {
         Self::admin(&env).require_auth();
+        Self::log_admin_action(
+            &env,
+            &Self::admin(&env),
+            "pause",
+            AdminValue::Bool(Self::paused_now(&env)),
+            AdminValue::Bool(true),
+        );
         env.storage().instance().set(&DataKey::Paused, &true);
+        env.storage()
+            .instance()
+            .remove(&DataKey::GuardianPauseExpiry);
         Self::bump_instance_ttl(&env);
+        Self::sync_repay_pause_window(&env);
         events::paused(&env);
         env.events()
             .publish((Symbol::new(&env, "ContractPaused"),), ());
@@ -1758,117 +3709,448 @@ impl LoanManager {
 
     pub fn unpause(env: Env) {
         Self::admin(&env).require_auth();
+        Self::log_admin_action(
+            &env,
+            &Self::admin(&env),
+            "unpause",
+            AdminValue::Bool(Self::paused_now(&env)),
+            AdminValue::Bool(false),
+        );
         env.storage().instance().set(&DataKey::Paused, &false);
+        env.storage()
+            .instance()
+            .remove(&DataKey::GuardianPauseExpiry);
         Self::bump_instance_ttl(&env);
+        Self::sync_repay_pause_window(&env);
         events::unpaused(&env);
         env.events()
             .publish((Symbol::new(&env, "ContractUnpaused"),), ());
     }
 
-    pub fn is_paused(env: Env) -> bool {
-        Self::bump_instance_ttl(&env);
+    /// Record the governance contract that appoints the guardian. The admin
+    /// names it once; after that only the current governance can move it.
+    pub fn set_governance(env: Env, governance: Address) {
+        let current: Option<Address> = env.storage().instance().get(&DataKey::Governance);
+        let actor = current.clone().unwrap_or_else(|| Self::admin(&env));
+        actor.require_auth();
+        Self::log_admin_action(
+            &env,
+            &actor,
+            "set_governance",
+            current.map_or(AdminValue::None, AdminValue::Address),
+            AdminValue::Address(governance.clone()),
+        );
         env.storage()
             .instance()
-            .get(&DataKey::Paused)
-            .unwrap_or(false)
+            .set(&DataKey::Governance, &governance);
+        Self::bump_instance_ttl(&env);
+        events::governance_set(&env, governance);
     }
 
-    pub fn check_default(env: Env, loan_id: u32) -> Result<(), LoanError> {
-        Self::admin(&env).require_auth();
-        Self::require_not_paused(&env)?;
+    pub fn get_governance(env: Env) -> Option<Address> {
+        Self::bump_instance_ttl(&env);
+        env.storage().instance().get(&DataKey::Governance)
+    }
 
-        let loan_key = DataKey::Loan(loan_id);
-        let mut loan: Loan = env
+    /// Name the guardian allowed to call `guardian_pause`, or clear it with
+    /// `None`. Only the governance recorded by `set_governance` may do this;
+    /// the admin alone cannot appoint the account that can pause it.
+    pub fn set_guardian(env: Env, guardian: Option<Address>) -> Result<(), LoanError> {
+        let governance: Address = env
             .storage()
-            .persistent()
-            .get(&loan_key)
-            .ok_or(LoanError::LoanNotFound)?;
-        Self::bump_persistent_ttl(&env, &loan_key);
+            .instance()
+            .get(&DataKey::Governance)
+            .ok_or(LoanError::GovernanceNotSet)?;
+        governance.require_auth();
+        Self::log_admin_action(
+            &env,
+            &governance,
+            "set_guardian",
+            env.storage()
+                .instance()
+                .get::<DataKey, Address>(&DataKey::Guardian)
+                .map_or(AdminValue::None, AdminValue::Address),
+            guardian
+                .clone()
+                .map_or(AdminValue::None, AdminValue::Address),
+        );
+        match &guardian {
+            Some(guardian) => env.storage().instance().set(&DataKey::Guardian, guardian),
+            None => env.storage().instance().remove(&DataKey::Guardian),
+        }
+        Self::bump_instance_ttl(&env);
+        events::guardian_set(&env, guardian);
+        Ok(())
+    }
 
-        if loan.status != LoanStatus::Approved {
-            return Err(LoanError::LoanNotActive);
+    pub fn get_guardian(env: Env) -> Option<Address> {
+        Self::bump_instance_ttl(&env);
+        env.storage().instance().get(&DataKey::Guardian)
+    }
+
+    pub fn set_guardian_pause_ledgers(env: Env, ledgers: u32) -> Result<(), LoanError> {
+        Self::admin(&env).require_auth();
+        if ledgers == 0 {
+            return Err(LoanError::InvalidPauseWindow);
         }
+        let old_ledgers = Self::get_guardian_pause_ledgers(env.clone());
+        Self::check_param_change(
+            &env,
+            LoanParam::GuardianPauseLedgers,
+            old_ledgers as i128,
+            ledgers as i128,
+        )?;
+        env.storage()
+            .instance()
+            .set(&DataKey::GuardianPauseLedgers, &ledgers);
+        Self::bump_instance_ttl(&env);
+        Self::log_admin_action(
+            &env,
+            &Self::admin(&env),
+            "set_guardian_pause_ledgers",
+            AdminValue::U32(old_ledgers),
+            AdminValue::U32(ledgers),
+        );
+        events::guardian_pause_ledgers_updated(&env, ledgers);
+        Ok(())
+    }
 
-        let current_ledger = env.ledger().sequence();
-        let default_eligible_after = loan
-            .due_date
-            .checked_add(Self::default_window_ledgers(&env))
-            .expect("default window overflow");
-        if current_ledger <= default_eligible_after {
-            return Err(LoanError::LoanNotPastDue);
+    pub fn get_guardian_pause_ledgers(env: Env) -> u32 {
+        Self::bump_instance_ttl(&env);
+        env.storage()
+            .instance()
+            .get(&DataKey::GuardianPauseLedgers)
+            .unwrap_or(Self::DEFAULT_GUARDIAN_PAUSE_LEDGERS)
+    }
+
+    /// Pause immediately on the guardian's authority. The pause lapses after
+    /// `get_guardian_pause_ledgers` ledgers unless the admin ratifies it; the
+    /// guardian has no way to unpause. Returns the expiry ledger.
+    ///
+    /// The guardian gets one pause per governance decision: once it lapses,
+    /// the admin has to pause, unpause or ratify before it can pause again.
+    pub fn guardian_pause(env: Env) -> Result<u32, LoanError> {
+        let guardian: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Guardian)
+            .ok_or(LoanError::GuardianNotSet)?;
+        guardian.require_auth();
+        if Self::paused_now(&env) {
+            return Err(LoanError::AlreadyPaused);
+        }
+        // A lapsed guardian pause keeps its expiry until the admin acts.
+        if env.storage().instance().has(&DataKey::GuardianPauseExpiry) {
+            return Err(LoanError::GuardianPauseSpent);
         }
 
-        loan.status = LoanStatus::Defaulted;
-        env.storage().persistent().set(&loan_key, &loan);
-        Self::bump_persistent_ttl(&env, &loan_key);
-        Self::decrement_borrower_loan_count(&env, &loan.borrower);
-        Self::seize_collateral_internal(&env, loan_id);
+        let expiry = env
+            .ledger()
+            .sequence()
+            .saturating_add(Self::get_guardian_pause_ledgers(env.clone()));
+        env.storage().instance().set(&DataKey::Paused, &true);
+        env.storage()
+            .instance()
+            .set(&DataKey::GuardianPauseExpiry, &expiry);
+        Self::bump_instance_ttl(&env);
+        Self::sync_repay_pause_window(&env);
+        Self::log_admin_action(
+            &env,
+            &guardian,
+            "guardian_pause",
+            AdminValue::None,
+            AdminValue::U32(expiry),
+        );
+        events::guardian_paused(&env, guardian, expiry);
+        Ok(expiry)
+    }
 
-        let nft_contract = Self::nft_contract(&env);
-        let nft_client = NftClient::new(&env, &nft_contract);
-        nft_client.decrease_score(
-            &loan.borrower,
-            &Self::DEFAULT_SCORE_PENALTY_POINTS,
-            &Some(env.current_contract_address()),
+    /// Turn the active guardian pause into an indefinite one. Admin only.
+    pub fn ratify_pause(env: Env) -> Result<(), LoanError> {
+        Self::admin(&env).require_auth();
+        let expiry =
+            Self::get_guardian_pause_expiry(env.clone()).ok_or(LoanError::NoGuardianPause)?;
+        env.storage()
+            .instance()
+            .remove(&DataKey::GuardianPauseExpiry);
+        Self::bump_instance_ttl(&env);
+        Self::sync_repay_pause_window(&env);
+        Self::log_admin_action(
+            &env,
+            &Self::admin(&env),
+            "ratify_pause",
+            AdminValue::U32(expiry),
+            AdminValue::None,
         );
-        nft_client.record_default(&loan.borrower, &Some(env.current_contract_address()));
+        events::pause_ratified(&env, expiry);
+        Ok(())
+    }
 
-        events::loan_defaulted(&env, loan_id, loan.borrower.clone());
+    /// Expiry ledger of the guardian pause currently in force, if any.
+    pub fn get_guardian_pause_expiry(env: Env) -> Option<u32> {
+        Self::bump_instance_ttl(&env);
+        if !Self::paused_now(&env) {
+            return None;
+        }
+        env.storage().instance().get(&DataKey::GuardianPauseExpiry)
+    }
 
-        // Remove loan from storage after emitting terminal event
-        // env.storage().persistent().remove(&loan_key);
+    /// Pause a single operation without touching the rest of the contract.
+    /// While `Repayments` is paused, interest and late fees stop accruing.
+    pub fn pause_operation(env: Env, operation: LoanOperation) {
+        Self::set_operation_paused(&env, operation, true);
+    }
 
-        Ok(())
+    pub fn unpause_operation(env: Env, operation: LoanOperation) {
+        Self::set_operation_paused(&env, operation, false);
     }
 
-    pub fn check_defaults(env: Env, loan_ids: Vec<u32>) -> Result<(), LoanError> {
-        Self::admin(&env).require_auth();
-        Self::require_not_paused(&env)?;
+    /// The operation's own flag; the global pause is reported by `is_paused`.
+    pub fn is_operation_paused(env: Env, operation: LoanOperation) -> bool {
+        Self::bump_instance_ttl(&env);
+        Self::operation_paused(&env, operation)
+    }
+
+    /// Ledger windows in which repayments were blocked, oldest first.
+    pub fn get_repay_pause_windows(env: Env) -> Vec<PauseWindow> {
+        Self::bump_instance_ttl(&env);
+        Self::read_repay_pause_windows(&env)
+    }
 
+    /// Walk up to `limit` loans for the earliest ledger an open loan still
+    /// measures repayment pauses from. Once a pass has seen every loan, the
+    /// windows that ended before it are dropped. Callable by anyone;
+    /// returns whether this call completed a pass.
+    pub fn prune_repay_pause_windows(env: Env, limit: u32) -> Result<bool, LoanError> {
+        if limit == 0 {
+            return Err(LoanError::InvalidMigrationBatch);
+        }
+        Self::bump_instance_ttl(&env);
+        if Self::stored_version(&env) < Self::CURRENT_VERSION {
+            return Err(LoanError::MigrationPending);
+        }
+        let now = env.ledger().sequence();
+        let mut scan = env
+            .storage()
+            .instance()
+            .get(&DataKey::RepayPauseScan)
+            .unwrap_or(PauseWindowScan {
+                next_loan_id: 1,
+                started_at: now,
+                floor: now,
+            });
+        let last_loan_id: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::LoanCounter)
+            .unwrap_or(0);
+        let end = scan
+            .next_loan_id
+            .saturating_add(limit)
+            .min(last_loan_id.saturating_add(1));
+        for loan_id in scan.next_loan_id..end {
+            if let Some(reference) = env
+                .storage()
+                .persistent()
+                .get::<DataKey, Loan>(&DataKey::Loan(loan_id))
+                .and_then(|loan| Self::pause_reference_ledger(&loan))
+            {
+                scan.floor = scan.floor.min(reference);
+            }
+        }
+        scan.next_loan_id = end;
+        if end <= last_loan_id {
+            env.storage()
+                .instance()
+                .set(&DataKey::RepayPauseScan, &scan);
+            return Ok(false);
+        }
+
+        env.storage().instance().remove(&DataKey::RepayPauseScan);
+        let floor = scan.floor.min(scan.started_at);
+        let mut windows = Self::read_repay_pause_windows(&env);
+        let before = windows.len();
+        while windows
+            .first()
+            .is_some_and(|window| window.end.is_some_and(|end| end <= floor))
+        {
+            windows.pop_front();
+        }
+        if windows.len() < before {
+            env.storage()
+                .instance()
+                .set(&DataKey::RepayPauseWindows, &windows);
+        }
+        Ok(true)
+    }
+
+    fn set_operation_paused(env: &Env, operation: LoanOperation, paused: bool) {
+        Self::admin(env).require_auth();
+        Self::log_admin_action(
+            env,
+            &Self::admin(env),
+            if paused {
+                "pause_operation"
+            } else {
+                "unpause_operation"
+            },
+            AdminValue::Operation(operation, Self::operation_paused(env, operation)),
+            AdminValue::Operation(operation, paused),
+        );
+        env.storage()
+            .instance()
+            .set(&DataKey::OperationPaused(operation), &paused);
+        if operation == LoanOperation::Repayments {
+            Self::sync_repay_pause_window(env);
+        }
+        Self::bump_instance_ttl(env);
+        events::operation_pause_set(env, operation, paused);
+    }
+
+    pub fn is_paused(env: Env) -> bool {
+        Self::bump_instance_ttl(&env);
+        Self::paused_now(&env)
+    }
+
+    /// Mark `loan_id` defaulted once `due_date + default_window` has passed,
+    /// not counting ledgers after the due date when repayments were paused.
+    /// Refused while repayments are blocked. Callable by anyone; `caller`
+    /// receives the keeper bounty, which is returned.
+    pub fn check_default(env: Env, caller: Address, loan_id: u32) -> Result<i128, LoanError> {
+        caller.require_auth();
+        Self::require_operation_active(&env, LoanOperation::Defaults)?;
+        Self::require_repayments_open(&env)?;
+        Self::process_default(&env, &caller, loan_id)
+    }
+
+    /// Batch form of `check_default`. Loans that are missing, not active or
+    /// not yet past their default window are reported and skipped.
+    pub fn check_defaults(
+        env: Env,
+        caller: Address,
+        loan_ids: Vec<u32>,
+    ) -> Result<Vec<DefaultCheck>, LoanError> {
+        caller.require_auth();
+        Self::require_operation_active(&env, LoanOperation::Defaults)?;
+        Self::require_repayments_open(&env)?;
+
+        let mut results = Vec::new(&env);
         for loan_id in loan_ids.iter() {
-            let loan_key = DataKey::Loan(loan_id);
-            let mut loan: Loan = match env.storage().persistent().get(&loan_key) {
-                Some(l) => l,
-                None => continue,
+            let result = match Self::process_default(&env, &caller, loan_id) {
+                Ok(bounty) => DefaultCheck::Defaulted(bounty),
+                Err(LoanError::LoanNotFound) => DefaultCheck::NotFound,
+                Err(LoanError::LoanNotActive) => DefaultCheck::NotActive,
+                Err(LoanError::LoanNotPastDue) => DefaultCheck::NotPastDue,
+                Err(e) => return Err(e),
             };
-            Self::bump_persistent_ttl(&env, &loan_key);
+            results.push_back(result);
+        }
 
-            if loan.status != LoanStatus::Approved {
-                continue;
-            }
+        Ok(results)
+    }
 
-            let current_ledger = env.ledger().sequence();
-            let default_eligible_after = loan
-                .due_date
-                .checked_add(Self::default_window_ledgers(&env))
-                .expect("default window overflow");
-            if current_ledger <= default_eligible_after {
-                continue;
-            }
+    /// Address that marked `loan_id` defaulted and took its bounty.
+    pub fn get_default_keeper(env: Env, loan_id: u32) -> Option<Address> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::DefaultKeeper(loan_id))
+    }
 
-            loan.status = LoanStatus::Defaulted;
-            env.storage().persistent().set(&loan_key, &loan);
-            Self::bump_persistent_ttl(&env, &loan_key);
-            Self::decrement_borrower_loan_count(&env, &loan.borrower);
-            Self::seize_collateral_internal(&env, loan_id);
+    /// Set the share of seized collateral (bps, at most 20%) paid to the
+    /// keeper who marks a default. Admin only.
+    pub fn set_keeper_bounty_bps(env: Env, bps: u32) -> Result<(), LoanError> {
+        Self::admin(&env).require_auth();
+        if bps > Self::MAX_KEEPER_BOUNTY_BPS {
+            return Err(LoanError::InvalidConfiguration);
+        }
+        let old_bps = Self::keeper_bounty_bps(&env);
+        Self::check_param_change(
+            &env,
+            LoanParam::KeeperBountyBps,
+            old_bps as i128,
+            bps as i128,
+        )?;
+        env.storage()
+            .instance()
+            .set(&DataKey::KeeperBountyBps, &bps);
+        Self::bump_instance_ttl(&env);
+        Self::log_admin_action(
+            &env,
+            &Self::admin(&env),
+            "set_keeper_bounty_bps",
+            AdminValue::U32(old_bps),
+            AdminValue::U32(bps),
+        );
+        events::keeper_bounty_bps_updated(&env, old_bps, bps);
+        Ok(())
+    }
 
-            let nft_contract = Self::nft_contract(&env);
-            let nft_client = NftClient::new(&env, &nft_contract);
-            nft_client.decrease_score(
-                &loan.borrower,
-                &Self::DEFAULT_SCORE_PENALTY_POINTS,
-                &Some(env.current_contract_address()),
-            );
-            nft_client.record_default(&loan.borrower, &Some(env.current_contract_address()));
+    pub fn get_keeper_bounty_bps(env: Env) -> u32 {
+        Self::bump_instance_ttl(&env);
+        Self::keeper_bounty_bps(&env)
+    }
 
-            events::loan_defaulted(&env, loan_id, loan.borrower.clone());
+    /// Set the flat bounty paid from the keeper reserve when a default
+    /// yields no collateral bounty. Like the reserve, it is denominated in
+    /// the `Token` set at initialization, whatever the defaulted loan's
+    /// asset. Admin only.
+    pub fn set_keeper_reserve_bounty(env: Env, amount: i128) -> Result<(), LoanError> {
+        Self::admin(&env).require_auth();
+        if amount < 0 {
+            return Err(LoanError::InvalidAmount);
+        }
+        let old_amount = Self::keeper_reserve_bounty(&env);
+        Self::check_param_change(&env, LoanParam::KeeperReserveBounty, old_amount, amount)?;
+        env.storage()
+            .instance()
+            .set(&DataKey::KeeperReserveBounty, &amount);
+        Self::bump_instance_ttl(&env);
+        Self::log_admin_action(
+            &env,
+            &Self::admin(&env),
+            "set_keeper_reserve_bounty",
+            AdminValue::I128(old_amount),
+            AdminValue::I128(amount),
+        );
+        events::keeper_reserve_bounty_updated(&env, old_amount, amount);
+        Ok(())
+    }
 
-            // Remove loan from storage after emitting terminal event
-            // env.storage().persistent().remove(&loan_key);
+    pub fn get_keeper_reserve_bounty(env: Env) -> i128 {
+        Self::bump_instance_ttl(&env);
+        Self::keeper_reserve_bounty(&env)
+    }
+
+    /// Add `amount` of `Token` to the keeper reserve. Anyone may fund it.
+    pub fn fund_keeper_reserve(env: Env, funder: Address, amount: i128) -> Result<(), LoanError> {
+        use soroban_sdk::token::TokenClient;
+
+        funder.require_auth();
+        if amount <= 0 {
+            return Err(LoanError::InvalidAmount);
         }
+        let token: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Token)
+            .ok_or(LoanError::NotInitialized)?;
+        TokenClient::new(&env, &token).transfer(&funder, &env.current_contract_address(), &amount);
 
+        let reserve = Self::keeper_reserve(&env)
+            .checked_add(amount)
+            .expect("keeper reserve overflow");
+        env.storage()
+            .instance()
+            .set(&DataKey::KeeperReserve, &reserve);
+        Self::bump_instance_ttl(&env);
+        events::keeper_reserve_funded(&env, funder, amount);
         Ok(())
     }
+
+    pub fn get_keeper_reserve(env: Env) -> i128 {
+        Self::bump_instance_ttl(&env);
+        Self::keeper_reserve(&env)
+    }
 }
 
 #[cfg(test)]
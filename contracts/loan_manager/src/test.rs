@@ -1,9 +1,22 @@
-use crate::{DataKey, Loan, LoanError, LoanManager, LoanManagerClient, LoanStatus};
+use crate::{
+    AdminValue, AssetConfig, DataKey, DefaultCheck, Loan, LoanError, LoanManager,
+    LoanManagerClient, LoanOperation, LoanParam, LoanStatus, LoanV3, MigrationRecord, ParamLimit,
+    ParamOverride, PauseWindow, RemittanceRecord,
+};
 use lending_pool::{LendingPool, LendingPoolClient};
-use remittance_nft::{RemittanceNFT, RemittanceNFTClient};
-use soroban_sdk::testutils::Ledger as _;
+use remittance_nft::{RemittanceNFT, RemittanceNFTClient, ScoreWeights};
+use soroban_sdk::testutils::{Ledger as _, MockAuth, MockAuthInvoke};
 use soroban_sdk::token::{Client as TokenClient, StellarAssetClient};
-use soroban_sdk::{testutils::Address as _, Address, BytesN, Env, String};
+use soroban_sdk::{
+    testutils::Address as _, Address, Bytes, BytesN, Env, IntoVal, String, Symbol, Val, Vec,
+};
+
+/// Step past `param`'s seeded limit: schedule an override for `value` and
+/// move the ledger to its eta, so the next setter call goes through.
+fn override_param(env: &Env, manager: &LoanManagerClient, param: LoanParam, value: i128) {
+    let eta = manager.schedule_param_override(&param, &ParamOverride::Value(value));
+    env.ledger().with_mut(|li| li.sequence_number = eta);
+}
 
 fn setup_test<'a>(
     env: &Env,
@@ -19,6 +32,13 @@ fn setup_test<'a>(
     let nft_contract_id = env.register(RemittanceNFT, ());
     let nft_client = RemittanceNFTClient::new(env, &nft_contract_id);
     nft_client.initialize(&admin);
+    // Keep the composite on repayment so loan outcomes map to whole points.
+    nft_client.set_score_weights(&ScoreWeights {
+        repayment_bps: 10_000,
+        remittance_bps: 0,
+        tenure_bps: 0,
+        utilization_bps: 0,
+    });
 
     // 2. Deploy a test token
     let token_admin = Address::generate(env);
@@ -36,6 +56,7 @@ fn setup_test<'a>(
 
     // Authorize LoanManager on NFT contract before initialization
     nft_client.authorize_minter(&loan_manager_id);
+    pool_client.set_loan_manager(&loan_manager_id);
 
     // 5. Initialize the Loan Manager with the NFT contract, lending pool, token, and admin
     loan_manager_client.initialize(&nft_contract_id, &pool_contract_id, &token_id, &admin);
@@ -60,7 +81,31 @@ fn test_upgrade_requires_admin_auth() {
     let (manager, _nft_client, _pool, _token, _token_admin) = setup_test(&env);
 
     env.mock_auths(&[]);
-    manager.upgrade(&create_upgrade_hash(&env));
+    manager.schedule_upgrade(&create_upgrade_hash(&env), &20_000);
+}
+
+#[test]
+fn test_upgrade_waits_for_scheduled_eta() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (manager, _nft_client, _pool, _token, _admin) = setup_test(&env);
+
+    let result = manager.try_schedule_upgrade(&create_upgrade_hash(&env), &100);
+    assert_eq!(result, Err(Ok(LoanError::InvalidUpgradeEta)));
+
+    manager.schedule_upgrade(&create_upgrade_hash(&env), &20_000);
+    assert_eq!(manager.get_scheduled_upgrade().unwrap().eta, 20_000);
+    env.ledger().with_mut(|li| li.sequence_number = 19_999);
+    assert_eq!(
+        manager.try_execute_upgrade(),
+        Err(Ok(LoanError::UpgradeTimelockActive))
+    );
+
+    manager.cancel_upgrade();
+    assert_eq!(
+        manager.try_execute_upgrade(),
+        Err(Ok(LoanError::NoUpgradeScheduled))
+    );
 }
 
 #[test]
@@ -82,16 +127,16 @@ fn test_loan_request_success() {
     let env = Env::default();
     env.mock_all_auths();
 
-    let (manager, nft_client, _pool, _token, _token_admin) = setup_test(&env);
+    let (manager, nft_client, _pool, token, _token_admin) = setup_test(&env);
     let borrower = Address::generate(&env);
-    assert_eq!(manager.version(), 3);
+    assert_eq!(manager.version(), 6);
 
     // Give borrower a score high enough to pass (>= 500)
     let history_hash = soroban_sdk::BytesN::from_array(&env, &[0u8; 32]);
     nft_client.mint(&borrower, &600, &history_hash, &None);
 
     // Should succeed and return loan_id
-    let loan_id = manager.request_loan(&borrower, &1000, &17280);
+    let loan_id = manager.request_loan(&borrower, &token, &1000, &17280);
     assert_eq!(loan_id, 1);
 
     // Verify loan was created with Pending status
@@ -109,7 +154,7 @@ fn test_loan_request_failure_low_score() {
     let env = Env::default();
     env.mock_all_auths();
 
-    let (manager, nft_client, _pool, _token, _token_admin) = setup_test(&env);
+    let (manager, nft_client, _pool, token, _token_admin) = setup_test(&env);
     let borrower = Address::generate(&env);
 
     // Give borrower a score too low to pass (< 500)
@@ -117,7 +162,7 @@ fn test_loan_request_failure_low_score() {
     nft_client.mint(&borrower, &400, &history_hash, &None);
 
     // Should panic
-    manager.request_loan(&borrower, &1000, &17280);
+    manager.request_loan(&borrower, &token, &1000, &17280);
 }
 
 #[test]
@@ -138,7 +183,7 @@ fn test_approve_loan_flow() {
     stellar_token.mint(&pool_client, &10000);
 
     // 3. Request a loan
-    let loan_id = manager.request_loan(&borrower, &1000, &17280);
+    let loan_id = manager.request_loan(&borrower, &token_id, &1000, &17280);
 
     // 4. Verify loan is pending
     let loan = manager.get_loan(&loan_id);
@@ -161,13 +206,13 @@ fn test_approve_loan_fails_when_pool_has_insufficient_liquidity() {
     let env = Env::default();
     env.mock_all_auths_allowing_non_root_auth();
 
-    let (manager, nft_client, _pool_client, _token_id, _token_admin) = setup_test(&env);
+    let (manager, nft_client, _pool_client, token_id, _token_admin) = setup_test(&env);
     let borrower = Address::generate(&env);
 
     let history_hash = soroban_sdk::BytesN::from_array(&env, &[0u8; 32]);
     nft_client.mint(&borrower, &600, &history_hash, &None);
 
-    let loan_id = manager.request_loan(&borrower, &1_000, &17280);
+    let loan_id = manager.request_loan(&borrower, &token_id, &1_000, &17280);
     let result = manager.try_approve_loan(&loan_id);
     assert_eq!(result, Err(Ok(LoanError::InsufficientPoolLiquidity)));
 
@@ -180,13 +225,13 @@ fn test_cancel_pending_loan() {
     let env = Env::default();
     env.mock_all_auths();
 
-    let (manager, nft_client, _pool, _token, _token_admin) = setup_test(&env);
+    let (manager, nft_client, _pool, token, _token_admin) = setup_test(&env);
     let borrower = Address::generate(&env);
 
     let history_hash = soroban_sdk::BytesN::from_array(&env, &[0u8; 32]);
     nft_client.mint(&borrower, &600, &history_hash, &None);
 
-    let loan_id = manager.request_loan(&borrower, &1_000, &17280);
+    let loan_id = manager.request_loan(&borrower, &token, &1_000, &17280);
     manager.cancel_loan(&borrower, &loan_id);
 
     let loan = manager.get_loan(&loan_id);
@@ -198,13 +243,13 @@ fn test_reject_pending_loan() {
     let env = Env::default();
     env.mock_all_auths();
 
-    let (manager, nft_client, _pool, _token, _token_admin) = setup_test(&env);
+    let (manager, nft_client, _pool, token, _token_admin) = setup_test(&env);
     let borrower = Address::generate(&env);
 
     let history_hash = soroban_sdk::BytesN::from_array(&env, &[0u8; 32]);
     nft_client.mint(&borrower, &600, &history_hash, &None);
 
-    let loan_id = manager.request_loan(&borrower, &1_000, &17280);
+    let loan_id = manager.request_loan(&borrower, &token, &1_000, &17280);
     manager.reject_loan(&loan_id, &String::from_str(&env, "manual review failed"));
 
     let loan = manager.get_loan(&loan_id);
@@ -229,7 +274,7 @@ fn test_cancel_pending_loan_returns_collateral() {
     let _borrower_balance_before = token_client.balance(&borrower);
     let _contract_balance_before = token_client.balance(&manager.address);
 
-    let loan_id = manager.request_loan(&borrower, &1_000, &17280);
+    let loan_id = manager.request_loan(&borrower, &token_id, &1_000, &17280);
     env.as_contract(&manager.address, || {
         let loan_key = DataKey::Loan(loan_id);
         let mut loan: Loan = env.storage().persistent().get(&loan_key).unwrap();
@@ -261,7 +306,7 @@ fn test_reject_pending_loan_returns_collateral() {
 
     let borrower_balance_before = token_client.balance(&borrower);
 
-    let loan_id = manager.request_loan(&borrower, &1_000, &17280);
+    let loan_id = manager.request_loan(&borrower, &token_id, &1_000, &17280);
     env.as_contract(&manager.address, || {
         let loan_key = DataKey::Loan(loan_id);
         let mut loan: Loan = env.storage().persistent().get(&loan_key).unwrap();
@@ -328,7 +373,7 @@ fn test_configurable_interest_rate_and_default_term() {
     let stellar_token = StellarAssetClient::new(&env, &token_id);
     stellar_token.mint(&pool_client, &10_000);
 
-    let loan_id = manager.request_loan(&borrower, &1_000, &20_000);
+    let loan_id = manager.request_loan(&borrower, &token_id, &1_000, &20_000);
     let pending_loan = manager.get_loan(&loan_id);
     assert_eq!(pending_loan.interest_rate_bps, 1_800);
 
@@ -372,7 +417,7 @@ fn test_legacy_zero_interest_config_falls_back_to_default() {
     let stellar_token = StellarAssetClient::new(&env, &token_id);
     stellar_token.mint(&pool_client, &10_000);
 
-    let loan_id = manager.request_loan(&borrower, &1_000, &17280);
+    let loan_id = manager.request_loan(&borrower, &token_id, &1_000, &17280);
     let pending_loan = manager.get_loan(&loan_id);
     assert_eq!(pending_loan.interest_rate_bps, 1_200);
 }
@@ -395,7 +440,7 @@ fn test_repayment_flow() {
     stellar_token.mint(&pool_client, &10_000);
     stellar_token.mint(&borrower, &10_000);
 
-    let loan_id = manager.request_loan(&borrower, &1000, &17280);
+    let loan_id = manager.request_loan(&borrower, &token_id, &1000, &17280);
     manager.approve_loan(&loan_id);
 
     env.ledger()
@@ -436,8 +481,9 @@ fn test_partial_repayment_tracks_split_balances() {
     stellar_token.mint(&pool_client, &2_000_000);
     stellar_token.mint(&borrower, &2_000_000);
 
+    override_param(&env, &manager, LoanParam::MaxLoanAmount, 1_000_000);
     manager.set_max_loan_amount(&1_000_000);
-    let loan_id = manager.request_loan(&borrower, &1_000_000, &17280);
+    let loan_id = manager.request_loan(&borrower, &token_id, &1_000_000, &17280);
     manager.approve_loan(&loan_id);
 
     manager.repay(&borrower, &loan_id, &400_000);
@@ -469,7 +515,7 @@ fn test_minimum_repayment_amount_enforced() {
     stellar_token.mint(&pool_client, &10_000);
     stellar_token.mint(&borrower, &10_000);
 
-    let loan_id = manager.request_loan(&borrower, &1_000, &17280);
+    let loan_id = manager.request_loan(&borrower, &token_id, &1_000, &17280);
     manager.approve_loan(&loan_id);
 
     manager.set_min_repayment_amount(&150);
@@ -493,7 +539,7 @@ fn test_full_repayment_ignores_minimum_amount() {
     stellar_token.mint(&pool_client, &10_000);
     stellar_token.mint(&borrower, &10_000);
 
-    let loan_id = manager.request_loan(&borrower, &1_000, &17280);
+    let loan_id = manager.request_loan(&borrower, &token_id, &1_000, &17280);
     manager.approve_loan(&loan_id);
 
     manager.set_min_repayment_amount(&150);
@@ -508,14 +554,14 @@ fn test_request_loan_above_max_amount_fails() {
     let env = Env::default();
     env.mock_all_auths();
 
-    let (manager, nft_client, _pool, _token, _token_admin) = setup_test(&env);
+    let (manager, nft_client, _pool, token, _token_admin) = setup_test(&env);
     let borrower = Address::generate(&env);
 
     let history_hash = soroban_sdk::BytesN::from_array(&env, &[0u8; 32]);
     nft_client.mint(&borrower, &700, &history_hash, &None);
     manager.set_max_loan_amount(&500);
 
-    let result = manager.try_request_loan(&borrower, &600, &17280);
+    let result = manager.try_request_loan(&borrower, &token, &600, &17280);
     assert_eq!(result, Err(Ok(LoanError::InvalidAmount)));
 }
 
@@ -535,7 +581,7 @@ fn test_small_repayment_does_not_change_score() {
     stellar_token.mint(&pool_client, &10_000);
     stellar_token.mint(&borrower, &10_000);
 
-    let loan_id = manager.request_loan(&borrower, &1000, &17280);
+    let loan_id = manager.request_loan(&borrower, &token_id, &1000, &17280);
     manager.approve_loan(&loan_id);
 
     manager.set_min_repayment_amount(&1);
@@ -559,7 +605,7 @@ fn test_late_full_repayment_applies_score_penalty() {
     stellar_token.mint(&pool_client, &20_000);
     stellar_token.mint(&borrower, &20_000);
 
-    let loan_id = manager.request_loan(&borrower, &1000, &17280);
+    let loan_id = manager.request_loan(&borrower, &token_id, &1000, &17280);
     manager.approve_loan(&loan_id);
 
     let due_date = manager.get_loan(&loan_id).due_date;
@@ -616,7 +662,7 @@ fn test_approve_already_approved_loan() {
     stellar_token.mint(&pool_client, &10000);
 
     // Request and approve loan
-    let loan_id = manager.request_loan(&borrower, &1000, &17280);
+    let loan_id = manager.request_loan(&borrower, &token_id, &1000, &17280);
     manager.approve_loan(&loan_id);
 
     // Try to approve again - should panic
@@ -638,7 +684,7 @@ fn test_approve_loan_insufficient_pool_liquidity() {
     let stellar_token = StellarAssetClient::new(&env, &token_id);
     stellar_token.mint(&pool_client, &100);
 
-    let loan_id = manager.request_loan(&borrower, &1000, &17280);
+    let loan_id = manager.request_loan(&borrower, &token_id, &1000, &17280);
     let result = manager.try_approve_loan(&loan_id);
     assert_eq!(result, Err(Ok(LoanError::InsufficientPoolLiquidity)));
 }
@@ -660,8 +706,8 @@ fn test_borrower_max_active_loans_enforced_and_released_on_repay() {
 
     manager.set_max_loans_per_borrower(&2);
 
-    let loan_1 = manager.request_loan(&borrower, &1000, &17280);
-    let loan_2 = manager.request_loan(&borrower, &1500, &17280);
+    let loan_1 = manager.request_loan(&borrower, &token_id, &1000, &17280);
+    let loan_2 = manager.request_loan(&borrower, &token_id, &1500, &17280);
     manager.approve_loan(&loan_1);
     manager.approve_loan(&loan_2);
     assert_eq!(manager.get_borrower_loan_count(&borrower), 2);
@@ -670,7 +716,7 @@ fn test_borrower_max_active_loans_enforced_and_released_on_repay() {
     assert_eq!(manager.get_loan(&loan_1).status, LoanStatus::Repaid);
     assert_eq!(manager.get_borrower_loan_count(&borrower), 1);
 
-    let loan_3 = manager.request_loan(&borrower, &500, &17280);
+    let loan_3 = manager.request_loan(&borrower, &token_id, &500, &17280);
     assert_eq!(loan_3, 3);
 }
 
@@ -691,13 +737,13 @@ fn test_borrower_max_active_loans_blocks_new_requests() {
 
     manager.set_max_loans_per_borrower(&2);
 
-    let loan_1 = manager.request_loan(&borrower, &1000, &17280);
-    let loan_2 = manager.request_loan(&borrower, &1500, &17280);
+    let loan_1 = manager.request_loan(&borrower, &token_id, &1000, &17280);
+    let loan_2 = manager.request_loan(&borrower, &token_id, &1500, &17280);
     manager.approve_loan(&loan_1);
     manager.approve_loan(&loan_2);
     assert_eq!(manager.get_borrower_loan_count(&borrower), 2);
 
-    manager.request_loan(&borrower, &500, &17280);
+    manager.request_loan(&borrower, &token_id, &500, &17280);
 }
 
 #[test]
@@ -706,13 +752,13 @@ fn test_request_loan_negative_amount() {
     let env = Env::default();
     env.mock_all_auths();
 
-    let (manager, nft_client, _pool, _token, _token_admin) = setup_test(&env);
+    let (manager, nft_client, _pool, token, _token_admin) = setup_test(&env);
     let borrower = Address::generate(&env);
 
     let history_hash = soroban_sdk::BytesN::from_array(&env, &[0u8; 32]);
     nft_client.mint(&borrower, &600, &history_hash, &None);
 
-    manager.request_loan(&borrower, &-1000, &17280);
+    manager.request_loan(&borrower, &token, &-1000, &17280);
 }
 
 #[test]
@@ -729,7 +775,7 @@ fn test_check_default_success() {
     let stellar_token = soroban_sdk::token::StellarAssetClient::new(&env, &token_id);
     stellar_token.mint(&pool_client, &10_000);
 
-    let loan_id = manager.request_loan(&borrower, &1000, &17280);
+    let loan_id = manager.request_loan(&borrower, &token_id, &1000, &17280);
     manager.approve_loan(&loan_id);
 
     assert!(!nft_client.is_seized(&borrower));
@@ -737,7 +783,7 @@ fn test_check_default_success() {
     env.ledger()
         .set_sequence_number(env.ledger().sequence() + 100_000);
 
-    manager.check_default(&loan_id);
+    manager.check_default(&Address::generate(&env), &loan_id);
 
     let loan = manager.get_loan(&loan_id);
     assert_eq!(loan.status, LoanStatus::Defaulted);
@@ -762,10 +808,10 @@ fn test_check_default_not_past_due() {
     let stellar_token = soroban_sdk::token::StellarAssetClient::new(&env, &token_id);
     stellar_token.mint(&pool_client, &10_000);
 
-    let loan_id = manager.request_loan(&borrower, &1000, &17280);
+    let loan_id = manager.request_loan(&borrower, &token_id, &1000, &17280);
     manager.approve_loan(&loan_id);
 
-    manager.check_default(&loan_id);
+    manager.check_default(&Address::generate(&env), &loan_id);
 }
 
 #[test]
@@ -784,7 +830,7 @@ fn test_check_default_already_repaid() {
     stellar_token.mint(&pool_client, &10_000);
     stellar_token.mint(&borrower, &10_000);
 
-    let loan_id = manager.request_loan(&borrower, &1000, &17280);
+    let loan_id = manager.request_loan(&borrower, &token_id, &1000, &17280);
     manager.approve_loan(&loan_id);
 
     manager.repay(&borrower, &loan_id, &1000);
@@ -792,7 +838,7 @@ fn test_check_default_already_repaid() {
     env.ledger()
         .set_sequence_number(env.ledger().sequence() + 40_000);
 
-    manager.check_default(&loan_id);
+    manager.check_default(&Address::generate(&env), &loan_id);
 }
 
 #[test]
@@ -810,13 +856,13 @@ fn test_check_default_respects_default_window() {
     stellar_token.mint(&pool_client, &10_000);
 
     manager.set_default_window_ledgers(&10_000);
-    let loan_id = manager.request_loan(&borrower, &1000, &17280);
+    let loan_id = manager.request_loan(&borrower, &token_id, &1000, &17280);
     manager.approve_loan(&loan_id);
 
     let due_date = manager.get_loan(&loan_id).due_date;
     env.ledger().set_sequence_number(due_date + 9_999);
 
-    let result = manager.try_check_default(&loan_id);
+    let result = manager.try_check_default(&Address::generate(&env), &loan_id);
     assert_eq!(result, Err(Ok(LoanError::LoanNotPastDue)));
 }
 
@@ -838,9 +884,9 @@ fn test_check_defaults_batch() {
     let stellar_token = soroban_sdk::token::StellarAssetClient::new(&env, &token_id);
     stellar_token.mint(&pool_client, &100_000);
 
-    let loan_id1 = manager.request_loan(&borrower1, &1000, &17280);
-    let loan_id2 = manager.request_loan(&borrower2, &1000, &17280);
-    let loan_id3 = manager.request_loan(&borrower3, &1000, &17280);
+    let loan_id1 = manager.request_loan(&borrower1, &token_id, &1000, &17280);
+    let loan_id2 = manager.request_loan(&borrower2, &token_id, &1000, &17280);
+    let loan_id3 = manager.request_loan(&borrower3, &token_id, &1000, &17280);
 
     manager.approve_loan(&loan_id1);
     manager.approve_loan(&loan_id2);
@@ -850,7 +896,7 @@ fn test_check_defaults_batch() {
         .set_sequence_number(env.ledger().sequence() + 100_000);
 
     let loan_ids = soroban_sdk::vec![&env, loan_id1, loan_id2, loan_id3];
-    manager.check_defaults(&loan_ids);
+    manager.check_defaults(&Address::generate(&env), &loan_ids);
 
     assert_eq!(manager.get_loan(&loan_id1).status, LoanStatus::Defaulted);
     assert_eq!(manager.get_loan(&loan_id2).status, LoanStatus::Defaulted);
@@ -883,7 +929,7 @@ fn test_overdue_repayment_charges_late_fee() {
     manager.set_late_fee_rate(&500);
     manager.set_grace_period_ledgers(&0);
     env.ledger().set_sequence_number(1);
-    let loan_id = manager.request_loan(&borrower, &1000, &17280);
+    let loan_id = manager.request_loan(&borrower, &token_id, &1000, &17280);
     manager.approve_loan(&loan_id);
 
     let due_date = manager.get_loan(&loan_id).due_date;
@@ -919,7 +965,7 @@ fn test_overdue_partial_repayment_still_reduces_principal() {
     manager.set_late_fee_rate(&500);
     manager.set_grace_period_ledgers(&0);
     env.ledger().set_sequence_number(1);
-    let loan_id = manager.request_loan(&borrower, &1_000, &17280);
+    let loan_id = manager.request_loan(&borrower, &token_id, &1_000, &17280);
     manager.approve_loan(&loan_id);
 
     let due_date = manager.get_loan(&loan_id).due_date;
@@ -950,9 +996,9 @@ fn test_late_fee_is_capped_at_quarter_principal() {
     let stellar_token = StellarAssetClient::new(&env, &token_id);
     stellar_token.mint(&pool_client, &10_000);
 
-    manager.set_late_fee_rate(&10_000);
+    manager.set_late_fee_rate(&1_000);
     manager.set_grace_period_ledgers(&0);
-    let loan_id = manager.request_loan(&borrower, &1000, &17280);
+    let loan_id = manager.request_loan(&borrower, &token_id, &1000, &17280);
     manager.approve_loan(&loan_id);
 
     let due_date = manager.get_loan(&loan_id).due_date;
@@ -978,7 +1024,7 @@ fn test_deposit_collateral_and_auto_release_on_full_repayment() {
     stellar_token.mint(&pool_client, &20_000);
     stellar_token.mint(&borrower, &20_000);
 
-    let loan_id = manager.request_loan(&borrower, &1_000, &17280);
+    let loan_id = manager.request_loan(&borrower, &token_id, &1_000, &17280);
     manager.approve_loan(&loan_id);
 
     let contract_balance_before = token_client.balance(&manager.address);
@@ -1017,7 +1063,7 @@ fn test_collateral_is_seized_on_default() {
     stellar_token.mint(&pool_client, &20_000);
     stellar_token.mint(&borrower, &20_000);
 
-    let loan_id = manager.request_loan(&borrower, &1_000, &17280);
+    let loan_id = manager.request_loan(&borrower, &token_id, &1_000, &17280);
     manager.approve_loan(&loan_id);
     manager.deposit_collateral(&loan_id, &400);
 
@@ -1028,7 +1074,7 @@ fn test_collateral_is_seized_on_default() {
     let default_window = manager.get_default_window_ledgers();
     env.ledger()
         .set_sequence_number(due_date + default_window + 1);
-    manager.check_default(&loan_id);
+    manager.check_default(&Address::generate(&env), &loan_id);
 
     assert_eq!(manager.get_loan(&loan_id).status, LoanStatus::Defaulted);
     assert_eq!(manager.get_collateral(&loan_id), 0);
@@ -1061,8 +1107,8 @@ fn test_collateral_is_seized_on_batch_default() {
     stellar_token.mint(&borrower1, &20_000);
     stellar_token.mint(&borrower2, &20_000);
 
-    let loan_id1 = manager.request_loan(&borrower1, &1_000, &17280);
-    let loan_id2 = manager.request_loan(&borrower2, &1_000, &17280);
+    let loan_id1 = manager.request_loan(&borrower1, &token_id, &1_000, &17280);
+    let loan_id2 = manager.request_loan(&borrower2, &token_id, &1_000, &17280);
     manager.approve_loan(&loan_id1);
     manager.approve_loan(&loan_id2);
     manager.deposit_collateral(&loan_id1, &300);
@@ -1076,7 +1122,7 @@ fn test_collateral_is_seized_on_batch_default() {
         .set_sequence_number(due_date + default_window + 1);
 
     let loan_ids = soroban_sdk::vec![&env, loan_id1, loan_id2];
-    manager.check_defaults(&loan_ids);
+    manager.check_defaults(&Address::generate(&env), &loan_ids);
 
     assert_eq!(manager.get_collateral(&loan_id1), 0);
     assert_eq!(manager.get_collateral(&loan_id2), 0);
@@ -1092,13 +1138,13 @@ fn test_deposit_collateral_rejects_non_active_loan() {
     let env = Env::default();
     env.mock_all_auths();
 
-    let (manager, nft_client, _pool, _token, _token_admin) = setup_test(&env);
+    let (manager, nft_client, _pool, token, _token_admin) = setup_test(&env);
     let borrower = Address::generate(&env);
 
     let history_hash = soroban_sdk::BytesN::from_array(&env, &[0u8; 32]);
     nft_client.mint(&borrower, &700, &history_hash, &None);
 
-    let loan_id = manager.request_loan(&borrower, &500, &17280);
+    let loan_id = manager.request_loan(&borrower, &token, &500, &17280);
     manager.deposit_collateral(&loan_id, &100);
 }
 
@@ -1117,7 +1163,7 @@ fn test_small_loan_interest_accrual_precision() {
     stellar_token.mint(&pool_address, &10_000);
 
     // Request a small loan of 50 units
-    let loan_id = manager.request_loan(&borrower, &50, &17280);
+    let loan_id = manager.request_loan(&borrower, &token_id, &50, &17280);
     manager.approve_loan(&loan_id);
 
     let initial_loan = manager.get_loan(&loan_id);
@@ -1154,7 +1200,7 @@ fn test_query_functions() {
     let stellar_token = StellarAssetClient::new(&env, &token_id);
     stellar_token.mint(&pool_address, &10_000);
 
-    let _loan_id = manager.request_loan(&borrower, &1000, &17280);
+    let _loan_id = manager.request_loan(&borrower, &token_id, &1000, &17280);
     assert_eq!(manager.get_total_loans(), 1);
 }
 
@@ -1180,13 +1226,13 @@ fn test_get_borrower_loans() {
     stellar_token.mint(&borrower, &10_000);
 
     // Request first loan
-    let loan_id_1 = manager.request_loan(&borrower, &1000, &17280);
+    let loan_id_1 = manager.request_loan(&borrower, &token_id, &1000, &17280);
     let borrower_loans = manager.get_borrower_loans(&borrower);
     assert_eq!(borrower_loans.len(), 1);
     assert_eq!(borrower_loans.get(0).unwrap(), loan_id_1);
 
     // Request second loan (while first is still pending)
-    let loan_id_2 = manager.request_loan(&borrower, &500, &17280);
+    let loan_id_2 = manager.request_loan(&borrower, &token_id, &500, &17280);
     let borrower_loans = manager.get_borrower_loans(&borrower);
     assert_eq!(borrower_loans.len(), 2);
     assert_eq!(borrower_loans.get(0).unwrap(), loan_id_1);
@@ -1240,12 +1286,1412 @@ fn test_pending_loans_count_against_cap() {
     client.set_max_loans_per_borrower(&2);
 
     // Request two loans (both pending) — should consume the full cap
-    let _loan_id_1 = client.request_loan(&borrower, &500, &17280);
-    let _loan_id_2 = client.request_loan(&borrower, &500, &17280);
+    let _loan_id_1 = client.request_loan(&borrower, &token_id, &500, &17280);
+    let _loan_id_2 = client.request_loan(&borrower, &token_id, &500, &17280);
 
     assert_eq!(client.get_borrower_loan_count(&borrower), 2);
 
     // Third request must be rejected even though neither loan is approved yet
-    let result = client.try_request_loan(&borrower, &500, &17280);
+    let result = client.try_request_loan(&borrower, &token_id, &500, &17280);
     assert_eq!(result, Err(Ok(LoanError::MaxLoansReached)));
 }
+
+fn create_asset(env: &Env) -> Address {
+    let asset_admin = Address::generate(env);
+    env.register_stellar_asset_contract_v2(asset_admin)
+        .address()
+}
+
+#[test]
+fn test_initial_token_is_supported_with_default_config() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (manager, _nft_client, _pool, token_id, _token_admin) = setup_test(&env);
+
+    assert!(manager.is_supported_asset(&token_id));
+    assert_eq!(manager.get_supported_assets().len(), 1);
+    let config = manager.get_asset_config(&token_id).unwrap();
+    assert_eq!(config.max_loan_amount, None);
+    assert_eq!(config.collateral_asset, token_id);
+}
+
+#[test]
+fn test_request_loan_rejects_unsupported_asset() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (manager, nft_client, _pool, _token_id, _token_admin) = setup_test(&env);
+    let borrower = Address::generate(&env);
+    nft_client.mint(
+        &borrower,
+        &600,
+        &BytesN::from_array(&env, &[0u8; 32]),
+        &None,
+    );
+
+    let unlisted = create_asset(&env);
+    let result = manager.try_request_loan(&borrower, &unlisted, &1_000, &17280);
+    assert_eq!(result, Err(Ok(LoanError::UnsupportedAsset)));
+}
+
+#[test]
+fn test_set_asset_config_rejects_invalid_values() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (manager, _nft_client, _pool, _token_id, _token_admin) = setup_test(&env);
+    let eurc = create_asset(&env);
+
+    let mut config = AssetConfig {
+        max_loan_amount: Some(0),
+        interest_rate_bps: None,
+        min_repayment_amount: None,
+        collateral_asset: eurc.clone(),
+    };
+    assert_eq!(
+        manager.try_set_asset_config(&eurc, &config),
+        Err(Ok(LoanError::InvalidAmount))
+    );
+
+    config.max_loan_amount = None;
+    config.interest_rate_bps = Some(0);
+    assert_eq!(
+        manager.try_set_asset_config(&eurc, &config),
+        Err(Ok(LoanError::InvalidRate))
+    );
+
+    config.interest_rate_bps = None;
+    config.min_repayment_amount = Some(-1);
+    assert_eq!(
+        manager.try_set_asset_config(&eurc, &config),
+        Err(Ok(LoanError::InvalidAmount))
+    );
+    assert!(!manager.is_supported_asset(&eurc));
+}
+
+#[test]
+fn test_loan_in_second_asset_uses_its_own_token_and_config() {
+    let env = Env::default();
+    env.mock_all_auths_allowing_non_root_auth();
+
+    let (manager, nft_client, pool_client, usdc, _token_admin) = setup_test(&env);
+    let borrower = Address::generate(&env);
+    nft_client.mint(
+        &borrower,
+        &650,
+        &BytesN::from_array(&env, &[0u8; 32]),
+        &None,
+    );
+
+    let eurc = create_asset(&env);
+    let collateral = create_asset(&env);
+    manager.set_asset_config(
+        &eurc,
+        &AssetConfig {
+            max_loan_amount: Some(2_000),
+            interest_rate_bps: Some(900),
+            min_repayment_amount: Some(50),
+            collateral_asset: collateral.clone(),
+        },
+    );
+    assert_eq!(manager.get_supported_assets().len(), 2);
+
+    StellarAssetClient::new(&env, &usdc).mint(&pool_client, &20_000);
+    StellarAssetClient::new(&env, &eurc).mint(&pool_client, &20_000);
+    StellarAssetClient::new(&env, &eurc).mint(&borrower, &1_000);
+    StellarAssetClient::new(&env, &collateral).mint(&borrower, &500);
+
+    let too_large = manager.try_request_loan(&borrower, &eurc, &2_001, &17280);
+    assert_eq!(too_large, Err(Ok(LoanError::InvalidAmount)));
+
+    let loan_id = manager.request_loan(&borrower, &eurc, &1_000, &17280);
+    let loan = manager.get_loan(&loan_id);
+    assert_eq!(loan.asset, eurc);
+    assert_eq!(loan.collateral_asset, collateral);
+    assert_eq!(loan.interest_rate_bps, 900);
+
+    manager.approve_loan(&loan_id);
+    let eurc_client = TokenClient::new(&env, &eurc);
+    let usdc_client = TokenClient::new(&env, &usdc);
+    let collateral_client = TokenClient::new(&env, &collateral);
+    assert_eq!(eurc_client.balance(&borrower), 2_000);
+    assert_eq!(eurc_client.balance(&pool_client), 19_000);
+    assert_eq!(usdc_client.balance(&pool_client), 20_000);
+
+    manager.deposit_collateral(&loan_id, &500);
+    assert_eq!(collateral_client.balance(&borrower), 0);
+    assert_eq!(collateral_client.balance(&manager.address), 500);
+
+    manager.repay(&borrower, &loan_id, &1_000);
+    assert_eq!(manager.get_loan(&loan_id).status, LoanStatus::Repaid);
+    assert_eq!(eurc_client.balance(&pool_client), 20_000);
+    assert_eq!(usdc_client.balance(&pool_client), 20_000);
+    assert_eq!(collateral_client.balance(&borrower), 500);
+}
+
+#[test]
+fn test_removed_asset_blocks_new_loans_but_allows_repayment() {
+    let env = Env::default();
+    env.mock_all_auths_allowing_non_root_auth();
+
+    let (manager, nft_client, pool_client, token_id, _token_admin) = setup_test(&env);
+    let borrower = Address::generate(&env);
+    nft_client.mint(
+        &borrower,
+        &650,
+        &BytesN::from_array(&env, &[0u8; 32]),
+        &None,
+    );
+
+    let stellar_token = StellarAssetClient::new(&env, &token_id);
+    stellar_token.mint(&pool_client, &20_000);
+
+    let active_id = manager.request_loan(&borrower, &token_id, &1_000, &17280);
+    manager.approve_loan(&active_id);
+    let pending_id = manager.request_loan(&borrower, &token_id, &1_000, &17280);
+
+    manager.remove_supported_asset(&token_id);
+    assert!(!manager.is_supported_asset(&token_id));
+    assert_eq!(manager.get_supported_assets().len(), 0);
+
+    assert_eq!(
+        manager.try_request_loan(&borrower, &token_id, &1_000, &17280),
+        Err(Ok(LoanError::UnsupportedAsset))
+    );
+    assert_eq!(
+        manager.try_approve_loan(&pending_id),
+        Err(Ok(LoanError::UnsupportedAsset))
+    );
+    assert_eq!(
+        manager.try_remove_supported_asset(&token_id),
+        Err(Ok(LoanError::UnsupportedAsset))
+    );
+
+    manager.repay(&borrower, &active_id, &1_000);
+    assert_eq!(manager.get_loan(&active_id).status, LoanStatus::Repaid);
+}
+
+#[test]
+fn test_repayment_reports_interest_to_pool_for_lock_boosts() {
+    let env = Env::default();
+    env.mock_all_auths_allowing_non_root_auth();
+
+    let (manager, nft_client, pool_address, token_id, _admin) = setup_test(&env);
+    let pool_client = LendingPoolClient::new(&env, &pool_address);
+    let borrower = Address::generate(&env);
+    let lender = Address::generate(&env);
+    nft_client.mint(
+        &borrower,
+        &650,
+        &BytesN::from_array(&env, &[0u8; 32]),
+        &None,
+    );
+
+    let stellar_token = StellarAssetClient::new(&env, &token_id);
+    stellar_token.mint(&lender, &10_000);
+    stellar_token.mint(&borrower, &1_000);
+    pool_client.set_lock_term(&17_280, &10_000);
+    pool_client.deposit_locked(&lender, &token_id, &10_000, &17_280);
+
+    env.ledger().set_sequence_number(100);
+    let loan_id = manager.request_loan(&borrower, &token_id, &1_000, &17280);
+    manager.approve_loan(&loan_id);
+    env.ledger()
+        .set_sequence_number(env.ledger().sequence() + 8_640);
+
+    let debt = manager.get_loan(&loan_id);
+    let interest = debt.accrued_interest;
+    assert!(interest > 0);
+    manager.repay(&borrower, &loan_id, &(1_000 + interest));
+
+    assert_eq!(manager.get_loan(&loan_id).interest_paid, interest);
+    assert_eq!(
+        pool_client.get_boost_reserve(&token_id),
+        interest * 1_000 / 10_000
+    );
+}
+
+fn remittance_record(env: &Env, period: u32) -> RemittanceRecord {
+    RemittanceRecord {
+        period,
+        amount: 2_000,
+        corridor: Symbol::new(env, "US_MX"),
+        count: 3,
+    }
+}
+
+fn nft_remittance_record(env: &Env, period: u32) -> remittance_nft::RemittanceRecord {
+    remittance_nft::RemittanceRecord {
+        period,
+        amount: 2_000,
+        corridor: Symbol::new(env, "US_MX"),
+        count: 3,
+    }
+}
+
+#[test]
+fn test_remittance_history_proof_is_alternative_to_min_score() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (manager, nft_client, _pool, token, _admin) = setup_test(&env);
+    let borrower = Address::generate(&env);
+    nft_client.mint(
+        &borrower,
+        &400,
+        &BytesN::from_array(&env, &[0u8; 32]),
+        &None,
+    );
+
+    // Two-leaf history tree: root = H(0x01 || min(l1, l2) || max(l1, l2)).
+    let l1 = nft_client.hash_history_record(&nft_remittance_record(&env, 1));
+    let l2 = nft_client.hash_history_record(&nft_remittance_record(&env, 2));
+    let (lo, hi) = if l1.to_array() <= l2.to_array() {
+        (&l1, &l2)
+    } else {
+        (&l2, &l1)
+    };
+    let mut preimage = Bytes::from_array(&env, &[1u8]);
+    preimage.append(&Bytes::from_array(&env, &lo.to_array()));
+    preimage.append(&Bytes::from_array(&env, &hi.to_array()));
+    let root: BytesN<32> = env.crypto().sha256(&preimage).into();
+    nft_client.update_history_hash(&borrower, &root, &None);
+
+    let both = Vec::from_array(
+        &env,
+        [remittance_record(&env, 1), remittance_record(&env, 2)],
+    );
+    let both_proofs = Vec::from_array(
+        &env,
+        [
+            Vec::from_array(&env, [l2.clone()]),
+            Vec::from_array(&env, [l1.clone()]),
+        ],
+    );
+
+    assert_eq!(
+        manager.try_request_loan(&borrower, &token, &1_000, &1_000),
+        Err(Ok(LoanError::InsufficientScore))
+    );
+    // Path is disabled until the admin sets a month requirement.
+    assert_eq!(
+        manager.try_request_loan_with_history(
+            &borrower,
+            &token,
+            &1_000,
+            &1_000,
+            &both,
+            &both_proofs
+        ),
+        Err(Ok(LoanError::InsufficientScore))
+    );
+
+    manager.set_min_remittance_months(&2);
+    assert_eq!(manager.get_min_remittance_months(), 2);
+
+    let repeated = Vec::from_array(
+        &env,
+        [remittance_record(&env, 1), remittance_record(&env, 1)],
+    );
+    let repeated_proofs = Vec::from_array(
+        &env,
+        [
+            Vec::from_array(&env, [l2.clone()]),
+            Vec::from_array(&env, [l2.clone()]),
+        ],
+    );
+    assert_eq!(
+        manager.try_request_loan_with_history(
+            &borrower,
+            &token,
+            &1_000,
+            &1_000,
+            &repeated,
+            &repeated_proofs
+        ),
+        Err(Ok(LoanError::InsufficientRemittanceHistory))
+    );
+
+    let mut forged = both.clone();
+    forged.set(0, remittance_record(&env, 3));
+    assert_eq!(
+        manager.try_request_loan_with_history(
+            &borrower,
+            &token,
+            &1_000,
+            &1_000,
+            &forged,
+            &both_proofs
+        ),
+        Err(Ok(LoanError::InvalidHistoryProof))
+    );
+
+    let loan_id =
+        manager.request_loan_with_history(&borrower, &token, &1_000, &1_000, &both, &both_proofs);
+    assert_eq!(manager.get_loan(&loan_id).status, LoanStatus::Pending);
+}
+
+#[test]
+fn test_recovery_carries_loans_to_new_holder() {
+    let env = Env::default();
+    env.mock_all_auths_allowing_non_root_auth();
+
+    let (manager, nft_client, _pool, token, _admin) = setup_test(&env);
+    nft_client.set_loan_hook(&Some(manager.address.clone()));
+    let lost = Address::generate(&env);
+    let new_holder = Address::generate(&env);
+    nft_client.mint(&lost, &600, &BytesN::from_array(&env, &[0u8; 32]), &None);
+
+    let first = manager.request_loan(&lost, &token, &1000, &17280);
+    let second = manager.request_loan(&lost, &token, &500, &17280);
+
+    nft_client.request_recovery(&lost, &new_holder);
+    nft_client.approve_recovery(&lost, &new_holder);
+
+    assert_eq!(manager.get_borrower_loans(&lost).len(), 0);
+    let loans = manager.get_borrower_loans(&new_holder);
+    assert_eq!(loans, Vec::from_array(&env, [first, second]));
+    assert_eq!(manager.get_loan(&first).borrower, new_holder);
+    assert_eq!(manager.get_loan(&second).borrower, new_holder);
+}
+
+#[test]
+fn test_nft_transfer_migrates_active_loans_and_scores_new_holder() {
+    let env = Env::default();
+    env.mock_all_auths_allowing_non_root_auth();
+
+    let (manager, nft_client, pool, token, _admin) = setup_test(&env);
+    nft_client.set_loan_hook(&Some(manager.address.clone()));
+    let old_wallet = Address::generate(&env);
+    let new_wallet = Address::generate(&env);
+    nft_client.mint(
+        &old_wallet,
+        &600,
+        &BytesN::from_array(&env, &[0u8; 32]),
+        &None,
+    );
+    StellarAssetClient::new(&env, &token).mint(&pool, &10_000);
+
+    let loan_id = manager.request_loan(&old_wallet, &token, &1_000, &17280);
+    manager.approve_loan(&loan_id);
+    assert!(manager.is_loan_migration_enabled());
+    assert!(manager.has_active_loans(&old_wallet));
+
+    nft_client.transfer(&old_wallet, &new_wallet, &None);
+    assert!(!manager.has_active_loans(&old_wallet));
+    assert!(manager.has_active_loans(&new_wallet));
+    assert_eq!(manager.get_loan(&loan_id).borrower, new_wallet);
+
+    // The new holder repays and the score lands on the live record.
+    StellarAssetClient::new(&env, &token).mint(&new_wallet, &500);
+    let result = manager.try_repay(&old_wallet, &loan_id, &500);
+    assert_eq!(result, Err(Ok(LoanError::BorrowerMismatch)));
+    manager.repay(&new_wallet, &loan_id, &500);
+    assert!(nft_client.get_score(&new_wallet) > 600);
+    assert_eq!(nft_client.get_score(&old_wallet), 0);
+}
+
+#[test]
+fn test_nft_transfer_needs_the_new_holders_consent_and_loan_room() {
+    let env = Env::default();
+    env.mock_all_auths_allowing_non_root_auth();
+
+    let (manager, nft_client, _pool, token, _admin) = setup_test(&env);
+    nft_client.set_loan_hook(&Some(manager.address.clone()));
+    manager.set_max_loans_per_borrower(&2);
+    let old_wallet = Address::generate(&env);
+    let new_wallet = Address::generate(&env);
+    nft_client.mint(
+        &old_wallet,
+        &600,
+        &BytesN::from_array(&env, &[0u8; 32]),
+        &None,
+    );
+    nft_client.mint(
+        &new_wallet,
+        &600,
+        &BytesN::from_array(&env, &[0u8; 32]),
+        &None,
+    );
+    manager.request_loan(&old_wallet, &token, &1_000, &17280);
+    manager.request_loan(&old_wallet, &token, &500, &17280);
+    manager.request_loan(&new_wallet, &token, &500, &17280);
+    nft_client.burn(&new_wallet, &None);
+
+    // Two incoming loans on top of the one `new_wallet` still owes.
+    let result = nft_client.try_transfer(&old_wallet, &new_wallet, &None);
+    assert_eq!(result, Err(Ok(remittance_nft::NftError::LoanHookRejected)));
+    assert_eq!(manager.get_borrower_loans(&old_wallet).len(), 2);
+
+    manager.set_max_loans_per_borrower(&3);
+    nft_client.transfer(&old_wallet, &new_wallet, &None);
+    assert!(env
+        .auths()
+        .iter()
+        .any(|(address, _)| *address == new_wallet));
+    assert_eq!(manager.get_borrower_loans(&new_wallet).len(), 3);
+}
+
+#[test]
+fn test_nft_transfer_blocked_while_loans_open_without_migration() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (manager, nft_client, _pool, token, _admin) = setup_test(&env);
+    nft_client.set_loan_hook(&Some(manager.address.clone()));
+    manager.set_loan_migration_enabled(&false);
+    let old_wallet = Address::generate(&env);
+    let new_wallet = Address::generate(&env);
+    nft_client.mint(
+        &old_wallet,
+        &600,
+        &BytesN::from_array(&env, &[0u8; 32]),
+        &None,
+    );
+
+    let loan_id = manager.request_loan(&old_wallet, &token, &1_000, &17280);
+    let result = nft_client.try_transfer(&old_wallet, &new_wallet, &None);
+    assert_eq!(result, Err(Ok(remittance_nft::NftError::LoanHookRejected)));
+    assert_eq!(nft_client.get_score(&old_wallet), 600);
+    assert_eq!(manager.get_loan(&loan_id).borrower, old_wallet);
+
+    // Once nothing is open the record may move; closed loans go with it.
+    manager.cancel_loan(&old_wallet, &loan_id);
+    nft_client.transfer(&old_wallet, &new_wallet, &None);
+    assert_eq!(nft_client.get_score(&new_wallet), 600);
+    assert_eq!(
+        manager.get_borrower_loans(&new_wallet),
+        Vec::from_array(&env, [loan_id])
+    );
+}
+
+#[test]
+fn test_private_scores_are_read_through_the_borrowers_grant() {
+    let env = Env::default();
+    env.mock_all_auths_allowing_non_root_auth();
+
+    let (manager, nft_client, pool, token, _admin) = setup_test(&env);
+    let borrower = Address::generate(&env);
+    nft_client.mint(
+        &borrower,
+        &600,
+        &BytesN::from_array(&env, &[0u8; 32]),
+        &None,
+    );
+    StellarAssetClient::new(&env, &token).mint(&pool, &10_000);
+    StellarAssetClient::new(&env, &token).mint(&borrower, &10_000);
+
+    // Requesting a loan grants the manager score access for its lifetime.
+    let loan_id = manager.request_loan(&borrower, &token, &1_000, &17280);
+    let grant = nft_client
+        .get_access_grant(&borrower, &manager.address)
+        .unwrap();
+    assert_eq!(grant.scope, remittance_nft::AccessScope::Score);
+    assert_eq!(
+        grant.expiry_ledger,
+        env.ledger().sequence() + 17280 + manager.get_default_window_ledgers()
+    );
+    manager.approve_loan(&loan_id);
+
+    nft_client.set_private(&borrower, &true);
+    assert_eq!(nft_client.get_score(&borrower), 0);
+    manager.repay(&borrower, &loan_id, &500);
+    let credited = nft_client.get_score_for(&borrower, &borrower);
+    assert!(credited > 600);
+
+    // Once revoked, the manager can no longer read the record, so the next
+    // repayment earns no credit.
+    nft_client.revoke_access(&borrower, &manager.address);
+    assert_eq!(
+        nft_client.try_get_score_for(&borrower, &manager.address),
+        Err(Ok(remittance_nft::NftError::AccessDenied))
+    );
+    manager.repay(&borrower, &loan_id, &200);
+    assert_eq!(nft_client.get_score_for(&borrower, &borrower), credited);
+
+    // A new request signs a fresh grant.
+    let loan_id = manager.request_loan(&borrower, &token, &1_000, &17280);
+    assert_eq!(manager.get_loan(&loan_id).status, LoanStatus::Pending);
+    assert!(nft_client.has_access(
+        &borrower,
+        &manager.address,
+        &remittance_nft::AccessScope::Score
+    ));
+}
+
+#[test]
+fn test_migration_rewrites_version_3_loans_with_assets() {
+    let env = Env::default();
+    env.mock_all_auths_allowing_non_root_auth();
+    let (manager, nft_client, pool_address, token_id, _admin) = setup_test(&env);
+    let borrower = Address::generate(&env);
+    nft_client.mint(
+        &borrower,
+        &600,
+        &BytesN::from_array(&env, &[0u8; 32]),
+        &None,
+    );
+    StellarAssetClient::new(&env, &token_id).mint(&pool_address, &10_000);
+    let loan_id = manager.request_loan(&borrower, &token_id, &1000, &17280);
+    let due_date = manager.get_loan(&loan_id).due_date;
+
+    // Rewrite storage as a version 3 deployment left it: a loan without
+    // assets and the borrower list in instance storage.
+    env.as_contract(&manager.address, || {
+        env.storage().persistent().set(
+            &DataKey::Loan(loan_id),
+            &LoanV3 {
+                borrower: borrower.clone(),
+                amount: 1000,
+                collateral_amount: 0,
+                principal_paid: 0,
+                interest_paid: 0,
+                accrued_interest: 0,
+                late_fee_paid: 0,
+                accrued_late_fee: 0,
+                interest_rate_bps: 1200,
+                due_date,
+                last_interest_ledger: env.ledger().sequence(),
+                last_late_fee_ledger: due_date,
+                status: LoanStatus::Pending,
+                interest_residual: 0,
+                extension_count: 0,
+                term_ledgers: 17280,
+            },
+        );
+        let loans: Vec<u32> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::BorrowerLoans(borrower.clone()))
+            .unwrap();
+        env.storage()
+            .persistent()
+            .remove(&DataKey::BorrowerLoans(borrower.clone()));
+        env.storage()
+            .instance()
+            .set(&DataKey::BorrowerLoans(borrower.clone()), &loans);
+        env.storage().instance().set(&DataKey::Version, &3u32);
+    });
+
+    let status = manager.migrate_batch(&1, &10);
+    assert_eq!(status.version, 4);
+    // Repeating the finished step's ids is harmless.
+    let status = manager.migrate_batch(&1, &10);
+    assert!(status.complete);
+
+    let loan = manager.get_loan(&loan_id);
+    assert_eq!(loan.asset, token_id);
+    assert_eq!(loan.collateral_asset, token_id);
+    assert_eq!(loan.amount, 1000);
+    assert_eq!(manager.get_borrower_loans(&borrower).len(), 1);
+    manager.approve_loan(&loan_id);
+}
+
+#[test]
+fn test_batched_migration_moves_borrower_lists_and_guards_writes() {
+    let env = Env::default();
+    env.mock_all_auths_allowing_non_root_auth();
+    let (manager, nft_client, pool_address, token_id, _admin) = setup_test(&env);
+    let borrower = Address::generate(&env);
+    nft_client.mint(
+        &borrower,
+        &600,
+        &BytesN::from_array(&env, &[0u8; 32]),
+        &None,
+    );
+    StellarAssetClient::new(&env, &token_id).mint(&pool_address, &10_000);
+
+    let first = manager.request_loan(&borrower, &token_id, &1000, &17280);
+    let second = manager.request_loan(&borrower, &token_id, &500, &17280);
+    let third = manager.request_loan(&borrower, &token_id, &250, &17280);
+
+    // Put the contract back into its version 4 layout.
+    env.as_contract(&manager.address, || {
+        let loans: Vec<u32> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::BorrowerLoans(borrower.clone()))
+            .unwrap();
+        env.storage()
+            .persistent()
+            .remove(&DataKey::BorrowerLoans(borrower.clone()));
+        env.storage()
+            .instance()
+            .set(&DataKey::BorrowerLoans(borrower.clone()), &loans);
+        env.storage().instance().set(&DataKey::Version, &4u32);
+        // Version 5 deployments had no limits until the admin opted in.
+        env.storage()
+            .persistent()
+            .remove(&DataKey::ParamLimit(LoanParam::InterestRate));
+    });
+
+    assert_eq!(
+        manager.try_request_loan(&borrower, &token_id, &100, &17280),
+        Err(Ok(LoanError::MigrationPending))
+    );
+    assert_eq!(
+        manager.try_migrate_batch(&1, &0),
+        Err(Ok(LoanError::InvalidMigrationBatch))
+    );
+
+    let status = manager.migrate_batch(&1, &2);
+    assert_eq!(status.version, 4);
+    assert_eq!(status.cursor, 3);
+    assert!(!status.complete);
+    assert_eq!(
+        manager.try_migrate_batch(&4, &1),
+        Err(Ok(LoanError::MigrationCursorAhead))
+    );
+
+    // Reads keep working mid-migration via the instance fallback.
+    assert_eq!(manager.get_borrower_loans(&borrower).len(), 3);
+
+    let status = manager.migrate_batch(&3, &10);
+    assert!(status.complete);
+    assert_eq!(status.version, 6);
+    assert_eq!(manager.version(), 6);
+    assert_eq!(
+        manager.get_migration_log(),
+        Vec::from_array(
+            &env,
+            [
+                MigrationRecord {
+                    from_version: 4,
+                    to_version: 5,
+                    ledger: env.ledger().sequence(),
+                },
+                MigrationRecord {
+                    from_version: 5,
+                    to_version: 6,
+                    ledger: env.ledger().sequence(),
+                },
+            ]
+        )
+    );
+    assert!(manager.get_param_limit(&LoanParam::InterestRate).is_some());
+
+    env.as_contract(&manager.address, || {
+        assert!(!env
+            .storage()
+            .instance()
+            .has(&DataKey::BorrowerLoans(borrower.clone())));
+    });
+    assert_eq!(
+        manager.get_borrower_loans(&borrower),
+        Vec::from_array(&env, [first, second, third])
+    );
+
+    manager.approve_loan(&first);
+    assert_eq!(manager.get_loan(&first).status, LoanStatus::Approved);
+}
+
+#[test]
+fn test_guardian_pause_on_pool_cascades_until_it_lapses() {
+    let env = Env::default();
+    env.mock_all_auths_allowing_non_root_auth();
+    let (manager, nft_client, pool_address, token_id, _admin) = setup_test(&env);
+    let borrower = Address::generate(&env);
+    nft_client.mint(
+        &borrower,
+        &600,
+        &BytesN::from_array(&env, &[0u8; 32]),
+        &None,
+    );
+    StellarAssetClient::new(&env, &token_id).mint(&pool_address, &10_000);
+
+    let pool_client = LendingPoolClient::new(&env, &pool_address);
+    pool_client.set_governance(&Address::generate(&env));
+    pool_client.set_guardian(&Some(Address::generate(&env)));
+    let expiry = pool_client.guardian_pause();
+
+    assert_eq!(
+        manager.try_request_loan(&borrower, &token_id, &1000, &17280),
+        Err(Ok(LoanError::PoolPaused))
+    );
+
+    env.ledger().with_mut(|li| li.sequence_number = expiry);
+    manager.request_loan(&borrower, &token_id, &1000, &17280);
+}
+
+#[test]
+fn test_guardian_pause_is_single_use_until_governance_acts() {
+    let env = Env::default();
+    env.mock_all_auths_allowing_non_root_auth();
+    let (manager, _nft_client, _pool_address, _token_id, _admin) = setup_test(&env);
+    manager.set_governance(&Address::generate(&env));
+    manager.set_guardian(&Some(Address::generate(&env)));
+
+    let expiry = manager.guardian_pause();
+    assert_eq!(
+        manager.try_guardian_pause(),
+        Err(Ok(LoanError::AlreadyPaused))
+    );
+    env.ledger().with_mut(|li| li.sequence_number = expiry);
+    assert!(!manager.is_paused());
+    assert_eq!(
+        manager.try_guardian_pause(),
+        Err(Ok(LoanError::GuardianPauseSpent))
+    );
+
+    manager.unpause();
+    manager.guardian_pause();
+    assert!(manager.is_paused());
+}
+
+#[test]
+fn test_paused_repayments_suspend_interest_and_late_fees() {
+    let env = Env::default();
+    env.mock_all_auths_allowing_non_root_auth();
+
+    let (manager, nft_client, pool_client, token_id, _token_admin) = setup_test(&env);
+    let borrower = Address::generate(&env);
+    nft_client.mint(
+        &borrower,
+        &600,
+        &BytesN::from_array(&env, &[0u8; 32]),
+        &None,
+    );
+
+    let stellar_token = StellarAssetClient::new(&env, &token_id);
+    stellar_token.mint(&pool_client, &10_000);
+    stellar_token.mint(&borrower, &10_000);
+
+    manager.set_late_fee_rate(&500);
+    manager.set_grace_period_ledgers(&0);
+    env.ledger().set_sequence_number(1);
+    let loan_id = manager.request_loan(&borrower, &token_id, &1000, &17280);
+    manager.approve_loan(&loan_id);
+
+    let due_date = manager.get_loan(&loan_id).due_date;
+    env.ledger().set_sequence_number(due_date);
+    let interest_at_due = manager.get_loan(&loan_id).accrued_interest;
+    manager.pause_operation(&LoanOperation::Repayments);
+
+    env.ledger().set_sequence_number(due_date + 8_640);
+    assert_eq!(
+        manager.try_repay(&borrower, &loan_id, &300),
+        Err(Ok(LoanError::OperationPaused))
+    );
+    // Other operations keep running.
+    manager.request_loan(&borrower, &token_id, &100, &17280);
+
+    let loan = manager.get_loan(&loan_id);
+    assert_eq!(loan.accrued_interest, interest_at_due);
+    assert_eq!(loan.accrued_late_fee, 0);
+
+    manager.unpause_operation(&LoanOperation::Repayments);
+    assert_eq!(
+        manager.get_repay_pause_windows(),
+        Vec::from_array(
+            &env,
+            [PauseWindow {
+                start: due_date,
+                end: Some(due_date + 8_640),
+            }]
+        )
+    );
+
+    // Same totals as 8,640 overdue ledgers without a pause.
+    env.ledger().set_sequence_number(due_date + 17_280);
+    let loan = manager.get_loan(&loan_id);
+    assert_eq!(loan.accrued_interest, 180);
+    assert_eq!(loan.accrued_late_fee, 29);
+    manager.repay(&borrower, &loan_id, &300);
+}
+
+#[test]
+fn test_pool_and_nft_pauses_do_not_block_repayment() {
+    let env = Env::default();
+    env.mock_all_auths_allowing_non_root_auth();
+
+    let (manager, nft_client, pool_address, token_id, _admin) = setup_test(&env);
+    let borrower = Address::generate(&env);
+    nft_client.mint(
+        &borrower,
+        &600,
+        &BytesN::from_array(&env, &[0u8; 32]),
+        &None,
+    );
+    let stellar_token = StellarAssetClient::new(&env, &token_id);
+    stellar_token.mint(&pool_address, &10_000);
+    stellar_token.mint(&borrower, &10_000);
+    let loan_id = manager.request_loan(&borrower, &token_id, &1000, &17280);
+    manager.approve_loan(&loan_id);
+
+    LendingPoolClient::new(&env, &pool_address).pause();
+    nft_client.pause();
+    assert_eq!(
+        manager.try_request_loan(&borrower, &token_id, &100, &17280),
+        Err(Ok(LoanError::PoolPaused))
+    );
+    // No pause window covers these, so repayments must stay open.
+    manager.repay(&borrower, &loan_id, &500);
+    assert_eq!(manager.get_loan(&loan_id).principal_paid, 500);
+    assert_eq!(nft_client.get_score_for(&borrower, &borrower), 600);
+}
+
+#[test]
+fn test_pause_windows_are_pruned_once_no_open_loan_reaches_them() {
+    let env = Env::default();
+    env.mock_all_auths_allowing_non_root_auth();
+
+    let (manager, nft_client, pool_address, token_id, _admin) = setup_test(&env);
+    let borrower = Address::generate(&env);
+    nft_client.mint(
+        &borrower,
+        &600,
+        &BytesN::from_array(&env, &[0u8; 32]),
+        &None,
+    );
+    let stellar_token = StellarAssetClient::new(&env, &token_id);
+    stellar_token.mint(&pool_address, &10_000);
+    stellar_token.mint(&borrower, &10_000);
+
+    env.ledger().set_sequence_number(100);
+    let loan_id = manager.request_loan(&borrower, &token_id, &1000, &17280);
+    manager.approve_loan(&loan_id);
+    manager.pause_operation(&LoanOperation::Repayments);
+    env.ledger().set_sequence_number(200);
+    manager.unpause_operation(&LoanOperation::Repayments);
+    manager.request_loan(&borrower, &token_id, &100, &17280);
+
+    // The approved loan still measures interest from ledger 100.
+    env.ledger().set_sequence_number(300);
+    assert_eq!(
+        manager.try_prune_repay_pause_windows(&0),
+        Err(Ok(LoanError::InvalidMigrationBatch))
+    );
+    assert!(!manager.prune_repay_pause_windows(&1));
+    assert!(manager.prune_repay_pause_windows(&10));
+    assert_eq!(manager.get_repay_pause_windows().len(), 1);
+
+    let loan = manager.get_loan(&loan_id);
+    let payoff = loan.amount + loan.accrued_interest + loan.accrued_late_fee;
+    manager.repay(&borrower, &loan_id, &payoff);
+    assert!(manager.prune_repay_pause_windows(&10));
+    assert_eq!(manager.get_repay_pause_windows().len(), 0);
+}
+
+#[test]
+fn test_paused_nft_score_updates_do_not_block_repayment() {
+    let env = Env::default();
+    env.mock_all_auths_allowing_non_root_auth();
+    let (manager, nft_client, pool_address, token_id, _admin) = setup_test(&env);
+    let borrower = Address::generate(&env);
+    nft_client.mint(
+        &borrower,
+        &600,
+        &BytesN::from_array(&env, &[0u8; 32]),
+        &None,
+    );
+    let stellar_token = StellarAssetClient::new(&env, &token_id);
+    stellar_token.mint(&pool_address, &10_000);
+    stellar_token.mint(&borrower, &10_000);
+
+    let loan_id = manager.request_loan(&borrower, &token_id, &1000, &17280);
+    manager.approve_loan(&loan_id);
+
+    nft_client.pause_operation(&remittance_nft::NftOperation::ScoreUpdates);
+    manager.repay(&borrower, &loan_id, &500);
+    assert_eq!(nft_client.get_score(&borrower), 600);
+}
+
+#[test]
+fn test_param_limits_bound_rate_changes_until_override_matures() {
+    let env = Env::default();
+    env.mock_all_auths_allowing_non_root_auth();
+    let (manager, _nft_client, _pool_address, _token_id, _admin) = setup_test(&env);
+    assert_eq!(manager.get_interest_rate(), 1200);
+
+    let limit = ParamLimit {
+        max_change: 0,
+        max_change_bps: 2500,
+        window_ledgers: 17280,
+    };
+    manager.set_param_limit(&LoanParam::InterestRate, &Some(limit.clone()));
+
+    // 25% of the 1200 bps baseline may move within one window, in total.
+    manager.set_interest_rate(&1400);
+    assert_eq!(
+        manager.try_set_interest_rate(&1600),
+        Err(Ok(LoanError::ParamChangeTooLarge))
+    );
+    manager.set_interest_rate(&1500);
+
+    env.ledger().with_mut(|li| li.sequence_number += 17280);
+    manager.set_interest_rate(&1850);
+
+    assert_eq!(
+        manager.try_set_interest_rate(&4000),
+        Err(Ok(LoanError::ParamChangeTooLarge))
+    );
+    let eta =
+        manager.schedule_param_override(&LoanParam::InterestRate, &ParamOverride::Value(4000));
+    assert_eq!(
+        manager.try_set_interest_rate(&4000),
+        Err(Ok(LoanError::ParamOverrideTimelockActive))
+    );
+    env.ledger().with_mut(|li| li.sequence_number = eta);
+    manager.set_interest_rate(&4000);
+    assert_eq!(manager.get_interest_rate(), 4000);
+    assert_eq!(manager.get_param_override(&LoanParam::InterestRate), None);
+
+    // Tightening is immediate; loosening or removing waits for an override.
+    let looser = ParamLimit {
+        max_change_bps: 5000,
+        ..limit.clone()
+    };
+    assert_eq!(
+        manager.try_set_param_limit(&LoanParam::InterestRate, &Some(looser)),
+        Err(Ok(LoanError::ParamOverrideRequired))
+    );
+    let tighter = ParamLimit {
+        max_change_bps: 1000,
+        ..limit
+    };
+    manager.set_param_limit(&LoanParam::InterestRate, &Some(tighter.clone()));
+    assert_eq!(
+        manager.get_param_limit(&LoanParam::InterestRate),
+        Some(tighter)
+    );
+
+    let eta =
+        manager.schedule_param_override(&LoanParam::InterestRate, &ParamOverride::RemoveLimit);
+    assert_eq!(
+        manager.try_set_param_limit(&LoanParam::InterestRate, &None),
+        Err(Ok(LoanError::ParamOverrideTimelockActive))
+    );
+    env.ledger().with_mut(|li| li.sequence_number = eta);
+    manager.set_param_limit(&LoanParam::InterestRate, &None);
+    manager.set_interest_rate(&100);
+}
+
+#[test]
+fn test_param_limits_apply_to_per_asset_overrides() {
+    let env = Env::default();
+    env.mock_all_auths_allowing_non_root_auth();
+    let (manager, _nft_client, _pool_address, token_id, _admin) = setup_test(&env);
+
+    manager.set_param_limit(
+        &LoanParam::AssetInterestRate(token_id.clone()),
+        &Some(ParamLimit {
+            max_change: 100,
+            max_change_bps: 0,
+            window_ledgers: 17280,
+        }),
+    );
+    let mut config = AssetConfig {
+        max_loan_amount: None,
+        interest_rate_bps: None,
+        min_repayment_amount: None,
+        collateral_asset: token_id.clone(),
+    };
+    config.interest_rate_bps = Some(1500);
+    assert_eq!(
+        manager.try_set_asset_config(&token_id, &config),
+        Err(Ok(LoanError::ParamChangeTooLarge))
+    );
+    config.interest_rate_bps = Some(1300);
+    manager.set_asset_config(&token_id, &config);
+}
+
+#[test]
+fn test_limits_are_seeded_and_rate_oracle_waits_for_an_override() {
+    let env = Env::default();
+    env.mock_all_auths_allowing_non_root_auth();
+    let (manager, _nft_client, _pool_address, token_id, _admin) = setup_test(&env);
+
+    // Limits apply from initialization, for global and per-asset values.
+    assert!(manager.get_param_limit(&LoanParam::MinScore).is_some());
+    assert!(manager
+        .get_param_limit(&LoanParam::AssetInterestRate(token_id))
+        .is_some());
+    assert_eq!(manager.get_param_limit(&LoanParam::RateOracle), None);
+    assert_eq!(
+        manager.try_set_max_loan_amount(&1_000_000),
+        Err(Ok(LoanError::ParamChangeTooLarge))
+    );
+    manager.set_max_loan_amount(&60_000);
+
+    let oracle = Address::generate(&env);
+    assert_eq!(
+        manager.try_set_rate_oracle(&oracle),
+        Err(Ok(LoanError::ParamOverrideRequired))
+    );
+    let eta = manager.schedule_param_override(
+        &LoanParam::RateOracle,
+        &ParamOverride::Address(oracle.clone()),
+    );
+    assert_eq!(
+        manager.try_set_rate_oracle(&oracle),
+        Err(Ok(LoanError::ParamOverrideTimelockActive))
+    );
+    env.ledger().with_mut(|li| li.sequence_number = eta);
+    manager.set_rate_oracle(&oracle);
+    assert_eq!(manager.get_rate_oracle(), Some(oracle));
+}
+
+#[test]
+fn test_set_guardian_requires_governance_not_just_the_admin() {
+    let env = Env::default();
+    env.mock_all_auths_allowing_non_root_auth();
+    let (manager, _nft_client, _pool_address, _token_id, admin) = setup_test(&env);
+
+    let guardian = Address::generate(&env);
+    assert_eq!(
+        manager.try_set_guardian(&Some(guardian.clone())),
+        Err(Ok(LoanError::GovernanceNotSet))
+    );
+    let governance = Address::generate(&env);
+    manager.set_governance(&governance);
+
+    // A call signed by the admin alone no longer appoints a guardian.
+    let as_signer = |signer: &Address, fn_name: &'static str, args: Vec<Val>| {
+        env.mock_auths(&[MockAuth {
+            address: signer,
+            invoke: &MockAuthInvoke {
+                contract: &manager.address,
+                fn_name,
+                args,
+                sub_invokes: &[],
+            },
+        }]);
+    };
+    as_signer(
+        &admin,
+        "set_guardian",
+        (Some(guardian.clone()),).into_val(&env),
+    );
+    assert!(manager.try_set_guardian(&Some(guardian.clone())).is_err());
+    assert_eq!(manager.get_guardian(), None);
+    as_signer(&admin, "set_governance", (admin.clone(),).into_val(&env));
+    assert!(manager.try_set_governance(&admin).is_err());
+
+    as_signer(
+        &governance,
+        "set_guardian",
+        (Some(guardian.clone()),).into_val(&env),
+    );
+    manager.set_guardian(&Some(guardian.clone()));
+    assert_eq!(manager.get_guardian(), Some(guardian));
+}
+
+#[test]
+fn test_admin_actions_are_logged_with_old_and_new_values() {
+    let env = Env::default();
+    env.mock_all_auths_allowing_non_root_auth();
+    let (manager, _nft_client, _pool_address, _token_id, admin) = setup_test(&env);
+
+    let start = manager.get_admin_action_count();
+    assert!(start >= 1);
+    let first = manager.get_admin_actions(&0, &1).get(0).unwrap();
+    assert_eq!(first.action, Symbol::new(&env, "initialize"));
+    assert_eq!(first.new, AdminValue::Address(admin.clone()));
+
+    let old_score = manager.get_min_score();
+    manager.set_min_score(&650);
+    manager.pause_operation(&LoanOperation::Repayments);
+    let guardian = Address::generate(&env);
+    let governance = Address::generate(&env);
+    manager.set_governance(&governance);
+    manager.set_guardian(&Some(guardian.clone()));
+
+    let log = manager.get_admin_actions(&start, &10);
+    assert_eq!(log.len(), 4);
+
+    let score = log.get(0).unwrap();
+    assert_eq!(score.actor, admin);
+    assert_eq!(score.ledger, env.ledger().sequence());
+    assert_eq!(score.action, Symbol::new(&env, "set_min_score"));
+    assert_eq!(score.old, AdminValue::U32(old_score));
+    assert_eq!(score.new, AdminValue::U32(650));
+
+    let pause = log.get(1).unwrap();
+    assert_eq!(pause.action, Symbol::new(&env, "pause_operation"));
+    assert_eq!(
+        pause.old,
+        AdminValue::Operation(LoanOperation::Repayments, false)
+    );
+    assert_eq!(
+        pause.new,
+        AdminValue::Operation(LoanOperation::Repayments, true)
+    );
+
+    let set_governance = log.get(2).unwrap();
+    assert_eq!(set_governance.actor, admin);
+    assert_eq!(set_governance.new, AdminValue::Address(governance.clone()));
+
+    let set_guardian = log.get(3).unwrap();
+    assert_eq!(set_guardian.actor, governance);
+    assert_eq!(set_guardian.old, AdminValue::None);
+    assert_eq!(set_guardian.new, AdminValue::Address(guardian));
+}
+
+#[test]
+fn test_refinancing_is_logged_as_an_admin_action() {
+    let env = Env::default();
+    env.mock_all_auths_allowing_non_root_auth();
+    let (manager, nft_client, pool_client, token_id, admin) = setup_test(&env);
+    let borrower = Address::generate(&env);
+    nft_client.mint(
+        &borrower,
+        &650,
+        &BytesN::from_array(&env, &[0u8; 32]),
+        &None,
+    );
+    let stellar_token = StellarAssetClient::new(&env, &token_id);
+    stellar_token.mint(&pool_client, &20_000);
+    stellar_token.mint(&borrower, &20_000);
+
+    let loan_id = manager.request_loan(&borrower, &token_id, &1_000, &17280);
+    manager.approve_loan(&loan_id);
+    manager.deposit_collateral(&loan_id, &1_000);
+    manager.refinance_loan(&loan_id, &800, &17280);
+
+    let count = manager.get_admin_action_count();
+    let entry = manager.get_admin_actions(&(count - 1), &1).get(0).unwrap();
+    assert_eq!(entry.action, Symbol::new(&env, "refinance_loan"));
+    assert_eq!(entry.actor, admin);
+    assert_eq!(entry.old, AdminValue::Loan(loan_id, 1_000));
+    assert_eq!(entry.new, AdminValue::Loan(loan_id, 800));
+}
+
+#[test]
+fn test_keeper_takes_collateral_bounty_once() {
+    let env = Env::default();
+    env.mock_all_auths_allowing_non_root_auth();
+
+    let (manager, nft_client, pool_client, token_id, _token_admin) = setup_test(&env);
+    let borrower = Address::generate(&env);
+    let keeper = Address::generate(&env);
+
+    let history_hash = soroban_sdk::BytesN::from_array(&env, &[0u8; 32]);
+    nft_client.mint(&borrower, &650, &history_hash, &None);
+
+    let token_client = TokenClient::new(&env, &token_id);
+    let stellar_token = StellarAssetClient::new(&env, &token_id);
+    stellar_token.mint(&pool_client, &20_000);
+    stellar_token.mint(&borrower, &20_000);
+
+    assert_eq!(
+        manager.try_set_keeper_bounty_bps(&(LoanManager::MAX_KEEPER_BOUNTY_BPS + 1)),
+        Err(Ok(LoanError::InvalidConfiguration))
+    );
+    manager.set_keeper_bounty_bps(&500);
+
+    let loan_id = manager.request_loan(&borrower, &token_id, &1_000, &17280);
+    manager.approve_loan(&loan_id);
+    manager.deposit_collateral(&loan_id, &400);
+    let pool_balance_before = token_client.balance(&pool_client);
+
+    let due_date = manager.get_loan(&loan_id).due_date;
+    env.ledger()
+        .set_sequence_number(due_date + manager.get_default_window_ledgers() + 1);
+
+    assert_eq!(manager.check_default(&keeper, &loan_id), 20);
+    assert_eq!(token_client.balance(&keeper), 20);
+    assert_eq!(
+        token_client.balance(&pool_client),
+        pool_balance_before + 380
+    );
+    assert_eq!(manager.get_default_keeper(&loan_id), Some(keeper.clone()));
+
+    assert_eq!(
+        manager.try_check_default(&Address::generate(&env), &loan_id),
+        Err(Ok(LoanError::LoanNotActive))
+    );
+    assert_eq!(token_client.balance(&keeper), 20);
+}
+
+#[test]
+fn test_repayment_pauses_hold_off_defaults() {
+    let env = Env::default();
+    env.mock_all_auths_allowing_non_root_auth();
+    let (manager, nft_client, pool_client, token_id, _admin) = setup_test(&env);
+    let borrower = Address::generate(&env);
+    nft_client.mint(
+        &borrower,
+        &650,
+        &BytesN::from_array(&env, &[0u8; 32]),
+        &None,
+    );
+    StellarAssetClient::new(&env, &token_id).mint(&pool_client, &20_000);
+
+    let loan_id = manager.request_loan(&borrower, &token_id, &1_000, &17280);
+    manager.approve_loan(&loan_id);
+    let due_date = manager.get_loan(&loan_id).due_date;
+    let window = manager.get_default_window_ledgers();
+
+    // Borrowers cannot cure a default while they are unable to repay.
+    env.ledger().set_sequence_number(due_date + 100);
+    manager.pause_operation(&LoanOperation::Repayments);
+    env.ledger().set_sequence_number(due_date + window + 1);
+    let keeper = Address::generate(&env);
+    assert_eq!(
+        manager.try_check_default(&keeper, &loan_id),
+        Err(Ok(LoanError::OperationPaused))
+    );
+    assert_eq!(
+        manager.try_check_defaults(&keeper, &Vec::from_array(&env, [loan_id])),
+        Err(Ok(LoanError::OperationPaused))
+    );
+    env.ledger().set_sequence_number(due_date + 600);
+    manager.unpause_operation(&LoanOperation::Repayments);
+
+    // The 500 paused ledgers are added to the default window.
+    env.ledger().set_sequence_number(due_date + window + 500);
+    assert_eq!(
+        manager.try_check_default(&keeper, &loan_id),
+        Err(Ok(LoanError::LoanNotPastDue))
+    );
+    env.ledger().set_sequence_number(due_date + window + 501);
+    manager.check_default(&keeper, &loan_id);
+    assert_eq!(manager.get_loan(&loan_id).status, LoanStatus::Defaulted);
+}
+
+#[test]
+fn test_repayment_pauses_extend_the_repayment_window() {
+    let env = Env::default();
+    env.mock_all_auths_allowing_non_root_auth();
+    let (manager, nft_client, pool_client, token_id, _admin) = setup_test(&env);
+    let borrower = Address::generate(&env);
+    nft_client.mint(
+        &borrower,
+        &650,
+        &BytesN::from_array(&env, &[0u8; 32]),
+        &None,
+    );
+    StellarAssetClient::new(&env, &token_id).mint(&pool_client, &20_000);
+
+    let repay_id = manager.request_loan(&borrower, &token_id, &1_000, &17280);
+    manager.approve_loan(&repay_id);
+    let late_id = manager.request_loan(&borrower, &token_id, &1_000, &17280);
+    manager.approve_loan(&late_id);
+    let due_date = manager.get_loan(&repay_id).due_date;
+    let window = manager.get_default_window_ledgers();
+
+    env.ledger().set_sequence_number(due_date + 100);
+    manager.pause_operation(&LoanOperation::Repayments);
+    env.ledger().set_sequence_number(due_date + 600);
+    manager.unpause_operation(&LoanOperation::Repayments);
+
+    // Past the unextended window, but inside the 500 paused ledgers.
+    env.ledger().set_sequence_number(due_date + window + 500);
+    manager.repay(&borrower, &repay_id, &100);
+    assert_eq!(manager.get_loan(&repay_id).status, LoanStatus::Approved);
+
+    env.ledger().set_sequence_number(due_date + window + 501);
+    assert_eq!(
+        manager.try_repay(&borrower, &late_id, &100),
+        Err(Ok(LoanError::LoanPastDue))
+    );
+    manager.check_default(&Address::generate(&env), &late_id);
+    assert_eq!(manager.get_loan(&late_id).status, LoanStatus::Defaulted);
+}
+
+#[test]
+fn test_pending_migration_counts_as_a_repayment_pause() {
+    let env = Env::default();
+    env.mock_all_auths_allowing_non_root_auth();
+    let (manager, nft_client, pool_client, token_id, _admin) = setup_test(&env);
+    let borrower = Address::generate(&env);
+    nft_client.mint(
+        &borrower,
+        &650,
+        &BytesN::from_array(&env, &[0u8; 32]),
+        &None,
+    );
+    StellarAssetClient::new(&env, &token_id).mint(&pool_client, &20_000);
+
+    let repay_id = manager.request_loan(&borrower, &token_id, &1_000, &17280);
+    manager.approve_loan(&repay_id);
+    let late_id = manager.request_loan(&borrower, &token_id, &1_000, &17280);
+    manager.approve_loan(&late_id);
+    let due_date = manager.get_loan(&repay_id).due_date;
+    let window = manager.get_default_window_ledgers();
+
+    // What `execute_upgrade` leaves behind for code one version ahead.
+    env.ledger().set_sequence_number(due_date + 100);
+    env.as_contract(&manager.address, || {
+        env.storage().instance().set(&DataKey::Version, &5u32);
+        LoanManager::open_repay_pause_window(&env);
+    });
+    assert_eq!(
+        manager.try_repay(&borrower, &repay_id, &100),
+        Err(Ok(LoanError::MigrationPending))
+    );
+    env.ledger().set_sequence_number(due_date + 600);
+    assert!(manager.migrate_batch(&1, &10).complete);
+    assert_eq!(
+        manager.get_repay_pause_windows(),
+        Vec::from_array(
+            &env,
+            [PauseWindow {
+                start: due_date + 100,
+                end: Some(due_date + 600),
+            }]
+        )
+    );
+
+    env.ledger().set_sequence_number(due_date + window + 500);
+    manager.repay(&borrower, &repay_id, &100);
+    assert_eq!(manager.get_loan(&repay_id).status, LoanStatus::Approved);
+
+    env.ledger().set_sequence_number(due_date + window + 501);
+    assert_eq!(
+        manager.try_repay(&borrower, &late_id, &100),
+        Err(Ok(LoanError::LoanPastDue))
+    );
+}
+
+#[test]
+fn test_check_defaults_reports_each_loan_and_pays_reserve_bounty() {
+    let env = Env::default();
+    env.mock_all_auths_allowing_non_root_auth();
+
+    let (manager, nft_client, pool_client, token_id, _token_admin) = setup_test(&env);
+    let (borrower1, borrower2) = (Address::generate(&env), Address::generate(&env));
+    let keeper = Address::generate(&env);
+    let funder = Address::generate(&env);
+
+    let history_hash = soroban_sdk::BytesN::from_array(&env, &[0u8; 32]);
+    nft_client.mint(&borrower1, &600, &history_hash, &None);
+    nft_client.mint(&borrower2, &600, &history_hash, &None);
+
+    let token_client = TokenClient::new(&env, &token_id);
+    let stellar_token = StellarAssetClient::new(&env, &token_id);
+    stellar_token.mint(&pool_client, &100_000);
+    stellar_token.mint(&funder, &1_000);
+
+    manager.set_keeper_reserve_bounty(&25);
+    manager.fund_keeper_reserve(&funder, &40);
+    assert_eq!(manager.get_keeper_reserve(), 40);
+
+    let overdue = manager.request_loan(&borrower1, &token_id, &1_000, &17280);
+    manager.approve_loan(&overdue);
+    let pending = manager.request_loan(&borrower2, &token_id, &1_000, &17280);
+
+    let due_date = manager.get_loan(&overdue).due_date;
+    env.ledger()
+        .set_sequence_number(due_date + manager.get_default_window_ledgers() + 1);
+    let later = manager.request_loan(&borrower1, &token_id, &1_000, &17280);
+    manager.approve_loan(&later);
+
+    let results = manager.check_defaults(
+        &keeper,
+        &soroban_sdk::vec![&env, overdue, pending, later, 999],
+    );
+    assert_eq!(
+        results,
+        soroban_sdk::vec![
+            &env,
+            DefaultCheck::Defaulted(25),
+            DefaultCheck::NotActive,
+            DefaultCheck::NotPastDue,
+            DefaultCheck::NotFound,
+        ]
+    );
+    assert_eq!(token_client.balance(&keeper), 25);
+    assert_eq!(manager.get_keeper_reserve(), 15);
+}
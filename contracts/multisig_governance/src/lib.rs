@@ -3,8 +3,8 @@
 #[cfg(test)]
 mod test;
 use soroban_sdk::{
-    contract, contractimpl, contracttype, symbol_short, Address, BytesN, Env, IntoVal, Map, Symbol,
-    Vec,
+    contract, contracterror, contractimpl, contracttype, symbol_short, Address, BytesN, Env,
+    IntoVal, Map, Symbol, Vec,
 };
 
 // ─── Constants ────────────────────────────────────────────────────────────────
@@ -18,54 +18,211 @@ const MAX_SIGNERS: u32 = 20;
 /// Time-to-live for proposals before they expire (7 days in seconds).
 const PROPOSAL_TTL_SECONDS: u64 = 604_800;
 
+/// Proposals that may be open at once — bounds the active list.
+const MAX_ACTIVE_PROPOSALS: u32 = 10;
+
+/// Largest page `list_proposals` returns.
+const MAX_PROPOSAL_LIST_LIMIT: u32 = 50;
+
+/// Minimum notice for an upgrade of this contract itself, in ledgers
+/// (about 24 hours), matching what the governed contracts enforce.
+const MIN_UPGRADE_DELAY_LEDGERS: u32 = 17_280;
+
 // ─── Storage keys ─────────────────────────────────────────────────────────────
 
 const KEY_ADMIN: Symbol = symbol_short!("ADMIN");
 const KEY_VERSION: Symbol = symbol_short!("VERSION");
-const KEY_PENDING: Symbol = symbol_short!("PENDING");
+const KEY_ACTIVE: Symbol = symbol_short!("ACTIVE");
+const KEY_PROPOSAL: Symbol = symbol_short!("PROP");
+const KEY_VETO_THRESHOLD: Symbol = symbol_short!("VETO");
 const KEY_TARGET: Symbol = symbol_short!("TARGET");
 const KEY_LAST_CANCELLED_AT: Symbol = symbol_short!("CANCEL_AT");
 const KEY_PROPOSAL_COUNT: Symbol = symbol_short!("COUNT");
+const KEY_SIGNERS: Symbol = symbol_short!("SIGNERS");
+const KEY_MIN_THRESHOLD: Symbol = symbol_short!("MINTHRESH");
+const KEY_SELF_UPGRADE: Symbol = symbol_short!("SELFUPG");
+
+const KEY_ADMIN_LOG_LEN: Symbol = symbol_short!("ADMLOGLEN");
+const KEY_ADMIN_LOG_PAGE: Symbol = symbol_short!("ADMLOG");
+
+/// Entries per persistent admin audit log page.
+const ADMIN_LOG_PAGE_SIZE: u32 = 50;
+
+/// Largest page `get_admin_actions` returns.
+const MAX_ADMIN_LOG_LIMIT: u32 = 100;
 
 const REPROPOSAL_COOLDOWN_SECONDS: u64 = 3600; // 1 hour
 const CURRENT_VERSION: u32 = 1;
 
+/// Proposal records are kept for history, so their TTL is extended on every
+/// write like the other contracts' persistent entries.
+const PERSISTENT_TTL_THRESHOLD: u32 = 17_280;
+const PERSISTENT_TTL_BUMP: u32 = 518_400;
+
+// ─── Errors ───────────────────────────────────────────────────────────────────
+
+/// Failures returned by `GovernanceContract`. Codes keep the 40xx numbers the
+/// contract has always reported.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum GovernanceError {
+    AlreadyInitialized = 4001,
+    NotInitialized = 4002,
+    TargetNotSet = 4003,
+    ProposalNotFound = 4004,
+    ThresholdExceedsSigners = 4006,
+    ThresholdTooLow = 4007,
+    /// More than MAX_SIGNERS signers.
+    TooManySigners = 4008,
+    NotASigner = 4009,
+    TimelockNotElapsed = 4010,
+    ThresholdNotMet = 4011,
+    /// Transfer delay below MIN_TIMELOCK_SECONDS.
+    DelayTooShort = 4012,
+    EmptySigners = 4013,
+    /// A proposal was closed less than REPROPOSAL_COOLDOWN_SECONDS ago.
+    ReproposalCooldown = 4015,
+    ProposalExpired = 4016,
+    ProposalNotExpired = 4017,
+    ProposalNotActive = 4019,
+    DuplicateSigner = 4020,
+    /// Another upgrade of the same target is already scheduled there.
+    UpgradeAlreadyPending = 4021,
+    UpgradeAlreadyScheduled = 4022,
+    UpgradeNotScheduled = 4023,
+    NotAnUpgrade = 4024,
+    TooManyActiveProposals = 4025,
+    NotAnAdminTransfer = 4026,
+    /// Signer has no approval on the proposal to revoke.
+    NotApproved = 4027,
+    /// Vetoes are only accepted during the timelock of a proposal made
+    /// with a veto threshold.
+    VetoWindowClosed = 4028,
+    /// Veto threshold must be less than half of the signers.
+    InvalidVetoThreshold = 4029,
+    /// `execute_proposal` only runs guardian changes, pause ratifications,
+    /// unpauses, target admin handovers and signer changes.
+    NotAGuardianAction = 4030,
+    /// Proposals need the governance signer set named by `set_signers`.
+    SignersNotSet = 4031,
+    /// The signer set changes only through `propose_signer_change`.
+    SignersAlreadySet = 4032,
+}
+
 // ─── Types ────────────────────────────────────────────────────────────────────
 
-/// Status of a pending admin transfer proposal.
+/// Lifecycle status of a governance proposal.
 #[contracttype]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum ProposalStatus {
     Active = 0,
     Cancelled = 1,
+    Executed = 2,
+    Expired = 3,
+    /// Blocked by `veto_threshold` signers during the timelock.
+    Vetoed = 4,
+    /// Closed because another admin transfer was finalized first.
+    Superseded = 5,
 }
 
-/// A pending admin transfer proposal.
+/// What a proposal does once executed.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ProposalKind {
+    /// Hand the admin role to this address (may be a Gnosis Safe or DAO).
+    AdminTransfer(Address),
+    /// (target, wasm_hash, eta)
+    Upgrade(Address, BytesN<32>, u32),
+    /// (target, guardian) — name the target's emergency guardian.
+    SetGuardian(Address, Address),
+    /// Remove the target's emergency guardian.
+    ClearGuardian(Address),
+    /// Keep the target's guardian pause in force past its expiry.
+    RatifyPause(Address),
+    /// Lift a pause on the target.
+    Unpause(Address),
+    /// (target, new_admin) — hand the target's admin seat to `new_admin`,
+    /// who takes it with the target's `accept_admin`.
+    TargetAdminTransfer(Address, Address),
+    /// (signers, min_threshold) — replace the governance signer set.
+    SetSigners(Vec<Address>, u32),
+}
+
+/// A governance proposal. Records are kept by id for the life of the
+/// contract, so they can still be read after they are executed, cancelled
+/// or expired.
 #[contracttype]
 #[derive(Clone, Debug)]
-pub struct PendingTransfer {
+pub struct Proposal {
     /// Unique identifier for the proposal.
     pub id: u32,
-    /// Address that will become admin once finalized (may be a Gnosis Safe or DAO).
-    pub proposed_admin: Address,
-    /// Ordered list of addresses forming the multi-sig quorum.
+    pub kind: ProposalKind,
+    /// Admin that submitted the proposal.
+    pub proposer: Address,
+    /// Ordered list of addresses forming the multi-sig quorum, drawn from
+    /// the governance signer set.
     pub signers: Vec<Address>,
     /// Minimum approvals required before finalization is unblocked.
     pub threshold: u32,
-    /// Ledger timestamp after which finalize_admin_transfer may be called.
+    /// Ledger timestamp after which the proposal may be executed. Upgrades
+    /// are timelocked by their target instead and use `proposed_at`, as do
+    /// guardian actions, which have no timelock.
     pub executable_after: u64,
-    /// Map signer -> true for each signer that has approved.
-    pub approvals: Map<Address, bool>,
+    /// Upgrades only: whether the hash has been announced on the target.
+    pub scheduled: bool,
+    /// Map signer -> ledger timestamp of their first approval.
+    pub approvals: Map<Address, u64>,
+    /// Vetoes that block the proposal, or 0 if it cannot be vetoed.
+    pub veto_threshold: u32,
+    /// Map signer -> ledger timestamp of their veto.
+    pub vetoes: Map<Address, u64>,
     /// Timestamp when the proposal was created.
     pub proposed_at: u64,
     /// Lifecycle status of the proposal.
     pub status: ProposalStatus,
+    /// Timestamp the proposal left `Active`, or 0 while it is open.
+    pub closed_at: u64,
+    /// Address that executed, cancelled or expired the proposal.
+    pub closed_by: Option<Address>,
+    /// Reason given with an emergency cancellation.
+    pub reason: Option<soroban_sdk::String>,
+}
+
+/// A before or after value in an `AdminAction`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AdminValue {
+    None,
+    Bool(bool),
+    U32(u32),
+    I128(i128),
+    Address(Address),
+    Hash(BytesN<32>),
+    /// The contract a forwarded call was made on.
+    Target(Address),
+    /// (target, guardian)
+    Guardian(Address, Address),
+    /// (target, admin)
+    TargetAdmin(Address, Address),
+}
+
+/// One privileged call, as recorded in the admin audit log.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AdminAction {
+    pub ledger: u32,
+    pub actor: Address,
+    /// Name of the entrypoint that was called.
+    pub action: Symbol,
+    pub old: AdminValue,
+    pub new: AdminValue,
 }
 
 /// Emitted when a transfer is proposed.
 #[contracttype]
 #[derive(Clone, Debug)]
 pub struct AdminTransferProposedEvent {
+    pub proposal_id: u32,
     pub proposed_admin: Address,
     pub signers: Vec<Address>,
     pub threshold: u32,
@@ -78,6 +235,7 @@ pub struct AdminTransferProposedEvent {
 #[contracttype]
 #[derive(Clone, Debug)]
 pub struct AdminTransferFinalizedEvent {
+    pub proposal_id: u32,
     pub new_admin: Address,
     pub finalized_by: Address,
     pub approval_count: u32,
@@ -88,6 +246,7 @@ pub struct AdminTransferFinalizedEvent {
 #[contracttype]
 #[derive(Clone, Debug)]
 pub struct AdminTransferCancelledEvent {
+    pub proposal_id: u32,
     pub cancelled_by: Address,
     pub timestamp: u64,
 }
@@ -113,10 +272,34 @@ pub struct ProposalApprovedEvent {
     pub timestamp: u64,
 }
 
+/// Emitted when a signer withdraws their approval.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct ApprovalRevokedEvent {
+    pub proposal_id: u32,
+    pub signer: Address,
+    pub approvals_so_far: u32,
+    pub timestamp: u64,
+}
+
+/// Emitted each time a signer vetoes.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct ProposalVetoedEvent {
+    pub proposal_id: u32,
+    pub signer: Address,
+    pub vetoes_so_far: u32,
+    pub veto_threshold: u32,
+    /// Whether this veto closed the proposal.
+    pub blocked: bool,
+    pub timestamp: u64,
+}
+
 /// Emitted when a proposal expires due to TTL.
 #[contracttype]
 #[derive(Clone, Debug)]
 pub struct ProposalExpiredEvent {
+    pub proposal_id: u32,
     pub expired_by: Address,
     pub proposal_timestamp: u64,
     pub expiry_timestamp: u64,
@@ -135,41 +318,126 @@ impl GovernanceContract {
     /// `admin`           — current RemitLend admin.
     /// `target_contract` — the RemitLend contract whose admin will be updated
     ///                     when finalize_admin_transfer is called.
-    pub fn initialize(env: Env, admin: Address, target_contract: Address) {
+    pub fn initialize(
+        env: Env,
+        admin: Address,
+        target_contract: Address,
+    ) -> Result<(), GovernanceError> {
         if env.storage().instance().has(&KEY_ADMIN) {
-            panic!("already initialized");
+            return Err(GovernanceError::AlreadyInitialized);
         }
         env.storage().instance().set(&KEY_ADMIN, &admin);
         env.storage().instance().set(&KEY_TARGET, &target_contract);
         env.storage().instance().set(&KEY_VERSION, &CURRENT_VERSION);
         env.storage().instance().set(&KEY_PROPOSAL_COUNT, &0u32);
+        Self::log_admin_action(
+            &env,
+            &admin,
+            "initialize",
+            AdminValue::None,
+            AdminValue::Address(admin.clone()),
+        );
+        Ok(())
     }
 
     pub fn version(env: Env) -> u32 {
         env.storage().instance().get(&KEY_VERSION).unwrap_or(0)
     }
 
-    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
-        let admin = Self::read_admin(&env);
+    /// Record the running code's version after an upgrade of this contract,
+    /// which goes through `propose_upgrade` like any other target. Admin
+    /// only.
+    pub fn migrate(env: Env) -> Result<(), GovernanceError> {
+        let admin = Self::read_admin(&env)?;
         admin.require_auth();
 
         let old_version = Self::version(env.clone());
-        let new_version = old_version.saturating_add(1);
-        env.storage().instance().set(&KEY_VERSION, &new_version);
+        if old_version == CURRENT_VERSION {
+            return Ok(());
+        }
+        env.storage().instance().set(&KEY_VERSION, &CURRENT_VERSION);
+        Self::log_admin_action(
+            &env,
+            &admin,
+            "migrate",
+            AdminValue::U32(old_version),
+            AdminValue::U32(CURRENT_VERSION),
+        );
         env.events().publish(
-            (Symbol::new(&env, "ContractUpgraded"),),
-            (old_version, new_version),
+            (Symbol::new(&env, "ContractMigrated"),),
+            (old_version, CURRENT_VERSION),
         );
+        Ok(())
+    }
 
-        env.deployer().update_current_contract_wasm(new_wasm_hash);
+    // ── Signer set ────────────────────────────────────────────────────────────
+
+    /// Name the governance signer set and the fewest approvals any proposal
+    /// may require. Every proposal's signers must come from this set and its
+    /// threshold may not go below `min_threshold`. Admin only, and only
+    /// once; later changes go through `propose_signer_change`.
+    pub fn set_signers(
+        env: Env,
+        signers: Vec<Address>,
+        min_threshold: u32,
+    ) -> Result<(), GovernanceError> {
+        let admin = Self::read_admin(&env)?;
+        admin.require_auth();
+
+        if env.storage().instance().has(&KEY_SIGNERS) {
+            return Err(GovernanceError::SignersAlreadySet);
+        }
+        let signers = Self::unique_signers(&env, &signers, min_threshold)?;
+        Self::write_signers(&env, &admin, signers, min_threshold);
+        Ok(())
+    }
+
+    /// Propose replacing the governance signer set, returning the proposal
+    /// id. Admin only; `signers` and `threshold` pick the approvers from the
+    /// current set. Runs through `execute_proposal` once approved and
+    /// MIN_TIMELOCK_SECONDS have passed.
+    pub fn propose_signer_change(
+        env: Env,
+        new_signers: Vec<Address>,
+        new_min_threshold: u32,
+        signers: Vec<Address>,
+        threshold: u32,
+    ) -> Result<u32, GovernanceError> {
+        let new_signers = Self::unique_signers(&env, &new_signers, new_min_threshold)?;
+        let executable_after = env
+            .ledger()
+            .timestamp()
+            .saturating_add(MIN_TIMELOCK_SECONDS);
+        Self::propose_action(
+            &env,
+            ProposalKind::SetSigners(new_signers, new_min_threshold),
+            signers,
+            threshold,
+            executable_after,
+        )
+    }
+
+    pub fn get_signers(env: Env) -> Vec<Address> {
+        env.storage()
+            .instance()
+            .get(&KEY_SIGNERS)
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+
+    pub fn get_min_threshold(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&KEY_MIN_THRESHOLD)
+            .unwrap_or(0)
     }
 
     // ── Propose ───────────────────────────────────────────────────────────────
 
     /// Propose a transfer of the admin role.
     ///
-    /// Only the current admin may call this. Any pending proposal must be
-    /// cancelled before a new one can be submitted.
+    /// Only the current admin may call this. Up to MAX_ACTIVE_PROPOSALS
+    /// transfers may be open at once; each is approved, finalized or
+    /// cancelled by the id returned here.
     ///
     /// `delay_seconds` must be >= MIN_TIMELOCK_SECONDS (86400 = 24 h).
     /// `threshold` must be in [1, len(signers)] and len(signers) <= MAX_SIGNERS.
@@ -179,18 +447,13 @@ impl GovernanceContract {
         signers: Vec<Address>,
         threshold: u32,
         delay_seconds: u64,
-    ) {
-        let admin = Self::read_admin(&env);
+    ) -> Result<u32, GovernanceError> {
+        let admin = Self::read_admin(&env)?;
         admin.require_auth();
 
-        if let Some(pending) = env
-            .storage()
-            .instance()
-            .get::<Symbol, PendingTransfer>(&KEY_PENDING)
-        {
-            if pending.status == ProposalStatus::Active {
-                panic!("transfer already pending — cancel first (4005)");
-            }
+        let mut active = Self::active_proposals(&env);
+        if active.len() >= MAX_ACTIVE_PROPOSALS {
+            return Err(GovernanceError::TooManyActiveProposals);
         }
 
         if let Some(last_cancelled_at) = env
@@ -200,72 +463,45 @@ impl GovernanceContract {
         {
             let now = env.ledger().timestamp();
             if now < last_cancelled_at.saturating_add(REPROPOSAL_COOLDOWN_SECONDS) {
-                panic!(
-                    "must wait at least {} seconds after cancellation before re-proposing (4015)",
-                    REPROPOSAL_COOLDOWN_SECONDS
-                );
+                return Err(GovernanceError::ReproposalCooldown);
             }
         }
-        if signers.is_empty() {
-            panic!("signer list must not be empty (4013)");
-        }
-        if signers.len() > MAX_SIGNERS {
-            panic!("signer list exceeds MAX_SIGNERS of 20 (4008)");
-        }
-        // Ensure signer list contains unique addresses. Duplicates would allow
-        // the same key to be listed multiple times and potentially bypass
-        // the multi-signer threshold semantics. We build an explicit
-        // deduplicated `unique_signers` Vec that preserves order but rejects
-        // input lists containing duplicates.
-        let mut unique_signers: Vec<Address> = Vec::new(&env);
-        for s in signers.iter() {
-            if unique_signers.iter().any(|x| x == s) {
-                // Explicitly reject proposals containing duplicate signer
-                // entries to avoid any ambiguity in quorum semantics.
-                panic!("duplicate signer in signer list (4020)");
-            }
-            unique_signers.push_back(s.clone());
-        }
-        if threshold < 1 {
-            panic!("threshold must be >= 1 (4007)");
-        }
-        if threshold > signers.len() {
-            panic!("threshold exceeds signer count (4006)");
-        }
+        let unique_signers = Self::validate_quorum(&env, &signers, threshold)?;
         if delay_seconds < MIN_TIMELOCK_SECONDS {
-            panic!("delay must be >= 86400 seconds (24 hours) (4012)");
+            return Err(GovernanceError::DelayTooShort);
         }
+        let veto_threshold = Self::checked_veto_threshold(&env, &unique_signers)?;
 
         let now = env.ledger().timestamp();
         let executable_after = now.saturating_add(delay_seconds);
-
-        let proposal_count: u32 = env
-            .storage()
-            .instance()
-            .get(&KEY_PROPOSAL_COUNT)
-            .unwrap_or(0);
-        let proposal_id = proposal_count + 1;
-        env.storage()
-            .instance()
-            .set(&KEY_PROPOSAL_COUNT, &proposal_id);
-
-        let pending = PendingTransfer {
-            id: proposal_id,
-            proposed_admin: proposed_admin.clone(),
-            // Store the deduplicated signer list.
-            signers: unique_signers.clone(),
+        let proposal_id = Self::next_proposal_id(&env);
+
+        // Store the deduplicated signer list.
+        let mut proposal = Self::new_proposal(
+            &env,
+            proposal_id,
+            ProposalKind::AdminTransfer(proposed_admin.clone()),
+            &admin,
+            unique_signers,
             threshold,
             executable_after,
-            approvals: Map::new(&env),
-            proposed_at: now,
-            status: ProposalStatus::Active,
-        };
-
-        env.storage().instance().set(&KEY_PENDING, &pending);
-
+        );
+        proposal.veto_threshold = veto_threshold;
+        Self::write_proposal(&env, &proposal);
+        active.push_back(proposal_id);
+        env.storage().instance().set(&KEY_ACTIVE, &active);
+
+        Self::log_admin_action(
+            &env,
+            &admin,
+            "propose_admin_transfer",
+            AdminValue::Address(admin.clone()),
+            AdminValue::Address(proposed_admin.clone()),
+        );
         env.events().publish(
             (symbol_short!("GovProp"), admin.clone()),
             AdminTransferProposedEvent {
+                proposal_id,
                 proposed_admin,
                 signers,
                 threshold,
@@ -274,76 +510,684 @@ impl GovernanceContract {
                 timestamp: now,
             },
         );
+        Ok(proposal_id)
+    }
+
+    // ── Contract upgrades ─────────────────────────────────────────────────────
+
+    /// Propose upgrading `target` to `wasm_hash` at ledger `eta`, returning
+    /// the proposal id.
+    ///
+    /// This contract must be the target's admin. `target` may be this
+    /// contract itself, whose upgrade is then timelocked here. Upgrades
+    /// share the active list with admin transfers, so several may be open at
+    /// once; only one per target can be scheduled there at a time.
+    pub fn propose_upgrade(
+        env: Env,
+        target: Address,
+        wasm_hash: BytesN<32>,
+        eta: u32,
+        signers: Vec<Address>,
+        threshold: u32,
+    ) -> Result<u32, GovernanceError> {
+        let admin = Self::read_admin(&env)?;
+        admin.require_auth();
+
+        let mut active = Self::active_proposals(&env);
+        if active.len() >= MAX_ACTIVE_PROPOSALS {
+            return Err(GovernanceError::TooManyActiveProposals);
+        }
+        let unique_signers = Self::validate_quorum(&env, &signers, threshold)?;
+        let veto_threshold = Self::checked_veto_threshold(&env, &unique_signers)?;
+
+        let proposal_id = Self::next_proposal_id(&env);
+        let mut proposal = Self::new_proposal(
+            &env,
+            proposal_id,
+            ProposalKind::Upgrade(target.clone(), wasm_hash.clone(), eta),
+            &admin,
+            unique_signers,
+            threshold,
+            env.ledger().timestamp(),
+        );
+        proposal.veto_threshold = veto_threshold;
+        Self::write_proposal(&env, &proposal);
+        active.push_back(proposal_id);
+        env.storage().instance().set(&KEY_ACTIVE, &active);
+
+        Self::log_admin_action(
+            &env,
+            &admin,
+            "propose_upgrade",
+            AdminValue::Target(target.clone()),
+            AdminValue::Hash(wasm_hash.clone()),
+        );
+        env.events().publish(
+            (symbol_short!("GovUpgP"), target),
+            (proposal_id, wasm_hash, eta),
+        );
+        Ok(proposal_id)
+    }
+
+    /// Record a signer's approval of upgrade `proposal_id`. Idempotent;
+    /// approving withdraws any veto the signer cast earlier.
+    pub fn approve_upgrade(
+        env: Env,
+        signer: Address,
+        proposal_id: u32,
+    ) -> Result<(), GovernanceError> {
+        signer.require_auth();
+
+        let (mut proposal, _, _, _) = Self::read_upgrade(&env, proposal_id)?;
+        if proposal.status != ProposalStatus::Active {
+            return Err(GovernanceError::ProposalNotActive);
+        }
+        if !proposal.signers.iter().any(|s| s == signer) {
+            return Err(GovernanceError::NotASigner);
+        }
+        if proposal.vetoes.contains_key(signer.clone()) {
+            proposal.vetoes.remove(signer.clone());
+            Self::write_proposal(&env, &proposal);
+        }
+        Self::record_approval(&env, &mut proposal, &signer);
+
+        Self::log_admin_action(
+            &env,
+            &signer,
+            "approve_upgrade",
+            AdminValue::None,
+            AdminValue::U32(proposal_id),
+        );
+        env.events().publish(
+            (symbol_short!("GovUpgA"), signer),
+            (proposal_id, proposal.approvals.len(), proposal.threshold),
+        );
+        Ok(())
+    }
+
+    /// Announce approved upgrade `proposal_id` on its target. Callable by
+    /// anyone once the quorum is met and before the proposal expires.
+    pub fn schedule_upgrade(
+        env: Env,
+        caller: Address,
+        proposal_id: u32,
+    ) -> Result<(), GovernanceError> {
+        caller.require_auth();
+
+        let (mut proposal, target, wasm_hash, eta) = Self::read_upgrade(&env, proposal_id)?;
+        if proposal.status != ProposalStatus::Active {
+            return Err(GovernanceError::ProposalNotActive);
+        }
+        if proposal.scheduled {
+            return Err(GovernanceError::UpgradeAlreadyScheduled);
+        }
+        let now = env.ledger().timestamp();
+        if now >= proposal.proposed_at.saturating_add(PROPOSAL_TTL_SECONDS) {
+            return Err(GovernanceError::ProposalExpired);
+        }
+        if proposal.approvals.len() < proposal.threshold {
+            return Err(GovernanceError::ThresholdNotMet);
+        }
+        // The target holds a single scheduled upgrade; don't overwrite it.
+        for id in Self::active_proposals(&env).iter() {
+            let other = Self::read_proposal(&env, id)?;
+            if let ProposalKind::Upgrade(other_target, _, _) = &other.kind {
+                if other.scheduled && *other_target == target {
+                    return Err(GovernanceError::UpgradeAlreadyPending);
+                }
+            }
+        }
+
+        if target == env.current_contract_address() {
+            let earliest = env
+                .ledger()
+                .sequence()
+                .saturating_add(MIN_UPGRADE_DELAY_LEDGERS);
+            if eta < earliest {
+                return Err(GovernanceError::DelayTooShort);
+            }
+            env.storage().instance().set(&KEY_SELF_UPGRADE, &eta);
+        } else {
+            env.invoke_contract::<()>(
+                &target,
+                &Symbol::new(&env, "schedule_upgrade"),
+                soroban_sdk::vec![&env, wasm_hash.clone().into_val(&env), eta.into_val(&env)],
+            );
+        }
+
+        proposal.scheduled = true;
+        Self::write_proposal(&env, &proposal);
+        Self::log_admin_action(
+            &env,
+            &caller,
+            "schedule_upgrade",
+            AdminValue::Target(target.clone()),
+            AdminValue::Hash(wasm_hash),
+        );
+        env.events()
+            .publish((symbol_short!("GovUpgS"), target), (proposal_id, eta));
+        Ok(())
+    }
+
+    /// Swap the target's wasm once its timelock has passed. Callable by
+    /// anyone after `schedule_upgrade`.
+    pub fn execute_upgrade(
+        env: Env,
+        caller: Address,
+        proposal_id: u32,
+    ) -> Result<(), GovernanceError> {
+        caller.require_auth();
+
+        let (mut proposal, target, wasm_hash, _) = Self::read_upgrade(&env, proposal_id)?;
+        if proposal.status != ProposalStatus::Active {
+            return Err(GovernanceError::ProposalNotActive);
+        }
+        if !proposal.scheduled {
+            return Err(GovernanceError::UpgradeNotScheduled);
+        }
+        let upgrades_self = target == env.current_contract_address();
+        if upgrades_self {
+            let eta: u32 = env
+                .storage()
+                .instance()
+                .get(&KEY_SELF_UPGRADE)
+                .ok_or(GovernanceError::UpgradeNotScheduled)?;
+            if env.ledger().sequence() < eta {
+                return Err(GovernanceError::TimelockNotElapsed);
+            }
+            env.storage().instance().remove(&KEY_SELF_UPGRADE);
+        } else {
+            env.invoke_contract::<()>(
+                &target,
+                &Symbol::new(&env, "execute_upgrade"),
+                soroban_sdk::vec![&env],
+            );
+        }
+
+        Self::close_proposal(&env, &mut proposal, ProposalStatus::Executed, &caller);
+        Self::log_admin_action(
+            &env,
+            &caller,
+            "execute_upgrade",
+            AdminValue::Target(target.clone()),
+            AdminValue::Hash(wasm_hash.clone()),
+        );
+        env.events().publish(
+            (symbol_short!("GovUpgX"), target),
+            (proposal_id, wasm_hash.clone()),
+        );
+        // The new code records its version in `migrate`.
+        if upgrades_self {
+            env.deployer().update_current_contract_wasm(wasm_hash);
+        }
+        Ok(())
+    }
+
+    /// Drop upgrade `proposal_id`, withdrawing it from the target if it was
+    /// already scheduled there. Admin only.
+    pub fn cancel_upgrade(env: Env, proposal_id: u32) -> Result<(), GovernanceError> {
+        let admin = Self::read_admin(&env)?;
+        admin.require_auth();
+
+        let (mut proposal, target, wasm_hash, _) = Self::read_upgrade(&env, proposal_id)?;
+        if proposal.status != ProposalStatus::Active {
+            return Err(GovernanceError::ProposalNotActive);
+        }
+        if proposal.scheduled {
+            Self::withdraw_upgrade(&env, &target);
+        }
+
+        Self::close_proposal(&env, &mut proposal, ProposalStatus::Cancelled, &admin);
+        Self::log_admin_action(
+            &env,
+            &admin,
+            "cancel_upgrade",
+            AdminValue::Hash(wasm_hash),
+            AdminValue::None,
+        );
+        env.events()
+            .publish((symbol_short!("GovUpgC"), target), proposal_id);
+        Ok(())
+    }
+
+    // ── Guardian ──────────────────────────────────────────────────────────────
+
+    /// Propose naming (or clearing) the emergency guardian on `target`,
+    /// returning the proposal id. The guardian can pause the target at once
+    /// but cannot unpause it or change parameters. Admin only; this contract
+    /// must be the target's recorded governance (`set_governance`). Runs
+    /// through `execute_proposal` once `threshold` signers approve.
+    pub fn propose_guardian(
+        env: Env,
+        target: Address,
+        guardian: Option<Address>,
+        signers: Vec<Address>,
+        threshold: u32,
+    ) -> Result<u32, GovernanceError> {
+        let kind = match guardian {
+            Some(guardian) => ProposalKind::SetGuardian(target, guardian),
+            None => ProposalKind::ClearGuardian(target),
+        };
+        Self::propose_action(&env, kind, signers, threshold, env.ledger().timestamp())
+    }
+
+    /// Propose keeping a guardian pause on `target` in force past its
+    /// expiry. Admin only; runs through `execute_proposal` once `threshold`
+    /// signers approve.
+    pub fn propose_pause_ratification(
+        env: Env,
+        target: Address,
+        signers: Vec<Address>,
+        threshold: u32,
+    ) -> Result<u32, GovernanceError> {
+        Self::propose_action(
+            &env,
+            ProposalKind::RatifyPause(target),
+            signers,
+            threshold,
+            env.ledger().timestamp(),
+        )
+    }
+
+    /// Propose lifting a pause on `target`, including a guardian pause that
+    /// was ratified. Admin only; runs through `execute_proposal` once
+    /// `threshold` signers approve.
+    pub fn propose_unpause(
+        env: Env,
+        target: Address,
+        signers: Vec<Address>,
+        threshold: u32,
+    ) -> Result<u32, GovernanceError> {
+        Self::propose_action(
+            &env,
+            ProposalKind::Unpause(target),
+            signers,
+            threshold,
+            env.ledger().timestamp(),
+        )
+    }
+
+    /// Propose handing the admin seat of `target` to `new_admin`, returning
+    /// the proposal id. This contract must be the target's admin; it names
+    /// `new_admin` through the target's `propose_admin`, and `new_admin`
+    /// then calls the target's `accept_admin`. Admin only; runs through
+    /// `execute_proposal` once approved and MIN_TIMELOCK_SECONDS have
+    /// passed.
+    pub fn propose_target_admin_transfer(
+        env: Env,
+        target: Address,
+        new_admin: Address,
+        signers: Vec<Address>,
+        threshold: u32,
+    ) -> Result<u32, GovernanceError> {
+        let executable_after = env
+            .ledger()
+            .timestamp()
+            .saturating_add(MIN_TIMELOCK_SECONDS);
+        Self::propose_action(
+            &env,
+            ProposalKind::TargetAdminTransfer(target, new_admin),
+            signers,
+            threshold,
+            executable_after,
+        )
+    }
+
+    /// Record a signer's approval of a guardian change, pause ratification,
+    /// unpause, target admin handover or signer change. Idempotent;
+    /// approving withdraws any veto the signer cast earlier.
+    pub fn approve_proposal(
+        env: Env,
+        signer: Address,
+        proposal_id: u32,
+    ) -> Result<(), GovernanceError> {
+        signer.require_auth();
+
+        let mut proposal = Self::read_guardian_action(&env, proposal_id)?;
+        if proposal.status != ProposalStatus::Active {
+            return Err(GovernanceError::ProposalNotActive);
+        }
+        if !proposal.signers.iter().any(|s| s == signer) {
+            return Err(GovernanceError::NotASigner);
+        }
+        if proposal.vetoes.contains_key(signer.clone()) {
+            proposal.vetoes.remove(signer.clone());
+            Self::write_proposal(&env, &proposal);
+        }
+        Self::record_approval(&env, &mut proposal, &signer);
+
+        Self::log_admin_action(
+            &env,
+            &signer,
+            "approve_proposal",
+            AdminValue::None,
+            AdminValue::U32(proposal_id),
+        );
+        env.events().publish(
+            (symbol_short!("GovAppr"), signer.clone()),
+            ProposalApprovedEvent {
+                proposal_id,
+                signer,
+                approvals_so_far: proposal.approvals.len(),
+                threshold: proposal.threshold,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+
+    /// Apply an approved guardian change, pause ratification, unpause,
+    /// target admin handover or signer change. Callable by anyone once the
+    /// quorum is met and before the proposal expires. Guardian actions and
+    /// unpauses have no timelock, since a ratification has to land before
+    /// the guardian pause lapses; admin handovers and signer changes wait
+    /// until `executable_after`.
+    pub fn execute_proposal(
+        env: Env,
+        caller: Address,
+        proposal_id: u32,
+    ) -> Result<(), GovernanceError> {
+        caller.require_auth();
+
+        let mut proposal = Self::read_guardian_action(&env, proposal_id)?;
+        if proposal.status != ProposalStatus::Active {
+            return Err(GovernanceError::ProposalNotActive);
+        }
+        let now = env.ledger().timestamp();
+        if now < proposal.executable_after {
+            return Err(GovernanceError::TimelockNotElapsed);
+        }
+        if now >= proposal.proposed_at.saturating_add(PROPOSAL_TTL_SECONDS) {
+            return Err(GovernanceError::ProposalExpired);
+        }
+        if proposal.approvals.len() < proposal.threshold {
+            return Err(GovernanceError::ThresholdNotMet);
+        }
+
+        match proposal.kind.clone() {
+            ProposalKind::SetSigners(signers, min_threshold) => {
+                Self::write_signers(&env, &caller, signers, min_threshold)
+            }
+            ProposalKind::SetGuardian(target, guardian) => {
+                Self::apply_guardian(&env, &caller, target, Some(guardian))
+            }
+            ProposalKind::ClearGuardian(target) => {
+                Self::apply_guardian(&env, &caller, target, None)
+            }
+            ProposalKind::RatifyPause(target) => {
+                env.invoke_contract::<()>(
+                    &target,
+                    &Symbol::new(&env, "ratify_pause"),
+                    soroban_sdk::vec![&env],
+                );
+                Self::log_admin_action(
+                    &env,
+                    &caller,
+                    "ratify_pause",
+                    AdminValue::None,
+                    AdminValue::Target(target.clone()),
+                );
+                env.events()
+                    .publish((symbol_short!("GovRatify"), target), ());
+            }
+            ProposalKind::Unpause(target) => {
+                env.invoke_contract::<()>(
+                    &target,
+                    &Symbol::new(&env, "unpause"),
+                    soroban_sdk::vec![&env],
+                );
+                Self::log_admin_action(
+                    &env,
+                    &caller,
+                    "unpause",
+                    AdminValue::None,
+                    AdminValue::Target(target.clone()),
+                );
+                env.events()
+                    .publish((symbol_short!("GovUnpaus"), target), ());
+            }
+            ProposalKind::TargetAdminTransfer(target, new_admin) => {
+                env.invoke_contract::<()>(
+                    &target,
+                    &Symbol::new(&env, "propose_admin"),
+                    soroban_sdk::vec![&env, new_admin.clone().into_val(&env)],
+                );
+                Self::log_admin_action(
+                    &env,
+                    &caller,
+                    "propose_target_admin",
+                    AdminValue::Target(target.clone()),
+                    AdminValue::TargetAdmin(target.clone(), new_admin.clone()),
+                );
+                env.events()
+                    .publish((symbol_short!("GovTAdmin"), target), new_admin);
+            }
+            ProposalKind::AdminTransfer(_) | ProposalKind::Upgrade(..) => {
+                return Err(GovernanceError::NotAGuardianAction)
+            }
+        }
+        Self::close_proposal(&env, &mut proposal, ProposalStatus::Executed, &caller);
+        Ok(())
     }
 
     // ── Approve ───────────────────────────────────────────────────────────────
 
-    /// Record an approval from a designated signer.
+    /// Record an approval from a designated signer of `proposal_id`.
     ///
-    /// Idempotent — calling twice from the same signer records one approval.
+    /// Idempotent — calling twice from the same signer records one approval,
+    /// stamped with the time of the first call. Approving withdraws any veto
+    /// the signer cast earlier.
     /// Soroban's require_auth guarantees the caller genuinely controls `signer`.
-    pub fn approve_transfer(env: Env, signer: Address) {
+    pub fn approve_transfer(
+        env: Env,
+        signer: Address,
+        proposal_id: u32,
+    ) -> Result<(), GovernanceError> {
         signer.require_auth();
 
-        let mut pending: PendingTransfer = env
-            .storage()
-            .instance()
-            .get(&KEY_PENDING)
-            .expect("no pending transfer (4004)");
+        let (mut proposal, _) = Self::read_transfer(&env, proposal_id)?;
 
-        if pending.status != ProposalStatus::Active {
-            panic!("proposal is not active (4019)");
+        if proposal.status != ProposalStatus::Active {
+            return Err(GovernanceError::ProposalNotActive);
         }
 
-        let is_valid = pending.signers.iter().any(|s| s == signer);
+        let is_valid = proposal.signers.iter().any(|s| s == signer);
         if !is_valid {
-            panic!("caller is not in the signer list (4009)");
+            return Err(GovernanceError::NotASigner);
         }
 
-        // Map::set is idempotent — duplicate calls do not increment the count
-        pending.approvals.set(signer.clone(), true);
+        if proposal.vetoes.contains_key(signer.clone()) {
+            proposal.vetoes.remove(signer.clone());
+            Self::write_proposal(&env, &proposal);
+        }
+        Self::record_approval(&env, &mut proposal, &signer);
+
+        Self::log_admin_action(
+            &env,
+            &signer,
+            "approve_transfer",
+            AdminValue::None,
+            AdminValue::U32(proposal_id),
+        );
+        env.events().publish(
+            (symbol_short!("GovAppr"), signer.clone()),
+            ProposalApprovedEvent {
+                proposal_id,
+                signer,
+                approvals_so_far: proposal.approvals.len(),
+                threshold: proposal.threshold,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
 
-        let approvals_so_far = pending.approvals.len();
-        let threshold = pending.threshold;
-        let proposal_id = pending.id;
+    /// Withdraw `signer`'s approval of `proposal_id` before it is finalized,
+    /// or for an upgrade, before it is scheduled on its target. A scheduled
+    /// upgrade can still be stopped with `veto_proposal`.
+    pub fn revoke_approval(
+        env: Env,
+        signer: Address,
+        proposal_id: u32,
+    ) -> Result<(), GovernanceError> {
+        signer.require_auth();
 
-        env.storage().instance().set(&KEY_PENDING, &pending);
+        let mut proposal = Self::read_proposal(&env, proposal_id)?;
+        if proposal.status != ProposalStatus::Active {
+            return Err(GovernanceError::ProposalNotActive);
+        }
+        if proposal.scheduled {
+            return Err(GovernanceError::UpgradeAlreadyScheduled);
+        }
+        if !proposal.approvals.contains_key(signer.clone()) {
+            return Err(GovernanceError::NotApproved);
+        }
 
+        proposal.approvals.remove(signer.clone());
+        Self::write_proposal(&env, &proposal);
+
+        Self::log_admin_action(
+            &env,
+            &signer,
+            "revoke_approval",
+            AdminValue::U32(proposal_id),
+            AdminValue::None,
+        );
         env.events().publish(
-            (symbol_short!("GovAppr"), signer.clone()),
-            ProposalApprovedEvent {
+            (symbol_short!("GovRevk"), signer.clone()),
+            ApprovalRevokedEvent {
                 proposal_id,
                 signer,
-                approvals_so_far,
-                threshold,
+                approvals_so_far: proposal.approvals.len(),
                 timestamp: env.ledger().timestamp(),
             },
         );
+        Ok(())
+    }
+
+    /// Veto `proposal_id` while its timelock is still running: until
+    /// `executable_after` for an admin transfer, target admin handover or
+    /// signer change, or until execution for an
+    /// upgrade, whose timelock is enforced by its target, or a guardian
+    /// action, which has none. The signer's
+    /// approval, if any, is withdrawn. Once `veto_threshold` signers have
+    /// vetoed, the proposal is closed as `Vetoed` and a scheduled upgrade is
+    /// withdrawn from its target. Idempotent.
+    pub fn veto_proposal(
+        env: Env,
+        signer: Address,
+        proposal_id: u32,
+    ) -> Result<(), GovernanceError> {
+        signer.require_auth();
+
+        let mut proposal = Self::read_proposal(&env, proposal_id)?;
+        if proposal.status != ProposalStatus::Active {
+            return Err(GovernanceError::ProposalNotActive);
+        }
+        if !proposal.signers.iter().any(|s| s == signer) {
+            return Err(GovernanceError::NotASigner);
+        }
+        let now = env.ledger().timestamp();
+        let in_timelock = match proposal.kind {
+            ProposalKind::AdminTransfer(_)
+            | ProposalKind::TargetAdminTransfer(..)
+            | ProposalKind::SetSigners(..) => now < proposal.executable_after,
+            _ => true,
+        };
+        if proposal.veto_threshold == 0 || !in_timelock {
+            return Err(GovernanceError::VetoWindowClosed);
+        }
+
+        proposal.approvals.remove(signer.clone());
+        if !proposal.vetoes.contains_key(signer.clone()) {
+            proposal.vetoes.set(signer.clone(), now);
+        }
+        let vetoes_so_far = proposal.vetoes.len();
+        let blocked = vetoes_so_far >= proposal.veto_threshold;
+        if blocked {
+            if let ProposalKind::Upgrade(target, _, _) = &proposal.kind {
+                if proposal.scheduled {
+                    Self::withdraw_upgrade(&env, target);
+                }
+            }
+            Self::close_proposal(&env, &mut proposal, ProposalStatus::Vetoed, &signer);
+            env.storage().instance().set(&KEY_LAST_CANCELLED_AT, &now);
+        } else {
+            Self::write_proposal(&env, &proposal);
+        }
+
+        Self::log_admin_action(
+            &env,
+            &signer,
+            "veto_proposal",
+            AdminValue::None,
+            AdminValue::U32(proposal_id),
+        );
+        env.events().publish(
+            (symbol_short!("GovVeto"), signer.clone()),
+            ProposalVetoedEvent {
+                proposal_id,
+                signer,
+                vetoes_so_far,
+                veto_threshold: proposal.veto_threshold,
+                blocked,
+                timestamp: now,
+            },
+        );
+        Ok(())
+    }
+
+    /// Set how many signers must veto to block a proposal; 0 turns vetoes
+    /// off. Applies to proposals made after the change. Admin only.
+    pub fn set_veto_threshold(env: Env, veto_threshold: u32) -> Result<(), GovernanceError> {
+        let admin = Self::read_admin(&env)?;
+        admin.require_auth();
+
+        if veto_threshold.saturating_mul(2) >= MAX_SIGNERS {
+            return Err(GovernanceError::InvalidVetoThreshold);
+        }
+        let old = Self::get_veto_threshold(env.clone());
+        env.storage()
+            .instance()
+            .set(&KEY_VETO_THRESHOLD, &veto_threshold);
+
+        Self::log_admin_action(
+            &env,
+            &admin,
+            "set_veto_threshold",
+            AdminValue::U32(old),
+            AdminValue::U32(veto_threshold),
+        );
+        env.events()
+            .publish((symbol_short!("GovVetoT"), admin), veto_threshold);
+        Ok(())
     }
 
     // ── Finalize ──────────────────────────────────────────────────────────────
 
-    /// Finalize the pending admin transfer.
+    /// Finalize admin transfer `proposal_id`.
     ///
     /// Callable by anyone once BOTH conditions hold:
     ///   1. now >= executable_after   (timelock elapsed)
     ///   2. approval_count >= threshold
     ///
+    /// Every other open admin transfer is closed as `Superseded`.
+    ///
     /// Calls set_admin on the target RemitLend contract via cross-contract
     /// invocation. The target must expose:
     ///   pub fn set_admin(env: Env, new_admin: Address)
     /// and must verify the caller is this governance contract address.
-    pub fn finalize_admin_transfer(env: Env, caller: Address) {
+    pub fn finalize_admin_transfer(
+        env: Env,
+        caller: Address,
+        proposal_id: u32,
+    ) -> Result<(), GovernanceError> {
         caller.require_auth();
 
-        let pending: PendingTransfer = env
-            .storage()
-            .instance()
-            .get(&KEY_PENDING)
-            .expect("no pending transfer (4004)");
+        let (mut proposal, new_admin) = Self::read_transfer(&env, proposal_id)?;
 
-        if pending.status != ProposalStatus::Active {
-            panic!("proposal is not active (4019)");
+        if proposal.status != ProposalStatus::Active {
+            return Err(GovernanceError::ProposalNotActive);
         }
 
         // Get target early to prevent archiving issues in tests
@@ -351,29 +1195,27 @@ impl GovernanceContract {
             .storage()
             .instance()
             .get(&KEY_TARGET)
-            .expect("target contract not set");
+            .ok_or(GovernanceError::TargetNotSet)?;
 
         let now = env.ledger().timestamp();
 
         // INV-1: timelock must have elapsed
-        if now < pending.executable_after {
-            panic!("timelock not elapsed — wait until executable_after (4010)");
+        if now < proposal.executable_after {
+            return Err(GovernanceError::TimelockNotElapsed);
         }
 
         // INV-3: proposal must not have expired
-        let expiry_time = pending.proposed_at.saturating_add(PROPOSAL_TTL_SECONDS);
+        let expiry_time = proposal.proposed_at.saturating_add(PROPOSAL_TTL_SECONDS);
         if now >= expiry_time {
-            panic!("proposal has expired (4016)");
+            return Err(GovernanceError::ProposalExpired);
         }
 
         // INV-2: threshold must be met
-        let approval_count = pending.approvals.len();
-        if approval_count < pending.threshold {
-            panic!("threshold not met — more approvals required (4011)");
+        let approval_count = proposal.approvals.len();
+        if approval_count < proposal.threshold {
+            return Err(GovernanceError::ThresholdNotMet);
         }
 
-        let new_admin = pending.proposed_admin.clone();
-
         // 1. Interactions: Cross-contract call to update global admin in the RemitLend protocol contract.
         // If this call fails (panics/traps), the entire transaction will rollback by default in Soroban.
         // We call this FIRST to ensure the remote state change is attempted before committing local changes.
@@ -383,90 +1225,122 @@ impl GovernanceContract {
             soroban_sdk::vec![&env, new_admin.clone().into_val(&env)],
         );
 
-        // 2. Effects: Clear pending transfer and update local admin state only after successful interaction.
-        env.storage().instance().remove(&KEY_PENDING);
+        // 2. Effects: Close the proposal and update local admin state only after successful interaction.
+        Self::log_admin_action(
+            &env,
+            &caller,
+            "finalize_admin_transfer",
+            AdminValue::Address(Self::read_admin(&env)?),
+            AdminValue::Address(new_admin.clone()),
+        );
+        Self::close_proposal(&env, &mut proposal, ProposalStatus::Executed, &caller);
         env.storage().instance().set(&KEY_ADMIN, &new_admin);
 
+        // Transfers proposed under the previous admin must not later
+        // overwrite the new one.
+        for id in Self::active_proposals(&env).iter() {
+            let mut other = Self::read_proposal(&env, id)?;
+            if let ProposalKind::AdminTransfer(_) = other.kind {
+                Self::close_proposal(&env, &mut other, ProposalStatus::Superseded, &caller);
+            }
+        }
+
         // Update the last cancelled/proposal timestamp to enforce reproposal cooldown
         env.storage().instance().set(&KEY_LAST_CANCELLED_AT, &now);
 
         env.events().publish(
             (symbol_short!("GovFin"), new_admin.clone()),
             AdminTransferFinalizedEvent {
+                proposal_id,
                 new_admin,
                 finalized_by: caller,
                 approval_count,
                 timestamp: now,
             },
         );
+        Ok(())
     }
 
     // ── Cancel ────────────────────────────────────────────────────────────────
 
-    /// Cancel a pending transfer. Only the current admin may do this.
+    /// Cancel admin transfer `proposal_id`. Only the current admin may do this.
     /// After cancellation the process must restart from propose_admin_transfer.
-    pub fn cancel_admin_transfer(env: Env) {
-        let admin = Self::read_admin(&env);
+    pub fn cancel_admin_transfer(env: Env, proposal_id: u32) -> Result<(), GovernanceError> {
+        let admin = Self::read_admin(&env)?;
         admin.require_auth();
 
-        let mut pending: PendingTransfer = env
-            .storage()
-            .instance()
-            .get(&KEY_PENDING)
-            .expect("no pending transfer to cancel (4004)");
+        let (mut proposal, _) = Self::read_transfer(&env, proposal_id)?;
 
-        if pending.status == ProposalStatus::Cancelled {
-            return;
+        if proposal.status == ProposalStatus::Cancelled {
+            return Ok(());
+        }
+        if proposal.status != ProposalStatus::Active {
+            return Err(GovernanceError::ProposalNotActive);
         }
 
-        pending.status = ProposalStatus::Cancelled;
-        env.storage().instance().set(&KEY_PENDING, &pending);
+        Self::close_proposal(&env, &mut proposal, ProposalStatus::Cancelled, &admin);
 
         env.storage()
             .instance()
             .set(&KEY_LAST_CANCELLED_AT, &env.ledger().timestamp());
 
+        Self::log_admin_action(
+            &env,
+            &admin,
+            "cancel_admin_transfer",
+            AdminValue::U32(proposal_id),
+            AdminValue::None,
+        );
         env.events().publish(
             (symbol_short!("GovCncl"), admin.clone()),
             AdminTransferCancelledEvent {
+                proposal_id,
                 cancelled_by: admin,
                 timestamp: env.ledger().timestamp(),
             },
         );
+        Ok(())
     }
 
-    /// Forcefully cancel any open proposal regardless of its approval count.
-    /// Requires admin auth. Prevents further actions on the proposal.
+    /// Forcefully cancel an open proposal regardless of its approval count,
+    /// recording `reason` on it. Requires admin auth. Prevents further
+    /// actions on the proposal. Upgrades already scheduled on their target
+    /// are withdrawn with `cancel_upgrade` instead.
     pub fn emergency_cancel_proposal(
         env: Env,
         proposal_id: u32,
         reason: Option<soroban_sdk::String>,
-    ) {
-        let admin = Self::read_admin(&env);
+    ) -> Result<(), GovernanceError> {
+        let admin = Self::read_admin(&env)?;
         admin.require_auth();
 
-        let mut pending: PendingTransfer = env
-            .storage()
-            .instance()
-            .get(&KEY_PENDING)
-            .expect("no pending transfer to cancel (4004)");
-
-        if pending.id != proposal_id {
-            panic!("proposal ID mismatch (4018)");
+        let mut proposal = Self::read_proposal(&env, proposal_id)?;
+        if proposal.scheduled {
+            return Err(GovernanceError::UpgradeAlreadyScheduled);
         }
 
-        if pending.status == ProposalStatus::Cancelled {
-            return;
+        if proposal.status == ProposalStatus::Cancelled {
+            return Ok(());
+        }
+        if proposal.status != ProposalStatus::Active {
+            return Err(GovernanceError::ProposalNotActive);
         }
 
-        pending.status = ProposalStatus::Cancelled;
-        env.storage().instance().set(&KEY_PENDING, &pending);
+        proposal.reason = reason.clone();
+        Self::close_proposal(&env, &mut proposal, ProposalStatus::Cancelled, &admin);
 
         // We also set the cooldown for security, same as regular cancel.
         env.storage()
             .instance()
             .set(&KEY_LAST_CANCELLED_AT, &env.ledger().timestamp());
 
+        Self::log_admin_action(
+            &env,
+            &admin,
+            "emergency_cancel_proposal",
+            AdminValue::U32(proposal_id),
+            AdminValue::None,
+        );
         env.events().publish(
             (symbol_short!("GovEmerg"), admin.clone()),
             ProposalCancelledEvent {
@@ -476,35 +1350,41 @@ impl GovernanceContract {
                 timestamp: env.ledger().timestamp(),
             },
         );
+        Ok(())
     }
 
     // ── Expire ─────────────────────────────────────────────────────────────────
 
-    /// Expire a pending transfer proposal that has exceeded its TTL.
+    /// Expire a proposal that has exceeded its TTL.
     ///
     /// Anyone can call this function once the proposal has passed its TTL.
-    /// This cleans up stale proposals and allows new ones to be created.
-    pub fn expire_proposal(env: Env, caller: Address) {
+    /// The proposal is kept as `Expired` and no longer counts as active.
+    /// Upgrades already scheduled on their target are left to
+    /// `execute_upgrade` or `cancel_upgrade`.
+    pub fn expire_proposal(
+        env: Env,
+        caller: Address,
+        proposal_id: u32,
+    ) -> Result<(), GovernanceError> {
         caller.require_auth();
 
-        let pending: PendingTransfer = env
-            .storage()
-            .instance()
-            .get(&KEY_PENDING)
-            .expect("no pending transfer to expire (4004)");
+        let mut proposal = Self::read_proposal(&env, proposal_id)?;
 
-        if pending.status != ProposalStatus::Active {
-            panic!("proposal is not active (4019)");
+        if proposal.status != ProposalStatus::Active {
+            return Err(GovernanceError::ProposalNotActive);
+        }
+        if proposal.scheduled {
+            return Err(GovernanceError::UpgradeAlreadyScheduled);
         }
 
         let now = env.ledger().timestamp();
-        let expiry_time = pending.proposed_at.saturating_add(PROPOSAL_TTL_SECONDS);
+        let expiry_time = proposal.proposed_at.saturating_add(PROPOSAL_TTL_SECONDS);
 
         if now < expiry_time {
-            panic!("proposal has not yet expired (4017)");
+            return Err(GovernanceError::ProposalNotExpired);
         }
 
-        env.storage().instance().remove(&KEY_PENDING);
+        Self::close_proposal(&env, &mut proposal, ProposalStatus::Expired, &caller);
 
         // Set cooldown timestamp to prevent immediate reproposal spam
         env.storage().instance().set(&KEY_LAST_CANCELLED_AT, &now);
@@ -512,70 +1392,95 @@ impl GovernanceContract {
         env.events().publish(
             (symbol_short!("GovExp"), caller.clone()),
             ProposalExpiredEvent {
+                proposal_id,
                 expired_by: caller,
-                proposal_timestamp: pending.proposed_at,
+                proposal_timestamp: proposal.proposed_at,
                 expiry_timestamp: now,
             },
         );
+        Ok(())
     }
 
     // ── Views ─────────────────────────────────────────────────────────────────
 
-    pub fn get_current_admin(env: Env) -> Address {
+    pub fn get_current_admin(env: Env) -> Result<Address, GovernanceError> {
         Self::read_admin(&env)
     }
 
-    pub fn get_admin(env: Env) -> Address {
+    pub fn get_admin(env: Env) -> Result<Address, GovernanceError> {
         Self::read_admin(&env)
     }
 
-    pub fn get_target(env: Env) -> Address {
+    pub fn get_target(env: Env) -> Result<Address, GovernanceError> {
         env.storage()
             .instance()
             .get(&KEY_TARGET)
-            .expect("target contract not set")
+            .ok_or(GovernanceError::TargetNotSet)
     }
 
-    pub fn get_pending_transfer(env: Env) -> PendingTransfer {
-        env.storage()
+    pub fn get_proposal(env: Env, proposal_id: u32) -> Option<Proposal> {
+        env.storage().persistent().get(&(KEY_PROPOSAL, proposal_id))
+    }
+
+    /// Up to `limit` proposals with id >= `cursor`, oldest first, optionally
+    /// only those in `status`. `limit` is capped at 50; resume from the last
+    /// returned id + 1.
+    pub fn list_proposals(
+        env: Env,
+        status: Option<ProposalStatus>,
+        cursor: u32,
+        limit: u32,
+    ) -> Vec<Proposal> {
+        let count: u32 = env
+            .storage()
             .instance()
-            .get(&KEY_PENDING)
-            .expect("no pending transfer (4004)")
+            .get(&KEY_PROPOSAL_COUNT)
+            .unwrap_or(0);
+        let limit = limit.min(MAX_PROPOSAL_LIST_LIMIT);
+        let mut proposals = Vec::new(&env);
+        let mut id = cursor.max(1);
+        while id <= count && proposals.len() < limit {
+            if let Some(proposal) = Self::get_proposal(env.clone(), id) {
+                if status.as_ref().is_none_or(|s| *s == proposal.status) {
+                    proposals.push_back(proposal);
+                }
+            }
+            id += 1;
+        }
+        proposals
     }
 
-    pub fn get_pending(env: Env) -> Option<PendingTransfer> {
-        env.storage().instance().get(&KEY_PENDING)
+    /// Ids of the proposals that are still open.
+    pub fn get_active_proposals(env: Env) -> Vec<u32> {
+        Self::active_proposals(&env)
     }
 
     pub fn has_pending_transfer(env: Env) -> bool {
-        if let Some(pending) = env
-            .storage()
-            .instance()
-            .get::<Symbol, PendingTransfer>(&KEY_PENDING)
-        {
-            pending.status == ProposalStatus::Active
-        } else {
-            false
-        }
+        Self::active_proposals(&env).iter().any(|id| {
+            Self::get_proposal(env.clone(), id)
+                .is_some_and(|p| matches!(p.kind, ProposalKind::AdminTransfer(_)))
+        })
     }
 
-    pub fn get_approval_count(env: Env) -> u32 {
-        let pending: PendingTransfer = env
-            .storage()
-            .instance()
-            .get(&KEY_PENDING)
-            .expect("no pending transfer (4004)");
-        pending.approvals.len()
+    pub fn get_approval_count(env: Env, proposal_id: u32) -> Result<u32, GovernanceError> {
+        Ok(Self::read_proposal(&env, proposal_id)?.approvals.len())
     }
 
-    /// Returns seconds remaining until the timelock expires.
-    /// Returns 0 if already elapsed or no pending transfer exists.
-    pub fn get_timelock_remaining(env: Env) -> u64 {
-        match env
-            .storage()
+    pub fn get_veto_count(env: Env, proposal_id: u32) -> Result<u32, GovernanceError> {
+        Ok(Self::read_proposal(&env, proposal_id)?.vetoes.len())
+    }
+
+    pub fn get_veto_threshold(env: Env) -> u32 {
+        env.storage()
             .instance()
-            .get::<Symbol, PendingTransfer>(&KEY_PENDING)
-        {
+            .get(&KEY_VETO_THRESHOLD)
+            .unwrap_or(0)
+    }
+
+    /// Returns seconds remaining until the timelock of `proposal_id` expires.
+    /// Returns 0 if already elapsed or no such proposal exists.
+    pub fn get_timelock_remaining(env: Env, proposal_id: u32) -> u64 {
+        match Self::get_proposal(env.clone(), proposal_id) {
             None => 0,
             Some(p) => {
                 let now = env.ledger().timestamp();
@@ -588,12 +1493,400 @@ impl GovernanceContract {
         }
     }
 
+    /// Up to `limit` admin audit log entries starting at index `cursor`,
+    /// oldest first. `limit` is capped at 100.
+    pub fn get_admin_actions(env: Env, cursor: u32, limit: u32) -> Vec<AdminAction> {
+        let len = Self::admin_log_len(&env);
+        let mut entries = Vec::new(&env);
+        if cursor >= len {
+            return entries;
+        }
+        let end = cursor
+            .saturating_add(limit.min(MAX_ADMIN_LOG_LIMIT))
+            .min(len);
+
+        let mut page_index = cursor / ADMIN_LOG_PAGE_SIZE;
+        let mut page = Self::read_admin_log_page(&env, page_index);
+        for idx in cursor..end {
+            if idx / ADMIN_LOG_PAGE_SIZE != page_index {
+                page_index = idx / ADMIN_LOG_PAGE_SIZE;
+                page = Self::read_admin_log_page(&env, page_index);
+            }
+            entries.push_back(
+                page.get(idx % ADMIN_LOG_PAGE_SIZE)
+                    .expect("admin log page truncated"),
+            );
+        }
+        entries
+    }
+
+    pub fn get_admin_action_count(env: Env) -> u32 {
+        Self::admin_log_len(&env)
+    }
+
     // ── Private helpers ───────────────────────────────────────────────────────
 
-    fn read_admin(env: &Env) -> Address {
+    fn admin_log_len(env: &Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&KEY_ADMIN_LOG_LEN)
+            .unwrap_or(0)
+    }
+
+    fn read_admin_log_page(env: &Env, page: u32) -> Vec<AdminAction> {
+        env.storage()
+            .persistent()
+            .get(&(KEY_ADMIN_LOG_PAGE, page))
+            .unwrap_or_else(|| Vec::new(env))
+    }
+
+    /// Append a privileged call to the admin audit log. Entries are never
+    /// rewritten or removed.
+    fn log_admin_action(
+        env: &Env,
+        actor: &Address,
+        action: &str,
+        old: AdminValue,
+        new: AdminValue,
+    ) {
+        let index = Self::admin_log_len(env);
+        let page_index = index / ADMIN_LOG_PAGE_SIZE;
+        let mut page = Self::read_admin_log_page(env, page_index);
+        let entry = AdminAction {
+            ledger: env.ledger().sequence(),
+            actor: actor.clone(),
+            action: Symbol::new(env, action),
+            old,
+            new,
+        };
+        page.push_back(entry.clone());
+        env.storage()
+            .persistent()
+            .set(&(KEY_ADMIN_LOG_PAGE, page_index), &page);
+        env.storage()
+            .instance()
+            .set(&KEY_ADMIN_LOG_LEN, &(index + 1));
+        env.events().publish(
+            (symbol_short!("AdminLog"), entry.action.clone()),
+            (index, entry),
+        );
+    }
+
+    fn new_proposal(
+        env: &Env,
+        id: u32,
+        kind: ProposalKind,
+        proposer: &Address,
+        signers: Vec<Address>,
+        threshold: u32,
+        executable_after: u64,
+    ) -> Proposal {
+        Proposal {
+            id,
+            kind,
+            proposer: proposer.clone(),
+            signers,
+            threshold,
+            executable_after,
+            scheduled: false,
+            approvals: Map::new(env),
+            veto_threshold: 0,
+            vetoes: Map::new(env),
+            proposed_at: env.ledger().timestamp(),
+            status: ProposalStatus::Active,
+            closed_at: 0,
+            closed_by: None,
+            reason: None,
+        }
+    }
+
+    fn next_proposal_id(env: &Env) -> u32 {
+        let proposal_id = env
+            .storage()
+            .instance()
+            .get::<Symbol, u32>(&KEY_PROPOSAL_COUNT)
+            .unwrap_or(0)
+            + 1;
+        env.storage()
+            .instance()
+            .set(&KEY_PROPOSAL_COUNT, &proposal_id);
+        proposal_id
+    }
+
+    fn read_proposal(env: &Env, proposal_id: u32) -> Result<Proposal, GovernanceError> {
+        env.storage()
+            .persistent()
+            .get(&(KEY_PROPOSAL, proposal_id))
+            .ok_or(GovernanceError::ProposalNotFound)
+    }
+
+    /// Reads an admin transfer proposal along with its proposed admin.
+    fn read_transfer(env: &Env, proposal_id: u32) -> Result<(Proposal, Address), GovernanceError> {
+        let proposal = Self::read_proposal(env, proposal_id)?;
+        match proposal.kind.clone() {
+            ProposalKind::AdminTransfer(proposed_admin) => Ok((proposal, proposed_admin)),
+            _ => Err(GovernanceError::NotAnAdminTransfer),
+        }
+    }
+
+    /// Reads an upgrade proposal along with its target, hash and eta.
+    fn read_upgrade(
+        env: &Env,
+        proposal_id: u32,
+    ) -> Result<(Proposal, Address, BytesN<32>, u32), GovernanceError> {
+        let proposal = Self::read_proposal(env, proposal_id)?;
+        match proposal.kind.clone() {
+            ProposalKind::Upgrade(target, wasm_hash, eta) => Ok((proposal, target, wasm_hash, eta)),
+            _ => Err(GovernanceError::NotAnUpgrade),
+        }
+    }
+
+    /// Reads a guardian change, pause ratification, unpause, target admin
+    /// handover or signer change proposal.
+    fn read_guardian_action(env: &Env, proposal_id: u32) -> Result<Proposal, GovernanceError> {
+        let proposal = Self::read_proposal(env, proposal_id)?;
+        match proposal.kind {
+            ProposalKind::SetGuardian(..)
+            | ProposalKind::ClearGuardian(_)
+            | ProposalKind::RatifyPause(_)
+            | ProposalKind::Unpause(_)
+            | ProposalKind::TargetAdminTransfer(..)
+            | ProposalKind::SetSigners(..) => Ok(proposal),
+            ProposalKind::AdminTransfer(_) | ProposalKind::Upgrade(..) => {
+                Err(GovernanceError::NotAGuardianAction)
+            }
+        }
+    }
+
+    /// Opens a proposal run by `execute_proposal` once approved and
+    /// `executable_after` has passed.
+    fn propose_action(
+        env: &Env,
+        kind: ProposalKind,
+        signers: Vec<Address>,
+        threshold: u32,
+        executable_after: u64,
+    ) -> Result<u32, GovernanceError> {
+        let admin = Self::read_admin(env)?;
+        admin.require_auth();
+
+        let (action, new) = match kind.clone() {
+            ProposalKind::SetGuardian(target, guardian) => {
+                ("propose_guardian", AdminValue::Guardian(target, guardian))
+            }
+            ProposalKind::ClearGuardian(target) => ("propose_guardian", AdminValue::Target(target)),
+            ProposalKind::RatifyPause(target) => {
+                ("propose_pause_ratification", AdminValue::Target(target))
+            }
+            ProposalKind::Unpause(target) => ("propose_unpause", AdminValue::Target(target)),
+            ProposalKind::TargetAdminTransfer(target, new_admin) => (
+                "propose_target_admin_transfer",
+                AdminValue::TargetAdmin(target, new_admin),
+            ),
+            ProposalKind::SetSigners(_, min_threshold) => {
+                ("propose_signer_change", AdminValue::U32(min_threshold))
+            }
+            ProposalKind::AdminTransfer(_) | ProposalKind::Upgrade(..) => {
+                return Err(GovernanceError::NotAGuardianAction)
+            }
+        };
+        let mut active = Self::active_proposals(env);
+        if active.len() >= MAX_ACTIVE_PROPOSALS {
+            return Err(GovernanceError::TooManyActiveProposals);
+        }
+        let unique_signers = Self::validate_quorum(env, &signers, threshold)?;
+        let veto_threshold = Self::checked_veto_threshold(env, &unique_signers)?;
+
+        let proposal_id = Self::next_proposal_id(env);
+        let mut proposal = Self::new_proposal(
+            env,
+            proposal_id,
+            kind.clone(),
+            &admin,
+            unique_signers,
+            threshold,
+            executable_after,
+        );
+        proposal.veto_threshold = veto_threshold;
+        Self::write_proposal(env, &proposal);
+        active.push_back(proposal_id);
+        env.storage().instance().set(&KEY_ACTIVE, &active);
+
+        Self::log_admin_action(env, &admin, action, AdminValue::None, new);
+        env.events()
+            .publish((symbol_short!("GovActP"), admin), (proposal_id, kind));
+        Ok(proposal_id)
+    }
+
+    fn apply_guardian(env: &Env, caller: &Address, target: Address, guardian: Option<Address>) {
+        env.invoke_contract::<()>(
+            &target,
+            &Symbol::new(env, "set_guardian"),
+            soroban_sdk::vec![env, guardian.clone().into_val(env)],
+        );
+        Self::log_admin_action(
+            env,
+            caller,
+            "set_guardian",
+            AdminValue::None,
+            guardian
+                .clone()
+                .map_or(AdminValue::Target(target.clone()), |guardian| {
+                    AdminValue::Guardian(target.clone(), guardian)
+                }),
+        );
+        env.events()
+            .publish((symbol_short!("GovGuard"), target), guardian);
+    }
+
+    /// Withdraws a scheduled upgrade from `target`, or from this contract.
+    fn withdraw_upgrade(env: &Env, target: &Address) {
+        if *target == env.current_contract_address() {
+            env.storage().instance().remove(&KEY_SELF_UPGRADE);
+        } else {
+            env.invoke_contract::<()>(
+                target,
+                &Symbol::new(env, "cancel_upgrade"),
+                soroban_sdk::vec![env],
+            );
+        }
+    }
+
+    fn write_signers(env: &Env, actor: &Address, signers: Vec<Address>, min_threshold: u32) {
+        let old_threshold = Self::get_min_threshold(env.clone());
+        env.storage().instance().set(&KEY_SIGNERS, &signers);
+        env.storage()
+            .instance()
+            .set(&KEY_MIN_THRESHOLD, &min_threshold);
+        Self::log_admin_action(
+            env,
+            actor,
+            "set_signers",
+            AdminValue::U32(old_threshold),
+            AdminValue::U32(min_threshold),
+        );
+        env.events()
+            .publish((symbol_short!("GovSign"),), (signers, min_threshold));
+    }
+
+    fn write_proposal(env: &Env, proposal: &Proposal) {
+        let key = (KEY_PROPOSAL, proposal.id);
+        env.storage().persistent().set(&key, proposal);
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL_BUMP);
+    }
+
+    /// Stamps `signer`'s approval with the current time unless already present.
+    fn record_approval(env: &Env, proposal: &mut Proposal, signer: &Address) {
+        if !proposal.approvals.contains_key(signer.clone()) {
+            proposal
+                .approvals
+                .set(signer.clone(), env.ledger().timestamp());
+            Self::write_proposal(env, proposal);
+        }
+    }
+
+    /// Moves a proposal out of `Active`, keeping its record for history.
+    fn close_proposal(
+        env: &Env,
+        proposal: &mut Proposal,
+        status: ProposalStatus,
+        closed_by: &Address,
+    ) {
+        proposal.status = status;
+        proposal.closed_at = env.ledger().timestamp();
+        proposal.closed_by = Some(closed_by.clone());
+        Self::write_proposal(env, proposal);
+
+        let mut active = Self::active_proposals(env);
+        if let Some(index) = active.first_index_of(proposal.id) {
+            active.remove(index);
+            env.storage().instance().set(&KEY_ACTIVE, &active);
+        }
+    }
+
+    fn active_proposals(env: &Env) -> Vec<u32> {
+        env.storage()
+            .instance()
+            .get(&KEY_ACTIVE)
+            .unwrap_or_else(|| Vec::new(env))
+    }
+
+    /// The current veto threshold, which must stay below half of `signers`
+    /// so a veto minority can never also be the approving quorum.
+    fn checked_veto_threshold(env: &Env, signers: &Vec<Address>) -> Result<u32, GovernanceError> {
+        let veto_threshold = Self::get_veto_threshold(env.clone());
+        if veto_threshold > 0 && veto_threshold.saturating_mul(2) >= signers.len() {
+            return Err(GovernanceError::InvalidVetoThreshold);
+        }
+        Ok(veto_threshold)
+    }
+
+    /// Checks a proposal's signers and threshold against the governance
+    /// signer set and its minimum threshold, returning the signers in order.
+    fn validate_quorum(
+        env: &Env,
+        signers: &Vec<Address>,
+        threshold: u32,
+    ) -> Result<Vec<Address>, GovernanceError> {
+        let unique_signers = Self::unique_signers(env, signers, threshold)?;
+        let governance_signers: Vec<Address> = env
+            .storage()
+            .instance()
+            .get(&KEY_SIGNERS)
+            .ok_or(GovernanceError::SignersNotSet)?;
+        if unique_signers
+            .iter()
+            .any(|s| !governance_signers.contains(&s))
+        {
+            return Err(GovernanceError::NotASigner);
+        }
+        if threshold < Self::get_min_threshold(env.clone()) {
+            return Err(GovernanceError::ThresholdTooLow);
+        }
+        Ok(unique_signers)
+    }
+
+    /// Checks a signer list and threshold, returning the signers in order.
+    fn unique_signers(
+        env: &Env,
+        signers: &Vec<Address>,
+        threshold: u32,
+    ) -> Result<Vec<Address>, GovernanceError> {
+        if signers.is_empty() {
+            return Err(GovernanceError::EmptySigners);
+        }
+        if signers.len() > MAX_SIGNERS {
+            return Err(GovernanceError::TooManySigners);
+        }
+        // Ensure signer list contains unique addresses. Duplicates would allow
+        // the same key to be listed multiple times and potentially bypass
+        // the multi-signer threshold semantics. We build an explicit
+        // deduplicated `unique_signers` Vec that preserves order but rejects
+        // input lists containing duplicates.
+        let mut unique_signers: Vec<Address> = Vec::new(env);
+        for s in signers.iter() {
+            if unique_signers.iter().any(|x| x == s) {
+                // Explicitly reject proposals containing duplicate signer
+                // entries to avoid any ambiguity in quorum semantics.
+                return Err(GovernanceError::DuplicateSigner);
+            }
+            unique_signers.push_back(s.clone());
+        }
+        if threshold < 1 {
+            return Err(GovernanceError::ThresholdTooLow);
+        }
+        if threshold > signers.len() {
+            return Err(GovernanceError::ThresholdExceedsSigners);
+        }
+        Ok(unique_signers)
+    }
+
+    fn read_admin(env: &Env) -> Result<Address, GovernanceError> {
         env.storage()
             .instance()
             .get(&KEY_ADMIN)
-            .expect("contract not initialized (4002)")
+            .ok_or(GovernanceError::NotInitialized)
     }
 }
@@ -12,22 +12,82 @@ impl MockTarget {
             .instance()
             .set(&symbol_short!("admin"), &new_admin);
     }
-    pub fn has_pending_transfer(env: Env) -> bool {
-        if let Some(pending) = env
+    pub fn get_admin(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&symbol_short!("admin"))
+            .unwrap()
+    }
+    pub fn schedule_upgrade(env: Env, wasm_hash: BytesN<32>, eta: u32) {
+        env.storage()
+            .instance()
+            .set(&symbol_short!("upgrade"), &(wasm_hash, eta));
+    }
+    pub fn cancel_upgrade(env: Env) {
+        env.storage().instance().remove(&symbol_short!("upgrade"));
+    }
+    pub fn execute_upgrade(env: Env) {
+        let scheduled: (BytesN<32>, u32) = env
             .storage()
             .instance()
-            .get::<Symbol, PendingTransfer>(&KEY_PENDING)
-        {
-            pending.status == ProposalStatus::Active
-        } else {
-            false
+            .get(&symbol_short!("upgrade"))
+            .unwrap();
+        env.storage()
+            .instance()
+            .set(&symbol_short!("upgraded"), &scheduled.0);
+    }
+    pub fn get_scheduled_upgrade(env: Env) -> Option<(BytesN<32>, u32)> {
+        env.storage().instance().get(&symbol_short!("upgrade"))
+    }
+    pub fn get_upgraded(env: Env) -> Option<BytesN<32>> {
+        env.storage().instance().get(&symbol_short!("upgraded"))
+    }
+    pub fn set_guardian(env: Env, guardian: Option<Address>) {
+        match guardian {
+            Some(guardian) => env
+                .storage()
+                .instance()
+                .set(&symbol_short!("guardian"), &guardian),
+            None => env.storage().instance().remove(&symbol_short!("guardian")),
         }
     }
-    pub fn get_admin(env: Env) -> Address {
+    pub fn get_guardian(env: Env) -> Option<Address> {
+        env.storage().instance().get(&symbol_short!("guardian"))
+    }
+    pub fn ratify_pause(env: Env) {
         env.storage()
             .instance()
-            .get(&symbol_short!("admin"))
-            .unwrap()
+            .set(&symbol_short!("ratified"), &true);
+    }
+    pub fn is_ratified(env: Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&symbol_short!("ratified"))
+            .unwrap_or(false)
+    }
+    pub fn pause(env: Env) {
+        env.storage()
+            .instance()
+            .set(&symbol_short!("paused"), &true);
+    }
+    pub fn unpause(env: Env) {
+        env.storage()
+            .instance()
+            .set(&symbol_short!("paused"), &false);
+    }
+    pub fn is_paused(env: Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&symbol_short!("paused"))
+            .unwrap_or(false)
+    }
+    pub fn propose_admin(env: Env, new_admin: Address) {
+        env.storage()
+            .instance()
+            .set(&symbol_short!("proposed"), &new_admin);
+    }
+    pub fn get_proposed_admin(env: Env) -> Option<Address> {
+        env.storage().instance().get(&symbol_short!("proposed"))
     }
 }
 
@@ -70,10 +130,106 @@ fn version_is_initialized() {
 
 #[test]
 #[should_panic]
-fn upgrade_requires_admin_auth() {
+fn migrate_requires_admin_auth() {
     let (env, client, _admin, _) = setup();
     env.mock_auths(&[]);
-    client.upgrade(&create_upgrade_hash(&env));
+    client.migrate();
+}
+
+#[test]
+fn governance_upgrades_itself_through_a_timelocked_proposal() {
+    let (env, client, admin, _) = setup();
+    let s1 = Address::generate(&env);
+    let signers = soroban_sdk::vec![&env, s1.clone()];
+    client.set_signers(&signers, &1);
+    let this = client.address.clone();
+    set_ts(&env, 1000);
+
+    let id = client.propose_upgrade(&this, &create_upgrade_hash(&env), &1_000, &signers, &1);
+    client.approve_upgrade(&s1, &id);
+    // The eta must leave at least a day of notice.
+    assert_eq!(
+        client.try_schedule_upgrade(&admin, &id),
+        Err(Ok(GovernanceError::DelayTooShort))
+    );
+
+    let id = client.propose_upgrade(&this, &create_upgrade_hash(&env), &20_000, &signers, &1);
+    client.approve_upgrade(&s1, &id);
+    client.schedule_upgrade(&admin, &id);
+    assert_eq!(
+        client.try_execute_upgrade(&admin, &id),
+        Err(Ok(GovernanceError::TimelockNotElapsed))
+    );
+
+    client.cancel_upgrade(&id);
+    assert_eq!(
+        client.get_proposal(&id).unwrap().status,
+        ProposalStatus::Cancelled
+    );
+    assert_eq!(client.version(), 1);
+}
+
+#[test]
+fn proposals_draw_signers_from_the_governance_set() {
+    let (env, client, _admin, target) = setup();
+    let (s1, s2, s3) = (
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+    );
+    let outsider = Address::generate(&env);
+    let proposed = Address::generate(&env);
+    set_ts(&env, 1000);
+
+    let signers = soroban_sdk::vec![&env, s1.clone(), s2.clone()];
+    assert_eq!(
+        client.try_propose_admin_transfer(&proposed, &signers, &1, &MIN_TIMELOCK_SECONDS),
+        Err(Ok(GovernanceError::SignersNotSet))
+    );
+
+    client.set_signers(
+        &soroban_sdk::vec![&env, s1.clone(), s2.clone(), s3.clone()],
+        &2,
+    );
+    assert_eq!(
+        client.try_set_signers(&signers, &1),
+        Err(Ok(GovernanceError::SignersAlreadySet))
+    );
+    // The admin can pick neither outsiders nor a threshold below the floor.
+    let packed = soroban_sdk::vec![&env, outsider.clone()];
+    assert_eq!(
+        client.try_propose_admin_transfer(&proposed, &packed, &1, &MIN_TIMELOCK_SECONDS),
+        Err(Ok(GovernanceError::NotASigner))
+    );
+    assert_eq!(
+        client.try_propose_guardian(&target, &Some(outsider.clone()), &signers, &1),
+        Err(Ok(GovernanceError::ThresholdTooLow))
+    );
+
+    // Changing the set takes the current quorum and the timelock.
+    let id = client.propose_signer_change(
+        &soroban_sdk::vec![&env, s1.clone(), outsider.clone()],
+        &2,
+        &signers,
+        &2,
+    );
+    client.approve_proposal(&s1, &id);
+    client.approve_proposal(&s2, &id);
+    assert_eq!(
+        client.try_execute_proposal(&s1, &id),
+        Err(Ok(GovernanceError::TimelockNotElapsed))
+    );
+    set_ts(&env, 1000 + MIN_TIMELOCK_SECONDS);
+    client.execute_proposal(&s1, &id);
+    assert_eq!(
+        client.get_signers(),
+        soroban_sdk::vec![&env, s1.clone(), outsider.clone()]
+    );
+    assert_eq!(client.get_min_threshold(), 2);
+    assert_eq!(
+        client.try_propose_admin_transfer(&proposed, &signers, &2, &MIN_TIMELOCK_SECONDS),
+        Err(Ok(GovernanceError::NotASigner))
+    );
 }
 
 #[test]
@@ -82,15 +238,16 @@ fn finalize_succeeds_and_updates_local_and_remote_state() {
     let proposed = Address::generate(&env);
     let s = Address::generate(&env);
     let signers = Vec::from_slice(&env, core::slice::from_ref(&s));
+    client.set_signers(&signers, &1);
 
     set_ts(&env, 1000);
-    client.propose_admin_transfer(&proposed, &signers, &1, &MIN_TIMELOCK_SECONDS);
-    client.approve_transfer(&s);
+    let id = client.propose_admin_transfer(&proposed, &signers, &1, &MIN_TIMELOCK_SECONDS);
+    client.approve_transfer(&s, &id);
 
     set_ts(&env, 1000 + MIN_TIMELOCK_SECONDS + 1);
 
     // Call finalize
-    client.finalize_admin_transfer(&admin);
+    client.finalize_admin_transfer(&admin, &id);
 
     // 1. Local state updated
     assert_eq!(client.get_current_admin(), proposed);
@@ -113,25 +270,28 @@ fn exposes_target_pending_admin_and_approval_queries() {
     let proposed = Address::generate(&env);
     let signer = Address::generate(&env);
     let signers = Vec::from_slice(&env, core::slice::from_ref(&signer));
+    client.set_signers(&signers, &1);
 
     assert_eq!(client.get_admin(), admin);
     assert_eq!(client.get_target(), target);
-    assert!(client.get_pending().is_none());
+    assert!(client.get_proposal(&1).is_none());
 
     set_ts(&env, 1000);
-    client.propose_admin_transfer(&proposed, &signers, &1, &MIN_TIMELOCK_SECONDS);
-    client.approve_transfer(&signer);
+    let id = client.propose_admin_transfer(&proposed, &signers, &1, &MIN_TIMELOCK_SECONDS);
+    client.approve_transfer(&signer, &id);
 
-    let pending = client.get_pending().expect("pending transfer");
-    assert_eq!(pending.proposed_admin, proposed);
-    assert_eq!(client.get_approval_count(), 1);
+    let pending = client.get_proposal(&id).expect("pending transfer");
+    assert_eq!(pending.kind, ProposalKind::AdminTransfer(proposed));
+    assert_eq!(client.get_approval_count(&id), 1);
 }
 
 #[test]
-#[should_panic(expected = "already initialized")]
-fn double_initialize_panics() {
+fn double_initialize_returns_already_initialized() {
     let (env, client, _, _) = setup();
-    client.initialize(&Address::generate(&env), &Address::generate(&env));
+    assert_eq!(
+        client.try_initialize(&Address::generate(&env), &Address::generate(&env)),
+        Err(Ok(GovernanceError::AlreadyInitialized))
+    );
 }
 
 #[test]
@@ -141,67 +301,109 @@ fn propose_creates_pending_transfer() {
     let s1 = Address::generate(&env);
     let s2 = Address::generate(&env);
     let signers = Vec::from_slice(&env, &[s1, s2]);
+    client.set_signers(&signers, &1);
     set_ts(&env, 1000);
-    client.propose_admin_transfer(&proposed, &signers, &2, &MIN_TIMELOCK_SECONDS);
+    let id = client.propose_admin_transfer(&proposed, &signers, &2, &MIN_TIMELOCK_SECONDS);
     assert!(client.has_pending_transfer());
-    let p = client.get_pending_transfer();
+    let p = client.get_proposal(&id).unwrap();
     assert_eq!(p.threshold, 2);
     assert_eq!(p.executable_after, 1000 + MIN_TIMELOCK_SECONDS);
 }
 
 #[test]
-#[should_panic(expected = "delay must be >= 86400")]
 fn propose_rejects_short_delay() {
     let (env, client, _, _) = setup();
     let signers = Vec::from_slice(&env, &[Address::generate(&env)]);
-    client.propose_admin_transfer(&Address::generate(&env), &signers, &1, &3600);
+    client.set_signers(&signers, &1);
+    assert_eq!(
+        client.try_propose_admin_transfer(&Address::generate(&env), &signers, &1, &3600),
+        Err(Ok(GovernanceError::DelayTooShort))
+    );
 }
 
 #[test]
-#[should_panic(expected = "threshold exceeds signer count")]
 fn propose_rejects_threshold_exceeding_signers() {
     let (env, client, _, _) = setup();
     let signers = Vec::from_slice(&env, &[Address::generate(&env)]);
-    client.propose_admin_transfer(
-        &Address::generate(&env),
-        &signers,
-        &2,
-        &MIN_TIMELOCK_SECONDS,
+    client.set_signers(&signers, &1);
+    assert_eq!(
+        client.try_propose_admin_transfer(
+            &Address::generate(&env),
+            &signers,
+            &2,
+            &MIN_TIMELOCK_SECONDS,
+        ),
+        Err(Ok(GovernanceError::ThresholdExceedsSigners))
+    );
+}
+
+#[test]
+fn propose_allows_concurrent_proposals() {
+    let (env, client, admin, _) = setup();
+    let s = Address::generate(&env);
+    let signers = Vec::from_slice(&env, core::slice::from_ref(&s));
+    client.set_signers(&signers, &1);
+    let first_admin = Address::generate(&env);
+    let second_admin = Address::generate(&env);
+    set_ts(&env, 1000);
+    let first = client.propose_admin_transfer(&first_admin, &signers, &1, &MIN_TIMELOCK_SECONDS);
+    let second = client.propose_admin_transfer(&second_admin, &signers, &1, &MIN_TIMELOCK_SECONDS);
+    assert_ne!(first, second);
+    assert_eq!(
+        client.get_active_proposals(),
+        Vec::from_slice(&env, &[first, second])
     );
+
+    // Approving one does not touch the other.
+    client.approve_transfer(&s, &second);
+    assert_eq!(client.get_approval_count(&first), 0);
+
+    set_ts(&env, 1000 + MIN_TIMELOCK_SECONDS + 1);
+    client.finalize_admin_transfer(&admin, &second);
+    assert_eq!(client.get_current_admin(), second_admin);
+    assert!(client.get_active_proposals().is_empty());
+    assert!(!client.has_pending_transfer());
 }
 
 #[test]
-#[should_panic(expected = "transfer already pending")]
-fn propose_rejects_duplicate() {
+fn propose_caps_active_proposals() {
     let (env, client, _, _) = setup();
     let signers = Vec::from_slice(&env, &[Address::generate(&env)]);
-    client.propose_admin_transfer(
-        &Address::generate(&env),
-        &signers,
-        &1,
-        &MIN_TIMELOCK_SECONDS,
-    );
-    client.propose_admin_transfer(
-        &Address::generate(&env),
-        &signers,
-        &1,
-        &MIN_TIMELOCK_SECONDS,
+    client.set_signers(&signers, &1);
+    for _ in 0..MAX_ACTIVE_PROPOSALS {
+        client.propose_admin_transfer(
+            &Address::generate(&env),
+            &signers,
+            &1,
+            &MIN_TIMELOCK_SECONDS,
+        );
+    }
+    assert_eq!(
+        client.try_propose_admin_transfer(
+            &Address::generate(&env),
+            &signers,
+            &1,
+            &MIN_TIMELOCK_SECONDS,
+        ),
+        Err(Ok(GovernanceError::TooManyActiveProposals))
     );
 }
 
 #[test]
-#[should_panic(expected = "duplicate signer in signer list")]
 fn propose_rejects_duplicate_signer_address() {
     let (env, client, _, _) = setup();
     let s = Address::generate(&env);
     // Duplicate the same address in the signer list
     let signers = Vec::from_slice(&env, &[s.clone(), s.clone()]);
     set_ts(&env, 1000);
-    client.propose_admin_transfer(
-        &Address::generate(&env),
-        &signers,
-        &2,
-        &MIN_TIMELOCK_SECONDS,
+    assert_eq!(
+        client.try_propose_admin_transfer(
+            &Address::generate(&env),
+            &signers,
+            &2,
+            &MIN_TIMELOCK_SECONDS,
+        ),
+        Err(Ok(GovernanceError::DuplicateSigner))
     );
 }
 
@@ -211,17 +413,18 @@ fn approve_increments_count() {
     let s1 = Address::generate(&env);
     let s2 = Address::generate(&env);
     let signers = Vec::from_slice(&env, &[s1.clone(), s2.clone()]);
-    client.propose_admin_transfer(
+    client.set_signers(&signers, &1);
+    let id = client.propose_admin_transfer(
         &Address::generate(&env),
         &signers,
         &2,
         &MIN_TIMELOCK_SECONDS,
     );
-    assert_eq!(client.get_approval_count(), 0);
-    client.approve_transfer(&s1);
-    assert_eq!(client.get_approval_count(), 1);
-    client.approve_transfer(&s2);
-    assert_eq!(client.get_approval_count(), 2);
+    assert_eq!(client.get_approval_count(&id), 0);
+    client.approve_transfer(&s1, &id);
+    assert_eq!(client.get_approval_count(&id), 1);
+    client.approve_transfer(&s2, &id);
+    assert_eq!(client.get_approval_count(&id), 2);
 }
 
 #[test]
@@ -229,69 +432,77 @@ fn approve_is_idempotent() {
     let (env, client, _, _) = setup();
     let s = Address::generate(&env);
     let signers = Vec::from_slice(&env, core::slice::from_ref(&s));
-    client.propose_admin_transfer(
+    client.set_signers(&signers, &1);
+    let id = client.propose_admin_transfer(
         &Address::generate(&env),
         &signers,
         &1,
         &MIN_TIMELOCK_SECONDS,
     );
-    client.approve_transfer(&s);
-    client.approve_transfer(&s); // second call must not double-count
-    assert_eq!(client.get_approval_count(), 1);
+    client.approve_transfer(&s, &id);
+    client.approve_transfer(&s, &id); // second call must not double-count
+    assert_eq!(client.get_approval_count(&id), 1);
 }
 
 #[test]
-#[should_panic(expected = "caller is not in the signer list")]
 fn approve_rejects_non_signer() {
     let (env, client, _, _) = setup();
     let s = Address::generate(&env);
     let signers = Vec::from_slice(&env, &[s]);
-    client.propose_admin_transfer(
+    client.set_signers(&signers, &1);
+    let id = client.propose_admin_transfer(
         &Address::generate(&env),
         &signers,
         &1,
         &MIN_TIMELOCK_SECONDS,
     );
-    client.approve_transfer(&Address::generate(&env));
+    assert_eq!(
+        client.try_approve_transfer(&Address::generate(&env), &id),
+        Err(Ok(GovernanceError::NotASigner))
+    );
 }
 
 #[test]
-//#[should_panic(expected = "timelock not elapsed")]
-#[should_panic(expected = "timelock not elapsed")]
-fn finalize_before_timelock_panics() {
+fn finalize_before_timelock_returns_timelock_not_elapsed() {
     let (env, client, _, _) = setup();
     let s = Address::generate(&env);
     let signers = Vec::from_slice(&env, core::slice::from_ref(&s));
+    client.set_signers(&signers, &1);
     set_ts(&env, 1000);
-    client.propose_admin_transfer(
+    let id = client.propose_admin_transfer(
         &Address::generate(&env),
         &signers,
         &1,
         &MIN_TIMELOCK_SECONDS,
     );
-    client.approve_transfer(&s);
+    client.approve_transfer(&s, &id);
     set_ts(&env, 1000 + MIN_TIMELOCK_SECONDS - 1);
-    client.finalize_admin_transfer(&Address::generate(&env));
+    assert_eq!(
+        client.try_finalize_admin_transfer(&Address::generate(&env), &id),
+        Err(Ok(GovernanceError::TimelockNotElapsed))
+    );
 }
 
 #[test]
-//#[should_panic(expected = "threshold not met")]
-#[should_panic(expected = "threshold not met")]
-fn finalize_without_enough_approvals_panics() {
+fn finalize_without_enough_approvals_returns_threshold_not_met() {
     let (env, client, _, _) = setup();
     let s1 = Address::generate(&env);
     let s2 = Address::generate(&env);
     let signers = Vec::from_slice(&env, &[s1.clone(), s2]);
+    client.set_signers(&signers, &1);
     set_ts(&env, 1000);
-    client.propose_admin_transfer(
+    let id = client.propose_admin_transfer(
         &Address::generate(&env),
         &signers,
         &2,
         &MIN_TIMELOCK_SECONDS,
     );
-    client.approve_transfer(&s1); // only 1 of 2
+    client.approve_transfer(&s1, &id); // only 1 of 2
     set_ts(&env, 1000 + MIN_TIMELOCK_SECONDS + 1);
-    client.finalize_admin_transfer(&Address::generate(&env));
+    assert_eq!(
+        client.try_finalize_admin_transfer(&Address::generate(&env), &id),
+        Err(Ok(GovernanceError::ThresholdNotMet))
+    );
 }
 
 #[test]
@@ -299,15 +510,19 @@ fn timelock_remaining_counts_down() {
     let (env, client, _, _) = setup();
     let s = Address::generate(&env);
     let signers = Vec::from_slice(&env, &[s]);
+    client.set_signers(&signers, &1);
     set_ts(&env, 1000);
-    client.propose_admin_transfer(
+    let id = client.propose_admin_transfer(
         &Address::generate(&env),
         &signers,
         &1,
         &MIN_TIMELOCK_SECONDS,
     );
     set_ts(&env, 1000 + 3600);
-    assert_eq!(client.get_timelock_remaining(), MIN_TIMELOCK_SECONDS - 3600);
+    assert_eq!(
+        client.get_timelock_remaining(&id),
+        MIN_TIMELOCK_SECONDS - 3600
+    );
 }
 
 // #[test]
@@ -318,7 +533,7 @@ fn timelock_remaining_counts_down() {
 //     set_ts(&env, 1000);
 //     client.propose_admin_transfer(&Address::generate(&env), &signers, &1, &MIN_TIMELOCK_SECONDS);
 //     set_ts(&env, 1000 + MIN_TIMELOCK_SECONDS + 1);
-//     assert_eq!(client.get_timelock_remaining(), 0);
+//     assert_eq!(client.get_timelock_remaining(&id), 0);
 // }
 
 #[test]
@@ -326,11 +541,12 @@ fn timelock_remaining_returns_zero_after_expiry() {
     let (env, client, _, _) = setup();
     let s = Address::generate(&env);
     let signers = Vec::from_slice(&env, &[s]);
+    client.set_signers(&signers, &1);
 
     // Set initial time
     set_ts(&env, 1000);
 
-    client.propose_admin_transfer(
+    let id = client.propose_admin_transfer(
         &Address::generate(&env),
         &signers,
         &1,
@@ -340,7 +556,7 @@ fn timelock_remaining_returns_zero_after_expiry() {
     // Move WELL past expiry (not just +1)
     set_ts(&env, 1000 + MIN_TIMELOCK_SECONDS + 100);
 
-    assert_eq!(client.get_timelock_remaining(), 0);
+    assert_eq!(client.get_timelock_remaining(&id), 0);
 }
 
 #[test]
@@ -348,38 +564,42 @@ fn cancel_clears_pending() {
     let (env, client, _, _) = setup();
     let s = Address::generate(&env);
     let signers = Vec::from_slice(&env, &[s]);
-    client.propose_admin_transfer(
+    client.set_signers(&signers, &1);
+    let id = client.propose_admin_transfer(
         &Address::generate(&env),
         &signers,
         &1,
         &MIN_TIMELOCK_SECONDS,
     );
     assert!(client.has_pending_transfer());
-    client.cancel_admin_transfer();
+    client.cancel_admin_transfer(&id);
     assert!(!client.has_pending_transfer());
 }
 
 #[test]
-#[should_panic(expected = "must wait at least 3600 seconds after cancellation before re-proposing")]
 fn cancel_enforces_reproposal_cooldown() {
     let (env, client, _, _) = setup();
     let s = Address::generate(&env);
     let signers = Vec::from_slice(&env, &[s]);
+    client.set_signers(&signers, &1);
 
     set_ts(&env, 1000);
-    client.propose_admin_transfer(
+    let id = client.propose_admin_transfer(
         &Address::generate(&env),
         &signers,
         &1,
         &MIN_TIMELOCK_SECONDS,
     );
-    client.cancel_admin_transfer();
+    client.cancel_admin_transfer(&id);
 
-    client.propose_admin_transfer(
-        &Address::generate(&env),
-        &signers,
-        &1,
-        &MIN_TIMELOCK_SECONDS,
+    assert_eq!(
+        client.try_propose_admin_transfer(
+            &Address::generate(&env),
+            &signers,
+            &1,
+            &MIN_TIMELOCK_SECONDS,
+        ),
+        Err(Ok(GovernanceError::ReproposalCooldown))
     );
 }
 
@@ -388,15 +608,16 @@ fn cancel_allows_reproposal_after_cooldown() {
     let (env, client, _, _) = setup();
     let s = Address::generate(&env);
     let signers = Vec::from_slice(&env, &[s]);
+    client.set_signers(&signers, &1);
 
     set_ts(&env, 1000);
-    client.propose_admin_transfer(
+    let id = client.propose_admin_transfer(
         &Address::generate(&env),
         &signers,
         &1,
         &MIN_TIMELOCK_SECONDS,
     );
-    client.cancel_admin_transfer();
+    client.cancel_admin_transfer(&id);
 
     set_ts(&env, 1000 + REPROPOSAL_COOLDOWN_SECONDS + 1);
     client.propose_admin_transfer(
@@ -409,10 +630,12 @@ fn cancel_allows_reproposal_after_cooldown() {
 }
 
 #[test]
-#[should_panic(expected = "no pending transfer to cancel")]
-fn cancel_with_no_pending_panics() {
+fn cancel_with_no_pending_returns_proposal_not_found() {
     let (_env, client, _, _) = setup();
-    client.cancel_admin_transfer();
+    assert_eq!(
+        client.try_cancel_admin_transfer(&1),
+        Err(Ok(GovernanceError::ProposalNotFound))
+    );
 }
 
 #[test]
@@ -421,10 +644,11 @@ fn expire_proposal_works_after_ttl() {
     let proposed = Address::generate(&env);
     let s = Address::generate(&env);
     let signers = Vec::from_slice(&env, core::slice::from_ref(&s));
+    client.set_signers(&signers, &1);
 
     // Create proposal
     set_ts(&env, 1000);
-    client.propose_admin_transfer(&proposed, &signers, &1, &MIN_TIMELOCK_SECONDS);
+    let id = client.propose_admin_transfer(&proposed, &signers, &1, &MIN_TIMELOCK_SECONDS);
     assert!(client.has_pending_transfer());
 
     // Move past TTL
@@ -432,54 +656,62 @@ fn expire_proposal_works_after_ttl() {
 
     // Anyone can expire the proposal
     let caller = Address::generate(&env);
-    client.expire_proposal(&caller);
+    client.expire_proposal(&caller, &id);
 
     // Proposal should be gone
     assert!(!client.has_pending_transfer());
 }
 
 #[test]
-#[should_panic(expected = "proposal has not yet expired")]
-fn expire_proposal_fails_before_ttl() {
+fn expire_proposal_before_ttl_returns_proposal_not_expired() {
     let (env, client, _, _) = setup();
     let proposed = Address::generate(&env);
     let s = Address::generate(&env);
     let signers = Vec::from_slice(&env, core::slice::from_ref(&s));
+    client.set_signers(&signers, &1);
 
     // Create proposal
     set_ts(&env, 1000);
-    client.propose_admin_transfer(&proposed, &signers, &1, &MIN_TIMELOCK_SECONDS);
+    let id = client.propose_admin_transfer(&proposed, &signers, &1, &MIN_TIMELOCK_SECONDS);
 
     // Try to expire before TTL
     set_ts(&env, 1000 + PROPOSAL_TTL_SECONDS - 1);
-    client.expire_proposal(&Address::generate(&env));
+    assert_eq!(
+        client.try_expire_proposal(&Address::generate(&env), &id),
+        Err(Ok(GovernanceError::ProposalNotExpired))
+    );
 }
 
 #[test]
-#[should_panic(expected = "no pending transfer to expire")]
-fn expire_proposal_fails_with_no_pending() {
+fn expire_proposal_with_no_pending_returns_proposal_not_found() {
     let (env, client, _, _) = setup();
-    client.expire_proposal(&Address::generate(&env));
+    assert_eq!(
+        client.try_expire_proposal(&Address::generate(&env), &1),
+        Err(Ok(GovernanceError::ProposalNotFound))
+    );
 }
 
 #[test]
-#[should_panic(expected = "proposal has expired")]
-fn finalize_fails_after_expiry() {
+fn finalize_after_expiry_returns_proposal_expired() {
     let (env, client, admin, _) = setup();
     let proposed = Address::generate(&env);
     let s = Address::generate(&env);
     let signers = Vec::from_slice(&env, core::slice::from_ref(&s));
+    client.set_signers(&signers, &1);
 
     // Create proposal
     set_ts(&env, 1000);
-    client.propose_admin_transfer(&proposed, &signers, &1, &MIN_TIMELOCK_SECONDS);
-    client.approve_transfer(&s);
+    let id = client.propose_admin_transfer(&proposed, &signers, &1, &MIN_TIMELOCK_SECONDS);
+    client.approve_transfer(&s, &id);
 
     // Move past both timelock and TTL
     set_ts(&env, 1000 + MIN_TIMELOCK_SECONDS + PROPOSAL_TTL_SECONDS + 1);
 
     // Finalization should fail due to expiry
-    client.finalize_admin_transfer(&admin);
+    assert_eq!(
+        client.try_finalize_admin_transfer(&admin, &id),
+        Err(Ok(GovernanceError::ProposalExpired))
+    );
 }
 
 #[test]
@@ -488,21 +720,59 @@ fn finalize_works_within_ttl() {
     let proposed = Address::generate(&env);
     let s = Address::generate(&env);
     let signers = Vec::from_slice(&env, core::slice::from_ref(&s));
+    client.set_signers(&signers, &1);
 
     // Create proposal
     set_ts(&env, 1000);
-    client.propose_admin_transfer(&proposed, &signers, &1, &MIN_TIMELOCK_SECONDS);
-    client.approve_transfer(&s);
+    let id = client.propose_admin_transfer(&proposed, &signers, &1, &MIN_TIMELOCK_SECONDS);
+    client.approve_transfer(&s, &id);
 
     // Move past timelock but within TTL
     set_ts(&env, 1000 + MIN_TIMELOCK_SECONDS + 1);
 
     // Finalization should succeed
-    client.finalize_admin_transfer(&admin);
+    client.finalize_admin_transfer(&admin, &id);
     assert_eq!(client.get_current_admin(), proposed);
     assert!(!client.has_pending_transfer());
 }
 
+#[test]
+fn finalize_supersedes_other_open_transfers() {
+    let (env, client, admin, target) = setup();
+    let (first_admin, stale_admin) = (Address::generate(&env), Address::generate(&env));
+    let s = Address::generate(&env);
+    let signers = Vec::from_slice(&env, core::slice::from_ref(&s));
+    client.set_signers(&signers, &1);
+
+    set_ts(&env, 1000);
+    let first = client.propose_admin_transfer(&first_admin, &signers, &1, &MIN_TIMELOCK_SECONDS);
+    let stale = client.propose_admin_transfer(&stale_admin, &signers, &1, &MIN_TIMELOCK_SECONDS);
+    let upgrade =
+        client.propose_upgrade(&target, &create_upgrade_hash(&env), &20_000, &signers, &1);
+    client.approve_transfer(&s, &first);
+    client.approve_transfer(&s, &stale);
+
+    set_ts(&env, 1000 + MIN_TIMELOCK_SECONDS + 1);
+    client.finalize_admin_transfer(&admin, &first);
+
+    let record = client.get_proposal(&stale).unwrap();
+    assert_eq!(record.status, ProposalStatus::Superseded);
+    assert_eq!(record.closed_by, Some(admin.clone()));
+    assert_eq!(
+        client.try_finalize_admin_transfer(&admin, &stale),
+        Err(Ok(GovernanceError::ProposalNotActive))
+    );
+    assert_eq!(client.get_current_admin(), first_admin);
+    assert_eq!(
+        MockTargetClient::new(&env, &target).get_admin(),
+        first_admin
+    );
+    assert_eq!(
+        client.get_active_proposals(),
+        soroban_sdk::vec![&env, upgrade]
+    );
+}
+
 #[test]
 fn new_proposal_allowed_after_expiry() {
     let (env, client, _admin, _) = setup();
@@ -510,21 +780,22 @@ fn new_proposal_allowed_after_expiry() {
     let proposed2 = Address::generate(&env);
     let s = Address::generate(&env);
     let signers = Vec::from_slice(&env, core::slice::from_ref(&s));
+    client.set_signers(&signers, &1);
 
     // Create first proposal
     set_ts(&env, 1000);
-    client.propose_admin_transfer(&proposed1, &signers, &1, &MIN_TIMELOCK_SECONDS);
+    let id = client.propose_admin_transfer(&proposed1, &signers, &1, &MIN_TIMELOCK_SECONDS);
 
     // Expire it
     set_ts(&env, 1000 + PROPOSAL_TTL_SECONDS + 1);
-    client.expire_proposal(&Address::generate(&env));
+    client.expire_proposal(&Address::generate(&env), &id);
 
     // Should be able to create new proposal after cooldown (expiry triggers same cooldown as cancellation)
     set_ts(&env, 1000 + PROPOSAL_TTL_SECONDS + 3602);
-    client.propose_admin_transfer(&proposed2, &signers, &1, &MIN_TIMELOCK_SECONDS);
+    let id = client.propose_admin_transfer(&proposed2, &signers, &1, &MIN_TIMELOCK_SECONDS);
     assert!(client.has_pending_transfer());
-    let pending = client.get_pending_transfer();
-    assert_eq!(pending.proposed_admin, proposed2);
+    let pending = client.get_proposal(&id).unwrap();
+    assert_eq!(pending.kind, ProposalKind::AdminTransfer(proposed2));
 }
 #[test]
 fn emergency_cancel_works_and_prevents_finalization() {
@@ -532,10 +803,10 @@ fn emergency_cancel_works_and_prevents_finalization() {
     let proposed = Address::generate(&env);
     let s = Address::generate(&env);
     let signers = Vec::from_slice(&env, core::slice::from_ref(&s));
+    client.set_signers(&signers, &1);
 
     set_ts(&env, 1000);
-    client.propose_admin_transfer(&proposed, &signers, &1, &MIN_TIMELOCK_SECONDS);
-    let proposal_id = client.get_pending_transfer().id;
+    let proposal_id = client.propose_admin_transfer(&proposed, &signers, &1, &MIN_TIMELOCK_SECONDS);
 
     // Emergency cancel
     let reason = Some(soroban_sdk::String::from_str(&env, "Emergency"));
@@ -544,79 +815,683 @@ fn emergency_cancel_works_and_prevents_finalization() {
     // Proposal should no longer be "pending" (active)
     assert!(!client.has_pending_transfer());
     assert_eq!(
-        client.get_pending_transfer().status,
+        client.get_proposal(&proposal_id).unwrap().status,
         ProposalStatus::Cancelled
     );
 
     // Finalization should panic
     set_ts(&env, 1000 + MIN_TIMELOCK_SECONDS + 1);
-    // client.finalize_admin_transfer(&admin); // This would panic
+    // client.finalize_admin_transfer(&admin, &proposal_id); // This would panic
 }
 
 #[test]
-#[should_panic(expected = "proposal is not active")]
-fn cannot_approve_cancelled_proposal() {
+fn approve_cancelled_proposal_returns_proposal_not_active() {
     let (env, client, _admin, _) = setup();
     let s = Address::generate(&env);
     let signers = Vec::from_slice(&env, core::slice::from_ref(&s));
+    client.set_signers(&signers, &1);
 
-    client.propose_admin_transfer(
+    let id = client.propose_admin_transfer(
         &Address::generate(&env),
         &signers,
         &1,
         &MIN_TIMELOCK_SECONDS,
     );
-    let proposal_id = client.get_pending_transfer().id;
-    client.emergency_cancel_proposal(&proposal_id, &None);
+    client.emergency_cancel_proposal(&id, &None);
 
-    client.approve_transfer(&s);
+    assert_eq!(
+        client.try_approve_transfer(&s, &id),
+        Err(Ok(GovernanceError::ProposalNotActive))
+    );
 }
 
 #[test]
-#[should_panic(expected = "proposal is not active")]
-fn cannot_finalize_cancelled_proposal() {
+fn finalize_cancelled_proposal_returns_proposal_not_active() {
     let (env, client, admin, _) = setup();
     let s = Address::generate(&env);
     let signers = Vec::from_slice(&env, core::slice::from_ref(&s));
+    client.set_signers(&signers, &1);
 
     set_ts(&env, 1000);
-    client.propose_admin_transfer(
+    let id = client.propose_admin_transfer(
         &Address::generate(&env),
         &signers,
         &1,
         &MIN_TIMELOCK_SECONDS,
     );
-    let proposal_id = client.get_pending_transfer().id;
-    client.approve_transfer(&s);
+    client.approve_transfer(&s, &id);
 
-    client.emergency_cancel_proposal(&proposal_id, &None);
+    client.emergency_cancel_proposal(&id, &None);
 
     set_ts(&env, 1000 + MIN_TIMELOCK_SECONDS + 1);
-    client.finalize_admin_transfer(&admin);
+    assert_eq!(
+        client.try_finalize_admin_transfer(&admin, &id),
+        Err(Ok(GovernanceError::ProposalNotActive))
+    );
 }
 
 // ── Additional coverage tests ─────────────────────────────────────────────────
 
 #[test]
-#[should_panic(expected = "signer list must not be empty")]
 fn propose_rejects_empty_signers() {
     let (env, client, _, _) = setup();
     let signers: Vec<Address> = Vec::new(&env);
-    client.propose_admin_transfer(
-        &Address::generate(&env),
-        &signers,
-        &1,
-        &MIN_TIMELOCK_SECONDS,
+    assert_eq!(
+        client.try_propose_admin_transfer(
+            &Address::generate(&env),
+            &signers,
+            &1,
+            &MIN_TIMELOCK_SECONDS,
+        ),
+        Err(Ok(GovernanceError::EmptySigners))
     );
 }
 
 #[test]
-#[should_panic(expected = "signer list exceeds MAX_SIGNERS")]
 fn propose_rejects_too_many_signers() {
     let (env, client, _, _) = setup();
     let mut addrs = soroban_sdk::vec![&env];
     for _ in 0..21 {
         addrs.push_back(Address::generate(&env));
     }
-    client.propose_admin_transfer(&Address::generate(&env), &addrs, &1, &MIN_TIMELOCK_SECONDS);
+
+    assert_eq!(
+        client.try_propose_admin_transfer(
+            &Address::generate(&env),
+            &addrs,
+            &1,
+            &MIN_TIMELOCK_SECONDS
+        ),
+        Err(Ok(GovernanceError::TooManySigners))
+    );
+}
+
+// ── Contract upgrades ─────────────────────────────────────────────────────────
+
+#[test]
+fn upgrade_requires_quorum_before_scheduling_on_target() {
+    let (env, client, admin, target) = setup();
+    let (s1, s2) = (Address::generate(&env), Address::generate(&env));
+    let signers = soroban_sdk::vec![&env, s1.clone(), s2.clone()];
+    client.set_signers(&signers, &1);
+    let wasm_hash = create_upgrade_hash(&env);
+    set_ts(&env, 1000);
+
+    let id = client.propose_upgrade(&target, &wasm_hash, &20_000, &signers, &2);
+    client.approve_upgrade(&s1, &id);
+    assert_eq!(
+        client.try_schedule_upgrade(&admin, &id),
+        Err(Ok(GovernanceError::ThresholdNotMet))
+    );
+
+    client.approve_upgrade(&s2, &id);
+    assert_eq!(client.get_approval_count(&id), 2);
+    client.schedule_upgrade(&admin, &id);
+    let target_client = MockTargetClient::new(&env, &target);
+    assert_eq!(
+        target_client.get_scheduled_upgrade(),
+        Some((wasm_hash.clone(), 20_000))
+    );
+    assert!(client.get_proposal(&id).unwrap().scheduled);
+
+    client.execute_upgrade(&admin, &id);
+    assert_eq!(target_client.get_upgraded(), Some(wasm_hash));
+    assert_eq!(
+        client.get_proposal(&id).unwrap().status,
+        ProposalStatus::Executed
+    );
+    assert!(client.get_active_proposals().is_empty());
+}
+
+#[test]
+fn cancel_upgrade_withdraws_it_from_target() {
+    let (env, client, admin, target) = setup();
+    let s1 = Address::generate(&env);
+    let signers = soroban_sdk::vec![&env, s1.clone()];
+    client.set_signers(&signers, &1);
+    set_ts(&env, 1000);
+
+    let id = client.propose_upgrade(&target, &create_upgrade_hash(&env), &20_000, &signers, &1);
+    client.approve_upgrade(&s1, &id);
+    client.schedule_upgrade(&admin, &id);
+    client.cancel_upgrade(&id);
+
+    let target_client = MockTargetClient::new(&env, &target);
+    assert_eq!(target_client.get_scheduled_upgrade(), None);
+    assert_eq!(
+        client.get_proposal(&id).unwrap().status,
+        ProposalStatus::Cancelled
+    );
+}
+
+#[test]
+fn execute_upgrade_requires_scheduling_first() {
+    let (env, client, admin, target) = setup();
+    let s1 = Address::generate(&env);
+    let signers = soroban_sdk::vec![&env, s1.clone()];
+    client.set_signers(&signers, &1);
+    set_ts(&env, 1000);
+
+    let id = client.propose_upgrade(&target, &create_upgrade_hash(&env), &20_000, &signers, &1);
+    client.approve_upgrade(&s1, &id);
+    assert_eq!(
+        client.try_execute_upgrade(&admin, &id),
+        Err(Ok(GovernanceError::UpgradeNotScheduled))
+    );
+}
+
+#[test]
+fn several_upgrades_may_be_open_but_one_scheduled_per_target() {
+    let (env, client, admin, target) = setup();
+    let s1 = Address::generate(&env);
+    let signers = soroban_sdk::vec![&env, s1.clone()];
+    client.set_signers(&signers, &1);
+    set_ts(&env, 1000);
+
+    let first = client.propose_upgrade(&target, &create_upgrade_hash(&env), &20_000, &signers, &1);
+    let second = client.propose_upgrade(
+        &target,
+        &BytesN::from_array(&env, &[12u8; 32]),
+        &30_000,
+        &signers,
+        &1,
+    );
+    assert_eq!(client.get_active_proposals().len(), 2);
+
+    client.approve_upgrade(&s1, &first);
+    client.approve_upgrade(&s1, &second);
+    client.schedule_upgrade(&admin, &first);
+    assert_eq!(
+        client.try_schedule_upgrade(&admin, &second),
+        Err(Ok(GovernanceError::UpgradeAlreadyPending))
+    );
+
+    client.cancel_upgrade(&first);
+    client.schedule_upgrade(&admin, &second);
+    assert_eq!(
+        MockTargetClient::new(&env, &target).get_scheduled_upgrade(),
+        Some((BytesN::from_array(&env, &[12u8; 32]), 30_000))
+    );
+}
+
+#[test]
+fn guardian_is_set_and_pause_ratified_through_governance() {
+    let (env, client, admin, target) = setup();
+    let (s1, s2) = (Address::generate(&env), Address::generate(&env));
+    let signers = soroban_sdk::vec![&env, s1.clone(), s2.clone()];
+    client.set_signers(&signers, &1);
+    let guardian = Address::generate(&env);
+    let target_client = MockTargetClient::new(&env, &target);
+    set_ts(&env, 1000);
+
+    let id = client.propose_guardian(&target, &Some(guardian.clone()), &signers, &2);
+    client.approve_proposal(&s1, &id);
+    assert_eq!(
+        client.try_execute_proposal(&admin, &id),
+        Err(Ok(GovernanceError::ThresholdNotMet))
+    );
+    client.approve_proposal(&s2, &id);
+    client.execute_proposal(&admin, &id);
+    assert_eq!(target_client.get_guardian(), Some(guardian));
+    assert_eq!(
+        client.get_proposal(&id).unwrap().status,
+        ProposalStatus::Executed
+    );
+
+    let id = client.propose_pause_ratification(&target, &signers, &1);
+    client.approve_proposal(&s2, &id);
+    client.execute_proposal(&admin, &id);
+    assert!(target_client.is_ratified());
+
+    let id = client.propose_guardian(&target, &None, &signers, &1);
+    client.approve_proposal(&s1, &id);
+    client.execute_proposal(&admin, &id);
+    assert_eq!(target_client.get_guardian(), None);
+}
+
+#[test]
+fn ratified_pause_is_lifted_through_governance() {
+    let (env, client, admin, target) = setup();
+    let (s1, s2) = (Address::generate(&env), Address::generate(&env));
+    let signers = soroban_sdk::vec![&env, s1.clone(), s2.clone()];
+    client.set_signers(&signers, &1);
+    let target_client = MockTargetClient::new(&env, &target);
+    set_ts(&env, 1000);
+    target_client.pause();
+
+    let id = client.propose_pause_ratification(&target, &signers, &1);
+    client.approve_proposal(&s1, &id);
+    client.execute_proposal(&admin, &id);
+    assert!(target_client.is_paused());
+
+    let id = client.propose_unpause(&target, &signers, &2);
+    client.approve_proposal(&s1, &id);
+    assert_eq!(
+        client.try_execute_proposal(&admin, &id),
+        Err(Ok(GovernanceError::ThresholdNotMet))
+    );
+    client.approve_proposal(&s2, &id);
+    client.execute_proposal(&admin, &id);
+    assert!(!target_client.is_paused());
+    assert_eq!(
+        client.get_proposal(&id).unwrap().status,
+        ProposalStatus::Executed
+    );
+}
+
+#[test]
+fn admin_seat_of_any_target_is_handed_over_after_the_timelock() {
+    let (env, client, admin, _target) = setup();
+    let s = Address::generate(&env);
+    let signers = soroban_sdk::vec![&env, s.clone()];
+    client.set_signers(&signers, &1);
+    // A governed contract other than the transfer target.
+    let other = env.register(MockTarget, ());
+    let other_client = MockTargetClient::new(&env, &other);
+    let new_admin = Address::generate(&env);
+    set_ts(&env, 1000);
+
+    let id = client.propose_target_admin_transfer(&other, &new_admin, &signers, &1);
+    client.approve_proposal(&s, &id);
+    assert_eq!(
+        client.try_execute_proposal(&admin, &id),
+        Err(Ok(GovernanceError::TimelockNotElapsed))
+    );
+    assert_eq!(
+        client.try_finalize_admin_transfer(&admin, &id),
+        Err(Ok(GovernanceError::NotAnAdminTransfer))
+    );
+
+    set_ts(&env, 1000 + MIN_TIMELOCK_SECONDS);
+    client.execute_proposal(&admin, &id);
+    assert_eq!(other_client.get_proposed_admin(), Some(new_admin.clone()));
+    // The governance admin itself is unchanged.
+    assert_eq!(client.get_admin(), admin);
+
+    let log = client.get_admin_actions(&0, &100);
+    let entry = log.get(log.len() - 1).unwrap();
+    assert_eq!(entry.action, Symbol::new(&env, "propose_target_admin"));
+    assert_eq!(entry.new, AdminValue::TargetAdmin(other, new_admin));
+}
+
+#[test]
+fn guardian_actions_use_their_own_entrypoints() {
+    let (env, client, admin, target) = setup();
+    let s = Address::generate(&env);
+    let signers = soroban_sdk::vec![&env, s.clone()];
+    client.set_signers(&signers, &1);
+
+    let transfer = client.propose_admin_transfer(
+        &Address::generate(&env),
+        &signers,
+        &1,
+        &MIN_TIMELOCK_SECONDS,
+    );
+    assert_eq!(
+        client.try_approve_proposal(&s, &transfer),
+        Err(Ok(GovernanceError::NotAGuardianAction))
+    );
+    let ratify = client.propose_pause_ratification(&target, &signers, &1);
+    assert_eq!(
+        client.try_approve_transfer(&s, &ratify),
+        Err(Ok(GovernanceError::NotAnAdminTransfer))
+    );
+    client.approve_proposal(&s, &ratify);
+    assert_eq!(
+        client.try_execute_proposal(&admin, &transfer),
+        Err(Ok(GovernanceError::NotAGuardianAction))
+    );
+
+    client.emergency_cancel_proposal(&ratify, &None);
+    assert_eq!(
+        client.try_execute_proposal(&admin, &ratify),
+        Err(Ok(GovernanceError::ProposalNotActive))
+    );
+}
+
+#[test]
+#[should_panic]
+fn propose_guardian_requires_admin_auth() {
+    let (env, client, _admin, target) = setup();
+    let signers = soroban_sdk::vec![&env, Address::generate(&env)];
+    client.set_signers(&signers, &1);
+    env.mock_auths(&[]);
+    client.propose_guardian(&target, &Some(Address::generate(&env)), &signers, &1);
+}
+
+#[test]
+fn privileged_calls_are_appended_to_the_admin_log() {
+    let (env, client, admin, target) = setup();
+    let s = Address::generate(&env);
+    let signers = soroban_sdk::vec![&env, s.clone()];
+    client.set_signers(&signers, &1);
+    let guardian = Address::generate(&env);
+    let keeper = Address::generate(&env);
+
+    let id = client.propose_guardian(&target, &Some(guardian.clone()), &signers, &1);
+    client.approve_proposal(&s, &id);
+    client.execute_proposal(&keeper, &id);
+
+    assert_eq!(client.get_admin_action_count(), 5);
+    let log = client.get_admin_actions(&0, &10);
+    assert_eq!(log.len(), 5);
+
+    let init = log.get(0).unwrap();
+    assert_eq!(init.action, Symbol::new(&env, "initialize"));
+    assert_eq!(init.new, AdminValue::Address(admin.clone()));
+
+    let signer_set = log.get(1).unwrap();
+    assert_eq!(signer_set.action, Symbol::new(&env, "set_signers"));
+    assert_eq!(signer_set.new, AdminValue::U32(1));
+
+    let proposed = log.get(2).unwrap();
+    assert_eq!(proposed.actor, admin);
+    assert_eq!(proposed.action, Symbol::new(&env, "propose_guardian"));
+    assert_eq!(log.get(3).unwrap().actor, s);
+
+    let set = log.get(4).unwrap();
+    assert_eq!(set.actor, keeper);
+    assert_eq!(set.action, Symbol::new(&env, "set_guardian"));
+    assert_eq!(set.new, AdminValue::Guardian(target.clone(), guardian));
+
+    assert_eq!(client.get_admin_actions(&5, &10).len(), 0);
+}
+
+#[test]
+fn proposals_keep_their_full_lifecycle_after_closing() {
+    let (env, client, admin, target) = setup();
+    let (s1, s2) = (Address::generate(&env), Address::generate(&env));
+    let signers = soroban_sdk::vec![&env, s1.clone(), s2.clone()];
+    client.set_signers(&signers, &1);
+    let proposed = Address::generate(&env);
+    let keeper = Address::generate(&env);
+
+    set_ts(&env, 1000);
+    let executed = client.propose_admin_transfer(&proposed, &signers, &2, &MIN_TIMELOCK_SECONDS);
+    let cancelled = client.propose_admin_transfer(
+        &Address::generate(&env),
+        &signers,
+        &1,
+        &MIN_TIMELOCK_SECONDS,
+    );
+    let upgrade =
+        client.propose_upgrade(&target, &create_upgrade_hash(&env), &20_000, &signers, &1);
+
+    set_ts(&env, 1100);
+    client.approve_transfer(&s1, &executed);
+    set_ts(&env, 1200);
+    client.approve_transfer(&s2, &executed);
+    client.approve_transfer(&s1, &executed);
+    client.approve_upgrade(&s2, &upgrade);
+
+    let reason = Some(soroban_sdk::String::from_str(&env, "wrong admin"));
+    client.emergency_cancel_proposal(&cancelled, &reason);
+
+    set_ts(&env, 1000 + MIN_TIMELOCK_SECONDS + 1);
+    client.finalize_admin_transfer(&keeper, &executed);
+
+    let record = client.get_proposal(&executed).unwrap();
+    assert_eq!(record.kind, ProposalKind::AdminTransfer(proposed));
+    assert_eq!(record.proposer, admin);
+    assert_eq!(record.status, ProposalStatus::Executed);
+    assert_eq!(record.approvals.get(s1.clone()), Some(1100));
+    assert_eq!(record.approvals.get(s2.clone()), Some(1200));
+    assert_eq!(record.closed_at, 1000 + MIN_TIMELOCK_SECONDS + 1);
+    assert_eq!(record.closed_by, Some(keeper));
+
+    let record = client.get_proposal(&cancelled).unwrap();
+    assert_eq!(record.status, ProposalStatus::Cancelled);
+    assert_eq!(record.closed_by, Some(admin.clone()));
+    assert_eq!(record.reason, reason);
+
+    let record = client.get_proposal(&upgrade).unwrap();
+    assert_eq!(record.status, ProposalStatus::Active);
+    assert_eq!(record.approvals.get(s2), Some(1200));
+
+    let all = client.list_proposals(&None, &0, &10);
+    assert_eq!(all.len(), 3);
+    assert_eq!(all.get(0).unwrap().id, executed);
+
+    let open = client.list_proposals(&Some(ProposalStatus::Active), &0, &10);
+    assert_eq!(open.len(), 1);
+    assert_eq!(open.get(0).unwrap().id, upgrade);
+
+    let page = client.list_proposals(&None, &(executed + 1), &1);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get(0).unwrap().id, cancelled);
+    assert_eq!(
+        client.get_active_proposals(),
+        soroban_sdk::vec![&env, upgrade]
+    );
+    assert!(!client.has_pending_transfer());
+}
+
+#[test]
+fn upgrade_and_transfer_entrypoints_return_typed_errors() {
+    let (env, client, admin, target) = setup();
+    let s = Address::generate(&env);
+    let signers = soroban_sdk::vec![&env, s.clone()];
+    client.set_signers(&signers, &1);
+
+    assert_eq!(
+        client.try_approve_upgrade(&s, &1),
+        Err(Ok(GovernanceError::ProposalNotFound))
+    );
+
+    let upgrade =
+        client.propose_upgrade(&target, &create_upgrade_hash(&env), &20_000, &signers, &1);
+    assert_eq!(
+        client.try_approve_transfer(&s, &upgrade),
+        Err(Ok(GovernanceError::NotAnAdminTransfer))
+    );
+    let transfer = client.propose_admin_transfer(
+        &Address::generate(&env),
+        &signers,
+        &1,
+        &MIN_TIMELOCK_SECONDS,
+    );
+    assert_eq!(
+        client.try_approve_upgrade(&s, &transfer),
+        Err(Ok(GovernanceError::NotAnUpgrade))
+    );
+
+    client.approve_upgrade(&s, &upgrade);
+    client.schedule_upgrade(&admin, &upgrade);
+    assert_eq!(
+        client.try_schedule_upgrade(&admin, &upgrade),
+        Err(Ok(GovernanceError::UpgradeAlreadyScheduled))
+    );
+    assert_eq!(
+        client.try_get_approval_count(&(transfer + 1)),
+        Err(Ok(GovernanceError::ProposalNotFound))
+    );
+}
+
+#[test]
+fn signer_can_revoke_approval_before_finalization() {
+    let (env, client, admin, _) = setup();
+    let (s1, s2) = (Address::generate(&env), Address::generate(&env));
+    let signers = soroban_sdk::vec![&env, s1.clone(), s2.clone()];
+    client.set_signers(&signers, &1);
+    set_ts(&env, 1000);
+    let id = client.propose_admin_transfer(
+        &Address::generate(&env),
+        &signers,
+        &2,
+        &MIN_TIMELOCK_SECONDS,
+    );
+    client.approve_transfer(&s1, &id);
+    client.approve_transfer(&s2, &id);
+
+    client.revoke_approval(&s2, &id);
+    assert_eq!(client.get_approval_count(&id), 1);
+    assert_eq!(
+        client.try_revoke_approval(&s2, &id),
+        Err(Ok(GovernanceError::NotApproved))
+    );
+
+    set_ts(&env, 1000 + MIN_TIMELOCK_SECONDS + 1);
+    assert_eq!(
+        client.try_finalize_admin_transfer(&admin, &id),
+        Err(Ok(GovernanceError::ThresholdNotMet))
+    );
+}
+
+#[test]
+fn veto_minority_blocks_proposal_during_timelock() {
+    let (env, client, admin, _) = setup();
+    let signers = soroban_sdk::vec![
+        &env,
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+    ];
+    let (s1, s2, s3) = (
+        signers.get(0).unwrap(),
+        signers.get(1).unwrap(),
+        signers.get(2).unwrap(),
+    );
+    client.set_signers(&signers, &1);
+
+    assert_eq!(
+        client.try_set_veto_threshold(&(MAX_SIGNERS / 2)),
+        Err(Ok(GovernanceError::InvalidVetoThreshold))
+    );
+    client.set_veto_threshold(&2);
+    assert_eq!(client.get_veto_threshold(), 2);
+
+    // Two of three signers is not a minority.
+    let small = soroban_sdk::vec![&env, s1.clone(), s2.clone(), s3.clone()];
+    assert_eq!(
+        client.try_propose_admin_transfer(
+            &Address::generate(&env),
+            &small,
+            &2,
+            &MIN_TIMELOCK_SECONDS
+        ),
+        Err(Ok(GovernanceError::InvalidVetoThreshold))
+    );
+
+    set_ts(&env, 1000);
+    let id = client.propose_admin_transfer(
+        &Address::generate(&env),
+        &signers,
+        &3,
+        &MIN_TIMELOCK_SECONDS,
+    );
+    client.approve_transfer(&s1, &id);
+    client.veto_proposal(&s1, &id);
+    assert_eq!(client.get_approval_count(&id), 0);
+    assert_eq!(client.get_veto_count(&id), 1);
+
+    // Approving again withdraws the veto.
+    client.approve_transfer(&s1, &id);
+    assert_eq!(client.get_veto_count(&id), 0);
+
+    client.veto_proposal(&s2, &id);
+    client.veto_proposal(&s3, &id);
+    let record = client.get_proposal(&id).unwrap();
+    assert_eq!(record.status, ProposalStatus::Vetoed);
+    assert_eq!(record.closed_by, Some(s3.clone()));
+    assert!(!client.has_pending_transfer());
+
+    set_ts(&env, 1000 + MIN_TIMELOCK_SECONDS + 1);
+    assert_eq!(
+        client.try_finalize_admin_transfer(&admin, &id),
+        Err(Ok(GovernanceError::ProposalNotActive))
+    );
+}
+
+#[test]
+fn veto_is_rejected_after_timelock() {
+    let (env, client, _, _) = setup();
+    let signers = soroban_sdk::vec![
+        &env,
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+    ];
+    let s1 = signers.get(0).unwrap();
+    client.set_signers(&signers, &1);
+    client.set_veto_threshold(&1);
+
+    set_ts(&env, 1000);
+    let id = client.propose_admin_transfer(
+        &Address::generate(&env),
+        &signers,
+        &2,
+        &MIN_TIMELOCK_SECONDS,
+    );
+    set_ts(&env, 1000 + MIN_TIMELOCK_SECONDS);
+    assert_eq!(
+        client.try_veto_proposal(&s1, &id),
+        Err(Ok(GovernanceError::VetoWindowClosed))
+    );
+    assert_eq!(
+        client.try_veto_proposal(&Address::generate(&env), &id),
+        Err(Ok(GovernanceError::NotASigner))
+    );
+}
+
+#[test]
+fn signer_can_revoke_upgrade_approval_before_scheduling() {
+    let (env, client, admin, target) = setup();
+    let (s1, s2) = (Address::generate(&env), Address::generate(&env));
+    let signers = soroban_sdk::vec![&env, s1.clone(), s2.clone()];
+    client.set_signers(&signers, &1);
+    set_ts(&env, 1000);
+    let id = client.propose_upgrade(&target, &create_upgrade_hash(&env), &20_000, &signers, &2);
+    client.approve_upgrade(&s1, &id);
+    client.approve_upgrade(&s2, &id);
+
+    client.revoke_approval(&s2, &id);
+    assert_eq!(client.get_approval_count(&id), 1);
+    assert_eq!(
+        client.try_schedule_upgrade(&admin, &id),
+        Err(Ok(GovernanceError::ThresholdNotMet))
+    );
+
+    client.approve_upgrade(&s2, &id);
+    client.schedule_upgrade(&admin, &id);
+    assert_eq!(
+        client.try_revoke_approval(&s1, &id),
+        Err(Ok(GovernanceError::UpgradeAlreadyScheduled))
+    );
+}
+
+#[test]
+fn veto_withdraws_a_scheduled_upgrade_from_its_target() {
+    let (env, client, admin, target) = setup();
+    let signers = soroban_sdk::vec![
+        &env,
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+    ];
+    let (s1, s2) = (signers.get(0).unwrap(), signers.get(1).unwrap());
+    client.set_signers(&signers, &1);
+    client.set_veto_threshold(&1);
+    set_ts(&env, 1000);
+
+    let id = client.propose_upgrade(&target, &create_upgrade_hash(&env), &20_000, &signers, &2);
+    client.approve_upgrade(&s1, &id);
+    client.approve_upgrade(&s2, &id);
+    client.schedule_upgrade(&admin, &id);
+
+    set_ts(&env, 1000 + MIN_TIMELOCK_SECONDS);
+    client.veto_proposal(&s2, &id);
+
+    let record = client.get_proposal(&id).unwrap();
+    assert_eq!(record.status, ProposalStatus::Vetoed);
+    assert_eq!(record.approvals.len(), 1);
+    assert_eq!(
+        MockTargetClient::new(&env, &target).get_scheduled_upgrade(),
+        None
+    );
+    assert_eq!(
+        client.try_execute_upgrade(&admin, &id),
+        Err(Ok(GovernanceError::ProposalNotActive))
+    );
 }
@@ -1,9 +1,17 @@
 #![cfg_attr(not(test), no_std)]
 use soroban_sdk::{
-    contract, contracterror, contractimpl, contracttype, symbol_short, Address, BytesN, Env,
-    Symbol, Vec,
+    contract, contractclient, contracterror, contractimpl, contracttype, symbol_short, xdr::ToXdr,
+    Address, Bytes, BytesN, Env, IntoVal, String, Symbol, Val, Vec,
 };
 
+/// Implemented by contracts holding state keyed by a holder's address
+/// (LoanManager), so it can follow the record when it moves. Returning an
+/// error vetoes the move.
+#[contractclient(name = "LoanHookClient")]
+pub trait LoanHookInterface {
+    fn on_record_moved(env: Env, from: Address, to: Address, recovery: bool);
+}
+
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub enum NftError {
@@ -23,15 +31,131 @@ pub enum NftError {
     InvalidHistoryHash = 14,
     NoProposedAdmin = 15,
     RemintNotApproved = 16,
+    InvalidScoringPolicy = 17,
+    InvalidDecayConfig = 18,
+    UnknownAttestationProvider = 19,
+    AttestationAlreadySubmitted = 20,
+    InvalidAttestation = 21,
+    InvalidScoreWeights = 22,
+    SoulboundToken = 23,
+    RecoveryNotFound = 24,
+    RecoveryPending = 25,
+    RecoveryTimelockActive = 26,
+    LoanHookRejected = 27,
+    InvalidBaseUri = 28,
+    AccessDenied = 29,
+    InvalidGrantExpiry = 30,
+    InvalidUpgradeEta = 31,
+    NoUpgradeScheduled = 32,
+    UpgradeTimelockActive = 33,
+    MigrationPending = 34,
+    MigrationCursorAhead = 35,
+    InvalidMigrationBatch = 36,
+    GuardianNotSet = 37,
+    AlreadyPaused = 38,
+    NoGuardianPause = 39,
+    InvalidPauseWindow = 40,
+    OperationPaused = 41,
+    ParamChangeTooLarge = 42,
+    InvalidParamLimit = 43,
+    ParamOverrideRequired = 44,
+    ParamOverrideTimelockActive = 45,
+    NoParamOverride = 46,
+    /// The guardian's pause lapsed and governance has not paused, unpaused
+    /// or ratified since.
+    GuardianPauseSpent = 47,
+    /// The pending recovery names a different new holder than the caller
+    /// expected.
+    RecoveryHolderMismatch = 48,
+    /// Records cannot move until a loan hook is registered to vet the move.
+    LoanHookNotSet = 49,
+    /// `set_guardian` was called before `set_governance`.
+    GovernanceNotSet = 50,
 }
 
 #[contracttype]
 #[derive(Clone)]
 pub struct RemittanceMetadata {
     pub score: u32,
+    /// Merkle root over the holder's `RemittanceRecord`s; see
+    /// `verify_history_record` for the tree layout. All zeroes means no
+    /// history has been committed.
     pub history_hash: BytesN<32>,
 }
 
+/// How much of a private record a consumer may read. Each scope includes
+/// the ones before it.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AccessScope {
+    /// Composite score only
+    Score,
+    /// Score, history hash and component breakdown
+    Metadata,
+    /// Everything above plus the score history
+    History,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AccessGrant {
+    pub scope: AccessScope,
+    /// Last ledger at which the grant is honoured
+    pub expiry_ledger: u32,
+}
+
+/// A pending request to move a lost holder's record to `new_holder`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RecoveryRequest {
+    pub new_holder: Address,
+    pub requested_at: u32,
+    /// First ledger at which the admin may execute without the old holder
+    pub executable_after: u32,
+}
+
+/// Independent dimensions behind the composite score.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ScoreComponent {
+    Repayment,
+    Remittance,
+    Tenure,
+    Utilization,
+}
+
+/// Per-component scores, each on the same 0..=`MAX_SCORE` scale as the
+/// composite.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ScoreBreakdown {
+    pub repayment: u32,
+    pub remittance: u32,
+    pub tenure: u32,
+    pub utilization: u32,
+}
+
+/// Basis-point weights blending a `ScoreBreakdown` into the composite
+/// score; they must sum to 10_000.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ScoreWeights {
+    pub repayment_bps: u32,
+    pub remittance_bps: u32,
+    pub tenure_bps: u32,
+    pub utilization_bps: u32,
+}
+
+/// One remittance history leaf committed to by `history_hash`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RemittanceRecord {
+    pub period: u32,
+    pub amount: i128,
+    pub corridor: Symbol,
+    pub count: u32,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct ScoreHistoryEntry {
@@ -39,6 +163,245 @@ pub struct ScoreHistoryEntry {
     pub old_score: u32,
     pub new_score: u32,
     pub reason: Symbol,
+    /// Version of the `ScoringPolicy` in force when the change was made.
+    /// `0` marks entries recorded before scoring policies existed.
+    pub policy_version: u32,
+}
+
+/// Pre-v3 history entry layout, read only to migrate old histories.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LegacyScoreHistoryEntry {
+    pub ledger: u32,
+    pub old_score: u32,
+    pub new_score: u32,
+    pub reason: Symbol,
+}
+
+/// Rules that turn loan outcomes into score changes.
+///
+/// Repayment points are `amount / units_per_point`; points beyond
+/// `full_rate_points` in a single repayment only count at
+/// `diminishing_rate_bps`. Gains (repayment points plus on-time bonus) are
+/// capped at `max_gain_per_period` per `period_ledgers` window.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ScoringPolicy {
+    /// Assigned by the contract on every update; callers' value is ignored.
+    pub version: u32,
+    pub units_per_point: i128,
+    pub full_rate_points: u32,
+    pub diminishing_rate_bps: u32,
+    pub on_time_bonus: u32,
+    pub late_penalty_base: u32,
+    pub late_penalty_per_day: u32,
+    pub default_penalty: u32,
+    /// `0` disables the per-period cap.
+    pub max_gain_per_period: u32,
+    pub period_ledgers: u32,
+    /// Floor applied by penalties.
+    pub min_score: u32,
+}
+
+/// Inactivity decay: once `inactivity_ledgers` pass without repayment
+/// activity, the score moves `points_per_period` toward `baseline` for every
+/// further `period_ledgers`. It never crosses the baseline.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DecayConfig {
+    pub inactivity_ledgers: u32,
+    pub period_ledgers: u32,
+    pub points_per_period: u32,
+    pub baseline: u32,
+}
+
+/// Payload a remittance provider signs for one user and period.
+///
+/// The signed message is the XDR encoding of
+/// `(nft_contract_address, RemittanceAttestation)`, which binds the
+/// attestation to this deployment.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RemittanceAttestation {
+    pub user: Address,
+    pub period: u32,
+    pub amount: i128,
+    pub corridor: Symbol,
+    pub count: u32,
+}
+
+/// How verified remittance flows translate into score points.
+///
+/// Each attestation earns `amount / units_per_point` points, capped at
+/// `max_points_per_attestation`, plus `regularity_bonus` when the user also
+/// has an attestation for the immediately preceding period.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AttestationScoring {
+    pub units_per_point: i128,
+    pub max_points_per_attestation: u32,
+    pub regularity_bonus: u32,
+}
+
+/// Decay clock for one holder. `periods_applied` counts decay periods since
+/// `last_activity` that are already reflected in the stored score.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ActivityRecord {
+    pub last_activity: u32,
+    pub periods_applied: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ScoreGainWindow {
+    pub period_start: u32,
+    pub gained: u32,
+}
+
+/// Operations that can be paused individually with `pause_operation`.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum NftOperation {
+    Minting,
+    Transfers,
+    ScoreUpdates,
+}
+
+/// Numeric parameters whose setters are subject to `ParamLimit`s.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum NftParam {
+    RecoveryTimelock,
+    DefaultBurnThreshold,
+    GuardianPauseLedgers,
+    /// `ScoringPolicy` fields
+    UnitsPerPoint,
+    FullRatePoints,
+    DiminishingRate,
+    OnTimeBonus,
+    LatePenaltyBase,
+    LatePenaltyPerDay,
+    DefaultPenalty,
+    MaxGainPerPeriod,
+    GainPeriodLedgers,
+    MinScore,
+    /// `DecayConfig` fields; a disabled config counts as zero points per
+    /// period
+    DecayInactivity,
+    DecayPeriod,
+    DecayPoints,
+    DecayBaseline,
+    /// `ScoreWeights` fields
+    RepaymentWeight,
+    RemittanceWeight,
+    TenureWeight,
+    UtilizationWeight,
+    /// `AttestationScoring` fields
+    AttestationUnitsPerPoint,
+    AttestationMaxPoints,
+    AttestationRegularityBonus,
+}
+
+/// How far a parameter may move within one window. The allowance is the
+/// larger of `max_change` (in the parameter's own units) and `max_change_bps`
+/// of the value at the start of the window.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParamLimit {
+    pub max_change: i128,
+    pub max_change_bps: u32,
+    pub window_ledgers: u32,
+}
+
+/// The value a parameter started its current limit window at.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParamWindow {
+    pub start_ledger: u32,
+    pub baseline: i128,
+}
+
+/// A change that exceeds the registered limits: a value jump, or a looser
+/// (or removed) limit.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParamOverride {
+    Value(i128),
+    Limit(ParamLimit),
+    RemoveLimit,
+}
+
+/// An override waiting out `PARAM_OVERRIDE_DELAY_LEDGERS`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingParamOverride {
+    pub change: ParamOverride,
+    pub eta: u32,
+}
+
+/// A before or after value in an `AdminAction`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AdminValue {
+    None,
+    Bool(bool),
+    U32(u32),
+    I128(i128),
+    Address(Address),
+    Hash(BytesN<32>),
+    /// Per-operation pause flag
+    Operation(NftOperation, bool),
+    Limit(NftParam, ParamLimit),
+    Override(NftParam, ParamOverride),
+    /// user → per-holder flag (soulbound override, seizure)
+    UserFlag(Address, bool),
+    /// user → composite score around a direct admin score write
+    UserScore(Address, u32),
+    /// user → remittance history root
+    UserHash(Address, BytesN<32>),
+}
+
+/// One privileged call, as recorded in the admin audit log.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminAction {
+    pub ledger: u32,
+    pub actor: Address,
+    /// Name of the entrypoint that was called
+    pub action: Symbol,
+    pub old: AdminValue,
+    pub new: AdminValue,
+}
+
+/// Where storage stands relative to the running code.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MigrationStatus {
+    /// Schema version of stored data
+    pub version: u32,
+    /// `CURRENT_VERSION` of the running code
+    pub target_version: u32,
+    /// Next token id the current step will process
+    pub cursor: u32,
+    pub complete: bool,
+}
+
+/// One completed migration step.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MigrationRecord {
+    pub from_version: u32,
+    pub to_version: u32,
+    pub ledger: u32,
+}
+
+/// An upgrade announced ahead of time; executable from ledger `eta`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ScheduledUpgrade {
+    pub wasm_hash: BytesN<32>,
+    pub eta: u32,
 }
 
 #[contracttype]
@@ -56,6 +419,69 @@ pub enum DataKey {
     TransferCooldown(Address),
     Paused,
     ProposedAdmin,
+    /// Wasm hash announced by `schedule_upgrade`
+    ScheduledUpgrade,
+    /// Address allowed to call `guardian_pause`
+    Guardian,
+    /// Ledger at which the active guardian pause lapses; absent for admin pauses
+    GuardianPauseExpiry,
+    /// How many ledgers a guardian pause lasts unless ratified
+    GuardianPauseLedgers,
+    /// Per-operation pause flag, independent of the global `Paused`
+    OperationPaused(NftOperation),
+    /// param → registered rate-of-change limit
+    ParamLimit(NftParam),
+    /// param → current limit window
+    ParamWindow(NftParam),
+    /// param → override waiting for its timelock
+    ParamOverride(NftParam),
+    /// Next token id the running migration step will process
+    MigrationCursor,
+    /// Completed migration steps, oldest first
+    MigrationLog,
+    ScoringPolicy,
+    /// Single-Vec history with policy versions; superseded by pages.
+    ScoreHistoryV2(Address),
+    ScoreGainWindow(Address),
+    DecayConfig,
+    Activity(Address),
+    /// (token id, page) → up to `SCORE_HISTORY_PAGE_SIZE` entries, oldest first
+    ScoreHistoryPage(u32, u32),
+    /// Total number of history entries recorded for a token
+    ScoreHistoryLen(u32),
+    /// Component scores; absent until first component change, in which case
+    /// every component equals the stored composite
+    ScoreBreakdown(Address),
+    ScoreWeights,
+    /// Registered ed25519 public key of a remittance provider
+    AttestationProvider(BytesN<32>),
+    /// (provider, user, period) → attestation already accepted
+    Attestation(BytesN<32>, Address, u32),
+    LastAttestedPeriod(Address),
+    AttestationScoring,
+    /// Head of the hash chain over a holder's accepted attestations
+    AttestationChainHead(Address),
+    /// Contract-wide default for whether records may be transferred
+    SoulboundDefault,
+    /// Per-holder override of `SoulboundDefault`
+    Soulbound(Address),
+    /// lost holder → pending `RecoveryRequest`
+    Recovery(Address),
+    RecoveryTimelock,
+    /// Contract notified through `LoanHookInterface` when a record moves
+    LoanHook,
+    /// Next token id to hand out; ids start at 1 and are never reused
+    NextTokenId,
+    TokenOwner(u32),
+    TokenOf(Address),
+    CollectionName,
+    CollectionSymbol,
+    /// Prefix for `token_uri`, e.g. `ipfs://<cid>/`
+    BaseUri,
+    /// Holder has opted into selective disclosure
+    Private(Address),
+    /// (holder, consumer) → `AccessGrant`
+    AccessGrant(Address, Address),
 }
 
 #[contract]
@@ -67,13 +493,34 @@ impl RemittanceNFT {
     const INSTANCE_TTL_BUMP: u32 = 518400;
     const PERSISTENT_TTL_THRESHOLD: u32 = 17280;
     const PERSISTENT_TTL_BUMP: u32 = 518400;
-    const CURRENT_VERSION: u32 = 2;
+    const CURRENT_VERSION: u32 = 5;
+    const MIN_UPGRADE_DELAY_LEDGERS: u32 = 17280;
+    const PARAM_OVERRIDE_DELAY_LEDGERS: u32 = 7 * 17280;
+    const DEFAULT_PARAM_WINDOW_LEDGERS: u32 = 17280;
+    const DEFAULT_PARAM_MAX_CHANGE_BPS: u32 = 5000;
+    pub const ADMIN_LOG_PAGE_SIZE: u32 = 50;
+    pub const MAX_ADMIN_LOG_LIMIT: u32 = 100;
+    const DEFAULT_GUARDIAN_PAUSE_LEDGERS: u32 = 3 * 17280;
     const DEFAULT_BURN_THRESHOLD: u32 = 3;
-    pub const MAX_SCORE_HISTORY_ENTRIES: u32 = 50;
+    pub const SCORE_HISTORY_PAGE_SIZE: u32 = 50;
+    pub const MAX_SCORE_HISTORY_LIMIT: u32 = 100;
+    pub const MIGRATE_BATCH_SIZE: u32 = 50;
     const TRANSFER_COOLDOWN_LEDGERS: u32 = 17280;
     const MIN_CREDIT_SCORE: u32 = 300;
     pub const MAX_SCORE: u32 = 850;
     pub const MAX_ALLOWED_BURN_THRESHOLD: u32 = 1000; // Set as appropriate for your business logic
+    const LEDGERS_PER_DAY: u32 = 17280;
+    const BPS_DENOMINATOR: u32 = 10_000;
+    const DEFAULT_ATTESTATION_UNITS_PER_POINT: i128 = 1_000;
+    const DEFAULT_ATTESTATION_MAX_POINTS: u32 = 10;
+    const DEFAULT_ATTESTATION_REGULARITY_BONUS: u32 = 2;
+    /// Share of the composite that verified remittance flows carry until the
+    /// admin sets `ScoreWeights`; the rest is repayment.
+    const DEFAULT_REMITTANCE_WEIGHT_BPS: u32 = 2_000;
+    const DEFAULT_RECOVERY_TIMELOCK_LEDGERS: u32 = 7 * 17280;
+    const DEFAULT_COLLECTION_NAME: &'static str = "RemitLend Credit";
+    const DEFAULT_COLLECTION_SYMBOL: &'static str = "RLCREDIT";
+    pub const MAX_BASE_URI_LEN: u32 = 200;
 
     fn admin_key() -> soroban_sdk::Symbol {
         symbol_short!("ADMIN")
@@ -83,6 +530,18 @@ impl RemittanceNFT {
         symbol_short!("BURNTHR")
     }
 
+    fn governance_key() -> soroban_sdk::Symbol {
+        symbol_short!("GOV")
+    }
+    // `DataKey` is at the contracttype variant limit, so the admin audit log
+    // is keyed by symbol.
+    fn admin_log_len_key() -> soroban_sdk::Symbol {
+        symbol_short!("ADMLOGLEN")
+    }
+    fn admin_log_page_key(page: u32) -> (soroban_sdk::Symbol, u32) {
+        (symbol_short!("ADMLOG"), page)
+    }
+
     fn bump_instance_ttl(env: &Env) {
         env.storage()
             .instance()
@@ -134,7 +593,8 @@ impl RemittanceNFT {
         let metadata_key = DataKey::Metadata(user.clone());
         if let Some(metadata) = env.storage().persistent().get(&metadata_key) {
             Self::bump_persistent_ttl(env, &metadata_key);
-            return Some(metadata);
+            let metadata = Self::apply_decay(env, user, metadata);
+            return Some(Self::sync_composite(env, user, metadata));
         }
 
         let score_key = DataKey::Score(user.clone());
@@ -148,45 +608,351 @@ impl RemittanceNFT {
                 .set(&metadata_key, &migrated_metadata);
             Self::bump_persistent_ttl(env, &metadata_key);
             env.storage().persistent().remove(&score_key);
-            return Some(migrated_metadata);
+            return Some(Self::apply_decay(env, user, migrated_metadata));
         }
 
         None
     }
 
-    fn get_score_history_or_default(env: &Env, user: &Address) -> Vec<ScoreHistoryEntry> {
-        let key = DataKey::ScoreHistory(user.clone());
+    fn attestation_scoring(env: &Env) -> AttestationScoring {
+        env.storage()
+            .instance()
+            .get(&DataKey::AttestationScoring)
+            .unwrap_or(AttestationScoring {
+                units_per_point: Self::DEFAULT_ATTESTATION_UNITS_PER_POINT,
+                max_points_per_attestation: Self::DEFAULT_ATTESTATION_MAX_POINTS,
+                regularity_bonus: Self::DEFAULT_ATTESTATION_REGULARITY_BONUS,
+            })
+    }
+
+    fn score_weights(env: &Env) -> ScoreWeights {
+        env.storage()
+            .instance()
+            .get(&DataKey::ScoreWeights)
+            .unwrap_or(ScoreWeights {
+                repayment_bps: Self::BPS_DENOMINATOR - Self::DEFAULT_REMITTANCE_WEIGHT_BPS,
+                remittance_bps: Self::DEFAULT_REMITTANCE_WEIGHT_BPS,
+                tenure_bps: 0,
+                utilization_bps: 0,
+            })
+    }
+
+    fn read_breakdown(env: &Env, user: &Address, score: u32) -> ScoreBreakdown {
+        let key = DataKey::ScoreBreakdown(user.clone());
+        match env.storage().persistent().get(&key) {
+            Some(breakdown) => {
+                Self::bump_persistent_ttl(env, &key);
+                breakdown
+            }
+            None => ScoreBreakdown {
+                repayment: score,
+                remittance: score,
+                tenure: score,
+                utilization: score,
+            },
+        }
+    }
+
+    fn component_score(breakdown: &ScoreBreakdown, component: ScoreComponent) -> u32 {
+        match component {
+            ScoreComponent::Repayment => breakdown.repayment,
+            ScoreComponent::Remittance => breakdown.remittance,
+            ScoreComponent::Tenure => breakdown.tenure,
+            ScoreComponent::Utilization => breakdown.utilization,
+        }
+    }
+
+    fn set_component_score(breakdown: &mut ScoreBreakdown, component: ScoreComponent, value: u32) {
+        match component {
+            ScoreComponent::Repayment => breakdown.repayment = value,
+            ScoreComponent::Remittance => breakdown.remittance = value,
+            ScoreComponent::Tenure => breakdown.tenure = value,
+            ScoreComponent::Utilization => breakdown.utilization = value,
+        }
+    }
+
+    fn composite_score(env: &Env, breakdown: &ScoreBreakdown) -> u32 {
+        let weights = Self::score_weights(env);
+        let weighted = breakdown.repayment as u64 * weights.repayment_bps as u64
+            + breakdown.remittance as u64 * weights.remittance_bps as u64
+            + breakdown.tenure as u64 * weights.tenure_bps as u64
+            + breakdown.utilization as u64 * weights.utilization_bps as u64;
+        (weighted / Self::BPS_DENOMINATOR as u64) as u32
+    }
+
+    /// Stores `breakdown`, recomputes the composite into `metadata` and
+    /// records the change in history. Returns the previous composite.
+    fn commit_breakdown(
+        env: &Env,
+        user: &Address,
+        metadata: &mut RemittanceMetadata,
+        breakdown: &ScoreBreakdown,
+        reason: Symbol,
+    ) -> u32 {
+        let breakdown_key = DataKey::ScoreBreakdown(user.clone());
+        env.storage().persistent().set(&breakdown_key, breakdown);
+        Self::bump_persistent_ttl(env, &breakdown_key);
+
+        let old_score = metadata.score;
+        metadata.score = Self::composite_score(env, breakdown);
+        let metadata_key = DataKey::Metadata(user.clone());
+        env.storage().persistent().set(&metadata_key, metadata);
+        Self::bump_persistent_ttl(env, &metadata_key);
+        Self::append_score_history(env, user, old_score, metadata.score, reason);
+        old_score
+    }
+
+    /// Re-blends the composite after a weight change. Holders whose
+    /// breakdown was never split still carry one value everywhere, so only
+    /// stored breakdowns need checking.
+    fn sync_composite(
+        env: &Env,
+        user: &Address,
+        mut metadata: RemittanceMetadata,
+    ) -> RemittanceMetadata {
+        let breakdown: Option<ScoreBreakdown> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::ScoreBreakdown(user.clone()));
+        if let Some(breakdown) = breakdown {
+            let composite = Self::composite_score(env, &breakdown);
+            if composite != metadata.score {
+                let old_score = metadata.score;
+                metadata.score = composite;
+                let metadata_key = DataKey::Metadata(user.clone());
+                env.storage().persistent().set(&metadata_key, &metadata);
+                Self::bump_persistent_ttl(env, &metadata_key);
+                Self::append_score_history(
+                    env,
+                    user,
+                    old_score,
+                    composite,
+                    symbol_short!("WEIGHTS"),
+                );
+            }
+        }
+        metadata
+    }
+
+    fn decay_config(env: &Env) -> Option<DecayConfig> {
+        env.storage().instance().get(&DataKey::DecayConfig)
+    }
+
+    /// Restart the decay clock after repayment activity.
+    fn touch_activity(env: &Env, user: &Address) {
+        let key = DataKey::Activity(user.clone());
+        let record = ActivityRecord {
+            last_activity: env.ledger().sequence(),
+            periods_applied: 0,
+        };
+        env.storage().persistent().set(&key, &record);
+        Self::bump_persistent_ttl(env, &key);
+    }
+
+    /// Ledger of `user`'s most recent score history entry, if any.
+    fn last_history_ledger(env: &Env, user: &Address) -> Option<u32> {
+        let (token_id, len) = Self::score_history_index(env, user)?;
+        let last = len.checked_sub(1)?;
+        Self::read_score_history_page(env, token_id, last / Self::SCORE_HISTORY_PAGE_SIZE)
+            .get(last % Self::SCORE_HISTORY_PAGE_SIZE)
+            .map(|entry| entry.ledger)
+    }
+
+    /// Persists any decay that has come due since the score was last
+    /// written. The result depends only on the ledger sequence, so reads
+    /// agree regardless of when (or whether) a keeper touches the account.
+    ///
+    /// Holders without an activity record (minted before decay existed)
+    /// are idle since their last score history entry, or since ledger 0 if
+    /// they have none. Nothing is written until decay is actually due.
+    fn apply_decay(
+        env: &Env,
+        user: &Address,
+        mut metadata: RemittanceMetadata,
+    ) -> RemittanceMetadata {
+        let config = match Self::decay_config(env) {
+            Some(config) => config,
+            None => return metadata,
+        };
+
+        let key = DataKey::Activity(user.clone());
+        let mut record = match env
+            .storage()
+            .persistent()
+            .get::<DataKey, ActivityRecord>(&key)
+        {
+            Some(record) => {
+                Self::bump_persistent_ttl(env, &key);
+                record
+            }
+            None => ActivityRecord {
+                last_activity: Self::last_history_ledger(env, user).unwrap_or(0),
+                periods_applied: 0,
+            },
+        };
+
+        let idle = env
+            .ledger()
+            .sequence()
+            .saturating_sub(record.last_activity)
+            .saturating_sub(config.inactivity_ledgers);
+        let due_periods = idle / config.period_ledgers;
+        if due_periods <= record.periods_applied {
+            return metadata;
+        }
+
+        let pending = (due_periods - record.periods_applied) as u64;
+        let step = pending.saturating_mul(config.points_per_period as u64);
+        let decay_toward_baseline = |score: u32| {
+            if score > config.baseline {
+                score - step.min((score - config.baseline) as u64) as u32
+            } else {
+                score + step.min((config.baseline - score) as u64) as u32
+            }
+        };
+
+        record.periods_applied = due_periods;
+        env.storage().persistent().set(&key, &record);
+        Self::bump_persistent_ttl(env, &key);
+
+        let breakdown = Self::read_breakdown(env, user, metadata.score);
+        let decayed = ScoreBreakdown {
+            repayment: decay_toward_baseline(breakdown.repayment),
+            remittance: decay_toward_baseline(breakdown.remittance),
+            tenure: decay_toward_baseline(breakdown.tenure),
+            utilization: decay_toward_baseline(breakdown.utilization),
+        };
+        if decayed != breakdown {
+            let old_score =
+                Self::commit_breakdown(env, user, &mut metadata, &decayed, symbol_short!("DECAY"));
+            Self::publish_score_event(
+                env,
+                symbol_short!("ScoreDcy"),
+                user,
+                (old_score, metadata.score),
+            );
+        }
+        metadata
+    }
+
+    /// Reads and removes a pre-pagination history, converting pre-v3
+    /// entries to policy version 0.
+    fn take_unpaged_score_history(env: &Env, user: &Address) -> Option<Vec<ScoreHistoryEntry>> {
+        let v2_key = DataKey::ScoreHistoryV2(user.clone());
         if let Some(history) = env
+            .storage()
+            .persistent()
+            .get::<DataKey, Vec<ScoreHistoryEntry>>(&v2_key)
+        {
+            env.storage().persistent().remove(&v2_key);
+            return Some(history);
+        }
+
+        let legacy_key = DataKey::ScoreHistory(user.clone());
+        let legacy = env
+            .storage()
+            .persistent()
+            .get::<DataKey, Vec<LegacyScoreHistoryEntry>>(&legacy_key)?;
+        let mut history = Vec::new(env);
+        for entry in legacy.iter() {
+            history.push_back(ScoreHistoryEntry {
+                ledger: entry.ledger,
+                old_score: entry.old_score,
+                new_score: entry.new_score,
+                reason: entry.reason,
+                policy_version: 0,
+            });
+        }
+        env.storage().persistent().remove(&legacy_key);
+        Some(history)
+    }
+
+    /// Token id `user`'s history pages are keyed by, and the number of
+    /// entries recorded, paginating any single-Vec history left over from
+    /// before v4 on first access. Pages stay with the token, so moving a
+    /// record never copies them. `None` for addresses without a token.
+    fn score_history_index(env: &Env, user: &Address) -> Option<(u32, u32)> {
+        let token_id = env
+            .storage()
+            .persistent()
+            .get::<DataKey, u32>(&DataKey::TokenOf(user.clone()))?;
+        let len_key = DataKey::ScoreHistoryLen(token_id);
+        if let Some(len) = env.storage().persistent().get::<DataKey, u32>(&len_key) {
+            Self::bump_persistent_ttl(env, &len_key);
+            return Some((token_id, len));
+        }
+
+        let history = match Self::take_unpaged_score_history(env, user) {
+            Some(history) => history,
+            None => return Some((token_id, 0)),
+        };
+        let mut page = Vec::new(env);
+        let mut page_index = 0u32;
+        for entry in history.iter() {
+            page.push_back(entry);
+            if page.len() == Self::SCORE_HISTORY_PAGE_SIZE {
+                Self::write_score_history_page(env, token_id, page_index, &page);
+                page = Vec::new(env);
+                page_index += 1;
+            }
+        }
+        if !page.is_empty() {
+            Self::write_score_history_page(env, token_id, page_index, &page);
+        }
+        let len = history.len();
+        Self::write_score_history_len(env, token_id, len);
+        Some((token_id, len))
+    }
+
+    fn score_history_len(env: &Env, user: &Address) -> u32 {
+        Self::score_history_index(env, user).map_or(0, |(_, len)| len)
+    }
+
+    fn write_score_history_len(env: &Env, token_id: u32, len: u32) {
+        let key = DataKey::ScoreHistoryLen(token_id);
+        env.storage().persistent().set(&key, &len);
+        Self::bump_persistent_ttl(env, &key);
+    }
+
+    fn read_score_history_page(env: &Env, token_id: u32, page: u32) -> Vec<ScoreHistoryEntry> {
+        let key = DataKey::ScoreHistoryPage(token_id, page);
+        match env
             .storage()
             .persistent()
             .get::<DataKey, Vec<ScoreHistoryEntry>>(&key)
         {
-            Self::bump_persistent_ttl(env, &key);
-            // Migration: truncate to the most recent MAX_SCORE_HISTORY_ENTRIES if over limit
-            let len = history.len();
-            if len > Self::MAX_SCORE_HISTORY_ENTRIES {
-                let keep_from = len - Self::MAX_SCORE_HISTORY_ENTRIES;
-                let mut truncated = Vec::new(env);
-                for (idx, entry) in history.iter().enumerate() {
-                    if (idx as u32) >= keep_from {
-                        truncated.push_back(entry);
-                    }
-                }
-                Self::write_score_history(env, user, truncated.clone());
-                return truncated;
+            Some(entries) => {
+                Self::bump_persistent_ttl(env, &key);
+                entries
             }
-            history
-        } else {
-            Vec::new(env)
+            None => Vec::new(env),
         }
     }
 
-    fn write_score_history(env: &Env, user: &Address, history: Vec<ScoreHistoryEntry>) {
-        let key = DataKey::ScoreHistory(user.clone());
-        env.storage().persistent().set(&key, &history);
+    fn write_score_history_page(
+        env: &Env,
+        token_id: u32,
+        page: u32,
+        entries: &Vec<ScoreHistoryEntry>,
+    ) {
+        let key = DataKey::ScoreHistoryPage(token_id, page);
+        env.storage().persistent().set(&key, entries);
         Self::bump_persistent_ttl(env, &key);
     }
 
+    /// Gives a record minted before token ids existed its id, so its
+    /// history has pages to live in.
+    fn ensure_token_id(env: &Env, user: &Address) {
+        if !env
+            .storage()
+            .persistent()
+            .has(&DataKey::TokenOf(user.clone()))
+        {
+            Self::assign_token_id(env, user);
+        }
+    }
+
+    /// Appends one entry, touching only the last page and the length.
     fn append_score_history(
         env: &Env,
         user: &Address,
@@ -194,70 +960,411 @@ impl RemittanceNFT {
         new_score: u32,
         reason: Symbol,
     ) {
-        let current_history = Self::get_score_history_or_default(env, user);
-        let mut next_history = Vec::new(env);
-        let current_len = current_history.len();
-        let keep_from = current_len.saturating_sub(Self::MAX_SCORE_HISTORY_ENTRIES - 1);
-
-        for (idx, entry) in current_history.iter().enumerate() {
-            if (idx as u32) >= keep_from {
-                next_history.push_back(entry);
-            }
-        }
-
-        next_history.push_back(ScoreHistoryEntry {
+        Self::ensure_token_id(env, user);
+        let (token_id, len) =
+            Self::score_history_index(env, user).expect("token id was just ensured");
+        let page_index = len / Self::SCORE_HISTORY_PAGE_SIZE;
+        let mut page = Self::read_score_history_page(env, token_id, page_index);
+        page.push_back(ScoreHistoryEntry {
             ledger: env.ledger().sequence(),
             old_score,
             new_score,
             reason,
+            policy_version: Self::scoring_policy(env).version,
         });
-        Self::write_score_history(env, user, next_history);
+        Self::write_score_history_page(env, token_id, page_index, &page);
+        Self::write_score_history_len(env, token_id, len + 1);
     }
 
-    fn has_active_nft(env: &Env, user: &Address) -> bool {
-        let metadata_key = DataKey::Metadata(user.clone());
-        if env.storage().persistent().has(&metadata_key) {
-            Self::bump_persistent_ttl(env, &metadata_key);
-            return true;
-        }
-
-        let score_key = DataKey::Score(user.clone());
-        let has_legacy = env.storage().persistent().has(&score_key);
-        if has_legacy {
-            Self::bump_persistent_ttl(env, &score_key);
+    fn default_scoring_policy() -> ScoringPolicy {
+        ScoringPolicy {
+            version: 1,
+            units_per_point: 100,
+            full_rate_points: Self::MAX_SCORE,
+            diminishing_rate_bps: Self::BPS_DENOMINATOR,
+            on_time_bonus: 0,
+            late_penalty_base: 10,
+            late_penalty_per_day: 0,
+            default_penalty: 50,
+            max_gain_per_period: 0,
+            period_ledgers: 0,
+            min_score: Self::MIN_CREDIT_SCORE,
         }
-        has_legacy
     }
 
-    fn default_burn_threshold(env: &Env) -> u32 {
-        let key = Self::burn_threshold_key();
-        Self::bump_instance_ttl(env);
+    fn scoring_policy(env: &Env) -> ScoringPolicy {
         env.storage()
             .instance()
-            .get(&key)
-            .unwrap_or(Self::DEFAULT_BURN_THRESHOLD)
+            .get(&DataKey::ScoringPolicy)
+            .unwrap_or_else(Self::default_scoring_policy)
     }
 
-    fn has_any_remittance_state(env: &Env, user: &Address) -> bool {
-        env.storage()
-            .persistent()
-            .has(&DataKey::Metadata(user.clone()))
-            || env
-                .storage()
-                .persistent()
-                .has(&DataKey::Score(user.clone()))
+    fn validate_scoring_policy(policy: &ScoringPolicy) -> Result<(), NftError> {
+        if policy.units_per_point <= 0
+            || policy.diminishing_rate_bps > Self::BPS_DENOMINATOR
+            || policy.min_score > Self::MAX_SCORE
+            || (policy.max_gain_per_period > 0 && policy.period_ledgers == 0)
+        {
+            return Err(NftError::InvalidScoringPolicy);
+        }
+        Ok(())
     }
 
-    fn burn_internal(env: &Env, user: &Address) {
-        env.storage()
-            .persistent()
-            .remove(&DataKey::Metadata(user.clone()));
-        env.storage()
-            .persistent()
+    fn repayment_points(policy: &ScoringPolicy, repayment_amount: i128) -> u32 {
+        let raw = repayment_amount / policy.units_per_point;
+        let raw = if raw > (Self::MAX_SCORE as i128) {
+            Self::MAX_SCORE
+        } else {
+            raw as u32
+        };
+        if raw <= policy.full_rate_points {
+            return raw;
+        }
+        let excess = (raw - policy.full_rate_points) as u64 * policy.diminishing_rate_bps as u64
+            / Self::BPS_DENOMINATOR as u64;
+        policy.full_rate_points.saturating_add(excess as u32)
+    }
+
+    /// Clips `points` to what is left of the user's allowance for the
+    /// current period and records the gain against it.
+    fn consume_gain_allowance(
+        env: &Env,
+        policy: &ScoringPolicy,
+        user: &Address,
+        points: u32,
+    ) -> u32 {
+        if policy.max_gain_per_period == 0 || points == 0 {
+            return points;
+        }
+        let key = DataKey::ScoreGainWindow(user.clone());
+        let now = env.ledger().sequence();
+        let mut window: ScoreGainWindow =
+            env.storage()
+                .persistent()
+                .get(&key)
+                .unwrap_or(ScoreGainWindow {
+                    period_start: now,
+                    gained: 0,
+                });
+        if now >= window.period_start.saturating_add(policy.period_ledgers) {
+            window = ScoreGainWindow {
+                period_start: now,
+                gained: 0,
+            };
+        }
+        let allowed = policy
+            .max_gain_per_period
+            .saturating_sub(window.gained)
+            .min(points);
+        window.gained = window.gained.saturating_add(allowed);
+        env.storage().persistent().set(&key, &window);
+        Self::bump_persistent_ttl(env, &key);
+        allowed
+    }
+
+    /// Adds up to `points` to one component, subject to the policy's
+    /// per-period gain cap.
+    fn raise_score(
+        env: &Env,
+        user: &Address,
+        policy: &ScoringPolicy,
+        component: ScoreComponent,
+        points: u32,
+        reason: Symbol,
+    ) -> Result<(), NftError> {
+        let mut metadata = Self::get_or_migrate_metadata(env, user).ok_or(NftError::NftNotFound)?;
+
+        let mut breakdown = Self::read_breakdown(env, user, metadata.score);
+        let current = Self::component_score(&breakdown, component);
+        let headroom = Self::MAX_SCORE.saturating_sub(current);
+        let gain = Self::consume_gain_allowance(env, policy, user, points.min(headroom));
+        if gain == 0 {
+            return Ok(());
+        }
+        Self::set_component_score(&mut breakdown, component, current + gain);
+
+        Self::commit_breakdown(env, user, &mut metadata, &breakdown, reason);
+        Self::publish_score_event(env, symbol_short!("ScoreUpd"), user, metadata.score);
+        Ok(())
+    }
+
+    /// Subtracts `penalty_points` from one component, never taking it below
+    /// the policy's `min_score`.
+    fn lower_score(
+        env: &Env,
+        user: &Address,
+        policy: &ScoringPolicy,
+        component: ScoreComponent,
+        penalty_points: u32,
+        reason: Symbol,
+    ) -> Result<(), NftError> {
+        let mut metadata = Self::get_or_migrate_metadata(env, user).ok_or(NftError::NftNotFound)?;
+
+        let mut breakdown = Self::read_breakdown(env, user, metadata.score);
+        let current = Self::component_score(&breakdown, component);
+        let lowered = current.saturating_sub(penalty_points).max(policy.min_score);
+        if lowered >= current {
+            return Ok(());
+        }
+        Self::set_component_score(&mut breakdown, component, lowered);
+
+        let old_score = Self::commit_breakdown(env, user, &mut metadata, &breakdown, reason);
+        Self::publish_score_event(
+            env,
+            symbol_short!("ScoreDecr"),
+            user,
+            (old_score, metadata.score, symbol_short!("PEN")),
+        );
+        Ok(())
+    }
+
+    fn has_active_nft(env: &Env, user: &Address) -> bool {
+        let metadata_key = DataKey::Metadata(user.clone());
+        if env.storage().persistent().has(&metadata_key) {
+            Self::bump_persistent_ttl(env, &metadata_key);
+            return true;
+        }
+
+        let score_key = DataKey::Score(user.clone());
+        let has_legacy = env.storage().persistent().has(&score_key);
+        if has_legacy {
+            Self::bump_persistent_ttl(env, &score_key);
+        }
+        has_legacy
+    }
+
+    fn default_burn_threshold(env: &Env) -> u32 {
+        let key = Self::burn_threshold_key();
+        Self::bump_instance_ttl(env);
+        env.storage()
+            .instance()
+            .get(&key)
+            .unwrap_or(Self::DEFAULT_BURN_THRESHOLD)
+    }
+
+    fn has_any_remittance_state(env: &Env, user: &Address) -> bool {
+        env.storage()
+            .persistent()
+            .has(&DataKey::Metadata(user.clone()))
+            || env
+                .storage()
+                .persistent()
+                .has(&DataKey::Score(user.clone()))
+    }
+
+    fn soulbound(env: &Env, user: &Address) -> bool {
+        let key = DataKey::Soulbound(user.clone());
+        match env.storage().persistent().get::<DataKey, bool>(&key) {
+            Some(soulbound) => {
+                Self::bump_persistent_ttl(env, &key);
+                soulbound
+            }
+            None => env
+                .storage()
+                .instance()
+                .get(&DataKey::SoulboundDefault)
+                .unwrap_or(false),
+        }
+    }
+
+    fn recovery_timelock(env: &Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::RecoveryTimelock)
+            .unwrap_or(Self::DEFAULT_RECOVERY_TIMELOCK_LEDGERS)
+    }
+
+    fn loan_hook(env: &Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::LoanHook)
+    }
+
+    fn complete_recovery(
+        env: &Env,
+        lost: &Address,
+        request: &RecoveryRequest,
+    ) -> Result<(), NftError> {
+        let metadata = Self::get_or_migrate_metadata(env, lost).ok_or(NftError::NftNotFound)?;
+        if Self::has_any_remittance_state(env, &request.new_holder) {
+            return Err(NftError::DestinationOccupied);
+        }
+
+        Self::move_record(env, lost, &request.new_holder, &metadata, true)?;
+        env.events().publish(
+            (
+                symbol_short!("Recovered"),
+                lost.clone(),
+                request.new_holder.clone(),
+            ),
+            (),
+        );
+        Ok(())
+    }
+
+    fn is_private_record(env: &Env, user: &Address) -> bool {
+        let key = DataKey::Private(user.clone());
+        let private = env.storage().persistent().has(&key);
+        if private {
+            Self::bump_persistent_ttl(env, &key);
+        }
+        private
+    }
+
+    /// Score-change events carry the new score, so private records skip
+    /// them; granted consumers read the record through the `*_for` reads.
+    fn publish_score_event<D: IntoVal<Env, Val>>(
+        env: &Env,
+        topic: Symbol,
+        user: &Address,
+        data: D,
+    ) {
+        if !Self::is_private_record(env, user) {
+            env.events().publish((topic, user.clone()), data);
+        }
+    }
+
+    fn scope_rank(scope: AccessScope) -> u32 {
+        match scope {
+            AccessScope::Score => 1,
+            AccessScope::Metadata => 2,
+            AccessScope::History => 3,
+        }
+    }
+
+    fn grant_covers(env: &Env, user: &Address, consumer: &Address, scope: AccessScope) -> bool {
+        let key = DataKey::AccessGrant(user.clone(), consumer.clone());
+        match env.storage().persistent().get::<DataKey, AccessGrant>(&key) {
+            Some(grant) => {
+                Self::bump_persistent_ttl(env, &key);
+                env.ledger().sequence() <= grant.expiry_ledger
+                    && Self::scope_rank(grant.scope) >= Self::scope_rank(scope)
+            }
+            None => false,
+        }
+    }
+
+    /// Gate for the `*_for` reads: public records are open to any signed
+    /// consumer, private ones need a live grant of sufficient scope. This
+    /// holds for authorized minters too; LoanManager asks the borrower for
+    /// a grant when they request a loan.
+    fn require_read_access(
+        env: &Env,
+        user: &Address,
+        consumer: &Address,
+        scope: AccessScope,
+    ) -> Result<(), NftError> {
+        consumer.require_auth();
+        if consumer != user
+            && Self::is_private_record(env, user)
+            && !Self::grant_covers(env, user, consumer, scope)
+        {
+            return Err(NftError::AccessDenied);
+        }
+        Ok(())
+    }
+
+    fn read_score_history(
+        env: &Env,
+        user: &Address,
+        cursor: u32,
+        limit: u32,
+    ) -> Vec<ScoreHistoryEntry> {
+        let mut entries = Vec::new(env);
+        let Some((token_id, len)) = Self::score_history_index(env, user) else {
+            return entries;
+        };
+        if cursor >= len {
+            return entries;
+        }
+        let end = cursor
+            .saturating_add(limit.min(Self::MAX_SCORE_HISTORY_LIMIT))
+            .min(len);
+
+        let mut page_index = cursor / Self::SCORE_HISTORY_PAGE_SIZE;
+        let mut page = Self::read_score_history_page(env, token_id, page_index);
+        for idx in cursor..end {
+            if idx / Self::SCORE_HISTORY_PAGE_SIZE != page_index {
+                page_index = idx / Self::SCORE_HISTORY_PAGE_SIZE;
+                page = Self::read_score_history_page(env, token_id, page_index);
+            }
+            entries.push_back(
+                page.get(idx % Self::SCORE_HISTORY_PAGE_SIZE)
+                    .expect("history page truncated"),
+            );
+        }
+        entries
+    }
+
+    fn assign_token_id(env: &Env, user: &Address) -> u32 {
+        let token_id: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::NextTokenId)
+            .unwrap_or(1);
+        env.storage()
+            .instance()
+            .set(&DataKey::NextTokenId, &(token_id + 1));
+
+        let owner_key = DataKey::TokenOwner(token_id);
+        env.storage().persistent().set(&owner_key, user);
+        Self::bump_persistent_ttl(env, &owner_key);
+        let token_key = DataKey::TokenOf(user.clone());
+        env.storage().persistent().set(&token_key, &token_id);
+        Self::bump_persistent_ttl(env, &token_key);
+
+        env.events()
+            .publish((symbol_short!("mint"), user.clone()), token_id);
+        token_id
+    }
+
+    fn burn_internal(env: &Env, user: &Address) {
+        let token_key = DataKey::TokenOf(user.clone());
+        if let Some(token_id) = env.storage().persistent().get::<DataKey, u32>(&token_key) {
+            env.storage()
+                .persistent()
+                .remove(&DataKey::TokenOwner(token_id));
+            env.storage().persistent().remove(&token_key);
+            // Token ids are never reused, so the history pages are simply
+            // left to expire.
+            env.storage()
+                .persistent()
+                .remove(&DataKey::ScoreHistoryLen(token_id));
+            env.events()
+                .publish((symbol_short!("burn"), user.clone()), token_id);
+        }
+
+        env.storage()
+            .persistent()
+            .remove(&DataKey::Metadata(user.clone()));
+        env.storage()
+            .persistent()
             .remove(&DataKey::Score(user.clone()));
+        env.storage()
+            .persistent()
+            .remove(&DataKey::ScoreHistoryV2(user.clone()));
         env.storage()
             .persistent()
             .remove(&DataKey::ScoreHistory(user.clone()));
+        env.storage()
+            .persistent()
+            .remove(&DataKey::ScoreGainWindow(user.clone()));
+        env.storage()
+            .persistent()
+            .remove(&DataKey::Activity(user.clone()));
+        env.storage()
+            .persistent()
+            .remove(&DataKey::AttestationChainHead(user.clone()));
+        env.storage()
+            .persistent()
+            .remove(&DataKey::ScoreBreakdown(user.clone()));
+        env.storage()
+            .persistent()
+            .remove(&DataKey::Soulbound(user.clone()));
+        env.storage()
+            .persistent()
+            .remove(&DataKey::Recovery(user.clone()));
+        env.storage()
+            .persistent()
+            .remove(&DataKey::Private(user.clone()));
         env.storage()
             .persistent()
             .remove(&DataKey::Seized(user.clone()));
@@ -288,6 +1395,14 @@ impl RemittanceNFT {
             .instance()
             .set(&DataKey::Version, &Self::CURRENT_VERSION);
         env.storage().instance().set(&DataKey::Paused, &false);
+        Self::seed_param_limits(&env);
+        Self::log_admin_action(
+            &env,
+            &admin,
+            "initialize",
+            AdminValue::None,
+            AdminValue::Address(admin.clone()),
+        );
         Self::bump_instance_ttl(&env);
         // Admin is automatically authorized to mint
         let key = DataKey::AuthorizedMinter(admin.clone());
@@ -296,50 +1411,776 @@ impl RemittanceNFT {
         Ok(())
     }
 
-    fn assert_not_paused(env: &Env) -> Result<(), NftError> {
-        Self::bump_instance_ttl(env);
+    /// Moves a holder's entire credit record to `to`, which must be empty,
+    /// and hands any linked loans over through the loan hook. Shared by
+    /// `transfer` and recovery.
+    fn move_record(
+        env: &Env,
+        from: &Address,
+        to: &Address,
+        metadata: &RemittanceMetadata,
+        recovery: bool,
+    ) -> Result<(), NftError> {
+        // The hook runs first so a veto leaves the record untouched. Without
+        // one nothing could stop a borrower walking away from open loans.
+        let hook = Self::loan_hook(env).ok_or(NftError::LoanHookNotSet)?;
+        let moved = LoanHookClient::new(env, &hook).try_on_record_moved(from, to, &recovery);
+        if !matches!(moved, Ok(Ok(()))) {
+            return Err(NftError::LoanHookRejected);
+        }
+
+        let from_metadata_key = DataKey::Metadata(from.clone());
+        let to_metadata_key = DataKey::Metadata(to.clone());
+        env.storage().persistent().set(&to_metadata_key, metadata);
+        Self::bump_persistent_ttl(env, &to_metadata_key);
+        env.storage().persistent().remove(&from_metadata_key);
+        env.storage()
+            .persistent()
+            .remove(&DataKey::Score(from.clone()));
+
+        // History pages are keyed by the token, which moves below; only a
+        // pre-v4 single-Vec history under the old address needs paging first.
+        Self::ensure_token_id(env, from);
+        Self::score_history_index(env, from);
+
+        let from_activity_key = DataKey::Activity(from.clone());
+        if let Some(record) = env
+            .storage()
+            .persistent()
+            .get::<DataKey, ActivityRecord>(&from_activity_key)
+        {
+            let to_activity_key = DataKey::Activity(to.clone());
+            env.storage().persistent().set(&to_activity_key, &record);
+            Self::bump_persistent_ttl(env, &to_activity_key);
+            env.storage().persistent().remove(&from_activity_key);
+        }
+
+        let from_breakdown_key = DataKey::ScoreBreakdown(from.clone());
+        if let Some(breakdown) = env
+            .storage()
+            .persistent()
+            .get::<DataKey, ScoreBreakdown>(&from_breakdown_key)
+        {
+            let to_breakdown_key = DataKey::ScoreBreakdown(to.clone());
+            env.storage()
+                .persistent()
+                .set(&to_breakdown_key, &breakdown);
+            Self::bump_persistent_ttl(env, &to_breakdown_key);
+            env.storage().persistent().remove(&from_breakdown_key);
+        }
+
+        let from_chain_key = DataKey::AttestationChainHead(from.clone());
+        if let Some(head) = env
+            .storage()
+            .persistent()
+            .get::<DataKey, BytesN<32>>(&from_chain_key)
+        {
+            let to_chain_key = DataKey::AttestationChainHead(to.clone());
+            env.storage().persistent().set(&to_chain_key, &head);
+            Self::bump_persistent_ttl(env, &to_chain_key);
+            env.storage().persistent().remove(&from_chain_key);
+        }
+
+        let from_window_key = DataKey::ScoreGainWindow(from.clone());
+        if let Some(window) = env
+            .storage()
+            .persistent()
+            .get::<DataKey, ScoreGainWindow>(&from_window_key)
+        {
+            let to_window_key = DataKey::ScoreGainWindow(to.clone());
+            env.storage().persistent().set(&to_window_key, &window);
+            Self::bump_persistent_ttl(env, &to_window_key);
+            env.storage().persistent().remove(&from_window_key);
+        }
+
+        let from_default_key = DataKey::DefaultCount(from.clone());
+        if let Some(default_count) = env
+            .storage()
+            .persistent()
+            .get::<DataKey, u32>(&from_default_key)
+        {
+            let to_default_key = DataKey::DefaultCount(to.clone());
+            env.storage()
+                .persistent()
+                .set(&to_default_key, &default_count);
+            Self::bump_persistent_ttl(env, &to_default_key);
+            env.storage().persistent().remove(&from_default_key);
+        }
+
+        let from_seized_key = DataKey::Seized(from.clone());
+        if env.storage().persistent().has(&from_seized_key) {
+            let to_seized_key = DataKey::Seized(to.clone());
+            env.storage().persistent().set(&to_seized_key, &true);
+            Self::bump_persistent_ttl(env, &to_seized_key);
+            env.storage().persistent().remove(&from_seized_key);
+        }
+
+        env.storage()
+            .persistent()
+            .remove(&DataKey::RemintApproval(from.clone()));
+
+        env.storage()
+            .persistent()
+            .remove(&DataKey::TransferCooldown(from.clone()));
+        let to_cooldown_key = DataKey::TransferCooldown(to.clone());
+        let next_allowed_ledger = env
+            .ledger()
+            .sequence()
+            .saturating_add(Self::TRANSFER_COOLDOWN_LEDGERS);
+        env.storage()
+            .persistent()
+            .set(&to_cooldown_key, &next_allowed_ledger);
+        Self::bump_persistent_ttl(env, &to_cooldown_key);
+
+        let from_soulbound_key = DataKey::Soulbound(from.clone());
+        if let Some(soulbound) = env
+            .storage()
+            .persistent()
+            .get::<DataKey, bool>(&from_soulbound_key)
+        {
+            let to_soulbound_key = DataKey::Soulbound(to.clone());
+            env.storage()
+                .persistent()
+                .set(&to_soulbound_key, &soulbound);
+            Self::bump_persistent_ttl(env, &to_soulbound_key);
+            env.storage().persistent().remove(&from_soulbound_key);
+        }
+        env.storage()
+            .persistent()
+            .remove(&DataKey::Recovery(from.clone()));
+
+        let from_private_key = DataKey::Private(from.clone());
+        if env.storage().persistent().has(&from_private_key) {
+            let to_private_key = DataKey::Private(to.clone());
+            env.storage().persistent().set(&to_private_key, &true);
+            Self::bump_persistent_ttl(env, &to_private_key);
+            env.storage().persistent().remove(&from_private_key);
+        }
+        // Other grants were given to the old address and lapse with it; the
+        // loan hook's grant follows its loans.
+        if let Some(hook) = Self::loan_hook(env) {
+            let from_grant_key = DataKey::AccessGrant(from.clone(), hook.clone());
+            if let Some(grant) = env
+                .storage()
+                .persistent()
+                .get::<DataKey, AccessGrant>(&from_grant_key)
+            {
+                let to_grant_key = DataKey::AccessGrant(to.clone(), hook);
+                env.storage().persistent().set(&to_grant_key, &grant);
+                Self::bump_persistent_ttl(env, &to_grant_key);
+                env.storage().persistent().remove(&from_grant_key);
+            }
+        }
+
+        let from_token_key = DataKey::TokenOf(from.clone());
+        if let Some(token_id) = env
+            .storage()
+            .persistent()
+            .get::<DataKey, u32>(&from_token_key)
+        {
+            let owner_key = DataKey::TokenOwner(token_id);
+            env.storage().persistent().set(&owner_key, to);
+            Self::bump_persistent_ttl(env, &owner_key);
+            let to_token_key = DataKey::TokenOf(to.clone());
+            env.storage().persistent().set(&to_token_key, &token_id);
+            Self::bump_persistent_ttl(env, &to_token_key);
+            env.storage().persistent().remove(&from_token_key);
+            env.events().publish(
+                (symbol_short!("transfer"), from.clone(), to.clone()),
+                token_id,
+            );
+        }
+        Ok(())
+    }
+
+    fn stored_version(env: &Env) -> u32 {
+        env.storage().instance().get(&DataKey::Version).unwrap_or(0)
+    }
+
+    fn migration_cursor(env: &Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::MigrationCursor)
+            .unwrap_or(1)
+    }
+
+    fn migration_status(env: &Env) -> MigrationStatus {
+        let version = Self::stored_version(env);
+        MigrationStatus {
+            version,
+            target_version: Self::CURRENT_VERSION,
+            cursor: Self::migration_cursor(env),
+            complete: version >= Self::CURRENT_VERSION,
+        }
+    }
+
+    /// Whether the step from `from_version` rewrites per-holder entries and
+    /// so must be batched over token ids.
+    fn step_migrates_records(from_version: u32) -> bool {
+        from_version == 3
+    }
+
+    /// Per-holder work of the step from `from_version`.
+    fn migrate_record(env: &Env, from_version: u32, user: &Address) {
+        if from_version == 3 {
+            // 3 → 4: legacy `Score` entries become `Metadata` and single-Vec
+            // score histories are split into pages.
+            Self::get_or_migrate_metadata(env, user);
+            Self::score_history_len(env, user);
+        }
+    }
+
+    /// Instance-level work of the step from `from_version`, then records
+    /// the step and advances the stored version. Returns the new version.
+    fn complete_migration_step(env: &Env, from_version: u32) -> u32 {
+        if from_version < 4 && !env.storage().instance().has(&Self::burn_threshold_key()) {
+            env.storage()
+                .instance()
+                .set(&Self::burn_threshold_key(), &Self::DEFAULT_BURN_THRESHOLD);
+        }
+        if from_version == 4 {
+            // 4 → 5: rate-of-change limits stop being opt-in.
+            Self::seed_param_limits(env);
+        }
+
+        let to_version = from_version + 1;
+        let mut log: Vec<MigrationRecord> = env
+            .storage()
+            .instance()
+            .get(&DataKey::MigrationLog)
+            .unwrap_or(Vec::new(env));
+        log.push_back(MigrationRecord {
+            from_version,
+            to_version,
+            ledger: env.ledger().sequence(),
+        });
+        env.storage().instance().set(&DataKey::MigrationLog, &log);
+        env.storage().instance().set(&DataKey::Version, &to_version);
+        env.storage().instance().remove(&DataKey::MigrationCursor);
+        env.events().publish(
+            (Symbol::new(env, "MigrationStep"),),
+            (from_version, to_version),
+        );
+        to_version
+    }
+
+    /// Effective pause state. A guardian pause stops counting once its
+    /// expiry ledger is reached, without needing a write.
+    fn paused_now(env: &Env) -> bool {
         let paused: bool = env
             .storage()
             .instance()
             .get(&DataKey::Paused)
             .unwrap_or(false);
-        if paused {
+        if !paused {
+            return false;
+        }
+        match env
+            .storage()
+            .instance()
+            .get::<DataKey, u32>(&DataKey::GuardianPauseExpiry)
+        {
+            Some(expiry) => env.ledger().sequence() < expiry,
+            None => true,
+        }
+    }
+
+    /// SHA-256 of a value's XDR, so struct-valued settings fit in an
+    /// `AdminAction` without copying the whole value into the log.
+    fn admin_digest<T: ToXdr>(env: &Env, value: T) -> AdminValue {
+        AdminValue::Hash(env.crypto().sha256(&value.to_xdr(env)).to_bytes())
+    }
+
+    fn admin_log_len(env: &Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&Self::admin_log_len_key())
+            .unwrap_or(0)
+    }
+
+    fn read_admin_log_page(env: &Env, page: u32) -> Vec<AdminAction> {
+        let key = Self::admin_log_page_key(page);
+        match env.storage().persistent().get::<_, Vec<AdminAction>>(&key) {
+            Some(entries) => {
+                env.storage().persistent().extend_ttl(
+                    &key,
+                    Self::PERSISTENT_TTL_THRESHOLD,
+                    Self::PERSISTENT_TTL_BUMP,
+                );
+                entries
+            }
+            None => Vec::new(env),
+        }
+    }
+
+    /// Append a privileged call to the admin audit log. Entries are never
+    /// rewritten or removed.
+    fn log_admin_action(
+        env: &Env,
+        actor: &Address,
+        action: &str,
+        old: AdminValue,
+        new: AdminValue,
+    ) {
+        let index = Self::admin_log_len(env);
+        let page_index = index / Self::ADMIN_LOG_PAGE_SIZE;
+        let mut page = Self::read_admin_log_page(env, page_index);
+        let entry = AdminAction {
+            ledger: env.ledger().sequence(),
+            actor: actor.clone(),
+            action: Symbol::new(env, action),
+            old,
+            new,
+        };
+        page.push_back(entry.clone());
+
+        let key = Self::admin_log_page_key(page_index);
+        env.storage().persistent().set(&key, &page);
+        env.storage().persistent().extend_ttl(
+            &key,
+            Self::PERSISTENT_TTL_THRESHOLD,
+            Self::PERSISTENT_TTL_BUMP,
+        );
+        env.storage()
+            .instance()
+            .set(&Self::admin_log_len_key(), &(index + 1));
+        Self::bump_instance_ttl(env);
+        env.events().publish(
+            (Symbol::new(env, "AdminAction"), entry.action.clone()),
+            (index, entry),
+        );
+    }
+
+    /// Audit value for a direct admin score write: the user and their
+    /// composite, or only the user while the record is private.
+    fn admin_score_value(env: &Env, user: &Address) -> AdminValue {
+        match Self::get_or_migrate_metadata(env, user) {
+            Some(metadata) if !Self::is_private_record(env, user) => {
+                AdminValue::UserScore(user.clone(), metadata.score)
+            }
+            _ => AdminValue::Address(user.clone()),
+        }
+    }
+
+    fn read_param_limit(env: &Env, param: &NftParam) -> Option<ParamLimit> {
+        let key = DataKey::ParamLimit(param.clone());
+        let limit = env.storage().persistent().get(&key);
+        if limit.is_some() {
+            Self::bump_persistent_ttl(env, &key);
+        }
+        limit
+    }
+
+    /// Limit seeded for `param` at initialization: half the current value
+    /// per day, or a fixed step in the parameter's units for small values.
+    fn default_param_limit(param: &NftParam) -> ParamLimit {
+        let max_change = match param {
+            NftParam::RecoveryTimelock
+            | NftParam::GuardianPauseLedgers
+            | NftParam::GainPeriodLedgers
+            | NftParam::DecayInactivity
+            | NftParam::DecayPeriod => Self::LEDGERS_PER_DAY as i128,
+            NftParam::DefaultBurnThreshold => 1,
+            NftParam::UnitsPerPoint => 100,
+            NftParam::AttestationUnitsPerPoint => Self::DEFAULT_ATTESTATION_UNITS_PER_POINT,
+            NftParam::DiminishingRate
+            | NftParam::RepaymentWeight
+            | NftParam::RemittanceWeight
+            | NftParam::TenureWeight
+            | NftParam::UtilizationWeight => 2_000,
+            NftParam::FullRatePoints
+            | NftParam::OnTimeBonus
+            | NftParam::LatePenaltyBase
+            | NftParam::LatePenaltyPerDay
+            | NftParam::DefaultPenalty
+            | NftParam::MaxGainPerPeriod
+            | NftParam::MinScore
+            | NftParam::DecayPoints
+            | NftParam::DecayBaseline
+            | NftParam::AttestationMaxPoints
+            | NftParam::AttestationRegularityBonus => 50,
+        };
+        ParamLimit {
+            max_change,
+            max_change_bps: Self::DEFAULT_PARAM_MAX_CHANGE_BPS,
+            window_ledgers: Self::DEFAULT_PARAM_WINDOW_LEDGERS,
+        }
+    }
+
+    /// Register the default limit for every parameter that has none, so
+    /// limits are in force without the admin opting in. Existing limits
+    /// are left alone.
+    fn seed_param_limits(env: &Env) {
+        let params = [
+            NftParam::RecoveryTimelock,
+            NftParam::DefaultBurnThreshold,
+            NftParam::GuardianPauseLedgers,
+            NftParam::UnitsPerPoint,
+            NftParam::FullRatePoints,
+            NftParam::DiminishingRate,
+            NftParam::OnTimeBonus,
+            NftParam::LatePenaltyBase,
+            NftParam::LatePenaltyPerDay,
+            NftParam::DefaultPenalty,
+            NftParam::MaxGainPerPeriod,
+            NftParam::GainPeriodLedgers,
+            NftParam::MinScore,
+            NftParam::DecayInactivity,
+            NftParam::DecayPeriod,
+            NftParam::DecayPoints,
+            NftParam::DecayBaseline,
+            NftParam::RepaymentWeight,
+            NftParam::RemittanceWeight,
+            NftParam::TenureWeight,
+            NftParam::UtilizationWeight,
+            NftParam::AttestationUnitsPerPoint,
+            NftParam::AttestationMaxPoints,
+            NftParam::AttestationRegularityBonus,
+        ];
+        for param in params {
+            let key = DataKey::ParamLimit(param.clone());
+            if !env.storage().persistent().has(&key) {
+                env.storage()
+                    .persistent()
+                    .set(&key, &Self::default_param_limit(&param));
+                Self::bump_persistent_ttl(env, &key);
+            }
+        }
+    }
+
+    fn validate_param_limit(limit: &ParamLimit) -> Result<(), NftError> {
+        if limit.max_change < 0 || limit.window_ledgers == 0 {
+            return Err(NftError::InvalidParamLimit);
+        }
+        Ok(())
+    }
+
+    /// Use up the pending override for `param` if it is `expected` and its
+    /// timelock has passed.
+    fn consume_param_override(
+        env: &Env,
+        param: &NftParam,
+        expected: &ParamOverride,
+    ) -> Result<(), NftError> {
+        let key = DataKey::ParamOverride(param.clone());
+        let pending: PendingParamOverride = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .filter(|pending: &PendingParamOverride| pending.change == *expected)
+            .ok_or(NftError::ParamOverrideRequired)?;
+        if env.ledger().sequence() < pending.eta {
+            return Err(NftError::ParamOverrideTimelockActive);
+        }
+        env.storage().persistent().remove(&key);
+        Ok(())
+    }
+
+    /// Enforce `param`'s rate-of-change limit on a move from `old` to `new`.
+    /// Movement is measured from the value at the start of the current
+    /// window, so several small steps cannot add up past the allowance. A
+    /// matured override for exactly `new` lets a larger jump through and
+    /// starts a fresh window.
+    fn check_param_change(
+        env: &Env,
+        param: NftParam,
+        old: i128,
+        new: i128,
+    ) -> Result<(), NftError> {
+        if old == new {
+            return Ok(());
+        }
+        let Some(limit) = Self::read_param_limit(env, &param) else {
+            return Ok(());
+        };
+
+        let now = env.ledger().sequence();
+        let window_key = DataKey::ParamWindow(param.clone());
+        let mut window = env
+            .storage()
+            .persistent()
+            .get::<DataKey, ParamWindow>(&window_key)
+            .filter(|window| now < window.start_ledger.saturating_add(limit.window_ledgers))
+            .unwrap_or(ParamWindow {
+                start_ledger: now,
+                baseline: old,
+            });
+
+        let relative = window
+            .baseline
+            .checked_abs()
+            .and_then(|value| value.checked_mul(limit.max_change_bps as i128))
+            .map(|value| value / 10_000)
+            .unwrap_or(i128::MAX);
+        let allowance = limit.max_change.max(relative);
+        let moved = new
+            .checked_sub(window.baseline)
+            .and_then(i128::checked_abs)
+            .unwrap_or(i128::MAX);
+        if moved > allowance {
+            Self::consume_param_override(env, &param, &ParamOverride::Value(new)).map_err(
+                |err| {
+                    if err == NftError::ParamOverrideRequired {
+                        NftError::ParamChangeTooLarge
+                    } else {
+                        err
+                    }
+                },
+            )?;
+            window = ParamWindow {
+                start_ledger: now,
+                baseline: new,
+            };
+        }
+
+        env.storage().persistent().set(&window_key, &window);
+        Self::bump_persistent_ttl(env, &window_key);
+        Ok(())
+    }
+
+    fn operation_paused(env: &Env, operation: NftOperation) -> bool {
+        env.storage()
+            .instance()
+            .get(&DataKey::OperationPaused(operation))
+            .unwrap_or(false)
+    }
+
+    /// `assert_not_paused`, plus the operation's own pause flag.
+    fn assert_operation_active(env: &Env, operation: NftOperation) -> Result<(), NftError> {
+        Self::assert_not_paused(env)?;
+        if Self::operation_paused(env, operation) {
+            return Err(NftError::OperationPaused);
+        }
+        Ok(())
+    }
+
+    fn assert_not_paused(env: &Env) -> Result<(), NftError> {
+        Self::bump_instance_ttl(env);
+        if Self::stored_version(env) < Self::CURRENT_VERSION {
+            return Err(NftError::MigrationPending);
+        }
+        if Self::paused_now(env) {
             return Err(NftError::ContractPaused);
         }
         Ok(())
     }
 
-    pub fn version(env: Env) -> u32 {
-        Self::bump_instance_ttl(&env);
-        env.storage().instance().get(&DataKey::Version).unwrap_or(0)
+    pub fn version(env: Env) -> u32 {
+        Self::bump_instance_ttl(&env);
+        env.storage().instance().get(&DataKey::Version).unwrap_or(0)
+    }
+
+    /// Announce an upgrade to `wasm_hash`, executable from ledger `eta`,
+    /// which must be at least `MIN_UPGRADE_DELAY_LEDGERS` away. Replaces
+    /// any upgrade already scheduled.
+    pub fn schedule_upgrade(env: Env, wasm_hash: BytesN<32>, eta: u32) -> Result<(), NftError> {
+        Self::admin(&env).require_auth();
+        let earliest = env
+            .ledger()
+            .sequence()
+            .saturating_add(Self::MIN_UPGRADE_DELAY_LEDGERS);
+        if eta < earliest {
+            return Err(NftError::InvalidUpgradeEta);
+        }
+
+        env.storage().instance().set(
+            &DataKey::ScheduledUpgrade,
+            &ScheduledUpgrade {
+                wasm_hash: wasm_hash.clone(),
+                eta,
+            },
+        );
+        Self::bump_instance_ttl(&env);
+        Self::log_admin_action(
+            &env,
+            &Self::admin(&env),
+            "schedule_upgrade",
+            AdminValue::None,
+            AdminValue::Hash(wasm_hash.clone()),
+        );
+        env.events()
+            .publish((Symbol::new(&env, "UpgradeScheduled"),), (wasm_hash, eta));
+        Ok(())
+    }
+
+    pub fn cancel_upgrade(env: Env) -> Result<(), NftError> {
+        Self::admin(&env).require_auth();
+        let scheduled: ScheduledUpgrade = env
+            .storage()
+            .instance()
+            .get(&DataKey::ScheduledUpgrade)
+            .ok_or(NftError::NoUpgradeScheduled)?;
+        env.storage().instance().remove(&DataKey::ScheduledUpgrade);
+        Self::log_admin_action(
+            &env,
+            &Self::admin(&env),
+            "cancel_upgrade",
+            AdminValue::Hash(scheduled.wasm_hash.clone()),
+            AdminValue::None,
+        );
+        env.events().publish(
+            (Symbol::new(&env, "UpgradeCancelled"),),
+            scheduled.wasm_hash,
+        );
+        Ok(())
+    }
+
+    /// Swap in the scheduled wasm once its eta has passed. `Version` is
+    /// left alone: the new code records its own `CURRENT_VERSION` in
+    /// `migrate`.
+    pub fn execute_upgrade(env: Env) -> Result<(), NftError> {
+        Self::admin(&env).require_auth();
+        let scheduled: ScheduledUpgrade = env
+            .storage()
+            .instance()
+            .get(&DataKey::ScheduledUpgrade)
+            .ok_or(NftError::NoUpgradeScheduled)?;
+        if env.ledger().sequence() < scheduled.eta {
+            return Err(NftError::UpgradeTimelockActive);
+        }
+
+        env.storage().instance().remove(&DataKey::ScheduledUpgrade);
+        Self::log_admin_action(
+            &env,
+            &Self::admin(&env),
+            "execute_upgrade",
+            AdminValue::U32(Self::version(env.clone())),
+            AdminValue::Hash(scheduled.wasm_hash.clone()),
+        );
+        env.events().publish(
+            (Symbol::new(&env, "ContractUpgraded"),),
+            (Self::version(env.clone()), scheduled.wasm_hash.clone()),
+        );
+        env.deployer()
+            .update_current_contract_wasm(scheduled.wasm_hash);
+        Ok(())
+    }
+
+    pub fn get_scheduled_upgrade(env: Env) -> Option<ScheduledUpgrade> {
+        Self::bump_instance_ttl(&env);
+        env.storage().instance().get(&DataKey::ScheduledUpgrade)
+    }
+
+    /// Advance the pending storage migration by one `MIGRATE_BATCH_SIZE`
+    /// slice from the stored cursor. Call repeatedly until
+    /// `get_migration_status` reports it complete; `migrate_batch` gives
+    /// control over the slice.
+    pub fn migrate(env: Env) -> Result<(), NftError> {
+        let cursor = Self::migration_cursor(&env);
+        Self::migrate_batch(env, cursor, Self::MIGRATE_BATCH_SIZE)?;
+        Ok(())
     }
 
-    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
+    /// Give the listed holders' records a token id if they were minted
+    /// before token ids existed, so `migrate_batch` can reach them, and
+    /// bring them up to date. Safe to repeat; the stored version only
+    /// advances through `migrate_batch`.
+    pub fn migrate_holders(env: Env, users: Vec<Address>) {
         Self::admin(&env).require_auth();
-        let old_version = Self::version(env.clone());
-        let new_version = old_version.saturating_add(1);
-        env.storage()
-            .instance()
-            .set(&DataKey::Version, &new_version);
-        env.events().publish(
-            (Symbol::new(&env, "ContractUpgraded"),),
-            (old_version, new_version),
+
+        for user in users.iter() {
+            if !Self::has_any_remittance_state(&env, &user) {
+                continue;
+            }
+            Self::ensure_token_id(&env, &user);
+            // Record steps are idempotent, so replaying all of them is safe.
+            for from_version in 0..Self::CURRENT_VERSION {
+                Self::migrate_record(&env, from_version, &user);
+            }
+        }
+        Self::log_admin_action(
+            &env,
+            &Self::admin(&env),
+            "migrate_holders",
+            AdminValue::None,
+            AdminValue::U32(users.len()),
         );
-        env.deployer().update_current_contract_wasm(new_wasm_hash);
+        Self::bump_instance_ttl(&env);
     }
 
-    pub fn migrate(env: Env) {
+    /// Run the next slice of the pending storage migration.
+    ///
+    /// Steps go one version at a time. Steps that rewrite per-holder entries
+    /// walk token ids from the stored cursor, `limit` ids per call; `start`
+    /// may repeat already-processed ids but may not skip ahead of the
+    /// cursor. Steps that only touch instance config run inline. Mutating
+    /// entrypoints return `MigrationPending` until the stored version
+    /// reaches `CURRENT_VERSION`.
+    pub fn migrate_batch(env: Env, start: u32, limit: u32) -> Result<MigrationStatus, NftError> {
         Self::admin(&env).require_auth();
+        if limit == 0 {
+            return Err(NftError::InvalidMigrationBatch);
+        }
 
-        if !env.storage().instance().has(&Self::burn_threshold_key()) {
-            env.storage()
+        let mut version = Self::stored_version(&env);
+        let entry_version = version;
+        if version < Self::CURRENT_VERSION && Self::step_migrates_records(version) {
+            let cursor = Self::migration_cursor(&env);
+            if start > cursor {
+                return Err(NftError::MigrationCursorAhead);
+            }
+            let last_token_id = env
+                .storage()
                 .instance()
-                .set(&Self::burn_threshold_key(), &Self::DEFAULT_BURN_THRESHOLD);
+                .get::<DataKey, u32>(&DataKey::NextTokenId)
+                .unwrap_or(1)
+                - 1;
+            let end = start
+                .saturating_add(limit)
+                .min(last_token_id.saturating_add(1));
+            for token_id in start..end {
+                if let Some(owner) = env
+                    .storage()
+                    .persistent()
+                    .get::<DataKey, Address>(&DataKey::TokenOwner(token_id))
+                {
+                    Self::migrate_record(&env, version, &owner);
+                }
+            }
+
+            let next_cursor = cursor.max(end);
+            if next_cursor <= last_token_id {
+                env.storage()
+                    .instance()
+                    .set(&DataKey::MigrationCursor, &next_cursor);
+                Self::bump_instance_ttl(&env);
+                Self::log_admin_action(
+                    &env,
+                    &Self::admin(&env),
+                    "migrate_batch",
+                    AdminValue::U32(entry_version),
+                    AdminValue::U32(version),
+                );
+                return Ok(Self::migration_status(&env));
+            }
+            version = Self::complete_migration_step(&env, version);
         }
+
+        while version < Self::CURRENT_VERSION && !Self::step_migrates_records(version) {
+            version = Self::complete_migration_step(&env, version);
+        }
+        Self::log_admin_action(
+            &env,
+            &Self::admin(&env),
+            "migrate_batch",
+            AdminValue::U32(entry_version),
+            AdminValue::U32(version),
+        );
+        Self::bump_instance_ttl(&env);
+        Ok(Self::migration_status(&env))
+    }
+
+    pub fn get_migration_status(env: Env) -> MigrationStatus {
+        Self::bump_instance_ttl(&env);
+        Self::migration_status(&env)
+    }
+
+    pub fn get_migration_log(env: Env) -> Vec<MigrationRecord> {
+        Self::bump_instance_ttl(&env);
         env.storage()
             .instance()
-            .set(&DataKey::Version, &Self::CURRENT_VERSION);
-        Self::bump_instance_ttl(&env);
+            .get(&DataKey::MigrationLog)
+            .unwrap_or(Vec::new(&env))
     }
 
     /// Authorize a contract or account to mint NFTs
@@ -350,6 +2191,13 @@ impl RemittanceNFT {
         env.storage().persistent().set(&key, &true);
         Self::bump_persistent_ttl(&env, &key);
 
+        Self::log_admin_action(
+            &env,
+            &Self::admin(&env),
+            "authorize_minter",
+            AdminValue::None,
+            AdminValue::Address(minter.clone()),
+        );
         env.events().publish((symbol_short!("MntAuth"), minter), ());
     }
 
@@ -361,6 +2209,13 @@ impl RemittanceNFT {
             .persistent()
             .remove(&DataKey::AuthorizedMinter(minter.clone()));
 
+        Self::log_admin_action(
+            &env,
+            &Self::admin(&env),
+            "revoke_minter",
+            AdminValue::Address(minter.clone()),
+            AdminValue::None,
+        );
         env.events().publish((symbol_short!("MntRev"), minter), ());
     }
 
@@ -384,8 +2239,9 @@ impl RemittanceNFT {
         history_hash: BytesN<32>,
         minter: Option<Address>,
     ) -> Result<(), NftError> {
-        let _admin_direct_mint = minter.is_none();
+        let admin_call = minter.is_none();
         Self::require_admin_or_authorized_minter(&env, minter)?;
+        Self::assert_operation_active(&env, NftOperation::Minting)?;
 
         let metadata_key = DataKey::Metadata(user.clone());
         let score_key = DataKey::Score(user.clone());
@@ -408,6 +2264,17 @@ impl RemittanceNFT {
 
         env.storage().persistent().set(&metadata_key, &metadata);
         Self::bump_persistent_ttl(&env, &metadata_key);
+        Self::touch_activity(&env, &user);
+        Self::assign_token_id(&env, &user);
+        if admin_call {
+            Self::log_admin_action(
+                &env,
+                &Self::admin(&env),
+                "mint",
+                AdminValue::None,
+                Self::admin_score_value(&env, &user),
+            );
+        }
         env.events()
             .publish((symbol_short!("Mint"), user), initial_score);
 
@@ -433,7 +2300,7 @@ impl RemittanceNFT {
     ) -> Result<(), NftError> {
         // Admin-only — no minter bypass allowed for remints.
         Self::admin(&env).require_auth();
-        Self::assert_not_paused(&env)?;
+        Self::assert_operation_active(&env, NftOperation::Minting)?;
 
         // Must be a previously burned account — not a first-time mint.
         let burned_key = DataKey::Burned(user.clone());
@@ -478,7 +2345,16 @@ impl RemittanceNFT {
         };
         env.storage().persistent().set(&metadata_key, &metadata);
         Self::bump_persistent_ttl(&env, &metadata_key);
+        Self::touch_activity(&env, &user);
+        Self::assign_token_id(&env, &user);
 
+        Self::log_admin_action(
+            &env,
+            &Self::admin(&env),
+            "admin_remint",
+            AdminValue::None,
+            AdminValue::Address(user.clone()),
+        );
         // Emit a distinct AdminRemint event — auditably separate from Mint events.
         env.events()
             .publish((symbol_short!("AdmRemint"), user.clone()), initial_score);
@@ -486,19 +2362,237 @@ impl RemittanceNFT {
         Ok(())
     }
 
+    pub fn name(env: Env) -> String {
+        Self::bump_instance_ttl(&env);
+        env.storage()
+            .instance()
+            .get(&DataKey::CollectionName)
+            .unwrap_or_else(|| String::from_str(&env, Self::DEFAULT_COLLECTION_NAME))
+    }
+
+    pub fn symbol(env: Env) -> String {
+        Self::bump_instance_ttl(&env);
+        env.storage()
+            .instance()
+            .get(&DataKey::CollectionSymbol)
+            .unwrap_or_else(|| String::from_str(&env, Self::DEFAULT_COLLECTION_SYMBOL))
+    }
+
+    pub fn set_collection_info(env: Env, name: String, symbol: String) {
+        Self::admin(&env).require_auth();
+        Self::log_admin_action(
+            &env,
+            &Self::admin(&env),
+            "set_collection_info",
+            Self::admin_digest(&env, (Self::name(env.clone()), Self::symbol(env.clone()))),
+            Self::admin_digest(&env, (name.clone(), symbol.clone())),
+        );
+        env.storage()
+            .instance()
+            .set(&DataKey::CollectionName, &name);
+        env.storage()
+            .instance()
+            .set(&DataKey::CollectionSymbol, &symbol);
+        Self::bump_instance_ttl(&env);
+        env.events()
+            .publish((symbol_short!("CollUpd"),), (name, symbol));
+    }
+
+    /// Set the prefix `token_uri` appends token ids to. Up to
+    /// `MAX_BASE_URI_LEN` bytes.
+    pub fn set_base_uri(env: Env, base_uri: String) -> Result<(), NftError> {
+        Self::admin(&env).require_auth();
+        if base_uri.len() > Self::MAX_BASE_URI_LEN {
+            return Err(NftError::InvalidBaseUri);
+        }
+        Self::log_admin_action(
+            &env,
+            &Self::admin(&env),
+            "set_base_uri",
+            Self::admin_digest(&env, Self::base_uri(env.clone())),
+            Self::admin_digest(&env, base_uri.clone()),
+        );
+        env.storage().instance().set(&DataKey::BaseUri, &base_uri);
+        Self::bump_instance_ttl(&env);
+        env.events().publish((symbol_short!("UriUpd"),), base_uri);
+        Ok(())
+    }
+
+    pub fn base_uri(env: Env) -> String {
+        Self::bump_instance_ttl(&env);
+        env.storage()
+            .instance()
+            .get(&DataKey::BaseUri)
+            .unwrap_or_else(|| String::from_str(&env, ""))
+    }
+
+    pub fn owner_of(env: Env, token_id: u32) -> Result<Address, NftError> {
+        let key = DataKey::TokenOwner(token_id);
+        let owner = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .ok_or(NftError::NftNotFound)?;
+        Self::bump_persistent_ttl(&env, &key);
+        Ok(owner)
+    }
+
+    /// Token id of a holder's record. Records minted before token ids
+    /// existed get one through `migrate`.
+    pub fn token_id_of(env: Env, user: Address) -> Option<u32> {
+        let key = DataKey::TokenOf(user);
+        let token_id = env.storage().persistent().get(&key);
+        if token_id.is_some() {
+            Self::bump_persistent_ttl(&env, &key);
+        }
+        token_id
+    }
+
+    /// `base_uri` followed by the decimal token id.
+    pub fn token_uri(env: Env, token_id: u32) -> Result<String, NftError> {
+        if !env
+            .storage()
+            .persistent()
+            .has(&DataKey::TokenOwner(token_id))
+        {
+            return Err(NftError::NftNotFound);
+        }
+
+        let base_uri = Self::base_uri(env.clone());
+        let base_len = base_uri.len() as usize;
+        let mut buf = [0u8; Self::MAX_BASE_URI_LEN as usize + 10];
+        base_uri.copy_into_slice(&mut buf[..base_len]);
+
+        let mut digits = [0u8; 10];
+        let mut digit_count = 0;
+        let mut remaining = token_id;
+        loop {
+            digits[digit_count] = b'0' + (remaining % 10) as u8;
+            digit_count += 1;
+            remaining /= 10;
+            if remaining == 0 {
+                break;
+            }
+        }
+        for (offset, digit) in digits[..digit_count].iter().rev().enumerate() {
+            buf[base_len + offset] = *digit;
+        }
+        Ok(String::from_bytes(&env, &buf[..base_len + digit_count]))
+    }
+
     /// Get the metadata (score and history hash) for a user's NFT
+    /// Returns `None` for private records; see `get_metadata_for`.
     pub fn get_metadata(env: Env, user: Address) -> Option<RemittanceMetadata> {
+        if Self::is_private_record(&env, &user) {
+            return None;
+        }
         Self::get_or_migrate_metadata(&env, &user)
     }
 
     /// Get the score for a user.
-    /// Returns 0 if the user has no NFT.
+    /// Returns 0 if the user has no NFT or keeps their record private.
     pub fn get_score(env: Env, user: Address) -> u32 {
+        if Self::is_private_record(&env, &user) {
+            return 0;
+        }
         Self::get_or_migrate_metadata(&env, &user)
             .map(|metadata| metadata.score)
             .unwrap_or(0)
     }
 
+    /// `get_metadata` for a consumer holding a `Metadata` grant.
+    pub fn get_metadata_for(
+        env: Env,
+        user: Address,
+        consumer: Address,
+    ) -> Result<Option<RemittanceMetadata>, NftError> {
+        Self::require_read_access(&env, &user, &consumer, AccessScope::Metadata)?;
+        Ok(Self::get_or_migrate_metadata(&env, &user))
+    }
+
+    /// `get_score` for a consumer holding a `Score` grant.
+    pub fn get_score_for(env: Env, user: Address, consumer: Address) -> Result<u32, NftError> {
+        Self::require_read_access(&env, &user, &consumer, AccessScope::Score)?;
+        Ok(Self::get_or_migrate_metadata(&env, &user)
+            .map(|metadata| metadata.score)
+            .unwrap_or(0))
+    }
+
+    /// Hide the record from the public reads; only consumers granted access
+    /// can then read it through the `*_for` variants.
+    pub fn set_private(env: Env, user: Address, private: bool) -> Result<(), NftError> {
+        user.require_auth();
+        if !Self::has_any_remittance_state(&env, &user) {
+            return Err(NftError::NftNotFound);
+        }
+
+        let key = DataKey::Private(user.clone());
+        if private {
+            env.storage().persistent().set(&key, &true);
+            Self::bump_persistent_ttl(&env, &key);
+        } else {
+            env.storage().persistent().remove(&key);
+        }
+        env.events()
+            .publish((symbol_short!("PrivSet"), user), private);
+        Ok(())
+    }
+
+    pub fn is_private(env: Env, user: Address) -> bool {
+        Self::is_private_record(&env, &user)
+    }
+
+    /// Let `consumer` read the holder's record up to `scope` through the
+    /// last ledger `expiry_ledger`. Replaces any earlier grant.
+    pub fn grant_access(
+        env: Env,
+        user: Address,
+        consumer: Address,
+        scope: AccessScope,
+        expiry_ledger: u32,
+    ) -> Result<(), NftError> {
+        user.require_auth();
+        if expiry_ledger < env.ledger().sequence() {
+            return Err(NftError::InvalidGrantExpiry);
+        }
+
+        let key = DataKey::AccessGrant(user.clone(), consumer.clone());
+        env.storage().persistent().set(
+            &key,
+            &AccessGrant {
+                scope,
+                expiry_ledger,
+            },
+        );
+        Self::bump_persistent_ttl(&env, &key);
+        env.events().publish(
+            (symbol_short!("AccGrant"), user, consumer),
+            (scope, expiry_ledger),
+        );
+        Ok(())
+    }
+
+    pub fn revoke_access(env: Env, user: Address, consumer: Address) {
+        user.require_auth();
+        env.storage()
+            .persistent()
+            .remove(&DataKey::AccessGrant(user.clone(), consumer.clone()));
+        env.events()
+            .publish((symbol_short!("AccRevoke"), user, consumer), ());
+    }
+
+    /// Whether `consumer` holds a live grant covering `scope`, regardless
+    /// of whether the record is currently private.
+    pub fn has_access(env: Env, user: Address, consumer: Address, scope: AccessScope) -> bool {
+        Self::grant_covers(&env, &user, &consumer, scope)
+    }
+
+    pub fn get_access_grant(env: Env, user: Address, consumer: Address) -> Option<AccessGrant> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::AccessGrant(user, consumer))
+    }
+
     /// Update the score for a user's NFT based on a repayment amount.
     pub fn update_score(
         env: Env,
@@ -509,111 +2603,510 @@ impl RemittanceNFT {
         if repayment_amount <= 0 {
             return Err(NftError::InvalidRepaymentAmount);
         }
+        let admin_call = minter.is_none();
+        Self::require_admin_or_authorized_minter(&env, minter)?;
+        Self::assert_operation_active(&env, NftOperation::ScoreUpdates)?;
+        let old = admin_call.then(|| Self::admin_score_value(&env, &user));
+
+        let policy = Self::scoring_policy(&env);
+        let points = Self::repayment_points(&policy, repayment_amount);
+        Self::raise_score(
+            &env,
+            &user,
+            &policy,
+            ScoreComponent::Repayment,
+            points,
+            symbol_short!("REPAY"),
+        )?;
+        Self::touch_activity(&env, &user);
+        if let Some(old) = old {
+            Self::log_admin_action(
+                &env,
+                &Self::admin(&env),
+                "update_score",
+                old,
+                Self::admin_score_value(&env, &user),
+            );
+        }
+        Ok(())
+    }
+
+    /// Score a repayment under the active policy.
+    ///
+    /// A payment that completes a loan `ledgers_late` past its due date
+    /// (after any grace period) earns no points and instead costs
+    /// `late_penalty_base + late_penalty_per_day * days_late`. Any other
+    /// payment earns repayment points, plus `on_time_bonus` when it completes
+    /// the loan on time.
+    pub fn record_repayment(
+        env: Env,
+        user: Address,
+        repayment_amount: i128,
+        completed: bool,
+        ledgers_late: u32,
+        minter: Option<Address>,
+    ) -> Result<(), NftError> {
+        if repayment_amount <= 0 {
+            return Err(NftError::InvalidRepaymentAmount);
+        }
+        let admin_call = minter.is_none();
+        Self::require_admin_or_authorized_minter(&env, minter)?;
+        Self::assert_operation_active(&env, NftOperation::ScoreUpdates)?;
+        let old = admin_call.then(|| Self::admin_score_value(&env, &user));
+
+        let policy = Self::scoring_policy(&env);
+        if completed && ledgers_late > 0 {
+            let days_late = ledgers_late.div_ceil(Self::LEDGERS_PER_DAY);
+            let penalty = policy
+                .late_penalty_base
+                .saturating_add(policy.late_penalty_per_day.saturating_mul(days_late));
+            Self::lower_score(
+                &env,
+                &user,
+                &policy,
+                ScoreComponent::Repayment,
+                penalty,
+                symbol_short!("LATE"),
+            )?;
+        } else {
+            let mut points = Self::repayment_points(&policy, repayment_amount);
+            let mut reason = symbol_short!("REPAY");
+            if completed && policy.on_time_bonus > 0 {
+                points = points.saturating_add(policy.on_time_bonus);
+                reason = symbol_short!("ONTIME");
+            }
+            Self::raise_score(
+                &env,
+                &user,
+                &policy,
+                ScoreComponent::Repayment,
+                points,
+                reason,
+            )?;
+        }
+        Self::touch_activity(&env, &user);
+        if let Some(old) = old {
+            Self::log_admin_action(
+                &env,
+                &Self::admin(&env),
+                "record_repayment",
+                old,
+                Self::admin_score_value(&env, &user),
+            );
+        }
+        Ok(())
+    }
+
+    /// Apply the active policy's default penalty.
+    pub fn apply_default_penalty(
+        env: Env,
+        user: Address,
+        minter: Option<Address>,
+    ) -> Result<(), NftError> {
+        let admin_call = minter.is_none();
+        Self::require_admin_or_authorized_minter(&env, minter)?;
+        Self::assert_operation_active(&env, NftOperation::ScoreUpdates)?;
+        let old = admin_call.then(|| Self::admin_score_value(&env, &user));
+
+        let policy = Self::scoring_policy(&env);
+        Self::lower_score(
+            &env,
+            &user,
+            &policy,
+            ScoreComponent::Repayment,
+            policy.default_penalty,
+            symbol_short!("DEFAULT"),
+        )?;
+        if let Some(old) = old {
+            Self::log_admin_action(
+                &env,
+                &Self::admin(&env),
+                "apply_default_penalty",
+                old,
+                Self::admin_score_value(&env, &user),
+            );
+        }
+        Ok(())
+    }
+
+    pub fn decrease_score(env: Env, user: Address, penalty_points: u32, minter: Option<Address>) {
+        let admin_call = minter.is_none();
+        Self::require_admin_or_authorized_minter(&env, minter)
+            .unwrap_or_else(|_| panic!("unauthorized minter"));
+        Self::assert_operation_active(&env, NftOperation::ScoreUpdates)
+            .unwrap_or_else(|_| panic!("score updates paused"));
+
+        if Self::get_or_migrate_metadata(&env, &user).is_none() {
+            panic!("user does not have an NFT");
+        }
+        let old = admin_call.then(|| Self::admin_score_value(&env, &user));
+        let policy = Self::scoring_policy(&env);
+        Self::lower_score(
+            &env,
+            &user,
+            &policy,
+            ScoreComponent::Repayment,
+            penalty_points,
+            symbol_short!("DEC"),
+        )
+        .expect("score update failed");
+        if let Some(old) = old {
+            Self::log_admin_action(
+                &env,
+                &Self::admin(&env),
+                "decrease_score",
+                old,
+                Self::admin_score_value(&env, &user),
+            );
+        }
+    }
+
+    /// Replace the scoring policy. The stored copy gets the next version
+    /// number, which every later history entry records.
+    ///
+    /// Only new score changes are affected; existing scores are not
+    /// recomputed.
+    pub fn set_scoring_policy(env: Env, policy: ScoringPolicy) -> Result<u32, NftError> {
+        Self::admin(&env).require_auth();
+        Self::validate_scoring_policy(&policy)?;
+
+        let old = Self::scoring_policy(&env);
+        for (param, old, new) in [
+            (
+                NftParam::UnitsPerPoint,
+                old.units_per_point,
+                policy.units_per_point,
+            ),
+            (
+                NftParam::FullRatePoints,
+                old.full_rate_points as i128,
+                policy.full_rate_points as i128,
+            ),
+            (
+                NftParam::DiminishingRate,
+                old.diminishing_rate_bps as i128,
+                policy.diminishing_rate_bps as i128,
+            ),
+            (
+                NftParam::OnTimeBonus,
+                old.on_time_bonus as i128,
+                policy.on_time_bonus as i128,
+            ),
+            (
+                NftParam::LatePenaltyBase,
+                old.late_penalty_base as i128,
+                policy.late_penalty_base as i128,
+            ),
+            (
+                NftParam::LatePenaltyPerDay,
+                old.late_penalty_per_day as i128,
+                policy.late_penalty_per_day as i128,
+            ),
+            (
+                NftParam::DefaultPenalty,
+                old.default_penalty as i128,
+                policy.default_penalty as i128,
+            ),
+            (
+                NftParam::MaxGainPerPeriod,
+                old.max_gain_per_period as i128,
+                policy.max_gain_per_period as i128,
+            ),
+            (
+                NftParam::GainPeriodLedgers,
+                old.period_ledgers as i128,
+                policy.period_ledgers as i128,
+            ),
+            (
+                NftParam::MinScore,
+                old.min_score as i128,
+                policy.min_score as i128,
+            ),
+        ] {
+            Self::check_param_change(&env, param, old, new)?;
+        }
+
+        let version = old.version.checked_add(1).expect("policy version overflow");
+        let policy = ScoringPolicy { version, ..policy };
+        Self::log_admin_action(
+            &env,
+            &Self::admin(&env),
+            "set_scoring_policy",
+            AdminValue::U32(version - 1),
+            AdminValue::U32(version),
+        );
+        env.storage()
+            .instance()
+            .set(&DataKey::ScoringPolicy, &policy);
+        Self::bump_instance_ttl(&env);
+
+        env.events()
+            .publish((symbol_short!("PolicyUpd"), version), policy);
+        Ok(version)
+    }
+
+    pub fn get_scoring_policy(env: Env) -> ScoringPolicy {
+        Self::bump_instance_ttl(&env);
+        Self::scoring_policy(&env)
+    }
+
+    /// Configure inactivity decay, or disable it with `None`.
+    ///
+    /// Decay periods are counted from each holder's last repayment, so
+    /// enabling decay applies retroactively to holders who have been idle
+    /// longer than `inactivity_ledgers`.
+    pub fn set_decay_config(env: Env, config: Option<DecayConfig>) -> Result<(), NftError> {
+        Self::admin(&env).require_auth();
+        if matches!(&config, Some(config) if config.period_ledgers == 0
+            || config.points_per_period == 0
+            || config.baseline > Self::MAX_SCORE)
+        {
+            return Err(NftError::InvalidDecayConfig);
+        }
+
+        let old = Self::decay_config(&env);
+        Self::check_param_change(
+            &env,
+            NftParam::DecayPoints,
+            old.as_ref().map_or(0, |old| old.points_per_period) as i128,
+            config.as_ref().map_or(0, |new| new.points_per_period) as i128,
+        )?;
+        if let (Some(old), Some(new)) = (&old, &config) {
+            for (param, old, new) in [
+                (
+                    NftParam::DecayInactivity,
+                    old.inactivity_ledgers,
+                    new.inactivity_ledgers,
+                ),
+                (
+                    NftParam::DecayPeriod,
+                    old.period_ledgers,
+                    new.period_ledgers,
+                ),
+                (NftParam::DecayBaseline, old.baseline, new.baseline),
+            ] {
+                Self::check_param_change(&env, param, old as i128, new as i128)?;
+            }
+        }
+
+        Self::log_admin_action(
+            &env,
+            &Self::admin(&env),
+            "set_decay_config",
+            Self::admin_digest(&env, old),
+            Self::admin_digest(&env, config.clone()),
+        );
+        match config {
+            Some(config) => {
+                env.storage().instance().set(&DataKey::DecayConfig, &config);
+                env.events()
+                    .publish((symbol_short!("DecayUpd"),), Some(config));
+            }
+            None => {
+                env.storage().instance().remove(&DataKey::DecayConfig);
+                env.events()
+                    .publish((symbol_short!("DecayUpd"),), None::<DecayConfig>);
+            }
+        }
+        Self::bump_instance_ttl(&env);
+        Ok(())
+    }
+
+    pub fn get_decay_config(env: Env) -> Option<DecayConfig> {
+        Self::bump_instance_ttl(&env);
+        Self::decay_config(&env)
+    }
+
+    /// Ledger of the holder's last repayment activity, if tracked.
+    /// Returns `None` for private records.
+    pub fn get_last_activity(env: Env, user: Address) -> Option<u32> {
+        if Self::is_private_record(&env, &user) {
+            return None;
+        }
+        let key = DataKey::Activity(user);
+        let record: Option<ActivityRecord> = env.storage().persistent().get(&key);
+        if record.is_some() {
+            Self::bump_persistent_ttl(&env, &key);
+        }
+        record.map(|record| record.last_activity)
+    }
+
+    /// Shift every score component by `delta`, clamped to 0..=`MAX_SCORE`.
+    pub fn apply_score_delta(
+        env: Env,
+        user: Address,
+        delta: i32,
+        minter: Option<Address>,
+    ) -> Result<(), NftError> {
+        let admin_call = minter.is_none();
+        Self::require_admin_or_authorized_minter(&env, minter)?;
+        Self::assert_operation_active(&env, NftOperation::ScoreUpdates)?;
+
+        let mut metadata =
+            Self::get_or_migrate_metadata(&env, &user).ok_or(NftError::NftNotFound)?;
+        let old = admin_call.then(|| Self::admin_score_value(&env, &user));
+
+        let shift = |score: u32| {
+            let shifted = (score as i64 + delta as i64).clamp(0, Self::MAX_SCORE as i64);
+            u32::try_from(shifted).expect("score overflow")
+        };
+        let breakdown = Self::read_breakdown(&env, &user, metadata.score);
+        let shifted = ScoreBreakdown {
+            repayment: shift(breakdown.repayment),
+            remittance: shift(breakdown.remittance),
+            tenure: shift(breakdown.tenure),
+            utilization: shift(breakdown.utilization),
+        };
+        if shifted == breakdown {
+            return Ok(());
+        }
+
+        Self::commit_breakdown(&env, &user, &mut metadata, &shifted, symbol_short!("ADJ"));
+        if let Some(old) = old {
+            Self::log_admin_action(
+                &env,
+                &Self::admin(&env),
+                "apply_score_delta",
+                old,
+                Self::admin_score_value(&env, &user),
+            );
+        }
+        Self::publish_score_event(&env, symbol_short!("ScoreUpd"), &user, metadata.score);
+        Ok(())
+    }
+
+    /// Set one score component directly, e.g. from an off-chain tenure or
+    /// utilization model. The composite is re-blended from the weights.
+    pub fn set_score_component(
+        env: Env,
+        user: Address,
+        component: ScoreComponent,
+        value: u32,
+        minter: Option<Address>,
+    ) -> Result<(), NftError> {
+        let admin_call = minter.is_none();
         Self::require_admin_or_authorized_minter(&env, minter)?;
+        Self::assert_operation_active(&env, NftOperation::ScoreUpdates)?;
 
-        let metadata_key = DataKey::Metadata(user.clone());
         let mut metadata =
             Self::get_or_migrate_metadata(&env, &user).ok_or(NftError::NftNotFound)?;
+        let old = admin_call.then(|| Self::admin_score_value(&env, &user));
 
-        // Simple logic: 1 point per 100 units of repayment
-        let points_i128 = repayment_amount / 100;
-        if points_i128 == 0 {
+        let mut breakdown = Self::read_breakdown(&env, &user, metadata.score);
+        let value = value.min(Self::MAX_SCORE);
+        if Self::component_score(&breakdown, component) == value {
             return Ok(());
         }
-        let points = if points_i128 > (Self::MAX_SCORE as i128) {
-            Self::MAX_SCORE
-        } else {
-            points_i128 as u32
-        };
-        let old_score = metadata.score;
-        metadata.score = old_score.saturating_add(points).min(Self::MAX_SCORE);
+        Self::set_component_score(&mut breakdown, component, value);
 
-        env.storage().persistent().set(&metadata_key, &metadata);
-        Self::bump_persistent_ttl(&env, &metadata_key);
-        Self::append_score_history(
+        Self::commit_breakdown(
             &env,
             &user,
-            old_score,
-            metadata.score,
-            symbol_short!("REPAY"),
+            &mut metadata,
+            &breakdown,
+            symbol_short!("COMP"),
+        );
+        if let Some(old) = old {
+            Self::log_admin_action(
+                &env,
+                &Self::admin(&env),
+                "set_score_component",
+                old,
+                Self::admin_score_value(&env, &user),
+            );
+        }
+        Self::publish_score_event(
+            &env,
+            symbol_short!("CompUpd"),
+            &user,
+            (component, value, metadata.score),
         );
-        env.events()
-            .publish((symbol_short!("ScoreUpd"), user), metadata.score);
-
         Ok(())
     }
 
-    pub fn decrease_score(env: Env, user: Address, penalty_points: u32, minter: Option<Address>) {
-        Self::require_admin_or_authorized_minter(&env, minter)
-            .unwrap_or_else(|_| panic!("unauthorized minter"));
-
-        let metadata_key = DataKey::Metadata(user.clone());
-        let mut metadata = Self::get_or_migrate_metadata(&env, &user)
-            .unwrap_or_else(|| panic!("user does not have an NFT"));
-
-        let old_score = metadata.score;
-        let decreased = old_score.saturating_sub(penalty_points);
-        let new_score = decreased.max(Self::MIN_CREDIT_SCORE);
-        if new_score == old_score {
-            return;
+    /// Component scores behind `get_score`, or `None` without an NFT.
+    pub fn get_score_breakdown(env: Env, user: Address) -> Option<ScoreBreakdown> {
+        if Self::is_private_record(&env, &user) {
+            return None;
         }
-
-        metadata.score = new_score;
-        env.storage().persistent().set(&metadata_key, &metadata);
-        Self::bump_persistent_ttl(&env, &metadata_key);
-        Self::append_score_history(&env, &user, old_score, new_score, symbol_short!("DEC"));
-        env.events().publish(
-            (symbol_short!("ScoreDecr"), user),
-            (old_score, new_score, symbol_short!("PEN")),
-        );
+        let metadata = Self::get_or_migrate_metadata(&env, &user)?;
+        Some(Self::read_breakdown(&env, &user, metadata.score))
     }
 
-    /// Update the history hash for a user's NFT.
-    pub fn apply_score_delta(
+    /// `get_score_breakdown` for a consumer holding a `Metadata` grant.
+    pub fn get_score_breakdown_for(
         env: Env,
         user: Address,
-        delta: i32,
-        minter: Option<Address>,
-    ) -> Result<(), NftError> {
-        Self::require_admin_or_authorized_minter(&env, minter)?;
-
-        let metadata_key = DataKey::Metadata(user.clone());
-        let mut metadata =
-            Self::get_or_migrate_metadata(&env, &user).ok_or(NftError::NftNotFound)?;
-
-        let old_score = metadata.score as i64;
-        let next_score = old_score + delta as i64;
-        let bounded_score = next_score.clamp(0, Self::MAX_SCORE as i64);
-        let next_score_u32 = u32::try_from(bounded_score).expect("score overflow");
+        consumer: Address,
+    ) -> Result<Option<ScoreBreakdown>, NftError> {
+        Self::require_read_access(&env, &user, &consumer, AccessScope::Metadata)?;
+        Ok(Self::get_or_migrate_metadata(&env, &user)
+            .map(|metadata| Self::read_breakdown(&env, &user, metadata.score)))
+    }
 
-        if next_score_u32 == metadata.score {
-            return Ok(());
+    /// Replace the weights that blend components into the composite.
+    /// Stored composites are re-blended lazily on their next read.
+    pub fn set_score_weights(env: Env, weights: ScoreWeights) -> Result<(), NftError> {
+        Self::admin(&env).require_auth();
+        let total = weights.repayment_bps as u64
+            + weights.remittance_bps as u64
+            + weights.tenure_bps as u64
+            + weights.utilization_bps as u64;
+        if total != Self::BPS_DENOMINATOR as u64 {
+            return Err(NftError::InvalidScoreWeights);
         }
 
-        let previous_score = metadata.score;
-        metadata.score = next_score_u32;
+        let old = Self::score_weights(&env);
+        for (param, old, new) in [
+            (
+                NftParam::RepaymentWeight,
+                old.repayment_bps,
+                weights.repayment_bps,
+            ),
+            (
+                NftParam::RemittanceWeight,
+                old.remittance_bps,
+                weights.remittance_bps,
+            ),
+            (NftParam::TenureWeight, old.tenure_bps, weights.tenure_bps),
+            (
+                NftParam::UtilizationWeight,
+                old.utilization_bps,
+                weights.utilization_bps,
+            ),
+        ] {
+            Self::check_param_change(&env, param, old as i128, new as i128)?;
+        }
 
-        env.storage().persistent().set(&metadata_key, &metadata);
-        Self::bump_persistent_ttl(&env, &metadata_key);
-        Self::append_score_history(
+        Self::log_admin_action(
             &env,
-            &user,
-            previous_score,
-            metadata.score,
-            symbol_short!("ADJ"),
+            &Self::admin(&env),
+            "set_score_weights",
+            Self::admin_digest(&env, old),
+            Self::admin_digest(&env, weights.clone()),
         );
-        env.events()
-            .publish((symbol_short!("ScoreUpd"), user), metadata.score);
+        env.storage()
+            .instance()
+            .set(&DataKey::ScoreWeights, &weights);
+        Self::bump_instance_ttl(&env);
+
+        env.events().publish((symbol_short!("WeightUpd"),), weights);
         Ok(())
     }
 
-    /// Update the history hash for a user's NFT.
+    pub fn get_score_weights(env: Env) -> ScoreWeights {
+        Self::bump_instance_ttl(&env);
+        Self::score_weights(&env)
+    }
+
+    /// Replace the Merkle root of a user's remittance history.
     pub fn update_history_hash(
         env: Env,
         user: Address,
         new_history_hash: BytesN<32>,
         minter: Option<Address>,
     ) -> Result<(), NftError> {
+        let admin_call = minter.is_none();
         Self::require_admin_or_authorized_minter(&env, minter)?;
 
         if new_history_hash == BytesN::from_array(&env, &[0u8; 32]) {
@@ -627,6 +3120,15 @@ impl RemittanceNFT {
         if metadata.history_hash == new_history_hash {
             return Err(NftError::InvalidHistoryHash);
         }
+        if admin_call {
+            Self::log_admin_action(
+                &env,
+                &Self::admin(&env),
+                "update_history_hash",
+                AdminValue::UserHash(user.clone(), metadata.history_hash.clone()),
+                AdminValue::UserHash(user.clone(), new_history_hash.clone()),
+            );
+        }
         metadata.history_hash = new_history_hash;
 
         env.storage().persistent().set(&metadata_key, &metadata);
@@ -639,6 +3141,258 @@ impl RemittanceNFT {
         Ok(())
     }
 
+    /// Accept a provider-signed summary of `user`'s remittances for `period`.
+    ///
+    /// Anyone may relay an attestation; authority comes from the signature
+    /// of a registered provider key, checked with ed25519 over the payload
+    /// described on `RemittanceAttestation` (the host traps on a bad
+    /// signature). Each (provider, user, period) is accepted once.
+    ///
+    /// The attestation extends the holder's attestation chain as
+    /// `sha256(chain_head || payload)` and adds score points according to
+    /// `AttestationScoring`, subject to the scoring policy's period cap.
+    #[allow(clippy::too_many_arguments)]
+    pub fn submit_remittance_attestation(
+        env: Env,
+        provider: BytesN<32>,
+        user: Address,
+        period: u32,
+        amount: i128,
+        corridor: Symbol,
+        count: u32,
+        signature: BytesN<64>,
+    ) -> Result<(), NftError> {
+        Self::assert_operation_active(&env, NftOperation::ScoreUpdates)?;
+        if amount <= 0 || count == 0 {
+            return Err(NftError::InvalidAttestation);
+        }
+        if !Self::is_attestation_provider(env.clone(), provider.clone()) {
+            return Err(NftError::UnknownAttestationProvider);
+        }
+
+        let replay_key = DataKey::Attestation(provider.clone(), user.clone(), period);
+        if env.storage().persistent().has(&replay_key) {
+            return Err(NftError::AttestationAlreadySubmitted);
+        }
+
+        let attestation = RemittanceAttestation {
+            user: user.clone(),
+            period,
+            amount,
+            corridor: corridor.clone(),
+            count,
+        };
+        let payload = (env.current_contract_address(), attestation).to_xdr(&env);
+        env.crypto().ed25519_verify(&provider, &payload, &signature);
+
+        if !Self::has_active_nft(&env, &user) {
+            return Err(NftError::NftNotFound);
+        }
+
+        env.storage().persistent().set(&replay_key, &true);
+        Self::bump_persistent_ttl(&env, &replay_key);
+
+        let chain_key = DataKey::AttestationChainHead(user.clone());
+        let chain_head: BytesN<32> = env
+            .storage()
+            .persistent()
+            .get(&chain_key)
+            .unwrap_or_else(|| Self::default_history_hash(&env));
+        let mut chained = Bytes::from_array(&env, &chain_head.to_array());
+        chained.append(&payload);
+        let chain_head: BytesN<32> = env.crypto().sha256(&chained).into();
+        env.storage().persistent().set(&chain_key, &chain_head);
+        Self::bump_persistent_ttl(&env, &chain_key);
+
+        let scoring = Self::attestation_scoring(&env);
+        let volume_points = amount / scoring.units_per_point;
+        let mut points = if volume_points > scoring.max_points_per_attestation as i128 {
+            scoring.max_points_per_attestation
+        } else {
+            volume_points as u32
+        };
+
+        let last_period_key = DataKey::LastAttestedPeriod(user.clone());
+        let last_period: Option<u32> = env.storage().persistent().get(&last_period_key);
+        if period > 0 && last_period == Some(period - 1) {
+            points = points.saturating_add(scoring.regularity_bonus);
+        }
+        if last_period.is_none_or(|last| period > last) {
+            env.storage().persistent().set(&last_period_key, &period);
+        }
+        Self::bump_persistent_ttl(&env, &last_period_key);
+
+        let policy = Self::scoring_policy(&env);
+        Self::raise_score(
+            &env,
+            &user,
+            &policy,
+            ScoreComponent::Remittance,
+            points,
+            symbol_short!("ATTEST"),
+        )?;
+
+        env.events().publish(
+            (symbol_short!("Attested"), user, period),
+            (provider, corridor, amount, count, chain_head),
+        );
+        Ok(())
+    }
+
+    pub fn get_attestation_chain_head(env: Env, user: Address) -> Option<BytesN<32>> {
+        let key = DataKey::AttestationChainHead(user);
+        let head: Option<BytesN<32>> = env.storage().persistent().get(&key);
+        if head.is_some() {
+            Self::bump_persistent_ttl(&env, &key);
+        }
+        head
+    }
+
+    /// Leaf hash of `record` in a history tree: `sha256(0x00 || xdr(record))`.
+    pub fn hash_history_record(env: Env, record: RemittanceRecord) -> BytesN<32> {
+        let mut leaf = Bytes::from_array(&env, &[0u8]);
+        leaf.append(&record.to_xdr(&env));
+        env.crypto().sha256(&leaf).into()
+    }
+
+    /// Check that `record` is committed to by `user`'s `history_hash`.
+    ///
+    /// Interior nodes are `sha256(0x01 || min(a, b) || max(a, b))`, so a
+    /// proof is just the sibling hashes from leaf to root, with no
+    /// left/right flags. The domain prefixes keep leaves and nodes from
+    /// being confused.
+    pub fn verify_history_record(
+        env: Env,
+        user: Address,
+        record: RemittanceRecord,
+        proof: Vec<BytesN<32>>,
+    ) -> bool {
+        let root = match Self::get_or_migrate_metadata(&env, &user) {
+            Some(metadata) => metadata.history_hash,
+            None => return false,
+        };
+        if root == Self::default_history_hash(&env) {
+            return false;
+        }
+
+        let mut node = Self::hash_history_record(env.clone(), record);
+        for sibling in proof.iter() {
+            let (lo, hi) = if node.to_array() <= sibling.to_array() {
+                (node, sibling)
+            } else {
+                (sibling, node)
+            };
+            let mut preimage = Bytes::from_array(&env, &[1u8]);
+            preimage.append(&Bytes::from_array(&env, &lo.to_array()));
+            preimage.append(&Bytes::from_array(&env, &hi.to_array()));
+            node = env.crypto().sha256(&preimage).into();
+        }
+        node == root
+    }
+
+    pub fn register_attestation_provider(env: Env, provider: BytesN<32>) {
+        Self::admin(&env).require_auth();
+
+        let key = DataKey::AttestationProvider(provider.clone());
+        env.storage().persistent().set(&key, &true);
+        Self::bump_persistent_ttl(&env, &key);
+
+        Self::log_admin_action(
+            &env,
+            &Self::admin(&env),
+            "register_attestation_provider",
+            AdminValue::None,
+            AdminValue::Hash(provider.clone()),
+        );
+        env.events()
+            .publish((symbol_short!("ProvAdded"),), provider);
+    }
+
+    pub fn remove_attestation_provider(env: Env, provider: BytesN<32>) {
+        Self::admin(&env).require_auth();
+
+        env.storage()
+            .persistent()
+            .remove(&DataKey::AttestationProvider(provider.clone()));
+
+        Self::log_admin_action(
+            &env,
+            &Self::admin(&env),
+            "remove_attestation_provider",
+            AdminValue::Hash(provider.clone()),
+            AdminValue::None,
+        );
+        env.events().publish((symbol_short!("ProvRmvd"),), provider);
+    }
+
+    pub fn is_attestation_provider(env: Env, provider: BytesN<32>) -> bool {
+        let key = DataKey::AttestationProvider(provider);
+        let registered = env.storage().persistent().has(&key);
+        if registered {
+            Self::bump_persistent_ttl(&env, &key);
+        }
+        registered
+    }
+
+    pub fn is_attestation_submitted(
+        env: Env,
+        provider: BytesN<32>,
+        user: Address,
+        period: u32,
+    ) -> bool {
+        env.storage()
+            .persistent()
+            .has(&DataKey::Attestation(provider, user, period))
+    }
+
+    pub fn set_attestation_scoring(env: Env, scoring: AttestationScoring) -> Result<(), NftError> {
+        Self::admin(&env).require_auth();
+        if scoring.units_per_point <= 0 {
+            return Err(NftError::InvalidScoringPolicy);
+        }
+
+        let old = Self::attestation_scoring(&env);
+        for (param, old, new) in [
+            (
+                NftParam::AttestationUnitsPerPoint,
+                old.units_per_point,
+                scoring.units_per_point,
+            ),
+            (
+                NftParam::AttestationMaxPoints,
+                old.max_points_per_attestation as i128,
+                scoring.max_points_per_attestation as i128,
+            ),
+            (
+                NftParam::AttestationRegularityBonus,
+                old.regularity_bonus as i128,
+                scoring.regularity_bonus as i128,
+            ),
+        ] {
+            Self::check_param_change(&env, param, old, new)?;
+        }
+
+        Self::log_admin_action(
+            &env,
+            &Self::admin(&env),
+            "set_attestation_scoring",
+            Self::admin_digest(&env, old),
+            Self::admin_digest(&env, scoring.clone()),
+        );
+        env.storage()
+            .instance()
+            .set(&DataKey::AttestationScoring, &scoring);
+        Self::bump_instance_ttl(&env);
+
+        env.events().publish((symbol_short!("AttScUpd"),), scoring);
+        Ok(())
+    }
+
+    pub fn get_attestation_scoring(env: Env) -> AttestationScoring {
+        Self::bump_instance_ttl(&env);
+        Self::attestation_scoring(&env)
+    }
+
     /// Mark a borrower's collateral as seized.
     ///
     /// # Seized flag semantics
@@ -655,6 +3409,7 @@ impl RemittanceNFT {
         user: Address,
         minter: Option<Address>,
     ) -> Result<(), NftError> {
+        let admin_call = minter.is_none();
         Self::require_admin_or_authorized_minter(&env, minter)?;
 
         let metadata_key = DataKey::Metadata(user.clone());
@@ -672,6 +3427,15 @@ impl RemittanceNFT {
 
         env.storage().persistent().set(&seized_key, &true);
         Self::bump_persistent_ttl(&env, &seized_key);
+        if admin_call {
+            Self::log_admin_action(
+                &env,
+                &Self::admin(&env),
+                "seize_collateral",
+                AdminValue::UserFlag(user.clone(), false),
+                AdminValue::UserFlag(user.clone(), true),
+            );
+        }
         env.events().publish((symbol_short!("Seized"), user), ());
 
         Ok(())
@@ -682,6 +3446,7 @@ impl RemittanceNFT {
         user: Address,
         minter: Option<Address>,
     ) -> Result<(), NftError> {
+        let admin_call = minter.is_none();
         Self::require_admin_or_authorized_minter(&env, minter)?;
 
         if !Self::has_active_nft(&env, &user) {
@@ -698,6 +3463,15 @@ impl RemittanceNFT {
             .expect("default count overflow");
         env.storage().persistent().set(&default_key, &updated_count);
         Self::bump_persistent_ttl(&env, &default_key);
+        if admin_call {
+            Self::log_admin_action(
+                &env,
+                &Self::admin(&env),
+                "record_default",
+                AdminValue::Address(user.clone()),
+                AdminValue::U32(updated_count),
+            );
+        }
 
         let seized_key = DataKey::Seized(user.clone());
         if !env.storage().persistent().has(&seized_key) {
@@ -711,114 +3485,451 @@ impl RemittanceNFT {
             Self::burn_internal(&env, &user);
         }
 
-        Ok(())
+        Ok(())
+    }
+
+    pub fn burn(env: Env, user: Address, minter: Option<Address>) -> Result<(), NftError> {
+        let admin_call = minter.is_none();
+        Self::require_admin_or_authorized_minter(&env, minter)?;
+
+        if !Self::has_active_nft(&env, &user) {
+            return Err(NftError::NftNotFound);
+        }
+
+        if admin_call {
+            Self::log_admin_action(
+                &env,
+                &Self::admin(&env),
+                "burn",
+                Self::admin_score_value(&env, &user),
+                AdminValue::None,
+            );
+        }
+        Self::burn_internal(&env, &user);
+
+        Ok(())
+    }
+
+    pub fn transfer(
+        env: Env,
+        from: Address,
+        to: Address,
+        minter: Option<Address>,
+    ) -> Result<(), NftError> {
+        if from == to {
+            return Err(NftError::SelfTransfer);
+        }
+
+        from.require_auth();
+        let admin_call = minter.is_none();
+        Self::require_admin_or_authorized_minter(&env, minter)?;
+        Self::assert_operation_active(&env, NftOperation::Transfers)?;
+
+        if Self::soulbound(&env, &from) {
+            return Err(NftError::SoulboundToken);
+        }
+
+        let transfer_cooldown_key = DataKey::TransferCooldown(from.clone());
+        if let Some(next_allowed_ledger) = env
+            .storage()
+            .persistent()
+            .get::<DataKey, u32>(&transfer_cooldown_key)
+        {
+            Self::bump_persistent_ttl(&env, &transfer_cooldown_key);
+            if env.ledger().sequence() < next_allowed_ledger {
+                return Err(NftError::TransferCooldownActive);
+            }
+        }
+
+        let metadata = Self::get_or_migrate_metadata(&env, &from).ok_or(NftError::NftNotFound)?;
+
+        if Self::has_any_remittance_state(&env, &to) {
+            return Err(NftError::DestinationOccupied);
+        }
+
+        Self::move_record(&env, &from, &to, &metadata, false)?;
+        if admin_call {
+            Self::log_admin_action(
+                &env,
+                &Self::admin(&env),
+                "transfer",
+                AdminValue::Address(from.clone()),
+                AdminValue::Address(to.clone()),
+            );
+        }
+
+        env.events()
+            .publish((symbol_short!("Transfer"), from, to), ());
+
+        Ok(())
+    }
+
+    /// Set whether records are soulbound (non-transferable) unless a
+    /// holder-level override says otherwise.
+    pub fn set_soulbound_default(env: Env, soulbound: bool) {
+        Self::admin(&env).require_auth();
+        Self::log_admin_action(
+            &env,
+            &Self::admin(&env),
+            "set_soulbound_default",
+            AdminValue::Bool(
+                env.storage()
+                    .instance()
+                    .get(&DataKey::SoulboundDefault)
+                    .unwrap_or(false),
+            ),
+            AdminValue::Bool(soulbound),
+        );
+        env.storage()
+            .instance()
+            .set(&DataKey::SoulboundDefault, &soulbound);
+        Self::bump_instance_ttl(&env);
+        env.events()
+            .publish((symbol_short!("SoulDflt"),), soulbound);
+    }
+
+    /// Override the soulbound default for one holder; `None` clears it.
+    pub fn set_soulbound(env: Env, user: Address, soulbound: Option<bool>) -> Result<(), NftError> {
+        Self::admin(&env).require_auth();
+        if !Self::has_any_remittance_state(&env, &user) {
+            return Err(NftError::NftNotFound);
+        }
+
+        let key = DataKey::Soulbound(user.clone());
+        Self::log_admin_action(
+            &env,
+            &Self::admin(&env),
+            "set_soulbound",
+            env.storage()
+                .persistent()
+                .get::<DataKey, bool>(&key)
+                .map_or(AdminValue::Address(user.clone()), |old| {
+                    AdminValue::UserFlag(user.clone(), old)
+                }),
+            soulbound.map_or(AdminValue::Address(user.clone()), |new| {
+                AdminValue::UserFlag(user.clone(), new)
+            }),
+        );
+        match soulbound {
+            Some(value) => {
+                env.storage().persistent().set(&key, &value);
+                Self::bump_persistent_ttl(&env, &key);
+            }
+            None => env.storage().persistent().remove(&key),
+        }
+        env.events()
+            .publish((symbol_short!("SoulSet"), user), soulbound);
+        Ok(())
+    }
+
+    pub fn is_soulbound(env: Env, user: Address) -> bool {
+        Self::bump_instance_ttl(&env);
+        Self::soulbound(&env, &user)
+    }
+
+    /// Ask to move `lost`'s record to the caller's fresh address. Completes
+    /// once `lost` approves it, or once the admin executes it after the
+    /// recovery timelock. A newer request replaces a pending one and restarts
+    /// the timelock. Soulbound records and transfer cooldowns do not apply.
+    pub fn request_recovery(env: Env, lost: Address, new_holder: Address) -> Result<(), NftError> {
+        new_holder.require_auth();
+        Self::assert_not_paused(&env)?;
+        if lost == new_holder {
+            return Err(NftError::SelfTransfer);
+        }
+        Self::get_or_migrate_metadata(&env, &lost).ok_or(NftError::NftNotFound)?;
+        if Self::has_any_remittance_state(&env, &new_holder) {
+            return Err(NftError::DestinationOccupied);
+        }
+
+        let key = DataKey::Recovery(lost.clone());
+        let now = env.ledger().sequence();
+        let request = RecoveryRequest {
+            new_holder: new_holder.clone(),
+            requested_at: now,
+            executable_after: now.saturating_add(Self::recovery_timelock(&env)),
+        };
+        env.storage().persistent().set(&key, &request);
+        Self::bump_persistent_ttl(&env, &key);
+
+        env.events().publish(
+            (symbol_short!("RecReq"), lost, new_holder),
+            request.executable_after,
+        );
+        Ok(())
+    }
+
+    /// The old holder consents to the pending recovery to `new_holder`,
+    /// completing it at once.
+    pub fn approve_recovery(env: Env, lost: Address, new_holder: Address) -> Result<(), NftError> {
+        lost.require_auth();
+        Self::assert_not_paused(&env)?;
+        let request = Self::pending_recovery(&env, &lost, &new_holder)?;
+        Self::complete_recovery(&env, &lost, &request)
+    }
+
+    /// Admin (or governance acting as admin) completes a recovery to
+    /// `new_holder` the old holder could not approve, once its timelock has
+    /// elapsed.
+    pub fn execute_recovery(env: Env, lost: Address, new_holder: Address) -> Result<(), NftError> {
+        Self::admin(&env).require_auth();
+        Self::assert_not_paused(&env)?;
+        let request = Self::pending_recovery(&env, &lost, &new_holder)?;
+        if env.ledger().sequence() < request.executable_after {
+            return Err(NftError::RecoveryTimelockActive);
+        }
+        Self::log_admin_action(
+            &env,
+            &Self::admin(&env),
+            "execute_recovery",
+            AdminValue::Address(lost.clone()),
+            AdminValue::Address(request.new_holder.clone()),
+        );
+        Self::complete_recovery(&env, &lost, &request)
+    }
+
+    /// The holder rejects a recovery they did not ask for.
+    pub fn cancel_recovery(env: Env, lost: Address) -> Result<(), NftError> {
+        lost.require_auth();
+        Self::remove_recovery(&env, &lost)
     }
 
-    pub fn burn(env: Env, user: Address, minter: Option<Address>) -> Result<(), NftError> {
-        Self::require_admin_or_authorized_minter(&env, minter)?;
+    /// Admin drops a stale or disputed recovery request.
+    pub fn reject_recovery(env: Env, lost: Address) -> Result<(), NftError> {
+        Self::admin(&env).require_auth();
+        Self::remove_recovery(&env, &lost)?;
+        Self::log_admin_action(
+            &env,
+            &Self::admin(&env),
+            "reject_recovery",
+            AdminValue::Address(lost.clone()),
+            AdminValue::None,
+        );
+        Ok(())
+    }
 
-        if !Self::has_active_nft(&env, &user) {
-            return Err(NftError::NftNotFound);
+    /// The pending request for `lost`, provided it still names `new_holder`;
+    /// a request replaced since the caller looked is never completed.
+    fn pending_recovery(
+        env: &Env,
+        lost: &Address,
+        new_holder: &Address,
+    ) -> Result<RecoveryRequest, NftError> {
+        let request: RecoveryRequest = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Recovery(lost.clone()))
+            .ok_or(NftError::RecoveryNotFound)?;
+        if request.new_holder != *new_holder {
+            return Err(NftError::RecoveryHolderMismatch);
         }
+        Ok(request)
+    }
 
-        Self::burn_internal(&env, &user);
-
+    fn remove_recovery(env: &Env, lost: &Address) -> Result<(), NftError> {
+        let key = DataKey::Recovery(lost.clone());
+        if !env.storage().persistent().has(&key) {
+            return Err(NftError::RecoveryNotFound);
+        }
+        env.storage().persistent().remove(&key);
+        env.events()
+            .publish((symbol_short!("RecCncl"), lost.clone()), ());
         Ok(())
     }
 
-    pub fn transfer(
+    pub fn get_recovery_request(env: Env, lost: Address) -> Option<RecoveryRequest> {
+        let key = DataKey::Recovery(lost);
+        let request = env.storage().persistent().get(&key);
+        if request.is_some() {
+            Self::bump_persistent_ttl(&env, &key);
+        }
+        request
+    }
+
+    /// Register, tighten, loosen or remove the rate-of-change limit on
+    /// `param`. Registering or tightening applies at once; loosening or
+    /// removing needs a matured `ParamOverride::Limit` for the same limit.
+    pub fn set_param_limit(
         env: Env,
-        from: Address,
-        to: Address,
-        minter: Option<Address>,
+        param: NftParam,
+        limit: Option<ParamLimit>,
     ) -> Result<(), NftError> {
-        if from == to {
-            return Err(NftError::SelfTransfer);
+        Self::admin(&env).require_auth();
+        if let Some(limit) = &limit {
+            Self::validate_param_limit(limit)?;
         }
 
-        from.require_auth();
-        Self::require_admin_or_authorized_minter(&env, minter)?;
-
-        let transfer_cooldown_key = DataKey::TransferCooldown(from.clone());
-        if let Some(next_allowed_ledger) = env
-            .storage()
-            .persistent()
-            .get::<DataKey, u32>(&transfer_cooldown_key)
-        {
-            Self::bump_persistent_ttl(&env, &transfer_cooldown_key);
-            if env.ledger().sequence() < next_allowed_ledger {
-                return Err(NftError::TransferCooldownActive);
+        let old_limit = Self::read_param_limit(&env, &param);
+        let tightening = match (old_limit.clone(), &limit) {
+            (None, _) => true,
+            (Some(old), Some(new)) => {
+                new.max_change <= old.max_change
+                    && new.max_change_bps <= old.max_change_bps
+                    && new.window_ledgers >= old.window_ledgers
             }
+            (Some(_), None) => false,
+        };
+        if !tightening {
+            Self::consume_param_override(
+                &env,
+                &param,
+                &match &limit {
+                    Some(limit) => ParamOverride::Limit(limit.clone()),
+                    None => ParamOverride::RemoveLimit,
+                },
+            )?;
         }
 
-        let metadata = Self::get_or_migrate_metadata(&env, &from).ok_or(NftError::NftNotFound)?;
-
-        if Self::has_any_remittance_state(&env, &to) {
-            return Err(NftError::DestinationOccupied);
+        let key = DataKey::ParamLimit(param.clone());
+        match &limit {
+            Some(limit) => {
+                env.storage().persistent().set(&key, limit);
+                Self::bump_persistent_ttl(&env, &key);
+            }
+            None => env.storage().persistent().remove(&key),
         }
+        Self::log_admin_action(
+            &env,
+            &Self::admin(&env),
+            "set_param_limit",
+            old_limit.map_or(AdminValue::None, |old| {
+                AdminValue::Limit(param.clone(), old)
+            }),
+            limit.clone().map_or(AdminValue::None, |new| {
+                AdminValue::Limit(param.clone(), new)
+            }),
+        );
+        env.events()
+            .publish((Symbol::new(&env, "ParamLimitSet"), param), limit);
+        Ok(())
+    }
 
-        let from_metadata_key = DataKey::Metadata(from.clone());
-        let to_metadata_key = DataKey::Metadata(to.clone());
-        env.storage().persistent().set(&to_metadata_key, &metadata);
-        Self::bump_persistent_ttl(&env, &to_metadata_key);
-        env.storage().persistent().remove(&from_metadata_key);
-        env.storage()
-            .persistent()
-            .remove(&DataKey::Score(from.clone()));
+    pub fn get_param_limit(env: Env, param: NftParam) -> Option<ParamLimit> {
+        Self::read_param_limit(&env, &param)
+    }
 
-        let from_history_key = DataKey::ScoreHistory(from.clone());
-        if let Some(history) = env
-            .storage()
-            .persistent()
-            .get::<DataKey, Vec<ScoreHistoryEntry>>(&from_history_key)
-        {
-            let to_history_key = DataKey::ScoreHistory(to.clone());
-            env.storage().persistent().set(&to_history_key, &history);
-            Self::bump_persistent_ttl(&env, &to_history_key);
-            env.storage().persistent().remove(&from_history_key);
+    /// Announce a change that exceeds `param`'s limits. Once
+    /// `PARAM_OVERRIDE_DELAY_LEDGERS` have passed, the matching setter (or
+    /// `set_param_limit`) call goes through. Replaces any earlier override
+    /// for the same parameter.
+    pub fn schedule_param_override(
+        env: Env,
+        param: NftParam,
+        change: ParamOverride,
+    ) -> Result<u32, NftError> {
+        Self::admin(&env).require_auth();
+        if let ParamOverride::Limit(limit) = &change {
+            Self::validate_param_limit(limit)?;
         }
 
-        let from_default_key = DataKey::DefaultCount(from.clone());
-        if let Some(default_count) = env
+        let eta = env
+            .ledger()
+            .sequence()
+            .saturating_add(Self::PARAM_OVERRIDE_DELAY_LEDGERS);
+        let key = DataKey::ParamOverride(param.clone());
+        let replaced: Option<PendingParamOverride> = env.storage().persistent().get(&key);
+        env.storage().persistent().set(
+            &key,
+            &PendingParamOverride {
+                change: change.clone(),
+                eta,
+            },
+        );
+        Self::bump_persistent_ttl(&env, &key);
+        Self::log_admin_action(
+            &env,
+            &Self::admin(&env),
+            "schedule_param_override",
+            replaced.map_or(AdminValue::None, |old| {
+                AdminValue::Override(param.clone(), old.change)
+            }),
+            AdminValue::Override(param.clone(), change.clone()),
+        );
+        env.events().publish(
+            (Symbol::new(&env, "ParamOverrideScheduled"), param),
+            (change, eta),
+        );
+        Ok(eta)
+    }
+
+    pub fn cancel_param_override(env: Env, param: NftParam) -> Result<(), NftError> {
+        Self::admin(&env).require_auth();
+        let key = DataKey::ParamOverride(param.clone());
+        let pending: PendingParamOverride = env
             .storage()
             .persistent()
-            .get::<DataKey, u32>(&from_default_key)
-        {
-            let to_default_key = DataKey::DefaultCount(to.clone());
-            env.storage()
-                .persistent()
-                .set(&to_default_key, &default_count);
-            Self::bump_persistent_ttl(&env, &to_default_key);
-            env.storage().persistent().remove(&from_default_key);
-        }
-
-        let from_seized_key = DataKey::Seized(from.clone());
-        if env.storage().persistent().has(&from_seized_key) {
-            let to_seized_key = DataKey::Seized(to.clone());
-            env.storage().persistent().set(&to_seized_key, &true);
-            Self::bump_persistent_ttl(&env, &to_seized_key);
-            env.storage().persistent().remove(&from_seized_key);
-        }
+            .get(&key)
+            .ok_or(NftError::NoParamOverride)?;
+        env.storage().persistent().remove(&key);
+        Self::log_admin_action(
+            &env,
+            &Self::admin(&env),
+            "cancel_param_override",
+            AdminValue::Override(param.clone(), pending.change),
+            AdminValue::None,
+        );
+        env.events()
+            .publish((Symbol::new(&env, "ParamOverrideCancelled"), param), ());
+        Ok(())
+    }
 
+    pub fn get_param_override(env: Env, param: NftParam) -> Option<PendingParamOverride> {
         env.storage()
             .persistent()
-            .remove(&DataKey::RemintApproval(from.clone()));
+            .get(&DataKey::ParamOverride(param))
+    }
 
-        env.storage().persistent().remove(&transfer_cooldown_key);
-        let to_cooldown_key = DataKey::TransferCooldown(to.clone());
-        let next_allowed_ledger = env
-            .ledger()
-            .sequence()
-            .saturating_add(Self::TRANSFER_COOLDOWN_LEDGERS);
+    pub fn set_recovery_timelock(env: Env, ledgers: u32) -> Result<(), NftError> {
+        Self::admin(&env).require_auth();
+        let old_ledgers = Self::recovery_timelock(&env);
+        Self::check_param_change(
+            &env,
+            NftParam::RecoveryTimelock,
+            old_ledgers as i128,
+            ledgers as i128,
+        )?;
         env.storage()
-            .persistent()
-            .set(&to_cooldown_key, &next_allowed_ledger);
-        Self::bump_persistent_ttl(&env, &to_cooldown_key);
+            .instance()
+            .set(&DataKey::RecoveryTimelock, &ledgers);
+        Self::bump_instance_ttl(&env);
+        Self::log_admin_action(
+            &env,
+            &Self::admin(&env),
+            "set_recovery_timelock",
+            AdminValue::U32(old_ledgers),
+            AdminValue::U32(ledgers),
+        );
+        env.events().publish((symbol_short!("RecTlUpd"),), ledgers);
+        Ok(())
+    }
 
-        env.events()
-            .publish((symbol_short!("Transfer"), from, to), ());
+    pub fn get_recovery_timelock(env: Env) -> u32 {
+        Self::bump_instance_ttl(&env);
+        Self::recovery_timelock(&env)
+    }
 
-        Ok(())
+    /// Register the contract told about every record move (LoanManager), or
+    /// clear it with `None`. Transfers and recoveries fail while unset.
+    pub fn set_loan_hook(env: Env, hook: Option<Address>) {
+        Self::admin(&env).require_auth();
+        Self::log_admin_action(
+            &env,
+            &Self::admin(&env),
+            "set_loan_hook",
+            Self::loan_hook(&env).map_or(AdminValue::None, AdminValue::Address),
+            hook.clone().map_or(AdminValue::None, AdminValue::Address),
+        );
+        match &hook {
+            Some(address) => env.storage().instance().set(&DataKey::LoanHook, address),
+            None => env.storage().instance().remove(&DataKey::LoanHook),
+        }
+        Self::bump_instance_ttl(&env);
+        env.events().publish((symbol_short!("HookUpd"),), hook);
+    }
+
+    pub fn get_loan_hook(env: Env) -> Option<Address> {
+        Self::bump_instance_ttl(&env);
+        Self::loan_hook(&env)
     }
 
     /// Returns true if the borrower's collateral has been seized.
@@ -853,6 +3964,13 @@ impl RemittanceNFT {
         Self::admin(&env).require_auth();
         Self::assert_not_paused(&env)?;
 
+        Self::log_admin_action(
+            &env,
+            &Self::admin(&env),
+            "approve_remint",
+            AdminValue::None,
+            AdminValue::Address(user.clone()),
+        );
         let approval_key = DataKey::RemintApproval(user);
         env.storage().persistent().set(&approval_key, &true);
         Self::bump_persistent_ttl(&env, &approval_key);
@@ -866,6 +3984,20 @@ impl RemittanceNFT {
         Self::admin(&env).require_auth();
         Self::assert_not_paused(&env)?;
 
+        let old_threshold = Self::default_burn_threshold(&env);
+        Self::check_param_change(
+            &env,
+            NftParam::DefaultBurnThreshold,
+            old_threshold as i128,
+            threshold as i128,
+        )?;
+        Self::log_admin_action(
+            &env,
+            &Self::admin(&env),
+            "set_default_burn_threshold",
+            AdminValue::U32(old_threshold),
+            AdminValue::U32(threshold),
+        );
         env.storage()
             .instance()
             .set(&Self::burn_threshold_key(), &threshold);
@@ -878,23 +4010,39 @@ impl RemittanceNFT {
         Self::default_burn_threshold(&env)
     }
 
+    /// Up to `limit` history entries starting at index `cursor`, oldest
+    /// first. `limit` is capped at `MAX_SCORE_HISTORY_LIMIT`; pass
+    /// `cursor + returned.len()` to fetch the next batch.
     pub fn get_score_history(
         env: Env,
         user: Address,
-        offset: u32,
+        cursor: u32,
         limit: u32,
     ) -> Vec<ScoreHistoryEntry> {
-        let history = Self::get_score_history_or_default(&env, &user);
-        let len = history.len();
-        if offset >= len {
+        if Self::is_private_record(&env, &user) {
             return Vec::new(&env);
         }
-        let mut page = Vec::new(&env);
-        let end = (offset + limit).min(len);
-        for idx in offset..end {
-            page.push_back(history.get(idx).unwrap());
+        Self::read_score_history(&env, &user, cursor, limit)
+    }
+
+    /// `get_score_history` for a consumer holding a `History` grant.
+    pub fn get_score_history_for(
+        env: Env,
+        user: Address,
+        consumer: Address,
+        cursor: u32,
+        limit: u32,
+    ) -> Result<Vec<ScoreHistoryEntry>, NftError> {
+        Self::require_read_access(&env, &user, &consumer, AccessScope::History)?;
+        Ok(Self::read_score_history(&env, &user, cursor, limit))
+    }
+
+    /// Returns 0 for private records.
+    pub fn get_score_history_len(env: Env, user: Address) -> u32 {
+        if Self::is_private_record(&env, &user) {
+            return 0;
         }
-        page
+        Self::score_history_len(&env, &user)
     }
 
     /// Get the number of ledgers remaining in a user's transfer cooldown.
@@ -921,24 +4069,248 @@ impl RemittanceNFT {
         approved
     }
 
-    pub fn is_paused(env: Env) -> bool {
+    /// Record the governance contract that appoints the guardian. The admin
+    /// names it once; after that only the current governance can move it.
+    pub fn set_governance(env: Env, governance: Address) {
+        let current: Option<Address> = env.storage().instance().get(&Self::governance_key());
+        let actor = current.clone().unwrap_or_else(|| Self::admin(&env));
+        actor.require_auth();
+        Self::log_admin_action(
+            &env,
+            &actor,
+            "set_governance",
+            current.map_or(AdminValue::None, AdminValue::Address),
+            AdminValue::Address(governance.clone()),
+        );
+        env.storage()
+            .instance()
+            .set(&Self::governance_key(), &governance);
+        Self::bump_instance_ttl(&env);
+        env.events()
+            .publish((Symbol::new(&env, "GovernanceSet"),), governance);
+    }
+
+    pub fn get_governance(env: Env) -> Option<Address> {
+        Self::bump_instance_ttl(&env);
+        env.storage().instance().get(&Self::governance_key())
+    }
+
+    /// Name the guardian allowed to call `guardian_pause`, or clear it with
+    /// `None`. Only the governance recorded by `set_governance` may do this;
+    /// the admin alone cannot appoint the account that can pause it.
+    pub fn set_guardian(env: Env, guardian: Option<Address>) -> Result<(), NftError> {
+        let governance: Address = env
+            .storage()
+            .instance()
+            .get(&Self::governance_key())
+            .ok_or(NftError::GovernanceNotSet)?;
+        governance.require_auth();
+        Self::log_admin_action(
+            &env,
+            &governance,
+            "set_guardian",
+            env.storage()
+                .instance()
+                .get::<DataKey, Address>(&DataKey::Guardian)
+                .map_or(AdminValue::None, AdminValue::Address),
+            guardian
+                .clone()
+                .map_or(AdminValue::None, AdminValue::Address),
+        );
+        match &guardian {
+            Some(guardian) => env.storage().instance().set(&DataKey::Guardian, guardian),
+            None => env.storage().instance().remove(&DataKey::Guardian),
+        }
+        Self::bump_instance_ttl(&env);
+        env.events()
+            .publish((Symbol::new(&env, "GuardianSet"),), guardian);
+        Ok(())
+    }
+
+    pub fn get_guardian(env: Env) -> Option<Address> {
         Self::bump_instance_ttl(&env);
+        env.storage().instance().get(&DataKey::Guardian)
+    }
+
+    pub fn set_guardian_pause_ledgers(env: Env, ledgers: u32) -> Result<(), NftError> {
+        Self::admin(&env).require_auth();
+        if ledgers == 0 {
+            return Err(NftError::InvalidPauseWindow);
+        }
+        let old_ledgers = Self::get_guardian_pause_ledgers(env.clone());
+        Self::check_param_change(
+            &env,
+            NftParam::GuardianPauseLedgers,
+            old_ledgers as i128,
+            ledgers as i128,
+        )?;
         env.storage()
             .instance()
-            .get(&DataKey::Paused)
-            .unwrap_or(false)
+            .set(&DataKey::GuardianPauseLedgers, &ledgers);
+        Self::bump_instance_ttl(&env);
+        Self::log_admin_action(
+            &env,
+            &Self::admin(&env),
+            "set_guardian_pause_ledgers",
+            AdminValue::U32(old_ledgers),
+            AdminValue::U32(ledgers),
+        );
+        env.events()
+            .publish((Symbol::new(&env, "GuardianPauseLedgers"),), ledgers);
+        Ok(())
+    }
+
+    pub fn get_guardian_pause_ledgers(env: Env) -> u32 {
+        Self::bump_instance_ttl(&env);
+        env.storage()
+            .instance()
+            .get(&DataKey::GuardianPauseLedgers)
+            .unwrap_or(Self::DEFAULT_GUARDIAN_PAUSE_LEDGERS)
+    }
+
+    /// Pause immediately on the guardian's authority. The pause lapses after
+    /// `get_guardian_pause_ledgers` ledgers unless the admin ratifies it; the
+    /// guardian has no way to unpause. Returns the expiry ledger.
+    ///
+    /// The guardian gets one pause per governance decision: once it lapses,
+    /// the admin has to pause, unpause or ratify before it can pause again.
+    pub fn guardian_pause(env: Env) -> Result<u32, NftError> {
+        let guardian: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Guardian)
+            .ok_or(NftError::GuardianNotSet)?;
+        guardian.require_auth();
+        if Self::paused_now(&env) {
+            return Err(NftError::AlreadyPaused);
+        }
+        // A lapsed guardian pause keeps its expiry until the admin acts.
+        if env.storage().instance().has(&DataKey::GuardianPauseExpiry) {
+            return Err(NftError::GuardianPauseSpent);
+        }
+
+        let expiry = env
+            .ledger()
+            .sequence()
+            .saturating_add(Self::get_guardian_pause_ledgers(env.clone()));
+        env.storage().instance().set(&DataKey::Paused, &true);
+        env.storage()
+            .instance()
+            .set(&DataKey::GuardianPauseExpiry, &expiry);
+        Self::bump_instance_ttl(&env);
+        Self::log_admin_action(
+            &env,
+            &guardian,
+            "guardian_pause",
+            AdminValue::None,
+            AdminValue::U32(expiry),
+        );
+        env.events()
+            .publish((Symbol::new(&env, "GuardianPaused"), guardian), expiry);
+        Ok(expiry)
+    }
+
+    /// Turn the active guardian pause into an indefinite one. Admin only.
+    pub fn ratify_pause(env: Env) -> Result<(), NftError> {
+        Self::admin(&env).require_auth();
+        let expiry =
+            Self::get_guardian_pause_expiry(env.clone()).ok_or(NftError::NoGuardianPause)?;
+        env.storage()
+            .instance()
+            .remove(&DataKey::GuardianPauseExpiry);
+        Self::bump_instance_ttl(&env);
+        Self::log_admin_action(
+            &env,
+            &Self::admin(&env),
+            "ratify_pause",
+            AdminValue::U32(expiry),
+            AdminValue::None,
+        );
+        env.events()
+            .publish((Symbol::new(&env, "PauseRatified"),), expiry);
+        Ok(())
+    }
+
+    /// Expiry ledger of the guardian pause currently in force, if any.
+    pub fn get_guardian_pause_expiry(env: Env) -> Option<u32> {
+        Self::bump_instance_ttl(&env);
+        if !Self::paused_now(&env) {
+            return None;
+        }
+        env.storage().instance().get(&DataKey::GuardianPauseExpiry)
+    }
+
+    /// Pause a single operation without touching the rest of the contract.
+    pub fn pause_operation(env: Env, operation: NftOperation) {
+        Self::set_operation_paused(&env, operation, true);
+    }
+
+    pub fn unpause_operation(env: Env, operation: NftOperation) {
+        Self::set_operation_paused(&env, operation, false);
+    }
+
+    /// The operation's own flag; the global pause is reported by `is_paused`.
+    pub fn is_operation_paused(env: Env, operation: NftOperation) -> bool {
+        Self::bump_instance_ttl(&env);
+        Self::operation_paused(&env, operation)
+    }
+
+    fn set_operation_paused(env: &Env, operation: NftOperation, paused: bool) {
+        Self::admin(env).require_auth();
+        Self::log_admin_action(
+            env,
+            &Self::admin(env),
+            if paused {
+                "pause_operation"
+            } else {
+                "unpause_operation"
+            },
+            AdminValue::Operation(operation, Self::operation_paused(env, operation)),
+            AdminValue::Operation(operation, paused),
+        );
+        env.storage()
+            .instance()
+            .set(&DataKey::OperationPaused(operation), &paused);
+        Self::bump_instance_ttl(env);
+        env.events()
+            .publish((Symbol::new(env, "OperationPauseSet"), operation), paused);
+    }
+
+    pub fn is_paused(env: Env) -> bool {
+        Self::bump_instance_ttl(&env);
+        Self::paused_now(&env)
     }
 
     pub fn pause(env: Env) {
         Self::admin(&env).require_auth();
+        Self::log_admin_action(
+            &env,
+            &Self::admin(&env),
+            "pause",
+            AdminValue::Bool(Self::paused_now(&env)),
+            AdminValue::Bool(true),
+        );
         env.storage().instance().set(&DataKey::Paused, &true);
+        env.storage()
+            .instance()
+            .remove(&DataKey::GuardianPauseExpiry);
         Self::bump_instance_ttl(&env);
         env.events().publish((symbol_short!("Paused"),), ());
     }
 
     pub fn unpause(env: Env) {
         Self::admin(&env).require_auth();
+        Self::log_admin_action(
+            &env,
+            &Self::admin(&env),
+            "unpause",
+            AdminValue::Bool(Self::paused_now(&env)),
+            AdminValue::Bool(false),
+        );
         env.storage().instance().set(&DataKey::Paused, &false);
+        env.storage()
+            .instance()
+            .remove(&DataKey::GuardianPauseExpiry);
         Self::bump_instance_ttl(&env);
         env.events().publish((symbol_short!("Unpaused"),), ());
     }
@@ -947,6 +4319,37 @@ impl RemittanceNFT {
         Self::admin(&env)
     }
 
+    /// Up to `limit` admin audit log entries starting at index `cursor`,
+    /// oldest first. `limit` is capped at `MAX_ADMIN_LOG_LIMIT`.
+    pub fn get_admin_actions(env: Env, cursor: u32, limit: u32) -> Vec<AdminAction> {
+        let len = Self::admin_log_len(&env);
+        let mut entries = Vec::new(&env);
+        if cursor >= len {
+            return entries;
+        }
+        let end = cursor
+            .saturating_add(limit.min(Self::MAX_ADMIN_LOG_LIMIT))
+            .min(len);
+
+        let mut page_index = cursor / Self::ADMIN_LOG_PAGE_SIZE;
+        let mut page = Self::read_admin_log_page(&env, page_index);
+        for idx in cursor..end {
+            if idx / Self::ADMIN_LOG_PAGE_SIZE != page_index {
+                page_index = idx / Self::ADMIN_LOG_PAGE_SIZE;
+                page = Self::read_admin_log_page(&env, page_index);
+            }
+            entries.push_back(
+                page.get(idx % Self::ADMIN_LOG_PAGE_SIZE)
+                    .expect("admin log page truncated"),
+            );
+        }
+        entries
+    }
+
+    pub fn get_admin_action_count(env: Env) -> u32 {
+        Self::admin_log_len(&env)
+    }
+
     pub fn propose_admin(env: Env, new_admin: Address) {
         let current_admin = Self::admin(&env);
         current_admin.require_auth();
@@ -956,6 +4359,13 @@ impl RemittanceNFT {
             .set(&DataKey::ProposedAdmin, &new_admin);
         Self::bump_instance_ttl(&env);
 
+        Self::log_admin_action(
+            &env,
+            &current_admin,
+            "propose_admin",
+            AdminValue::Address(current_admin.clone()),
+            AdminValue::Address(new_admin.clone()),
+        );
         env.events().publish(
             (Symbol::new(&env, "AdminProposed"), current_admin),
             new_admin,
@@ -970,6 +4380,13 @@ impl RemittanceNFT {
             .ok_or(NftError::NoProposedAdmin)?;
         proposed_admin.require_auth();
 
+        Self::log_admin_action(
+            &env,
+            &proposed_admin,
+            "accept_admin",
+            AdminValue::Address(Self::admin(&env)),
+            AdminValue::Address(proposed_admin.clone()),
+        );
         env.storage()
             .instance()
             .set(&Self::admin_key(), &proposed_admin);
@@ -985,6 +4402,13 @@ impl RemittanceNFT {
         let current_admin = Self::admin(&env);
         current_admin.require_auth();
 
+        Self::log_admin_action(
+            &env,
+            &current_admin,
+            "set_admin",
+            AdminValue::Address(current_admin.clone()),
+            AdminValue::Address(new_admin.clone()),
+        );
         env.storage().instance().set(&Self::admin_key(), &new_admin);
         env.storage().instance().remove(&DataKey::ProposedAdmin);
         Self::bump_instance_ttl(&env);
@@ -1,5 +1,29 @@
 use super::*;
-use soroban_sdk::{testutils::Address as _, testutils::Ledger as _, Address, BytesN, Env};
+use soroban_sdk::{
+    testutils::Address as _, testutils::Events as _, testutils::Ledger as _, testutils::MockAuth,
+    testutils::MockAuthInvoke, xdr::ToXdr, Address, Bytes, BytesN, Env, IntoVal, String, Val,
+};
+
+/// Stands in for LoanManager: lets every record move through.
+#[contract]
+struct AcceptingLoanHook;
+
+#[contractimpl]
+impl LoanHookInterface for AcceptingLoanHook {
+    fn on_record_moved(_env: Env, _from: Address, _to: Address, _recovery: bool) {}
+}
+
+fn register_loan_hook(env: &Env, client: &RemittanceNFTClient<'_>) {
+    let hook = env.register(AcceptingLoanHook, ());
+    client.set_loan_hook(&Some(hook));
+}
+
+/// Step past `param`'s seeded limit: schedule an override for `value` and
+/// move the ledger to its eta, so the next setter call goes through.
+fn override_param(env: &Env, client: &RemittanceNFTClient<'_>, param: NftParam, value: i128) {
+    let eta = client.schedule_param_override(&param, &ParamOverride::Value(value));
+    env.ledger().with_mut(|li| li.sequence_number = eta);
+}
 
 fn create_test_hash(env: &Env, value: u8) -> BytesN<32> {
     let mut hash_bytes = [0u8; 32];
@@ -20,7 +44,43 @@ fn test_upgrade_requires_admin_auth() {
     client.initialize(&admin);
 
     env.mock_auths(&[]);
-    client.upgrade(&create_test_hash(&env, 42));
+    client.schedule_upgrade(&create_test_hash(&env, 42), &20_000);
+}
+
+#[test]
+fn test_upgrade_is_scheduled_behind_a_timelock() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _) = setup_scored_user(&env, 600);
+    let wasm_hash = create_test_hash(&env, 42);
+
+    let result = client.try_schedule_upgrade(&wasm_hash, &17_279);
+    assert_eq!(result, Err(Ok(NftError::InvalidUpgradeEta)));
+    assert_eq!(
+        client.try_execute_upgrade(),
+        Err(Ok(NftError::NoUpgradeScheduled))
+    );
+
+    client.schedule_upgrade(&wasm_hash, &17_280);
+    assert_eq!(
+        client.get_scheduled_upgrade(),
+        Some(ScheduledUpgrade {
+            wasm_hash,
+            eta: 17_280,
+        })
+    );
+    assert_eq!(
+        client.try_execute_upgrade(),
+        Err(Ok(NftError::UpgradeTimelockActive))
+    );
+
+    client.cancel_upgrade();
+    assert_eq!(client.get_scheduled_upgrade(), None);
+    assert_eq!(
+        client.try_cancel_upgrade(),
+        Err(Ok(NftError::NoUpgradeScheduled))
+    );
+    assert_eq!(client.version(), 5);
 }
 
 #[test]
@@ -35,7 +95,8 @@ fn test_score_lifecycle() {
     let client = RemittanceNFTClient::new(&env, &contract_id);
 
     client.initialize(&admin);
-    assert_eq!(client.version(), 2);
+    use_repayment_only_weights(&client);
+    assert_eq!(client.version(), 5);
 
     let history_hash = create_test_hash(&env, 1);
 
@@ -240,6 +301,7 @@ fn test_backward_compatibility_migration() {
     let client = RemittanceNFTClient::new(&env, &contract_id);
 
     client.initialize(&admin);
+    use_repayment_only_weights(&client);
 
     // Simulate legacy Score data (old format)
     use super::DataKey;
@@ -288,6 +350,7 @@ fn test_update_score_migrates_legacy_data() {
     let client = RemittanceNFTClient::new(&env, &contract_id);
 
     client.initialize(&admin);
+    use_repayment_only_weights(&client);
 
     // Simulate legacy Score data
     use super::DataKey;
@@ -404,6 +467,7 @@ fn test_decrease_score_applies_floor_at_300() {
     let client = RemittanceNFTClient::new(&env, &contract_id);
 
     client.initialize(&admin);
+    use_repayment_only_weights(&client);
     let history_hash = create_test_hash(&env, 8);
     client.mint(&user, &320, &history_hash, &None);
 
@@ -523,6 +587,7 @@ fn test_score_update_is_isolated_to_owner() {
     let client = RemittanceNFTClient::new(&env, &contract_id);
 
     client.initialize(&admin);
+    use_repayment_only_weights(&client);
 
     client.mint(&alice, &100, &create_test_hash(&env, 1), &None);
     client.mint(&bob, &200, &create_test_hash(&env, 2), &None);
@@ -613,7 +678,7 @@ fn test_get_score_history_for_unknown_user_is_empty() {
 }
 
 #[test]
-fn test_score_history_tracks_and_caps_recent_updates() {
+fn test_score_history_keeps_every_update_across_pages() {
     let env = Env::default();
     env.mock_all_auths();
 
@@ -626,25 +691,115 @@ fn test_score_history_tracks_and_caps_recent_updates() {
     client.initialize(&admin);
     client.mint(&user, &500, &create_test_hash(&env, 7), &None);
 
-    // Add 52 updates — exceeds MAX_SCORE_HISTORY_ENTRIES (50)
+    // 52 updates spill past the first 50-entry page.
     for sequence in 1..=52u32 {
         env.ledger().set_sequence_number(sequence);
         client.update_score(&user, &100, &None);
     }
 
-    // Total history must be capped at MAX_SCORE_HISTORY_ENTRIES
+    assert_eq!(client.get_score_history_len(&user), 52);
     let history = client.get_score_history(&user, &0, &100);
-    assert_eq!(history.len(), RemittanceNFT::MAX_SCORE_HISTORY_ENTRIES);
+    assert_eq!(history.len(), 52);
+    assert_eq!(history.get(0).unwrap().ledger, 1);
+    assert_eq!(history.get(51).unwrap().ledger, 52);
 
-    // Oldest entry should be the 3rd update (sequences 1 and 2 were rotated out)
-    let first = history.get(0).unwrap();
-    assert_eq!(first.ledger, 3);
+    // A cursor window straddling the page boundary.
+    let window = client.get_score_history(&user, &48, &3);
+    assert_eq!(window.len(), 3);
+    assert_eq!(window.get(0).unwrap().ledger, 49);
+    assert_eq!(window.get(2).unwrap().ledger, 51);
 
-    // Newest entry should be the 52nd update
-    let last = history
-        .get(RemittanceNFT::MAX_SCORE_HISTORY_ENTRIES - 1)
-        .unwrap();
-    assert_eq!(last.ledger, 52);
+    assert_eq!(client.get_score_history(&user, &52, &10).len(), 0);
+}
+
+#[test]
+fn test_score_history_stays_with_the_token_on_transfer_and_burn() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, user) = setup_scored_user(&env, 300);
+    register_loan_hook(&env, &client);
+    for sequence in 1..=120u32 {
+        env.ledger().set_sequence_number(sequence);
+        client.update_score(&user, &100, &None);
+    }
+    let token_id = client.token_id_of(&user).unwrap();
+
+    let new_wallet = Address::generate(&env);
+    client.transfer(&user, &new_wallet, &None);
+    assert_eq!(client.get_score_history_len(&new_wallet), 120);
+    assert_eq!(client.get_score_history_len(&user), 0);
+    assert_eq!(
+        client
+            .get_score_history(&new_wallet, &119, &1)
+            .get(0)
+            .unwrap()
+            .ledger,
+        120
+    );
+
+    client.burn(&new_wallet, &None);
+    assert_eq!(client.get_score_history_len(&new_wallet), 0);
+    env.as_contract(&client.address, || {
+        assert!(!env
+            .storage()
+            .persistent()
+            .has(&DataKey::ScoreHistoryLen(token_id)));
+    });
+}
+
+#[test]
+fn test_score_history_limit_is_capped() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, user) = setup_scored_user(&env, 300);
+
+    for sequence in 1..=105u32 {
+        env.ledger().set_sequence_number(sequence);
+        client.update_score(&user, &100, &None);
+    }
+
+    let first = client.get_score_history(&user, &0, &u32::MAX);
+    assert_eq!(first.len(), RemittanceNFT::MAX_SCORE_HISTORY_LIMIT);
+    let rest = client.get_score_history(&user, &first.len(), &u32::MAX);
+    assert_eq!(rest.len(), 5);
+    assert_eq!(rest.get(4).unwrap().ledger, 105);
+}
+
+#[test]
+fn test_migrate_paginates_single_vec_score_history() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, user) = setup_scored_user(&env, 500);
+
+    let mut history = Vec::new(&env);
+    for ledger in 1..=60u32 {
+        history.push_back(ScoreHistoryEntry {
+            ledger,
+            old_score: 500,
+            new_score: 501,
+            reason: symbol_short!("REPAY"),
+            policy_version: 1,
+        });
+    }
+    env.as_contract(&client.address, || {
+        env.storage()
+            .persistent()
+            .set(&DataKey::ScoreHistoryV2(user.clone()), &history);
+    });
+
+    client.migrate_holders(&Vec::from_array(&env, [user.clone()]));
+
+    env.as_contract(&client.address, || {
+        let storage = env.storage().persistent();
+        assert!(!storage.has(&DataKey::ScoreHistoryV2(user.clone())));
+        assert!(storage.has(&DataKey::ScoreHistoryPage(1, 1)));
+    });
+    assert_eq!(client.get_score_history_len(&user), 60);
+
+    client.update_score(&user, &100, &None);
+    let tail = client.get_score_history(&user, &58, &10);
+    assert_eq!(tail.len(), 3);
+    assert_eq!(tail.get(1).unwrap().ledger, 60);
 }
 
 #[test]
@@ -741,6 +896,25 @@ fn test_record_default_auto_burns_after_threshold() {
     assert!(!client.is_seized(&user));
 }
 
+#[test]
+fn test_records_stay_put_until_a_loan_hook_is_registered() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, user) = setup_scored_user(&env, 600);
+    let new_wallet = Address::generate(&env);
+
+    let result = client.try_transfer(&user, &new_wallet, &None);
+    assert_eq!(result, Err(Ok(NftError::LoanHookNotSet)));
+    client.request_recovery(&user, &new_wallet);
+    let result = client.try_approve_recovery(&user, &new_wallet);
+    assert_eq!(result, Err(Ok(NftError::LoanHookNotSet)));
+    assert_eq!(client.get_score(&user), 600);
+
+    register_loan_hook(&env, &client);
+    client.approve_recovery(&user, &new_wallet);
+    assert_eq!(client.get_score(&new_wallet), 600);
+}
+
 #[test]
 fn test_transfer_moves_identity_state_to_new_wallet() {
     let env = Env::default();
@@ -754,6 +928,8 @@ fn test_transfer_moves_identity_state_to_new_wallet() {
     let client = RemittanceNFTClient::new(&env, &contract_id);
 
     client.initialize(&admin);
+    register_loan_hook(&env, &client);
+    use_repayment_only_weights(&client);
     client.mint(&old_wallet, &500, &create_test_hash(&env, 21), &None);
     client.update_score(&old_wallet, &300, &None);
     client.record_default(&old_wallet, &None);
@@ -872,6 +1048,7 @@ fn test_score_overflow_handling() {
     let client = RemittanceNFTClient::new(&env, &contract_id);
 
     client.initialize(&admin);
+    use_repayment_only_weights(&client);
 
     let history_hash = create_test_hash(&env, 1);
     client.mint(&user, &800, &history_hash, &None);
@@ -916,6 +1093,7 @@ fn test_get_transfer_cooldown_remaining_active() {
     let client = RemittanceNFTClient::new(&env, &contract_id);
 
     client.initialize(&admin);
+    register_loan_hook(&env, &client);
     env.ledger().set_sequence_number(100);
     client.mint(&from, &500, &create_test_hash(&env, 30), &None);
     client.transfer(&from, &to, &None);
@@ -941,6 +1119,7 @@ fn test_get_transfer_cooldown_remaining_expired() {
     let client = RemittanceNFTClient::new(&env, &contract_id);
 
     client.initialize(&admin);
+    register_loan_hook(&env, &client);
     env.ledger().set_sequence_number(100);
     client.mint(&from, &500, &create_test_hash(&env, 31), &None);
     client.transfer(&from, &to, &None);
@@ -1093,6 +1272,12 @@ fn test_set_default_burn_threshold_upper_bound_valid() {
     client.initialize(&admin);
 
     // Should succeed for valid threshold
+    override_param(
+        &env,
+        &client,
+        NftParam::DefaultBurnThreshold,
+        RemittanceNFT::MAX_ALLOWED_BURN_THRESHOLD as i128,
+    );
     client.set_default_burn_threshold(&RemittanceNFT::MAX_ALLOWED_BURN_THRESHOLD);
 }
 
@@ -1306,3 +1491,1328 @@ fn test_admin_remint_clears_seized_flag() {
 
     assert!(!client.is_seized(&user));
 }
+
+// ── Scoring policy ──────────────────────────────────────────────────────────
+
+fn setup_scored_user(env: &Env, initial_score: u32) -> (RemittanceNFTClient<'_>, Address) {
+    let admin = Address::generate(env);
+    let user = Address::generate(env);
+    let contract_id = env.register(RemittanceNFT, ());
+    let client = RemittanceNFTClient::new(env, &contract_id);
+    client.initialize(&admin);
+    client.mint(&user, &initial_score, &create_test_hash(env, 1), &None);
+    (client, user)
+}
+
+/// Pins the composite to the repayment component so the repayment rules can
+/// be checked point for point.
+fn use_repayment_only_weights(client: &RemittanceNFTClient<'_>) {
+    client.set_score_weights(&ScoreWeights {
+        repayment_bps: 10_000,
+        remittance_bps: 0,
+        tenure_bps: 0,
+        utilization_bps: 0,
+    });
+}
+
+#[test]
+fn test_default_scoring_policy_matches_legacy_rules() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, user) = setup_scored_user(&env, 500);
+    use_repayment_only_weights(&client);
+
+    let policy = client.get_scoring_policy();
+    assert_eq!(policy.version, 1);
+    assert_eq!(policy.units_per_point, 100);
+    assert_eq!(policy.late_penalty_base, 10);
+    assert_eq!(policy.default_penalty, 50);
+
+    client.record_repayment(&user, &1_000, &true, &0, &None);
+    assert_eq!(client.get_score(&user), 510);
+    client.record_repayment(&user, &1_000, &true, &1, &None);
+    assert_eq!(client.get_score(&user), 500);
+    client.apply_default_penalty(&user, &None);
+    assert_eq!(client.get_score(&user), 450);
+
+    let history = client.get_score_history(&user, &0, &10);
+    assert_eq!(history.len(), 3);
+    assert_eq!(history.get(1).unwrap().reason, symbol_short!("LATE"));
+    assert_eq!(history.get(2).unwrap().reason, symbol_short!("DEFAULT"));
+    assert_eq!(history.get(2).unwrap().policy_version, 1);
+}
+
+#[test]
+fn test_updated_policy_is_versioned_and_recorded_in_history() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, user) = setup_scored_user(&env, 500);
+    use_repayment_only_weights(&client);
+
+    client.update_score(&user, &500, &None);
+
+    override_param(&env, &client, NftParam::FullRatePoints, 20);
+    let version = client.set_scoring_policy(&ScoringPolicy {
+        version: 99,
+        units_per_point: 10,
+        full_rate_points: 20,
+        diminishing_rate_bps: 5_000,
+        on_time_bonus: 5,
+        late_penalty_base: 5,
+        late_penalty_per_day: 3,
+        default_penalty: 80,
+        max_gain_per_period: 0,
+        period_ledgers: 0,
+        min_score: 300,
+    });
+    assert_eq!(version, 2);
+    assert_eq!(client.get_scoring_policy().version, 2);
+
+    // 60 raw points: 20 at full rate, 40 at half rate, plus the on-time bonus.
+    client.record_repayment(&user, &600, &true, &0, &None);
+    assert_eq!(client.get_score(&user), 505 + 20 + 20 + 5);
+
+    // Two days and a bit late: 5 + 3 * 3.
+    client.record_repayment(&user, &600, &true, &(2 * 17_280 + 1), &None);
+    assert_eq!(client.get_score(&user), 550 - 14);
+
+    let history = client.get_score_history(&user, &0, &10);
+    assert_eq!(history.get(0).unwrap().policy_version, 1);
+    assert_eq!(history.get(1).unwrap().policy_version, 2);
+    assert_eq!(history.get(1).unwrap().reason, symbol_short!("ONTIME"));
+    assert_eq!(history.get(2).unwrap().policy_version, 2);
+}
+
+#[test]
+fn test_scoring_policy_caps_gains_per_period() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, user) = setup_scored_user(&env, 500);
+    use_repayment_only_weights(&client);
+
+    let mut policy = client.get_scoring_policy();
+    policy.max_gain_per_period = 15;
+    policy.period_ledgers = 1_000;
+    client.set_scoring_policy(&policy);
+
+    env.ledger().set_sequence_number(10);
+    client.update_score(&user, &1_000, &None);
+    client.update_score(&user, &1_000, &None);
+    assert_eq!(client.get_score(&user), 515);
+
+    // Penalties are never capped.
+    client.decrease_score(&user, &5, &None);
+    assert_eq!(client.get_score(&user), 510);
+
+    env.ledger().set_sequence_number(1_010);
+    client.update_score(&user, &1_000, &None);
+    assert_eq!(client.get_score(&user), 520);
+}
+
+#[test]
+fn test_set_scoring_policy_rejects_invalid_values() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _) = setup_scored_user(&env, 500);
+
+    let base = client.get_scoring_policy();
+
+    let mut policy = base.clone();
+    policy.units_per_point = 0;
+    assert_eq!(
+        client.try_set_scoring_policy(&policy),
+        Err(Ok(NftError::InvalidScoringPolicy))
+    );
+
+    let mut policy = base.clone();
+    policy.diminishing_rate_bps = 10_001;
+    assert_eq!(
+        client.try_set_scoring_policy(&policy),
+        Err(Ok(NftError::InvalidScoringPolicy))
+    );
+
+    let mut policy = base;
+    policy.max_gain_per_period = 10;
+    assert_eq!(
+        client.try_set_scoring_policy(&policy),
+        Err(Ok(NftError::InvalidScoringPolicy))
+    );
+    assert_eq!(client.get_scoring_policy().version, 1);
+}
+
+#[test]
+fn test_legacy_score_history_is_migrated_with_policy_version_zero() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, user) = setup_scored_user(&env, 500);
+
+    let mut legacy = Vec::new(&env);
+    legacy.push_back(LegacyScoreHistoryEntry {
+        ledger: 1,
+        old_score: 490,
+        new_score: 500,
+        reason: symbol_short!("REPAY"),
+    });
+    env.as_contract(&client.address, || {
+        env.storage()
+            .persistent()
+            .set(&DataKey::ScoreHistory(user.clone()), &legacy);
+    });
+
+    client.update_score(&user, &100, &None);
+
+    let history = client.get_score_history(&user, &0, &10);
+    assert_eq!(history.len(), 2);
+    assert_eq!(history.get(0).unwrap().policy_version, 0);
+    assert_eq!(history.get(1).unwrap().policy_version, 1);
+    env.as_contract(&client.address, || {
+        assert!(!env
+            .storage()
+            .persistent()
+            .has(&DataKey::ScoreHistory(user.clone())));
+    });
+}
+
+// ── Inactivity decay ────────────────────────────────────────────────────────
+
+fn decay_config() -> DecayConfig {
+    DecayConfig {
+        inactivity_ledgers: 1_000,
+        period_ledgers: 100,
+        points_per_period: 10,
+        baseline: 500,
+    }
+}
+
+#[test]
+fn test_score_decays_lazily_after_inactivity() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_sequence_number(100);
+    let (client, user) = setup_scored_user(&env, 700);
+    use_repayment_only_weights(&client);
+    client.set_decay_config(&Some(decay_config()));
+    assert_eq!(client.get_last_activity(&user), Some(100));
+
+    env.ledger().set_sequence_number(1_100);
+    assert_eq!(client.get_score(&user), 700);
+    assert_eq!(client.get_score_history(&user, &0, &10).len(), 0);
+
+    // 250 idle ledgers past the grace window: two full periods.
+    env.ledger().set_sequence_number(1_350);
+    assert_eq!(client.get_score(&user), 680);
+    assert_eq!(client.get_metadata(&user).unwrap().score, 680);
+    let history = client.get_score_history(&user, &0, &10);
+    assert_eq!(history.len(), 1);
+    let entry = history.get(0).unwrap();
+    assert_eq!(entry.reason, symbol_short!("DECAY"));
+    assert_eq!((entry.old_score, entry.new_score), (700, 680));
+
+    // Repayment restarts the clock.
+    client.update_score(&user, &1_000, &None);
+    assert_eq!(client.get_score(&user), 690);
+    env.ledger().set_sequence_number(2_349);
+    assert_eq!(client.get_score(&user), 690);
+    env.ledger().set_sequence_number(2_450);
+    assert_eq!(client.get_score(&user), 680);
+}
+
+#[test]
+fn test_decay_without_activity_record_counts_from_last_history_entry() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_sequence_number(100);
+    let (client, user) = setup_scored_user(&env, 700);
+    use_repayment_only_weights(&client);
+    env.ledger().set_sequence_number(200);
+    client.update_score(&user, &1_000, &None);
+    // As left by a deployment from before decay existed.
+    env.as_contract(&client.address, || {
+        env.storage()
+            .persistent()
+            .remove(&DataKey::Activity(user.clone()));
+    });
+    client.set_decay_config(&Some(decay_config()));
+
+    env.ledger().set_sequence_number(1_250);
+    assert_eq!(client.get_score(&user), 710);
+    // Reading did not start a clock of its own.
+    assert_eq!(client.get_last_activity(&user), None);
+
+    env.ledger().set_sequence_number(1_400);
+    assert_eq!(client.get_score(&user), 690);
+    assert_eq!(client.get_last_activity(&user), Some(200));
+}
+
+#[test]
+fn test_decay_moves_toward_baseline_without_crossing_it() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, high) = setup_scored_user(&env, 530);
+    let low = Address::generate(&env);
+    client.mint(&low, &480, &create_test_hash(&env, 2), &None);
+    client.set_decay_config(&Some(decay_config()));
+
+    env.ledger().set_sequence_number(5_000);
+    assert_eq!(client.get_score(&high), 500);
+    assert_eq!(client.get_score(&low), 500);
+}
+
+#[test]
+fn test_decay_is_off_by_default_and_validated() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, user) = setup_scored_user(&env, 700);
+    assert_eq!(client.get_decay_config(), None);
+
+    env.ledger().set_sequence_number(100_000);
+    assert_eq!(client.get_score(&user), 700);
+
+    let mut config = decay_config();
+    config.period_ledgers = 0;
+    assert_eq!(
+        client.try_set_decay_config(&Some(config)),
+        Err(Ok(NftError::InvalidDecayConfig))
+    );
+
+    client.set_decay_config(&Some(decay_config()));
+    client.set_decay_config(&None);
+    assert_eq!(client.get_decay_config(), None);
+}
+
+// ── Remittance attestations ─────────────────────────────────────────────────
+
+fn attestation_key(env: &Env, seed: u8) -> (ed25519_dalek::SigningKey, BytesN<32>) {
+    let signing_key = ed25519_dalek::SigningKey::from_bytes(&[seed; 32]);
+    let public_key = BytesN::from_array(env, &signing_key.verifying_key().to_bytes());
+    (signing_key, public_key)
+}
+
+fn sign_attestation(
+    env: &Env,
+    contract_id: &Address,
+    signing_key: &ed25519_dalek::SigningKey,
+    attestation: &RemittanceAttestation,
+) -> BytesN<64> {
+    use ed25519_dalek::Signer;
+    use soroban_sdk::xdr::ToXdr;
+
+    let payload = (contract_id.clone(), attestation.clone()).to_xdr(env);
+    let message: std::vec::Vec<u8> = payload.iter().collect();
+    BytesN::from_array(env, &signing_key.sign(&message).to_bytes())
+}
+
+fn attestation(env: &Env, user: &Address, period: u32, amount: i128) -> RemittanceAttestation {
+    RemittanceAttestation {
+        user: user.clone(),
+        period,
+        amount,
+        corridor: Symbol::new(env, "US_MX"),
+        count: 4,
+    }
+}
+
+fn submit(
+    client: &RemittanceNFTClient,
+    provider: &BytesN<32>,
+    attestation: &RemittanceAttestation,
+    signature: &BytesN<64>,
+) -> Result<(), NftError> {
+    match client.try_submit_remittance_attestation(
+        provider,
+        &attestation.user,
+        &attestation.period,
+        &attestation.amount,
+        &attestation.corridor,
+        &attestation.count,
+        signature,
+    ) {
+        Ok(_) => Ok(()),
+        Err(Ok(err)) => Err(err),
+        Err(Err(err)) => panic!("unexpected host error: {:?}", err),
+    }
+}
+
+#[test]
+fn test_attestations_score_volume_and_regularity_and_extend_chain() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, user) = setup_scored_user(&env, 500);
+    let (signing_key, provider) = attestation_key(&env, 3);
+    client.register_attestation_provider(&provider);
+    assert!(client.is_attestation_provider(&provider));
+
+    assert_eq!(client.get_attestation_chain_head(&user), None);
+    let initial_hash = client.get_metadata(&user).unwrap().history_hash;
+
+    // 25_000 units would be 25 points; capped at 10 per attestation.
+    let first = attestation(&env, &user, 7, 25_000);
+    let signature = sign_attestation(&env, &client.address, &signing_key, &first);
+    submit(&client, &provider, &first, &signature).unwrap();
+    // 500*0.8 + 510*0.2 under the default weights.
+    assert_eq!(client.get_score(&user), 502);
+    assert_eq!(client.get_score_breakdown(&user).unwrap().remittance, 510);
+    assert!(client.is_attestation_submitted(&provider, &user, &7));
+
+    let payload = (client.address.clone(), first).to_xdr(&env);
+    let mut chained = Bytes::from_array(&env, &[0u8; 32]);
+    chained.append(&payload);
+    let expected_head: BytesN<32> = env.crypto().sha256(&chained).into();
+    assert_eq!(
+        client.get_attestation_chain_head(&user),
+        Some(expected_head)
+    );
+    // The Merkle history root is left to the indexer.
+    assert_eq!(
+        client.get_metadata(&user).unwrap().history_hash,
+        initial_hash
+    );
+
+    // Consecutive period earns the regularity bonus.
+    let second = attestation(&env, &user, 8, 3_000);
+    let signature = sign_attestation(&env, &client.address, &signing_key, &second);
+    submit(&client, &provider, &second, &signature).unwrap();
+    assert_eq!(client.get_score(&user), 503);
+    assert_eq!(client.get_score_breakdown(&user).unwrap().remittance, 515);
+
+    let history = client.get_score_history(&user, &0, &10);
+    assert_eq!(history.get(1).unwrap().reason, symbol_short!("ATTEST"));
+}
+
+#[test]
+fn test_attestations_credit_only_the_remittance_component() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, user) = setup_scored_user(&env, 500);
+    client.set_score_component(&user, &ScoreComponent::Tenure, &700, &None);
+    assert_eq!(client.get_score(&user), 500);
+
+    let (signing_key, provider) = attestation_key(&env, 3);
+    client.register_attestation_provider(&provider);
+    let att = attestation(&env, &user, 1, 8_000);
+    let signature = sign_attestation(&env, &client.address, &signing_key, &att);
+    submit(&client, &provider, &att, &signature).unwrap();
+
+    let breakdown = client.get_score_breakdown(&user).unwrap();
+    assert_eq!(
+        (breakdown.repayment, breakdown.remittance, breakdown.tenure),
+        (500, 508, 700)
+    );
+    // 500*0.8 + 508*0.2
+    assert_eq!(client.get_score(&user), 501);
+
+    // With remittance weighted out, attestations leave the composite alone.
+    client.set_score_weights(&ScoreWeights {
+        repayment_bps: 8_000,
+        remittance_bps: 0,
+        tenure_bps: 2_000,
+        utilization_bps: 0,
+    });
+    assert_eq!(client.get_score(&user), 540);
+    let att = attestation(&env, &user, 2, 8_000);
+    let signature = sign_attestation(&env, &client.address, &signing_key, &att);
+    submit(&client, &provider, &att, &signature).unwrap();
+    assert_eq!(client.get_score(&user), 540);
+    assert_eq!(client.get_score_breakdown(&user).unwrap().remittance, 518);
+}
+
+#[test]
+fn test_attestation_replay_and_unknown_provider_are_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, user) = setup_scored_user(&env, 500);
+    let (signing_key, provider) = attestation_key(&env, 3);
+    let (other_key, other_provider) = attestation_key(&env, 4);
+    client.register_attestation_provider(&provider);
+
+    let att = attestation(&env, &user, 1, 5_000);
+    let signature = sign_attestation(&env, &client.address, &signing_key, &att);
+    submit(&client, &provider, &att, &signature).unwrap();
+    assert_eq!(
+        submit(&client, &provider, &att, &signature),
+        Err(NftError::AttestationAlreadySubmitted)
+    );
+
+    let other_signature = sign_attestation(&env, &client.address, &other_key, &att);
+    assert_eq!(
+        submit(&client, &other_provider, &att, &other_signature),
+        Err(NftError::UnknownAttestationProvider)
+    );
+
+    client.remove_attestation_provider(&provider);
+    let next = attestation(&env, &user, 2, 5_000);
+    let signature = sign_attestation(&env, &client.address, &signing_key, &next);
+    assert_eq!(
+        submit(&client, &provider, &next, &signature),
+        Err(NftError::UnknownAttestationProvider)
+    );
+
+    let empty = attestation(&env, &user, 3, 0);
+    assert_eq!(
+        submit(&client, &provider, &empty, &signature),
+        Err(NftError::InvalidAttestation)
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_attestation_with_tampered_amount_fails_signature_check() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, user) = setup_scored_user(&env, 500);
+    let (signing_key, provider) = attestation_key(&env, 3);
+    client.register_attestation_provider(&provider);
+
+    let signed = attestation(&env, &user, 1, 1_000);
+    let signature = sign_attestation(&env, &client.address, &signing_key, &signed);
+    let tampered = attestation(&env, &user, 1, 100_000);
+    let _ = submit(&client, &provider, &tampered, &signature);
+}
+
+// ── Merkle history ──────────────────────────────────────────────────────────
+
+fn history_record(env: &Env, period: u32) -> RemittanceRecord {
+    RemittanceRecord {
+        period,
+        amount: 1_000 + period as i128,
+        corridor: Symbol::new(env, "US_MX"),
+        count: 2,
+    }
+}
+
+fn hash_node(env: &Env, a: &BytesN<32>, b: &BytesN<32>) -> BytesN<32> {
+    let (lo, hi) = if a.to_array() <= b.to_array() {
+        (a, b)
+    } else {
+        (b, a)
+    };
+    let mut preimage = Bytes::from_array(env, &[1u8]);
+    preimage.append(&Bytes::from_array(env, &lo.to_array()));
+    preimage.append(&Bytes::from_array(env, &hi.to_array()));
+    env.crypto().sha256(&preimage).into()
+}
+
+#[test]
+fn test_verify_history_record_checks_merkle_inclusion() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, user) = setup_scored_user(&env, 500);
+
+    let leaves: std::vec::Vec<BytesN<32>> = (1..=4)
+        .map(|period| client.hash_history_record(&history_record(&env, period)))
+        .collect();
+    let left = hash_node(&env, &leaves[0], &leaves[1]);
+    let right = hash_node(&env, &leaves[2], &leaves[3]);
+    let root = hash_node(&env, &left, &right);
+
+    let proof = Vec::from_array(&env, [leaves[3].clone(), left.clone()]);
+    // Nothing committed yet.
+    assert!(!client.verify_history_record(&user, &history_record(&env, 3), &proof));
+
+    client.update_history_hash(&user, &root, &None);
+    assert!(client.verify_history_record(&user, &history_record(&env, 3), &proof));
+
+    let other_proof = Vec::from_array(&env, [leaves[0].clone(), right]);
+    assert!(client.verify_history_record(&user, &history_record(&env, 2), &other_proof));
+
+    // Wrong record, wrong proof, or wrong user all fail.
+    assert!(!client.verify_history_record(&user, &history_record(&env, 5), &proof));
+    assert!(!client.verify_history_record(&user, &history_record(&env, 2), &proof));
+    let stranger = Address::generate(&env);
+    assert!(!client.verify_history_record(&stranger, &history_record(&env, 3), &proof));
+}
+
+// ── Score components ────────────────────────────────────────────────────────
+
+#[test]
+fn test_score_breakdown_defaults_to_composite_and_tracks_repayments() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, user) = setup_scored_user(&env, 600);
+
+    let breakdown = client.get_score_breakdown(&user).unwrap();
+    assert_eq!(
+        breakdown,
+        ScoreBreakdown {
+            repayment: 600,
+            remittance: 600,
+            tenure: 600,
+            utilization: 600,
+        }
+    );
+    let weights = client.get_score_weights();
+    assert_eq!(
+        (weights.repayment_bps, weights.remittance_bps),
+        (8_000, 2_000)
+    );
+
+    client.update_score(&user, &1_000, &None);
+    let breakdown = client.get_score_breakdown(&user).unwrap();
+    assert_eq!(breakdown.repayment, 610);
+    assert_eq!(breakdown.tenure, 600);
+    // 610*0.8 + 600*0.2
+    assert_eq!(client.get_score(&user), 608);
+
+    let stranger = Address::generate(&env);
+    assert_eq!(client.get_score_breakdown(&stranger), None);
+}
+
+#[test]
+fn test_composite_score_follows_configured_weights() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, user) = setup_scored_user(&env, 600);
+
+    client.set_score_component(&user, &ScoreComponent::Tenure, &800, &None);
+    client.set_score_component(&user, &ScoreComponent::Utilization, &400, &None);
+    // Default weights leave tenure and utilization out of the composite.
+    assert_eq!(client.get_score(&user), 600);
+
+    client.set_score_weights(&ScoreWeights {
+        repayment_bps: 4_000,
+        remittance_bps: 3_000,
+        tenure_bps: 2_000,
+        utilization_bps: 1_000,
+    });
+    // 600*0.4 + 600*0.3 + 800*0.2 + 400*0.1
+    assert_eq!(client.get_score(&user), 620);
+    let len = client.get_score_history_len(&user);
+    let last = client
+        .get_score_history(&user, &(len - 1), &1)
+        .get(0)
+        .unwrap();
+    assert_eq!(last.reason, symbol_short!("WEIGHTS"));
+    assert_eq!((last.old_score, last.new_score), (600, 620));
+
+    client.set_score_component(&user, &ScoreComponent::Tenure, &850, &None);
+    assert_eq!(client.get_score(&user), 630);
+
+    // Values above the ceiling are clamped.
+    client.set_score_component(&user, &ScoreComponent::Utilization, &2_000, &None);
+    assert_eq!(
+        client.get_score_breakdown(&user).unwrap().utilization,
+        RemittanceNFT::MAX_SCORE
+    );
+}
+
+#[test]
+fn test_score_delta_shifts_every_component() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, user) = setup_scored_user(&env, 600);
+    use_repayment_only_weights(&client);
+    client.set_score_component(&user, &ScoreComponent::Remittance, &840, &None);
+
+    client.apply_score_delta(&user, &20, &None);
+    let breakdown = client.get_score_breakdown(&user).unwrap();
+    assert_eq!(breakdown.repayment, 620);
+    assert_eq!(breakdown.remittance, RemittanceNFT::MAX_SCORE);
+    assert_eq!(client.get_score(&user), 620);
+}
+
+#[test]
+fn test_set_score_weights_rejects_bad_totals() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _) = setup_scored_user(&env, 600);
+
+    let result = client.try_set_score_weights(&ScoreWeights {
+        repayment_bps: 5_000,
+        remittance_bps: 5_000,
+        tenure_bps: 1,
+        utilization_bps: 0,
+    });
+    assert_eq!(result, Err(Ok(NftError::InvalidScoreWeights)));
+
+    let result = client.try_set_score_weights(&ScoreWeights {
+        repayment_bps: u32::MAX,
+        remittance_bps: 10_001,
+        tenure_bps: 0,
+        utilization_bps: 0,
+    });
+    assert_eq!(result, Err(Ok(NftError::InvalidScoreWeights)));
+}
+
+#[test]
+fn test_scoring_setters_are_rate_limited_from_initialization() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _) = setup_scored_user(&env, 600);
+    assert!(client.get_param_limit(&NftParam::TenureWeight).is_some());
+
+    // Each field may move by its own allowance within a window.
+    let result = client.try_set_score_weights(&ScoreWeights {
+        repayment_bps: 5_000,
+        remittance_bps: 2_000,
+        tenure_bps: 3_000,
+        utilization_bps: 0,
+    });
+    assert_eq!(result, Err(Ok(NftError::ParamChangeTooLarge)));
+    client.set_score_weights(&ScoreWeights {
+        repayment_bps: 6_000,
+        remittance_bps: 2_000,
+        tenure_bps: 2_000,
+        utilization_bps: 0,
+    });
+
+    let mut policy = client.get_scoring_policy();
+    policy.default_penalty = 400;
+    assert_eq!(
+        client.try_set_scoring_policy(&policy),
+        Err(Ok(NftError::ParamChangeTooLarge))
+    );
+
+    // Enabling decay counts as moving its points per period up from zero.
+    let mut decay = decay_config();
+    decay.points_per_period = 100;
+    assert_eq!(
+        client.try_set_decay_config(&Some(decay)),
+        Err(Ok(NftError::ParamChangeTooLarge))
+    );
+    client.set_decay_config(&Some(decay_config()));
+
+    let mut scoring = client.get_attestation_scoring();
+    scoring.units_per_point = 1;
+    client.set_attestation_scoring(&scoring);
+    scoring.max_points_per_attestation = 500;
+    assert_eq!(
+        client.try_set_attestation_scoring(&scoring),
+        Err(Ok(NftError::ParamChangeTooLarge))
+    );
+}
+
+#[test]
+fn test_set_score_component_requires_authorized_minter() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, user) = setup_scored_user(&env, 600);
+
+    let minter = Address::generate(&env);
+    let result =
+        client.try_set_score_component(&user, &ScoreComponent::Tenure, &700, &Some(minter.clone()));
+    assert_eq!(result, Err(Ok(NftError::UnauthorizedMinter)));
+
+    client.authorize_minter(&minter);
+    client.set_score_component(&user, &ScoreComponent::Tenure, &700, &Some(minter));
+    assert_eq!(client.get_score_breakdown(&user).unwrap().tenure, 700);
+}
+
+// ── Soulbound records and recovery ──────────────────────────────────────────
+
+#[test]
+fn test_soulbound_default_and_override_control_transfer() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, user) = setup_scored_user(&env, 600);
+    register_loan_hook(&env, &client);
+    let buyer = Address::generate(&env);
+    assert!(!client.is_soulbound(&user));
+
+    client.set_soulbound_default(&true);
+    assert!(client.is_soulbound(&user));
+    let result = client.try_transfer(&user, &buyer, &None);
+    assert_eq!(result, Err(Ok(NftError::SoulboundToken)));
+
+    // A holder-level override wins over the contract default.
+    client.set_soulbound(&user, &Some(false));
+    assert!(!client.is_soulbound(&user));
+    client.transfer(&user, &buyer, &None);
+    assert!(!client.is_soulbound(&buyer));
+    assert_eq!(client.get_score(&buyer), 600);
+
+    client.set_soulbound(&buyer, &None);
+    assert!(client.is_soulbound(&buyer));
+    let stranger = Address::generate(&env);
+    let result = client.try_set_soulbound(&stranger, &Some(true));
+    assert_eq!(result, Err(Ok(NftError::NftNotFound)));
+}
+
+#[test]
+fn test_recovery_with_old_holder_consent_moves_soulbound_record() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, lost) = setup_scored_user(&env, 600);
+    register_loan_hook(&env, &client);
+    client.record_default(&lost, &None);
+    client.set_soulbound_default(&true);
+    let new_holder = Address::generate(&env);
+
+    client.request_recovery(&lost, &new_holder);
+    let request = client.get_recovery_request(&lost).unwrap();
+    assert_eq!(request.new_holder, new_holder);
+    assert_eq!(
+        request.executable_after,
+        request.requested_at + client.get_recovery_timelock()
+    );
+
+    client.approve_recovery(&lost, &new_holder);
+    assert!(client.get_metadata(&lost).is_none());
+    assert_eq!(client.get_recovery_request(&lost), None);
+    assert_eq!(client.get_score(&new_holder), 600);
+    assert_eq!(client.get_default_count(&new_holder), 1);
+    assert!(client.is_soulbound(&new_holder));
+}
+
+#[test]
+fn test_admin_recovery_waits_for_timelock() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, lost) = setup_scored_user(&env, 600);
+    override_param(&env, &client, NftParam::RecoveryTimelock, 1_000);
+    client.set_recovery_timelock(&1_000);
+    register_loan_hook(&env, &client);
+    let new_holder = Address::generate(&env);
+
+    client.request_recovery(&lost, &new_holder);
+    let result = client.try_execute_recovery(&lost, &new_holder);
+    assert_eq!(result, Err(Ok(NftError::RecoveryTimelockActive)));
+
+    env.ledger().with_mut(|li| li.sequence_number += 1_000);
+    client.execute_recovery(&lost, &new_holder);
+    assert_eq!(client.get_score(&new_holder), 600);
+    assert_eq!(client.get_score(&lost), 0);
+}
+
+#[test]
+fn test_recovery_requests_can_be_cancelled_and_replaced() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, lost) = setup_scored_user(&env, 600);
+    override_param(&env, &client, NftParam::RecoveryTimelock, 1_000);
+    client.set_recovery_timelock(&1_000);
+    let claimant = Address::generate(&env);
+    let other = Address::generate(&env);
+
+    let result = client.try_approve_recovery(&lost, &claimant);
+    assert_eq!(result, Err(Ok(NftError::RecoveryNotFound)));
+
+    client.request_recovery(&lost, &claimant);
+    env.ledger().with_mut(|li| li.sequence_number += 600);
+    // A newer request replaces the pending one and restarts its timelock.
+    client.request_recovery(&lost, &other);
+    let request = client.get_recovery_request(&lost).unwrap();
+    assert_eq!(request.new_holder, other);
+    env.ledger().with_mut(|li| li.sequence_number += 600);
+    let result = client.try_execute_recovery(&lost, &other);
+    assert_eq!(result, Err(Ok(NftError::RecoveryTimelockActive)));
+
+    // Neither the admin nor the old holder completes a swapped-in request.
+    env.ledger().with_mut(|li| li.sequence_number += 400);
+    let result = client.try_execute_recovery(&lost, &claimant);
+    assert_eq!(result, Err(Ok(NftError::RecoveryHolderMismatch)));
+    let result = client.try_approve_recovery(&lost, &claimant);
+    assert_eq!(result, Err(Ok(NftError::RecoveryHolderMismatch)));
+
+    client.cancel_recovery(&lost);
+    assert_eq!(client.get_recovery_request(&lost), None);
+
+    client.request_recovery(&lost, &other);
+    client.reject_recovery(&lost);
+    let result = client.try_reject_recovery(&lost);
+    assert_eq!(result, Err(Ok(NftError::RecoveryNotFound)));
+
+    // Destination must not already hold a record.
+    let occupied = Address::generate(&env);
+    client.mint(&occupied, &500, &create_test_hash(&env, 2), &None);
+    let result = client.try_request_recovery(&lost, &occupied);
+    assert_eq!(result, Err(Ok(NftError::DestinationOccupied)));
+}
+
+// ── Token ids and collection metadata ───────────────────────────────────────
+
+#[test]
+fn test_mint_assigns_sequential_token_ids_that_follow_the_record() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, first) = setup_scored_user(&env, 600);
+    register_loan_hook(&env, &client);
+    let second = Address::generate(&env);
+    client.mint(&second, &550, &create_test_hash(&env, 2), &None);
+
+    assert_eq!(client.token_id_of(&first), Some(1));
+    assert_eq!(client.token_id_of(&second), Some(2));
+    assert_eq!(client.owner_of(&2), second);
+
+    let new_wallet = Address::generate(&env);
+    client.transfer(&second, &new_wallet, &None);
+    assert_eq!(client.owner_of(&2), new_wallet);
+    assert_eq!(client.token_id_of(&second), None);
+    assert_eq!(client.token_id_of(&new_wallet), Some(2));
+
+    // Burned ids are retired; a remint gets a fresh one.
+    client.burn(&first, &None);
+    assert_eq!(client.try_owner_of(&1), Err(Ok(NftError::NftNotFound)));
+    client.approve_remint(&first);
+    client.admin_remint(&first, &400, &create_test_hash(&env, 3));
+    assert_eq!(client.token_id_of(&first), Some(3));
+}
+
+#[test]
+fn test_token_uri_appends_id_to_base_uri() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _) = setup_scored_user(&env, 600);
+    for seed in 2..=12u8 {
+        let user = Address::generate(&env);
+        client.mint(&user, &500, &create_test_hash(&env, seed), &None);
+    }
+
+    assert_eq!(client.token_uri(&1), String::from_str(&env, "1"));
+    client.set_base_uri(&String::from_str(&env, "ipfs://bafyrecord/"));
+    assert_eq!(
+        client.token_uri(&12),
+        String::from_str(&env, "ipfs://bafyrecord/12")
+    );
+    assert_eq!(client.try_token_uri(&13), Err(Ok(NftError::NftNotFound)));
+
+    let too_long = String::from_bytes(&env, &[b'a'; 201]);
+    let result = client.try_set_base_uri(&too_long);
+    assert_eq!(result, Err(Ok(NftError::InvalidBaseUri)));
+}
+
+#[test]
+fn test_collection_name_and_symbol_are_configurable() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _) = setup_scored_user(&env, 600);
+    assert_eq!(client.name(), String::from_str(&env, "RemitLend Credit"));
+    assert_eq!(client.symbol(), String::from_str(&env, "RLCREDIT"));
+
+    client.set_collection_info(
+        &String::from_str(&env, "Remittance Record"),
+        &String::from_str(&env, "REMIT"),
+    );
+    assert_eq!(client.name(), String::from_str(&env, "Remittance Record"));
+    assert_eq!(client.symbol(), String::from_str(&env, "REMIT"));
+}
+
+#[test]
+fn test_migrate_assigns_token_ids_to_existing_holders() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, user) = setup_scored_user(&env, 600);
+    let legacy = Address::generate(&env);
+    env.as_contract(&client.address, || {
+        env.storage().persistent().set(
+            &DataKey::Metadata(legacy.clone()),
+            &RemittanceMetadata {
+                score: 520,
+                history_hash: create_test_hash(&env, 9),
+            },
+        );
+    });
+    assert_eq!(client.token_id_of(&legacy), None);
+
+    client.migrate_holders(&Vec::from_array(&env, [user.clone(), legacy.clone()]));
+    assert_eq!(client.token_id_of(&user), Some(1));
+    assert_eq!(client.token_id_of(&legacy), Some(2));
+    assert_eq!(client.owner_of(&2), legacy);
+}
+
+// ── Selective disclosure ────────────────────────────────────────────────────
+
+#[test]
+fn test_private_record_is_hidden_from_public_reads() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, user) = setup_scored_user(&env, 600);
+    use_repayment_only_weights(&client);
+    client.update_score(&user, &1_000, &None);
+    let consumer = Address::generate(&env);
+
+    client.set_private(&user, &true);
+    assert!(client.is_private(&user));
+    assert_eq!(client.get_score(&user), 0);
+    assert!(client.get_metadata(&user).is_none());
+    assert_eq!(client.get_score_breakdown(&user), None);
+    assert_eq!(client.get_score_history(&user, &0, &10).len(), 0);
+    assert_eq!(client.get_score_history_len(&user), 0);
+    assert_eq!(client.get_last_activity(&user), None);
+
+    let result = client.try_get_score_for(&user, &consumer);
+    assert_eq!(result, Err(Ok(NftError::AccessDenied)));
+    // Holders can always read their own record.
+    assert_eq!(client.get_score_for(&user, &user), 610);
+
+    // Score changes on a private record publish nothing.
+    let minter = Address::generate(&env);
+    client.authorize_minter(&minter);
+    client.update_score(&user, &1_000, &Some(minter.clone()));
+    assert_eq!(env.events().all().len(), 0);
+    // Authorized minters need a grant like any other consumer.
+    assert_eq!(
+        client.try_get_score_for(&user, &minter),
+        Err(Ok(NftError::AccessDenied))
+    );
+    client.grant_access(&user, &minter, &AccessScope::Score, &100);
+    assert_eq!(client.get_score_for(&user, &minter), 620);
+    // A direct admin write is audited without the private score.
+    client.update_score(&user, &1_000, &None);
+    let count = client.get_admin_action_count();
+    let entry = client.get_admin_actions(&(count - 1), &1).get(0).unwrap();
+    assert_eq!(entry.action, Symbol::new(&env, "update_score"));
+    assert_eq!(entry.new, AdminValue::Address(user.clone()));
+
+    client.set_private(&user, &false);
+    assert_eq!(client.get_score(&user), 630);
+    assert_eq!(client.get_score_for(&user, &consumer), 630);
+    assert_eq!(client.get_score_history_len(&user), 3);
+}
+
+#[test]
+fn test_access_grants_are_scoped_expiring_and_revocable() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, user) = setup_scored_user(&env, 600);
+    use_repayment_only_weights(&client);
+    client.update_score(&user, &1_000, &None);
+    client.set_private(&user, &true);
+    let lender = Address::generate(&env);
+
+    client.grant_access(&user, &lender, &AccessScope::Score, &100);
+    assert!(client.has_access(&user, &lender, &AccessScope::Score));
+    assert!(!client.has_access(&user, &lender, &AccessScope::History));
+    assert_eq!(client.get_score_for(&user, &lender), 610);
+    assert!(matches!(
+        client.try_get_metadata_for(&user, &lender),
+        Err(Ok(NftError::AccessDenied))
+    ));
+
+    client.grant_access(&user, &lender, &AccessScope::History, &100);
+    assert_eq!(client.get_metadata_for(&user, &lender).unwrap().score, 610);
+    assert_eq!(
+        client
+            .get_score_breakdown_for(&user, &lender)
+            .unwrap()
+            .repayment,
+        610
+    );
+    assert_eq!(
+        client.get_score_history_for(&user, &lender, &0, &10).len(),
+        1
+    );
+
+    env.ledger().with_mut(|li| li.sequence_number = 101);
+    let result = client.try_get_score_for(&user, &lender);
+    assert_eq!(result, Err(Ok(NftError::AccessDenied)));
+    let result = client.try_grant_access(&user, &lender, &AccessScope::Score, &100);
+    assert_eq!(result, Err(Ok(NftError::InvalidGrantExpiry)));
+
+    client.grant_access(&user, &lender, &AccessScope::Score, &u32::MAX);
+    client.revoke_access(&user, &lender);
+    assert_eq!(client.get_access_grant(&user, &lender), None);
+    let result = client.try_get_score_for(&user, &lender);
+    assert_eq!(result, Err(Ok(NftError::AccessDenied)));
+}
+
+#[test]
+fn test_migrate_batch_pages_records_and_blocks_writes_until_done() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, user) = setup_scored_user(&env, 500);
+    let second = Address::generate(&env);
+    client.mint(&second, &600, &create_test_hash(&env, 2), &None);
+
+    let mut history = Vec::new(&env);
+    for ledger in 1..=3u32 {
+        history.push_back(ScoreHistoryEntry {
+            ledger,
+            old_score: 500,
+            new_score: 501,
+            reason: symbol_short!("REPAY"),
+            policy_version: 1,
+        });
+    }
+    env.as_contract(&client.address, || {
+        env.storage()
+            .persistent()
+            .set(&DataKey::ScoreHistoryV2(second.clone()), &history);
+        env.storage().instance().set(&DataKey::Version, &3u32);
+        env.storage()
+            .persistent()
+            .remove(&DataKey::ParamLimit(NftParam::MinScore));
+    });
+
+    let third = Address::generate(&env);
+    assert_eq!(
+        client.try_mint(&third, &500, &create_test_hash(&env, 3), &None),
+        Err(Ok(NftError::MigrationPending))
+    );
+
+    let status = client.migrate_batch(&1, &1);
+    assert_eq!(status.cursor, 2);
+    assert!(!status.complete);
+    assert_eq!(
+        client.try_migrate_batch(&3, &1),
+        Err(Ok(NftError::MigrationCursorAhead))
+    );
+
+    // The argument-free entrypoint picks up from the stored cursor.
+    client.migrate();
+    let status = client.get_migration_status();
+    assert!(status.complete);
+    assert_eq!(status.version, RemittanceNFT::CURRENT_VERSION);
+    assert_eq!(client.get_migration_log().len(), 2);
+    assert!(client.get_param_limit(&NftParam::MinScore).is_some());
+    env.as_contract(&client.address, || {
+        assert!(!env
+            .storage()
+            .persistent()
+            .has(&DataKey::ScoreHistoryV2(second.clone())));
+    });
+    assert_eq!(client.get_score_history_len(&second), 3);
+
+    client.mint(&third, &500, &create_test_hash(&env, 3), &None);
+    assert_eq!(client.get_score(&user), 500);
+}
+
+#[test]
+fn test_set_guardian_requires_governance_not_just_the_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let client = RemittanceNFTClient::new(&env, &env.register(RemittanceNFT, ()));
+    client.initialize(&admin);
+
+    let guardian = Address::generate(&env);
+    assert_eq!(
+        client.try_set_guardian(&Some(guardian.clone())),
+        Err(Ok(NftError::GovernanceNotSet))
+    );
+    let governance = Address::generate(&env);
+    client.set_governance(&governance);
+
+    // A call signed by the admin alone no longer appoints a guardian.
+    let as_signer = |signer: &Address, fn_name: &'static str, args: Vec<Val>| {
+        env.mock_auths(&[MockAuth {
+            address: signer,
+            invoke: &MockAuthInvoke {
+                contract: &client.address,
+                fn_name,
+                args,
+                sub_invokes: &[],
+            },
+        }]);
+    };
+    as_signer(
+        &admin,
+        "set_guardian",
+        (Some(guardian.clone()),).into_val(&env),
+    );
+    assert!(client.try_set_guardian(&Some(guardian.clone())).is_err());
+    assert_eq!(client.get_guardian(), None);
+    as_signer(&admin, "set_governance", (admin.clone(),).into_val(&env));
+    assert!(client.try_set_governance(&admin).is_err());
+
+    as_signer(
+        &governance,
+        "set_guardian",
+        (Some(guardian.clone()),).into_val(&env),
+    );
+    client.set_guardian(&Some(guardian.clone()));
+    assert_eq!(client.get_guardian(), Some(guardian));
+}
+
+#[test]
+fn test_ratified_guardian_pause_outlives_its_expiry() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, user) = setup_scored_user(&env, 500);
+    let guardian = Address::generate(&env);
+    client.set_governance(&Address::generate(&env));
+    client.set_guardian(&Some(guardian));
+
+    assert_eq!(
+        client.try_ratify_pause(),
+        Err(Ok(NftError::NoGuardianPause))
+    );
+    let expiry = client.guardian_pause();
+    client.ratify_pause();
+    assert_eq!(client.get_guardian_pause_expiry(), None);
+
+    env.ledger().with_mut(|li| li.sequence_number = expiry + 1);
+    assert!(client.is_paused());
+    assert_eq!(
+        client.try_update_score(&user, &100, &None),
+        Err(Ok(NftError::ContractPaused))
+    );
+
+    client.unpause();
+    assert!(!client.is_paused());
+    client.update_score(&user, &100, &None);
+}
+
+#[test]
+fn test_guardian_cannot_pause_again_until_governance_acts() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _user) = setup_scored_user(&env, 500);
+    client.set_governance(&Address::generate(&env));
+    client.set_guardian(&Some(Address::generate(&env)));
+    let expiry = client.guardian_pause();
+
+    env.ledger().with_mut(|li| li.sequence_number = expiry);
+    assert!(!client.is_paused());
+    assert_eq!(
+        client.try_guardian_pause(),
+        Err(Ok(NftError::GuardianPauseSpent))
+    );
+
+    client.pause();
+    client.unpause();
+    client.guardian_pause();
+    assert!(client.is_paused());
+}
+
+#[test]
+#[should_panic]
+fn test_guardian_cannot_unpause() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _user) = setup_scored_user(&env, 500);
+    client.set_governance(&Address::generate(&env));
+    client.set_guardian(&Some(Address::generate(&env)));
+    client.guardian_pause();
+
+    env.mock_auths(&[]);
+    client.unpause();
+}
+
+#[test]
+fn test_paused_transfers_leave_minting_open() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, user) = setup_scored_user(&env, 500);
+    register_loan_hook(&env, &client);
+
+    client.pause_operation(&NftOperation::Transfers);
+    assert!(client.is_operation_paused(&NftOperation::Transfers));
+    assert!(!client.is_operation_paused(&NftOperation::Minting));
+
+    let new_wallet = Address::generate(&env);
+    assert_eq!(
+        client.try_transfer(&user, &new_wallet, &None),
+        Err(Ok(NftError::OperationPaused))
+    );
+    client.mint(
+        &Address::generate(&env),
+        &500,
+        &create_test_hash(&env, 2),
+        &None,
+    );
+
+    client.pause_operation(&NftOperation::ScoreUpdates);
+    assert_eq!(
+        client.try_update_score(&user, &100, &None),
+        Err(Ok(NftError::OperationPaused))
+    );
+
+    client.unpause_operation(&NftOperation::Transfers);
+    client.transfer(&user, &new_wallet, &None);
+}
+
+#[test]
+fn test_param_override_lets_recovery_timelock_jump_after_delay() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _user) = setup_scored_user(&env, 500);
+    let start = client.get_recovery_timelock();
+
+    client.set_param_limit(
+        &NftParam::RecoveryTimelock,
+        &Some(ParamLimit {
+            max_change: 0,
+            max_change_bps: 1_000,
+            window_ledgers: 17_280,
+        }),
+    );
+    client.set_recovery_timelock(&(start - start / 10));
+    assert_eq!(
+        client.try_set_recovery_timelock(&17_280),
+        Err(Ok(NftError::ParamChangeTooLarge))
+    );
+
+    let eta =
+        client.schedule_param_override(&NftParam::RecoveryTimelock, &ParamOverride::Value(17_280));
+    // An override only covers the exact value it announced.
+    env.ledger().with_mut(|li| li.sequence_number = eta);
+    assert_eq!(
+        client.try_set_recovery_timelock(&20_000),
+        Err(Ok(NftError::ParamChangeTooLarge))
+    );
+    client.set_recovery_timelock(&17_280);
+    assert_eq!(client.get_recovery_timelock(), 17_280);
+}
+
+#[test]
+fn test_admin_log_pages_across_storage_pages() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _user) = setup_scored_user(&env, 500);
+
+    // initialize, the admin mint and 60 toggles span two log pages.
+    for i in 0..60u32 {
+        client.set_soulbound_default(&i.is_multiple_of(2));
+    }
+    assert_eq!(client.get_admin_action_count(), 62);
+
+    let page = client.get_admin_actions(&45, &10);
+    assert_eq!(page.len(), 10);
+    for (offset, entry) in page.iter().enumerate() {
+        let toggle = 43 + offset as u32;
+        assert_eq!(entry.action, Symbol::new(&env, "set_soulbound_default"));
+        assert_eq!(entry.old, AdminValue::Bool(!toggle.is_multiple_of(2)));
+        assert_eq!(entry.new, AdminValue::Bool(toggle.is_multiple_of(2)));
+    }
+
+    let capped = client.get_admin_actions(&0, &500);
+    assert_eq!(capped.len(), 62);
+    assert_eq!(client.get_admin_actions(&62, &10).len(), 0);
+}
+
+#[test]
+fn test_direct_admin_record_writes_are_logged() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, user) = setup_scored_user(&env, 500);
+    use_repayment_only_weights(&client);
+    let minter = Address::generate(&env);
+    client.authorize_minter(&minter);
+
+    // Writes through an authorized minter are routine, not admin actions.
+    let start = client.get_admin_action_count();
+    client.update_score(&user, &1_000, &Some(minter.clone()));
+    client.apply_score_delta(&user, &5, &Some(minter.clone()));
+    assert_eq!(client.get_admin_action_count(), start);
+
+    client.update_score(&user, &1_000, &None);
+    client.decrease_score(&user, &20, &None);
+    client.apply_score_delta(&user, &-5, &None);
+    client.set_score_component(&user, &ScoreComponent::Repayment, &700, &None);
+    let burned = Address::generate(&env);
+    client.mint(&burned, &550, &create_test_hash(&env, 2), &None);
+    client.burn(&burned, &None);
+
+    let log = client.get_admin_actions(&start, &10);
+    let actions: std::vec::Vec<_> = log.iter().map(|entry| entry.action).collect();
+    assert_eq!(
+        actions,
+        [
+            "update_score",
+            "decrease_score",
+            "apply_score_delta",
+            "set_score_component",
+            "mint",
+            "burn",
+        ]
+        .map(|name| Symbol::new(&env, name))
+    );
+    let score_change = log.get(0).unwrap();
+    assert_eq!(score_change.old, AdminValue::UserScore(user.clone(), 515));
+    assert_eq!(score_change.new, AdminValue::UserScore(user.clone(), 525));
+    assert_eq!(
+        log.get(5).unwrap().old,
+        AdminValue::UserScore(burned.clone(), 550)
+    );
+    assert_eq!(log.get(5).unwrap().new, AdminValue::None);
+}
@@ -84,6 +84,11 @@ export function StepFinalSignature({
         if (!cancelled) setXdrError("Missing NEXT_PUBLIC_MANAGER_CONTRACT_ID configuration.");
         return;
       }
+      const loanAssetId = process.env.NEXT_PUBLIC_LOAN_ASSET_CONTRACT_ID;
+      if (!loanAssetId) {
+        if (!cancelled) setXdrError("Missing NEXT_PUBLIC_LOAN_ASSET_CONTRACT_ID configuration.");
+        return;
+      }
 
       if (!cancelled) {
         setIsBuildingXdr(true);
@@ -93,6 +98,7 @@ export function StepFinalSignature({
       try {
         const xdr = await buildUnsignedLoanRequestXdr({
           borrower: borrowerAddress,
+          asset: loanAssetId,
           amount: principal,
           term: data.termDays * 17280,
           contractId: managerContractId,
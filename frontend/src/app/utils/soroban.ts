@@ -14,6 +14,7 @@ const DEFAULT_NETWORK_PASSPHRASE = "Test SDF Network ; September 2015";
 
 interface BuildLoanRequestXdrParams {
   borrower: string;
+  asset: string;
   amount: number;
   term: number;
   contractId: string;
@@ -32,6 +33,7 @@ interface BuildRepaymentXdrParams {
 
 export async function buildUnsignedLoanRequestXdr({
   borrower,
+  asset,
   amount,
   term,
   contractId,
@@ -44,6 +46,7 @@ export async function buildUnsignedLoanRequestXdr({
   const amountScVal = nativeToScVal(BigInt(Math.floor(amount)), { type: "i128" });
   const termScVal = nativeToScVal(BigInt(term), { type: "u32" });
   const borrowerScVal = new Address(borrower).toScVal();
+  const assetScVal = Address.fromString(asset).toScVal();
 
   const tx = new TransactionBuilder(source, {
     fee: "10000",
@@ -55,7 +58,7 @@ export async function buildUnsignedLoanRequestXdr({
           new xdr.InvokeContractArgs({
             contractAddress: Address.fromString(contractId).toScAddress(),
             functionName: "request_loan",
-            args: [borrowerScVal, amountScVal, termScVal],
+            args: [borrowerScVal, assetScVal, amountScVal, termScVal],
           }),
         ),
         auth: [],